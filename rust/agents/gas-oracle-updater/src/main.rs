@@ -0,0 +1,19 @@
+//! The gas oracle updater keeps the gas oracles of IGPs up to date, pushing
+//! updates when the gas prices of remote chains drift from the on-chain data.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+use eyre::Result;
+
+use hyperlane_base::agent_main;
+
+use crate::updater::GasOracleUpdater;
+
+mod settings;
+mod updater;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<()> {
+    agent_main::<GasOracleUpdater>().await
+}
@@ -0,0 +1,190 @@
+//! Gas oracle updater configuration.
+
+use std::{collections::HashSet, time::Duration};
+
+use derive_more::{AsMut, AsRef, Deref, DerefMut};
+use eyre::Context;
+use hyperlane_base::{
+    impl_loadable_from_settings,
+    settings::{
+        parser::{RawAgentConf, ValueParser},
+        Settings,
+    },
+};
+use hyperlane_core::{cfg_unwrap_all, config::*, HyperlaneDomain, U256};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Settings for `GasOracleUpdater`
+#[derive(Debug, AsRef, AsMut, Deref, DerefMut)]
+pub struct GasOracleUpdaterSettings {
+    #[as_ref]
+    #[as_mut]
+    #[deref]
+    #[deref_mut]
+    base: Settings,
+
+    /// The gas oracles to keep up to date
+    pub gas_oracles: Vec<GasOracleConf>,
+    /// How frequently to poll remote gas prices
+    pub interval: Duration,
+    /// The drift from the on-chain gas data, in basis points, past which an
+    /// update is pushed
+    pub drift_threshold_bps: u64,
+    /// The number of slots since the gas data was last updated at which an
+    /// update is pushed even if it didn't drift, for gas oracles that reject
+    /// gas data older than some number of slots, i.e. Sealevel price accounts.
+    /// Should be below their `max_staleness_slots`.
+    pub max_update_age: Option<u64>,
+    /// Only log the updates that would be pushed
+    pub dry_run: bool,
+}
+
+/// The configuration of the gas oracle for a remote domain in an origin chain's IGP.
+#[derive(Debug, Clone)]
+pub struct GasOracleConf {
+    /// The chain of the IGP
+    pub origin: HyperlaneDomain,
+    /// The remote chain whose gas data is held by the gas oracle
+    pub remote: HyperlaneDomain,
+    /// The exchange rate of the remote native token to the origin's native
+    /// token, scaled as expected by the origin's gas oracle
+    pub token_exchange_rate: U256,
+    /// The number of decimals of the remote native token, for gas oracles that
+    /// account for decimals themselves
+    pub token_decimals: Option<u8>,
+    /// The minimum gas price to set, also used when the remote chain's
+    /// provider does not report a gas price
+    pub min_gas_price: Option<U256>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+struct RawGasOracleUpdaterSettings(Value);
+
+impl_loadable_from_settings!(GasOracleUpdater, RawGasOracleUpdaterSettings -> GasOracleUpdaterSettings);
+
+impl FromRawConf<RawGasOracleUpdaterSettings> for GasOracleUpdaterSettings {
+    fn from_config_filtered(
+        raw: RawGasOracleUpdaterSettings,
+        cwp: &ConfigPath,
+        _filter: (),
+    ) -> ConfigResult<Self> {
+        let mut err = ConfigParsingError::default();
+
+        let p = ValueParser::new(cwp.clone(), &raw.0);
+
+        // Expects gasOracles.{origin}.{remote}
+        let raw_gas_oracles: Vec<(String, String, ValueParser)> = p
+            .chain(&mut err)
+            .get_key("gasOracles")
+            .into_obj_iter()
+            .map(|origins| {
+                origins
+                    .flat_map(|(origin, remotes)| {
+                        remotes
+                            .into_obj_iter()
+                            .take_config_err(&mut err)
+                            .into_iter()
+                            .flatten()
+                            .map(move |(remote, conf)| (origin.clone(), remote, conf))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let chain_names: HashSet<&str> = raw_gas_oracles
+            .iter()
+            .flat_map(|(origin, remote, _)| [origin.as_str(), remote.as_str()])
+            .collect();
+
+        let base = p
+            .parse_from_raw_config::<Settings, RawAgentConf, Option<&HashSet<&str>>>(
+                Some(&chain_names),
+                "Parsing base config",
+            )
+            .take_config_err(&mut err);
+
+        let interval = p
+            .chain(&mut err)
+            .get_opt_key("interval")
+            .parse_u64()
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(60));
+
+        let drift_threshold_bps = p
+            .chain(&mut err)
+            .get_opt_key("driftThresholdBps")
+            .parse_u64()
+            .unwrap_or(1000);
+
+        let max_update_age = p
+            .chain(&mut err)
+            .get_opt_key("maxUpdateAge")
+            .parse_u64()
+            .end();
+
+        let dry_run = p
+            .chain(&mut err)
+            .get_opt_key("dryRun")
+            .parse_bool()
+            .unwrap_or(false);
+
+        cfg_unwrap_all!(cwp, err: [base]);
+
+        let gas_oracles = raw_gas_oracles
+            .iter()
+            .filter_map(|(origin, remote, conf)| {
+                let origin = base
+                    .lookup_domain(origin)
+                    .context("Missing configuration for an origin chain in `gasOracles`")
+                    .into_config_result(|| cwp + "gas_oracles")
+                    .take_config_err(&mut err)?;
+                let remote = base
+                    .lookup_domain(remote)
+                    .context("Missing configuration for a remote chain in `gasOracles`")
+                    .into_config_result(|| &conf.cwp)
+                    .take_config_err(&mut err)?;
+
+                let token_exchange_rate = conf
+                    .chain(&mut err)
+                    .get_key("tokenExchangeRate")
+                    .parse_u256()
+                    .end()?;
+                let token_decimals = conf
+                    .chain(&mut err)
+                    .get_opt_key("tokenDecimals")
+                    .parse_u64()
+                    .and_then(|decimals| {
+                        u8::try_from(decimals)
+                            .context("Expected token decimals to fit in a u8")
+                            .into_config_result(|| &conf.cwp + "token_decimals")
+                    })
+                    .end();
+                let min_gas_price = conf
+                    .chain(&mut err)
+                    .get_opt_key("minGasPrice")
+                    .parse_u256()
+                    .end();
+
+                Some(GasOracleConf {
+                    origin,
+                    remote,
+                    token_exchange_rate,
+                    token_decimals,
+                    min_gas_price,
+                })
+            })
+            .collect();
+
+        err.into_result(Self {
+            base,
+            gas_oracles,
+            interval,
+            drift_threshold_bps,
+            max_update_age,
+            dry_run,
+        })
+    }
+}
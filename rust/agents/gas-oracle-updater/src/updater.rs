@@ -0,0 +1,681 @@
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use derive_more::AsRef;
+use eyre::Result;
+use futures_util::future::try_join_all;
+use prometheus::{GaugeVec, IntCounterVec};
+use tokio::time::sleep;
+use tracing::{debug, error, info, info_span, instrument, warn, Instrument};
+
+use hyperlane_base::{
+    metrics::AgentMetrics, BaseAgent, ChainMetrics, CoreMetrics, HyperlaneAgentCore, MetricsUpdater,
+};
+use hyperlane_core::{
+    HyperlaneDomain, HyperlaneProvider, InterchainGasPaymaster, OracleGasData, RemoteGasData,
+    RemoteGasDataConfig, U256,
+};
+
+use crate::settings::{GasOracleConf, GasOracleUpdaterSettings};
+
+/// The number of basis points in 100%.
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// An agent that keeps the gas oracles of IGPs up to date
+#[derive(Debug, AsRef)]
+pub struct GasOracleUpdater {
+    #[as_ref]
+    core: HyperlaneAgentCore,
+    routes: Vec<GasOracleRoute>,
+    interval: Duration,
+    drift_threshold_bps: u64,
+    max_update_age: Option<u64>,
+    dry_run: bool,
+    metrics: GasOracleUpdaterMetrics,
+    core_metrics: Arc<CoreMetrics>,
+    agent_metrics: AgentMetrics,
+    chain_metrics: ChainMetrics,
+}
+
+/// The IGP on an origin chain and the provider of a remote chain whose gas
+/// data the IGP's gas oracle holds.
+#[derive(Debug, Clone)]
+struct GasOracleRoute {
+    conf: GasOracleConf,
+    igp: Arc<dyn InterchainGasPaymaster>,
+    remote_provider: Arc<dyn HyperlaneProvider>,
+}
+
+#[derive(Debug, Clone)]
+struct GasOracleUpdaterMetrics {
+    remote_gas_price: GaugeVec,
+    oracle_gas_price: GaugeVec,
+    updates: IntCounterVec,
+    update_failures: IntCounterVec,
+}
+
+impl GasOracleUpdaterMetrics {
+    fn new(metrics: &CoreMetrics) -> Result<Self> {
+        Ok(Self {
+            remote_gas_price: metrics.new_gauge(
+                "gas_oracle_remote_gas_price",
+                "The gas price of the remote chain that would be set in the origin's gas oracle",
+                &["origin", "remote"],
+            )?,
+            oracle_gas_price: metrics.new_gauge(
+                "gas_oracle_onchain_gas_price",
+                "The remote gas price currently held by the origin's gas oracle",
+                &["origin", "remote"],
+            )?,
+            updates: metrics.new_int_counter(
+                "gas_oracle_updates",
+                "Number of gas oracle updates pushed, or logged in dry-run mode",
+                &["origin", "remote"],
+            )?,
+            update_failures: metrics.new_int_counter(
+                "gas_oracle_update_failures",
+                "Number of gas oracle updates that failed",
+                &["origin", "remote"],
+            )?,
+        })
+    }
+}
+
+#[async_trait]
+impl BaseAgent for GasOracleUpdater {
+    const AGENT_NAME: &'static str = "gas-oracle-updater";
+
+    type Settings = GasOracleUpdaterSettings;
+
+    async fn from_settings(
+        settings: Self::Settings,
+        metrics: Arc<CoreMetrics>,
+        agent_metrics: AgentMetrics,
+        chain_metrics: ChainMetrics,
+        _tokio_console_server: console_subscriber::Server,
+    ) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        let core = settings.build_hyperlane_core(metrics.clone());
+
+        let mut routes = Vec::with_capacity(settings.gas_oracles.len());
+        for conf in settings.gas_oracles.iter() {
+            let igp = settings
+                .build_interchain_gas_paymaster(&conf.origin, &metrics)
+                .await?;
+            let remote_provider = settings.build_provider(&conf.remote, &metrics).await?;
+            routes.push(GasOracleRoute {
+                conf: conf.clone(),
+                igp: igp.into(),
+                remote_provider: remote_provider.into(),
+            });
+        }
+
+        Ok(Self {
+            core,
+            routes,
+            interval: settings.interval,
+            drift_threshold_bps: settings.drift_threshold_bps,
+            max_update_age: settings.max_update_age,
+            dry_run: settings.dry_run,
+            metrics: GasOracleUpdaterMetrics::new(&metrics)?,
+            core_metrics: metrics,
+            agent_metrics,
+            chain_metrics,
+        })
+    }
+
+    #[allow(clippy::async_yields_async)]
+    async fn run(self) {
+        let mut tasks = Vec::with_capacity(self.routes.len() + 1);
+
+        let server = self
+            .core
+            .settings
+            .server(self.core_metrics.clone())
+            .expect("Failed to create server");
+        let server_task = server
+            .run()
+            .instrument(info_span!("GasOracleUpdater server"));
+        tasks.push(server_task);
+
+        let origins: HashSet<&HyperlaneDomain> =
+            self.routes.iter().map(|r| &r.conf.origin).collect();
+        for origin in origins {
+            let chain_conf = self.core.settings.chain_setup(origin).unwrap();
+            let metrics_updater = MetricsUpdater::new(
+                chain_conf,
+                self.core_metrics.clone(),
+                self.agent_metrics.clone(),
+                self.chain_metrics.clone(),
+                Self::AGENT_NAME.to_string(),
+            )
+            .await
+            .unwrap();
+            tasks.push(metrics_updater.spawn());
+        }
+
+        for route in self.routes.iter().cloned() {
+            let span = info_span!(
+                "GasOracleUpdater",
+                origin = route.conf.origin.name(),
+                remote = route.conf.remote.name()
+            );
+            let updater = RouteUpdater {
+                route,
+                interval: self.interval,
+                drift_threshold_bps: self.drift_threshold_bps,
+                max_update_age: self.max_update_age,
+                dry_run: self.dry_run,
+                metrics: self.metrics.clone(),
+            };
+            tasks.push(tokio::spawn(updater.run()).instrument(span));
+        }
+
+        if let Err(err) = try_join_all(tasks).await {
+            error!(
+                ?err,
+                "One of the gas oracle updater tasks returned an error"
+            );
+        }
+    }
+}
+
+/// Keeps the gas oracle of a single route up to date.
+struct RouteUpdater {
+    route: GasOracleRoute,
+    interval: Duration,
+    drift_threshold_bps: u64,
+    max_update_age: Option<u64>,
+    dry_run: bool,
+    metrics: GasOracleUpdaterMetrics,
+}
+
+impl RouteUpdater {
+    async fn run(self) {
+        loop {
+            if let Err(err) = self.tick().await {
+                self.metrics
+                    .update_failures
+                    .with_label_values(&self.labels())
+                    .inc();
+                warn!(?err, "Failed to update gas oracle");
+            }
+            sleep(self.interval).await;
+        }
+    }
+
+    fn labels(&self) -> [&str; 2] {
+        [self.route.conf.origin.name(), self.route.conf.remote.name()]
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    async fn tick(&self) -> Result<()> {
+        let conf = &self.route.conf;
+
+        let Some(gas_price) = self.remote_gas_price().await? else {
+            warn!("Remote chain did not report a gas price and no `minGasPrice` is configured");
+            return Ok(());
+        };
+        self.metrics
+            .remote_gas_price
+            .with_label_values(&self.labels())
+            .set(gas_price.to_f64_lossy());
+
+        let desired = RemoteGasData {
+            token_exchange_rate: conf.token_exchange_rate,
+            gas_price,
+            token_decimals: conf.token_decimals,
+        };
+        let current = self.route.igp.remote_gas_data(conf.remote.id()).await?;
+        if let Some(current) = current {
+            self.metrics
+                .oracle_gas_price
+                .with_label_values(&self.labels())
+                .set(current.remote_gas_data.gas_price.to_f64_lossy());
+        }
+
+        if !needs_update(
+            current.as_ref(),
+            &desired,
+            self.drift_threshold_bps,
+            self.max_update_age,
+        ) {
+            debug!(?current, ?desired, "Gas oracle is up to date");
+            return Ok(());
+        }
+
+        let config = RemoteGasDataConfig {
+            remote_domain: conf.remote.id(),
+            remote_gas_data: desired,
+        };
+        if self.dry_run {
+            info!(?current, ?config, "Dry run, not updating gas oracle");
+        } else {
+            let outcome = self.route.igp.set_remote_gas_data(vec![config]).await?;
+            if !outcome.executed {
+                eyre::bail!("Gas oracle update reverted: {:?}", outcome);
+            }
+            info!(?current, ?config, ?outcome, "Updated gas oracle");
+        }
+        self.metrics.updates.with_label_values(&self.labels()).inc();
+        Ok(())
+    }
+
+    /// The remote chain's gas price, floored at the configured minimum. This
+    /// is `eth_gasPrice` on EVM chains, the median recent prioritization fee
+    /// on Sealevel, and the configured minimum gas price on Cosmos.
+    async fn remote_gas_price(&self) -> Result<Option<U256>> {
+        let reported = self.route.remote_provider.get_gas_price().await?;
+        Ok(match (reported, self.route.conf.min_gas_price) {
+            (Some(reported), Some(min)) => Some(reported.max(min)),
+            (reported, min) => reported.or(min),
+        })
+    }
+}
+
+/// Whether the on-chain gas data should be replaced by `desired`, i.e. if it is
+/// missing, has different decimals, either value drifted past the threshold,
+/// or it was last updated `max_update_age` or more slots ago.
+fn needs_update(
+    current: Option<&OracleGasData>,
+    desired: &RemoteGasData,
+    threshold_bps: u64,
+    max_update_age: Option<u64>,
+) -> bool {
+    let Some(current) = current else {
+        return true;
+    };
+    if let (Some(age), Some(max_update_age)) = (current.slots_since_update(), max_update_age) {
+        if age >= max_update_age {
+            debug!(age, max_update_age, "Gas oracle is due for an update");
+            return true;
+        }
+    }
+    let current = &current.remote_gas_data;
+    current.token_decimals != desired.token_decimals
+        || drifted(current.gas_price, desired.gas_price, threshold_bps)
+        || drifted(
+            current.token_exchange_rate,
+            desired.token_exchange_rate,
+            threshold_bps,
+        )
+}
+
+/// Whether `desired` differs from `current` by more than `threshold_bps` of `current`.
+fn drifted(current: U256, desired: U256, threshold_bps: u64) -> bool {
+    if current.is_zero() {
+        return !desired.is_zero();
+    }
+    let diff = if current > desired {
+        current - desired
+    } else {
+        desired - current
+    };
+    diff.saturating_mul(BPS_DENOMINATOR.into()) > current.saturating_mul(threshold_bps.into())
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use hyperlane_core::{
+        BlockInfo, ChainInfo, ChainResult, FixedPointNumber, HyperlaneChain, HyperlaneContract,
+        HyperlaneDomainProtocol, HyperlaneDomainTechnicalStack, HyperlaneDomainType, TxOutcome,
+        TxnInfo, H256, H512,
+    };
+    use prometheus::Registry;
+
+    use super::*;
+
+    const TEST_REMOTE_DOMAIN: u32 = 1234;
+    const TEST_CURRENT_SLOT: u64 = 1000;
+    const TEST_MAX_UPDATE_AGE: u64 = 100;
+
+    fn gas_data(token_exchange_rate: u64, gas_price: u64) -> RemoteGasData {
+        RemoteGasData {
+            token_exchange_rate: token_exchange_rate.into(),
+            gas_price: gas_price.into(),
+            token_decimals: None,
+        }
+    }
+
+    #[test]
+    fn test_drifted() {
+        assert!(!drifted(100.into(), 110.into(), 1000));
+        assert!(drifted(100.into(), 111.into(), 1000));
+        assert!(drifted(100.into(), 89.into(), 1000));
+        assert!(!drifted(0.into(), 0.into(), 1000));
+        assert!(drifted(0.into(), 1.into(), 1000));
+    }
+
+    /// Gas data last updated `age` slots before `TEST_CURRENT_SLOT`.
+    fn oracle_gas_data(remote_gas_data: RemoteGasData, age: u64) -> OracleGasData {
+        OracleGasData {
+            remote_gas_data,
+            last_updated_slot: Some(TEST_CURRENT_SLOT - age),
+            current_slot: Some(TEST_CURRENT_SLOT),
+        }
+    }
+
+    #[test]
+    fn test_needs_update() {
+        let desired = gas_data(100, 100);
+        let current = |data| Some(OracleGasData::new(data));
+        assert!(needs_update(None, &desired, 1000, None));
+        assert!(!needs_update(
+            current(gas_data(100, 95)).as_ref(),
+            &desired,
+            1000,
+            None
+        ));
+        assert!(needs_update(
+            current(gas_data(100, 50)).as_ref(),
+            &desired,
+            1000,
+            None
+        ));
+        assert!(needs_update(
+            current(gas_data(50, 100)).as_ref(),
+            &desired,
+            1000,
+            None
+        ));
+
+        let mut with_decimals = desired;
+        with_decimals.token_decimals = Some(9);
+        assert!(needs_update(
+            current(desired).as_ref(),
+            &with_decimals,
+            1000,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_needs_update_past_max_update_age() {
+        let desired = gas_data(100, 100);
+        let max_update_age = Some(TEST_MAX_UPDATE_AGE);
+        assert!(!needs_update(
+            Some(&oracle_gas_data(desired, TEST_MAX_UPDATE_AGE - 1)),
+            &desired,
+            1000,
+            max_update_age
+        ));
+        assert!(needs_update(
+            Some(&oracle_gas_data(desired, TEST_MAX_UPDATE_AGE)),
+            &desired,
+            1000,
+            max_update_age
+        ));
+        // Without a maximum age, or for gas oracles that don't record when
+        // they were updated, unchanged gas data is never updated.
+        assert!(!needs_update(
+            Some(&oracle_gas_data(desired, 10 * TEST_MAX_UPDATE_AGE)),
+            &desired,
+            1000,
+            None
+        ));
+        assert!(!needs_update(
+            Some(&OracleGasData::new(desired)),
+            &desired,
+            1000,
+            max_update_age
+        ));
+    }
+
+    #[derive(Debug)]
+    struct MockRemoteProvider {
+        domain: HyperlaneDomain,
+        gas_price: Option<U256>,
+    }
+
+    impl HyperlaneChain for MockRemoteProvider {
+        fn domain(&self) -> &HyperlaneDomain {
+            &self.domain
+        }
+
+        fn provider(&self) -> Box<dyn HyperlaneProvider> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl HyperlaneProvider for MockRemoteProvider {
+        async fn get_block_by_hash(&self, _hash: &H256) -> ChainResult<BlockInfo> {
+            unimplemented!()
+        }
+
+        async fn get_txn_by_hash(&self, _hash: &H256) -> ChainResult<TxnInfo> {
+            unimplemented!()
+        }
+
+        async fn is_contract(&self, _address: &H256) -> ChainResult<bool> {
+            unimplemented!()
+        }
+
+        async fn get_balance(&self, _address: String) -> ChainResult<U256> {
+            unimplemented!()
+        }
+
+        async fn get_nonce(&self, _address: String) -> ChainResult<Option<u64>> {
+            unimplemented!()
+        }
+
+        async fn get_gas_price(&self) -> ChainResult<Option<U256>> {
+            Ok(self.gas_price)
+        }
+
+        async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
+            unimplemented!()
+        }
+    }
+
+    /// An IGP holding the gas data of a single remote domain, recording the
+    /// updates pushed to it.
+    #[derive(Debug)]
+    struct MockIgp {
+        domain: HyperlaneDomain,
+        remote_gas_data: Mutex<Option<OracleGasData>>,
+        updates: Mutex<Vec<RemoteGasDataConfig>>,
+    }
+
+    impl MockIgp {
+        fn gas_data(&self) -> Option<RemoteGasData> {
+            self.remote_gas_data
+                .lock()
+                .unwrap()
+                .map(|data| data.remote_gas_data)
+        }
+    }
+
+    impl HyperlaneChain for MockIgp {
+        fn domain(&self) -> &HyperlaneDomain {
+            &self.domain
+        }
+
+        fn provider(&self) -> Box<dyn HyperlaneProvider> {
+            unimplemented!()
+        }
+    }
+
+    impl HyperlaneContract for MockIgp {
+        fn address(&self) -> H256 {
+            H256::zero()
+        }
+    }
+
+    #[async_trait]
+    impl InterchainGasPaymaster for MockIgp {
+        async fn remote_gas_data(&self, remote_domain: u32) -> ChainResult<Option<OracleGasData>> {
+            assert_eq!(remote_domain, TEST_REMOTE_DOMAIN);
+            Ok(*self.remote_gas_data.lock().unwrap())
+        }
+
+        async fn set_remote_gas_data(
+            &self,
+            configs: Vec<RemoteGasDataConfig>,
+        ) -> ChainResult<TxOutcome> {
+            for config in configs.iter() {
+                *self.remote_gas_data.lock().unwrap() =
+                    Some(oracle_gas_data(config.remote_gas_data, 0));
+            }
+            self.updates.lock().unwrap().extend(configs);
+            Ok(TxOutcome {
+                transaction_id: H512::zero(),
+                executed: true,
+                gas_used: U256::zero(),
+                gas_price: FixedPointNumber::zero(),
+            })
+        }
+    }
+
+    fn route_updater(
+        remote_gas_price: Option<U256>,
+        min_gas_price: Option<U256>,
+        current: Option<OracleGasData>,
+        dry_run: bool,
+    ) -> (RouteUpdater, Arc<MockIgp>) {
+        let origin = HyperlaneDomain::new_test_domain("origin");
+        let remote = HyperlaneDomain::Unknown {
+            domain_id: TEST_REMOTE_DOMAIN,
+            domain_name: "remote".to_owned(),
+            domain_type: HyperlaneDomainType::LocalTestChain,
+            domain_protocol: HyperlaneDomainProtocol::Ethereum,
+            domain_technical_stack: HyperlaneDomainTechnicalStack::Other,
+        };
+        let igp = Arc::new(MockIgp {
+            domain: origin.clone(),
+            remote_gas_data: Mutex::new(current),
+            updates: Mutex::new(vec![]),
+        });
+        let remote_provider = Arc::new(MockRemoteProvider {
+            domain: remote.clone(),
+            gas_price: remote_gas_price,
+        });
+        let core_metrics =
+            CoreMetrics::new("dummy_gas_oracle_updater", 37582, Registry::new()).unwrap();
+
+        let updater = RouteUpdater {
+            route: GasOracleRoute {
+                conf: GasOracleConf {
+                    origin,
+                    remote,
+                    token_exchange_rate: 100.into(),
+                    token_decimals: None,
+                    min_gas_price,
+                },
+                igp: igp.clone(),
+                remote_provider,
+            },
+            interval: Duration::from_secs(1),
+            drift_threshold_bps: 1000,
+            max_update_age: Some(TEST_MAX_UPDATE_AGE),
+            dry_run,
+            metrics: GasOracleUpdaterMetrics::new(&core_metrics).unwrap(),
+        };
+        (updater, igp)
+    }
+
+    fn updates_count(updater: &RouteUpdater) -> u64 {
+        updater
+            .metrics
+            .updates
+            .with_label_values(&updater.labels())
+            .get()
+    }
+
+    #[tokio::test]
+    async fn test_tick_dry_run_does_not_update() {
+        let current = gas_data(100, 100);
+        let (updater, igp) = route_updater(
+            Some(200.into()),
+            None,
+            Some(OracleGasData::new(current)),
+            true,
+        );
+
+        updater.tick().await.unwrap();
+
+        // The update is counted, but not pushed.
+        assert_eq!(updates_count(&updater), 1);
+        assert!(igp.updates.lock().unwrap().is_empty());
+        assert_eq!(igp.gas_data(), Some(current));
+    }
+
+    #[tokio::test]
+    async fn test_tick_updates_drifted_gas_price() {
+        let (updater, igp) = route_updater(
+            Some(200.into()),
+            None,
+            Some(oracle_gas_data(gas_data(100, 100), 0)),
+            false,
+        );
+
+        updater.tick().await.unwrap();
+        assert_eq!(updates_count(&updater), 1);
+        assert_eq!(
+            igp.updates.lock().unwrap().as_slice(),
+            &[RemoteGasDataConfig {
+                remote_domain: TEST_REMOTE_DOMAIN,
+                remote_gas_data: gas_data(100, 200),
+            }]
+        );
+
+        // Up to date after the update.
+        updater.tick().await.unwrap();
+        assert_eq!(updates_count(&updater), 1);
+        assert_eq!(igp.updates.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tick_floors_at_min_gas_price() {
+        let (updater, igp) = route_updater(Some(50.into()), Some(80.into()), None, false);
+        updater.tick().await.unwrap();
+        assert_eq!(igp.gas_data(), Some(gas_data(100, 80)));
+
+        // The minimum is used when the remote chain doesn't report a gas price.
+        let (updater, igp) = route_updater(None, Some(80.into()), None, false);
+        updater.tick().await.unwrap();
+        assert_eq!(igp.gas_data(), Some(gas_data(100, 80)));
+
+        // Nothing to set without either.
+        let (updater, igp) = route_updater(None, None, None, false);
+        updater.tick().await.unwrap();
+        assert_eq!(updates_count(&updater), 0);
+        assert_eq!(igp.gas_data(), None);
+    }
+
+    #[tokio::test]
+    async fn test_tick_updates_unchanged_gas_price_past_max_update_age() {
+        let current = gas_data(100, 100);
+        let (updater, igp) = route_updater(
+            Some(100.into()),
+            None,
+            Some(oracle_gas_data(current, TEST_MAX_UPDATE_AGE - 1)),
+            false,
+        );
+        updater.tick().await.unwrap();
+        assert_eq!(updates_count(&updater), 0);
+
+        // The same gas price is pushed again once it's about to go stale.
+        *igp.remote_gas_data.lock().unwrap() = Some(oracle_gas_data(current, TEST_MAX_UPDATE_AGE));
+        updater.tick().await.unwrap();
+        assert_eq!(updates_count(&updater), 1);
+        assert_eq!(
+            igp.updates.lock().unwrap().as_slice(),
+            &[RemoteGasDataConfig {
+                remote_domain: TEST_REMOTE_DOMAIN,
+                remote_gas_data: current,
+            }]
+        );
+        assert_eq!(
+            igp.remote_gas_data
+                .lock()
+                .unwrap()
+                .and_then(|data| data.slots_since_update()),
+            Some(0)
+        );
+    }
+}
@@ -0,0 +1,469 @@
+//! Hot reloading of the relayer settings that can change without a restart:
+//! `whitelist`, `blacklist`, `gasPaymentEnforcement` and `metricAppContexts`.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{Debug, Display, Formatter},
+    path::PathBuf,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+
+use eyre::{eyre, Result};
+use hyperlane_base::{settings::loader::config_file_paths, LoadableFromSettings};
+use hyperlane_core::{HyperlaneDomain, U256};
+use tokio::{sync::Mutex, task::JoinHandle, time::sleep};
+use tracing::{info, info_span, instrument::Instrumented, warn, Instrument};
+
+use crate::{
+    msg::gas_payment::GasPaymentEnforcer,
+    settings::{matching_list::MatchingList, GasPaymentEnforcementConf, RelayerSettings},
+};
+
+/// A shared value that can be atomically replaced while other tasks read it.
+pub struct Reloadable<T>(Arc<RwLock<Arc<T>>>);
+
+impl<T> Reloadable<T> {
+    pub fn new(value: T) -> Self {
+        Self(Arc::new(RwLock::new(Arc::new(value))))
+    }
+
+    /// The current value
+    pub fn load(&self) -> Arc<T> {
+        self.0.read().expect("Reloadable lock poisoned").clone()
+    }
+
+    /// Replace the value, readers see the new value from their next `load`
+    pub fn store(&self, value: T) {
+        *self.0.write().expect("Reloadable lock poisoned") = Arc::new(value);
+    }
+}
+
+impl<T> Clone for Reloadable<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Default> Default for Reloadable<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug> Debug for Reloadable<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.load().fmt(f)
+    }
+}
+
+impl<T: Display> Display for Reloadable<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.load().fmt(f)
+    }
+}
+
+/// The settings that are applied by a reload.
+#[derive(Debug, Clone)]
+struct ReloadableSettings {
+    whitelist: MatchingList,
+    blacklist: MatchingList,
+    gas_payment_enforcement: Vec<GasPaymentEnforcementConf>,
+    metric_app_contexts: Vec<(MatchingList, String)>,
+}
+
+impl ReloadableSettings {
+    fn from_settings(settings: &RelayerSettings) -> Self {
+        Self {
+            whitelist: settings.whitelist.clone(),
+            blacklist: settings.blacklist.clone(),
+            gas_payment_enforcement: settings.gas_payment_enforcement.clone(),
+            metric_app_contexts: settings.metric_app_contexts.clone(),
+        }
+    }
+
+    /// The settings that differ from `other`, with their old and new values.
+    fn diff(&self, other: &Self) -> Vec<(&'static str, String, String)> {
+        [
+            (
+                "whitelist",
+                self.whitelist.to_string(),
+                other.whitelist.to_string(),
+            ),
+            (
+                "blacklist",
+                self.blacklist.to_string(),
+                other.blacklist.to_string(),
+            ),
+            (
+                "gasPaymentEnforcement",
+                format!("{:?}", self.gas_payment_enforcement),
+                format!("{:?}", other.gas_payment_enforcement),
+            ),
+            (
+                "metricAppContexts",
+                format!("{:?}", self.metric_app_contexts),
+                format!("{:?}", other.metric_app_contexts),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .collect()
+    }
+}
+
+/// The settings that only take effect on restart. A reload compares them with
+/// the ones the relayer was started with to warn about changes it ignores.
+#[derive(Debug, Default, PartialEq)]
+struct RestartSettings {
+    db: PathBuf,
+    origin_chains: HashSet<HyperlaneDomain>,
+    destination_chains: HashSet<HyperlaneDomain>,
+    transaction_gas_limit: Option<U256>,
+    skip_transaction_gas_limit_for: HashSet<u32>,
+    allow_local_checkpoint_syncers: bool,
+    minimum_signer_balances: HashMap<HyperlaneDomain, U256>,
+    config_reload_interval: Option<Duration>,
+    admin_token: Option<String>,
+    metrics_port: u16,
+    /// The debug representation of each chain's conf
+    chains: BTreeMap<String, String>,
+}
+
+impl RestartSettings {
+    fn from_settings(settings: &RelayerSettings) -> Self {
+        Self {
+            db: settings.db.clone(),
+            origin_chains: settings.origin_chains.clone(),
+            destination_chains: settings.destination_chains.clone(),
+            transaction_gas_limit: settings.transaction_gas_limit,
+            skip_transaction_gas_limit_for: settings.skip_transaction_gas_limit_for.clone(),
+            allow_local_checkpoint_syncers: settings.allow_local_checkpoint_syncers,
+            minimum_signer_balances: settings.minimum_signer_balances.clone(),
+            config_reload_interval: settings.config_reload_interval,
+            admin_token: settings.admin_token.clone(),
+            metrics_port: settings.metrics_port,
+            chains: settings
+                .chains
+                .iter()
+                .map(|(name, conf)| (name.clone(), format!("{conf:?}")))
+                .collect(),
+        }
+    }
+
+    /// The names of the settings that differ from `other`.
+    fn diff(&self, other: &Self) -> Vec<String> {
+        let mut changed: Vec<String> = [
+            ("db", self.db != other.db),
+            (
+                "relayChains",
+                self.origin_chains != other.origin_chains
+                    || self.destination_chains != other.destination_chains,
+            ),
+            (
+                "transactionGasLimit",
+                self.transaction_gas_limit != other.transaction_gas_limit,
+            ),
+            (
+                "skipTransactionGasLimitFor",
+                self.skip_transaction_gas_limit_for != other.skip_transaction_gas_limit_for,
+            ),
+            (
+                "allowLocalCheckpointSyncers",
+                self.allow_local_checkpoint_syncers != other.allow_local_checkpoint_syncers,
+            ),
+            (
+                "minimumSignerBalances",
+                self.minimum_signer_balances != other.minimum_signer_balances,
+            ),
+            (
+                "configReloadInterval",
+                self.config_reload_interval != other.config_reload_interval,
+            ),
+            ("adminToken", self.admin_token != other.admin_token),
+            ("metricsPort", self.metrics_port != other.metrics_port),
+        ]
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(setting, _)| setting.to_owned())
+        .collect();
+        let chains: BTreeMap<&String, (Option<&String>, Option<&String>)> = self
+            .chains
+            .keys()
+            .chain(other.chains.keys())
+            .map(|name| (name, (self.chains.get(name), other.chains.get(name))))
+            .collect();
+        changed.extend(
+            chains
+                .into_iter()
+                .filter(|(_, (old, new))| old != new)
+                .map(|(name, _)| format!("chains.{name}")),
+        );
+        changed
+    }
+}
+
+/// Reloads the relayer settings, either when its config files change or on
+/// request, and swaps the new values into the running relayer.
+pub struct ConfigReloader {
+    current: Mutex<ReloadableSettings>,
+    restart_settings: RestartSettings,
+    whitelist: Reloadable<MatchingList>,
+    blacklist: Reloadable<MatchingList>,
+    metric_app_contexts: Reloadable<Vec<(MatchingList, String)>>,
+    gas_payment_enforcers: Vec<Arc<GasPaymentEnforcer>>,
+}
+
+impl Debug for ConfigReloader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ConfigReloader {{ whitelist: {:?}, blacklist: {:?} }}",
+            self.whitelist, self.blacklist
+        )
+    }
+}
+
+impl ConfigReloader {
+    pub fn new(
+        settings: &RelayerSettings,
+        whitelist: Reloadable<MatchingList>,
+        blacklist: Reloadable<MatchingList>,
+        metric_app_contexts: Reloadable<Vec<(MatchingList, String)>>,
+        gas_payment_enforcers: Vec<Arc<GasPaymentEnforcer>>,
+    ) -> Self {
+        Self {
+            current: Mutex::new(ReloadableSettings::from_settings(settings)),
+            restart_settings: RestartSettings::from_settings(settings),
+            whitelist,
+            blacklist,
+            metric_app_contexts,
+            gas_payment_enforcers,
+        }
+    }
+
+    /// A reloader of default settings, which doesn't update any running
+    /// component
+    #[cfg(test)]
+    pub(crate) fn empty() -> Self {
+        Self {
+            current: Mutex::new(ReloadableSettings {
+                whitelist: MatchingList::default(),
+                blacklist: MatchingList::default(),
+                gas_payment_enforcement: vec![GasPaymentEnforcementConf::default()],
+                metric_app_contexts: vec![],
+            }),
+            restart_settings: RestartSettings::default(),
+            whitelist: Reloadable::default(),
+            blacklist: Reloadable::default(),
+            metric_app_contexts: Reloadable::default(),
+            gas_payment_enforcers: vec![],
+        }
+    }
+
+    /// Load and validate the settings, then apply the reloadable ones that
+    /// changed. Returns the names of the settings that were updated.
+    pub async fn reload(&self) -> Result<Vec<&'static str>> {
+        let settings = RelayerSettings::load()?;
+        let updated = self
+            .apply(ReloadableSettings::from_settings(&settings))
+            .await?;
+        let ignored = self
+            .restart_settings
+            .diff(&RestartSettings::from_settings(&settings));
+        if !ignored.is_empty() {
+            warn!(
+                ?ignored,
+                "Reloaded relayer config changes settings that only take effect on restart"
+            );
+        }
+        Ok(updated)
+    }
+
+    async fn apply(&self, new: ReloadableSettings) -> Result<Vec<&'static str>> {
+        if new.gas_payment_enforcement.is_empty() {
+            return Err(eyre!(
+                "At least one gas payment enforcement policy is required"
+            ));
+        }
+
+        let mut current = self.current.lock().await;
+        let diff = current.diff(&new);
+        for (setting, old, new) in &diff {
+            info!(setting, %old, %new, "Reloading relayer setting");
+        }
+        let updated: Vec<&'static str> = diff.into_iter().map(|(setting, _, _)| setting).collect();
+
+        // Only changed settings are stored, as storing a white or blacklist
+        // makes the message processors revisit the messages they skipped.
+        if updated.contains(&"whitelist") {
+            self.whitelist.store(new.whitelist.clone());
+        }
+        if updated.contains(&"blacklist") {
+            self.blacklist.store(new.blacklist.clone());
+        }
+        if updated.contains(&"metricAppContexts") {
+            self.metric_app_contexts
+                .store(new.metric_app_contexts.clone());
+        }
+        if updated.contains(&"gasPaymentEnforcement") {
+            for enforcer in &self.gas_payment_enforcers {
+                enforcer.set_policies(new.gas_payment_enforcement.clone());
+            }
+        }
+        *current = new;
+        Ok(updated)
+    }
+
+    /// Reload the settings whenever one of the config files is modified,
+    /// checking for modifications every `interval`.
+    pub fn spawn_watcher(self: Arc<Self>, interval: Duration) -> Instrumented<JoinHandle<()>> {
+        tokio::spawn(async move {
+            let mut modified = config_files_modified();
+            loop {
+                sleep(interval).await;
+                let latest = config_files_modified();
+                if latest == modified {
+                    continue;
+                }
+                modified = latest;
+                match self.reload().await {
+                    Ok(updated) => info!(?updated, "Reloaded relayer config"),
+                    Err(err) => warn!(
+                        ?err,
+                        "Invalid relayer config after config file change, keeping current config"
+                    ),
+                }
+            }
+        })
+        .instrument(info_span!("ConfigReloader"))
+    }
+}
+
+/// The modification time of each config file
+fn config_files_modified() -> HashMap<PathBuf, Option<SystemTime>> {
+    config_file_paths()
+        .into_iter()
+        .map(|path| {
+            let modified = std::fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use hyperlane_base::db::{test_utils, HyperlaneRocksDB};
+    use hyperlane_core::{HyperlaneDomain, HyperlaneMessage};
+
+    use super::*;
+
+    fn matching_list(json: &str) -> MatchingList {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn settings(whitelist: MatchingList) -> ReloadableSettings {
+        ReloadableSettings {
+            whitelist,
+            blacklist: MatchingList::default(),
+            gas_payment_enforcement: vec![GasPaymentEnforcementConf::default()],
+            metric_app_contexts: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_swaps_changed_settings() {
+        test_utils::run_test_db(|db| async move {
+            let enforcer = Arc::new(GasPaymentEnforcer::new(
+                [GasPaymentEnforcementConf::default()],
+                HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("test_reload"), db),
+            ));
+            let whitelist = Reloadable::default();
+            let reloader = ConfigReloader {
+                current: Mutex::new(settings(MatchingList::default())),
+                restart_settings: RestartSettings::default(),
+                whitelist: whitelist.clone(),
+                blacklist: Reloadable::default(),
+                metric_app_contexts: Reloadable::default(),
+                gas_payment_enforcers: vec![enforcer],
+            };
+
+            let message = HyperlaneMessage {
+                destination: 13371,
+                ..HyperlaneMessage::default()
+            };
+            assert!(whitelist.load().msg_matches(&message, true));
+
+            let updated = reloader
+                .apply(settings(matching_list(r#"[{"destinationdomain": 13372}]"#)))
+                .await
+                .unwrap();
+            assert_eq!(updated, vec!["whitelist"]);
+            assert!(!whitelist.load().msg_matches(&message, true));
+
+            // Applying the same settings again is a no-op, and doesn't store
+            // the whitelist again
+            let stored = whitelist.load();
+            let updated = reloader
+                .apply(settings(matching_list(r#"[{"destinationdomain": 13372}]"#)))
+                .await
+                .unwrap();
+            assert!(updated.is_empty());
+            assert!(Arc::ptr_eq(&stored, &whitelist.load()));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_apply_rejects_missing_gas_policies() {
+        let whitelist = Reloadable::default();
+        let reloader = ConfigReloader {
+            current: Mutex::new(settings(MatchingList::default())),
+            restart_settings: RestartSettings::default(),
+            whitelist: whitelist.clone(),
+            blacklist: Reloadable::default(),
+            metric_app_contexts: Reloadable::default(),
+            gas_payment_enforcers: vec![],
+        };
+
+        let mut new = settings(matching_list(r#"[{"destinationdomain": 13372}]"#));
+        new.gas_payment_enforcement.clear();
+        assert!(reloader.apply(new).await.is_err());
+        assert!(whitelist
+            .load()
+            .msg_matches(&HyperlaneMessage::default(), true));
+    }
+
+    #[test]
+    fn test_restart_settings_diff() {
+        let chain = |name: &str, conf: &str| (name.to_owned(), conf.to_owned());
+        let old = RestartSettings {
+            metrics_port: 9090,
+            chains: BTreeMap::from([chain("test1", "a"), chain("test2", "b")]),
+            ..Default::default()
+        };
+        assert!(old.diff(&old).is_empty());
+
+        let new = RestartSettings {
+            metrics_port: 9091,
+            transaction_gas_limit: Some(100.into()),
+            chains: BTreeMap::from([
+                chain("test1", "a"),
+                chain("test2", "c"),
+                chain("test3", "d"),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                "transactionGasLimit",
+                "metricsPort",
+                "chains.test2",
+                "chains.test3"
+            ]
+        );
+    }
+}
@@ -0,0 +1,335 @@
+//! Summarizes the gas payments the relayer has indexed against the destination
+//! gas it spent relaying the paid for messages, to judge the profitability of
+//! routes and apps.
+//!
+//! Payments are made in the origin's native token while gas is spent in the
+//! destination's, so payments are compared with costs in destination gas: the
+//! gas amount paid for versus the gas actually used. The gas used by a message
+//! that was submitted in a batch is its share of the batch tx, see
+//! `gas_used_by_operation`.
+
+use std::collections::BTreeMap;
+
+use eyre::Result;
+use hyperlane_base::{
+    db::{HyperlaneRocksDB, DB},
+    LoadableFromSettings,
+};
+use hyperlane_core::{
+    GasPaymentKey, HyperlaneMessage, InterchainGasExpenditure, InterchainGasPayment, H256, U256,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::settings::{matching_list::MatchingList, RelayerSettings};
+
+pub use hyperlane_base::settings::loader::GAS_REPORT_COMMAND;
+
+/// The number of worst paying messages listed by default.
+pub const DEFAULT_WORST_MESSAGES: usize = 20;
+
+/// Gas paid for and spent on a set of messages. Only messages the relayer has
+/// spent gas on are summed, so that payments are compared with costs like for
+/// like.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GasSummary {
+    /// All messages, including the ones not relayed yet
+    pub messages: u64,
+    /// Messages the relayer has spent gas on
+    pub relayed_messages: u64,
+    /// Destination gas paid for the relayed messages
+    pub gas_paid_for: U256,
+    /// Destination gas spent relaying the messages
+    pub gas_used: U256,
+    /// `gas_paid_for / gas_used`, below 1 when the relayer spends more gas
+    /// than was paid for
+    pub payment_to_cost_ratio: Option<f64>,
+}
+
+impl GasSummary {
+    fn add(&mut self, payment: &InterchainGasPayment, expenditure: &InterchainGasExpenditure) {
+        self.messages += 1;
+        if expenditure.gas_used.is_zero() {
+            return;
+        }
+        self.relayed_messages += 1;
+        self.gas_paid_for = self.gas_paid_for.saturating_add(payment.gas_amount);
+        self.gas_used = self.gas_used.saturating_add(expenditure.gas_used);
+        self.payment_to_cost_ratio = payment_to_cost_ratio(self.gas_paid_for, self.gas_used);
+    }
+}
+
+/// The gas summary of messages sent from one domain to another. Unlike gas,
+/// token amounts are only summed per route, where they are in a single
+/// denomination.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteGasSummary {
+    pub origin: u32,
+    pub destination: u32,
+    /// Payments for the relayed messages, in the origin's smallest
+    /// denomination
+    pub payment: U256,
+    /// Destination tokens spent relaying the messages, in the destination's
+    /// smallest denomination
+    pub tokens_used: U256,
+    #[serde(flatten)]
+    pub summary: GasSummary,
+}
+
+impl RouteGasSummary {
+    fn new(origin: u32, destination: u32) -> Self {
+        Self {
+            origin,
+            destination,
+            payment: U256::zero(),
+            tokens_used: U256::zero(),
+            summary: GasSummary::default(),
+        }
+    }
+
+    fn add(&mut self, payment: &InterchainGasPayment, expenditure: &InterchainGasExpenditure) {
+        self.summary.add(payment, expenditure);
+        if expenditure.gas_used.is_zero() {
+            return;
+        }
+        self.payment = self.payment.saturating_add(payment.payment);
+        self.tokens_used = self.tokens_used.saturating_add(expenditure.tokens_used);
+    }
+}
+
+/// The gas summary of the messages of an app context, see
+/// `metricAppContexts`. Messages that match no app context are summarized
+/// without one. Apps span routes, so token amounts aren't summed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppContextGasSummary {
+    pub app_context: Option<String>,
+    #[serde(flatten)]
+    pub summary: GasSummary,
+}
+
+/// Gas paid for and spent on a single relayed message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageGasSummary {
+    pub message_id: H256,
+    pub origin: u32,
+    pub destination: u32,
+    pub nonce: u32,
+    pub app_context: Option<String>,
+    pub payment: U256,
+    pub gas_paid_for: U256,
+    pub gas_used: U256,
+    pub tokens_used: U256,
+    pub payment_to_cost_ratio: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GasReport {
+    pub routes: Vec<RouteGasSummary>,
+    pub app_contexts: Vec<AppContextGasSummary>,
+    /// The relayed messages with the lowest payment to cost ratios, worst
+    /// first
+    pub worst_messages: Vec<MessageGasSummary>,
+}
+
+/// Build the gas report of every message dispatched from the origins of
+/// `dbs`, listing the `worst_messages` messages with the lowest payment to
+/// cost ratios.
+pub fn gas_report(
+    dbs: &[HyperlaneRocksDB],
+    app_contexts: &[(MatchingList, String)],
+    worst_messages: usize,
+) -> Result<GasReport> {
+    let mut routes: BTreeMap<(u32, u32), RouteGasSummary> = BTreeMap::new();
+    let mut contexts: BTreeMap<Option<String>, GasSummary> = BTreeMap::new();
+    let mut relayed = vec![];
+
+    for db in dbs {
+        let Some(highest_nonce) = db.retrieve_highest_seen_message_nonce()? else {
+            continue;
+        };
+        for nonce in 0..=highest_nonce {
+            // Messages may not have been indexed yet
+            let Some(message) = db.retrieve_message_by_nonce(nonce)? else {
+                continue;
+            };
+            let message_id = message.id();
+            let payment = db.retrieve_gas_payment_by_gas_payment_key(GasPaymentKey {
+                message_id,
+                destination: message.destination,
+            })?;
+            let expenditure = db.retrieve_gas_expenditure_by_message_id(message_id)?;
+            let app_context = app_context(app_contexts, &message);
+
+            routes
+                .entry((message.origin, message.destination))
+                .or_insert_with(|| RouteGasSummary::new(message.origin, message.destination))
+                .add(&payment, &expenditure);
+            contexts
+                .entry(app_context.clone())
+                .or_default()
+                .add(&payment, &expenditure);
+            if let Some(ratio) = payment_to_cost_ratio(payment.gas_amount, expenditure.gas_used) {
+                relayed.push(MessageGasSummary {
+                    message_id,
+                    origin: message.origin,
+                    destination: message.destination,
+                    nonce: message.nonce,
+                    app_context,
+                    payment: payment.payment,
+                    gas_paid_for: payment.gas_amount,
+                    gas_used: expenditure.gas_used,
+                    tokens_used: expenditure.tokens_used,
+                    payment_to_cost_ratio: ratio,
+                });
+            }
+        }
+    }
+
+    relayed.sort_by(|a, b| a.payment_to_cost_ratio.total_cmp(&b.payment_to_cost_ratio));
+    relayed.truncate(worst_messages);
+    Ok(GasReport {
+        routes: routes.into_values().collect(),
+        app_contexts: contexts
+            .into_iter()
+            .map(|(app_context, summary)| AppContextGasSummary {
+                app_context,
+                summary,
+            })
+            .collect(),
+        worst_messages: relayed,
+    })
+}
+
+/// Print the gas report of the relayer's database as json. The database can
+/// only be opened while the relayer is stopped, use the `/gas_report` endpoint
+/// of a running relayer instead.
+pub fn print_gas_report(worst_messages: usize) -> Result<()> {
+    let settings = RelayerSettings::load()?;
+    let db = DB::from_path(&settings.db)?;
+    let dbs = settings
+        .origin_chains
+        .iter()
+        .map(|origin| HyperlaneRocksDB::new(origin, db.clone()))
+        .collect::<Vec<_>>();
+    let report = gas_report(&dbs, &settings.metric_app_contexts, worst_messages)?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+/// Unlike the relayer's metrics, messages are only classified by the matching
+/// lists, not by whether they use the default ISM.
+fn app_context(
+    app_contexts: &[(MatchingList, String)],
+    message: &HyperlaneMessage,
+) -> Option<String> {
+    app_contexts
+        .iter()
+        .find(|(matching_list, _)| matching_list.msg_matches(message, false))
+        .map(|(_, app_context)| app_context.clone())
+}
+
+fn payment_to_cost_ratio(gas_paid_for: U256, gas_used: U256) -> Option<f64> {
+    (!gas_used.is_zero()).then(|| gas_paid_for.to_f64_lossy() / gas_used.to_f64_lossy())
+}
+
+#[cfg(test)]
+mod test {
+    use hyperlane_base::db::test_utils;
+    use hyperlane_core::{HyperlaneDomain, LogMeta};
+
+    use super::*;
+
+    fn message(nonce: u32, destination: u32, sender: u64) -> HyperlaneMessage {
+        HyperlaneMessage {
+            nonce,
+            origin: 1,
+            destination,
+            sender: H256::from_low_u64_be(sender),
+            ..Default::default()
+        }
+    }
+
+    fn relay(db: &HyperlaneRocksDB, message: &HyperlaneMessage, gas_paid_for: u64, gas_used: u64) {
+        db.store_message(message, 1).unwrap();
+        db.process_gas_payment(
+            InterchainGasPayment {
+                message_id: message.id(),
+                destination: message.destination,
+                payment: (gas_paid_for * 10).into(),
+                gas_amount: gas_paid_for.into(),
+            },
+            &LogMeta {
+                log_index: message.nonce.into(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        if gas_used > 0 {
+            db.process_gas_expenditure(InterchainGasExpenditure {
+                message_id: message.id(),
+                tokens_used: (gas_used * 2).into(),
+                gas_used: gas_used.into(),
+            })
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gas_report() {
+        test_utils::run_test_db(|db| async move {
+            let db =
+                HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("test_gas_report"), db);
+            relay(&db, &message(0, 2, 100), 1000, 500);
+            relay(&db, &message(1, 2, 200), 1000, 2000);
+            relay(&db, &message(2, 3, 100), 1000, 1000);
+            // Paid for but not relayed yet
+            relay(&db, &message(3, 3, 100), 1000, 0);
+
+            let app_contexts = vec![(
+                serde_json::from_str(
+                    r#"[{"senderaddress": "0x0000000000000000000000000000000000000064"}]"#,
+                )
+                .unwrap(),
+                "app".to_owned(),
+            )];
+            let report = gas_report(&[db], &app_contexts, 2).unwrap();
+
+            assert_eq!(report.routes.len(), 2);
+            let route = &report.routes[0];
+            assert_eq!((route.origin, route.destination), (1, 2));
+            assert_eq!(route.summary.messages, 2);
+            assert_eq!(route.summary.relayed_messages, 2);
+            assert_eq!(route.payment, 20000.into());
+            assert_eq!(route.summary.gas_paid_for, 2000.into());
+            assert_eq!(route.summary.gas_used, 2500.into());
+            assert_eq!(route.tokens_used, 5000.into());
+            assert_eq!(route.summary.payment_to_cost_ratio, Some(0.8));
+            let route = &report.routes[1];
+            assert_eq!(
+                (route.summary.messages, route.summary.relayed_messages),
+                (2, 1)
+            );
+            assert_eq!(route.summary.payment_to_cost_ratio, Some(1.0));
+
+            let contexts = report
+                .app_contexts
+                .iter()
+                .map(|c| {
+                    (
+                        c.app_context.as_deref(),
+                        c.summary.messages,
+                        c.summary.relayed_messages,
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(contexts, vec![(None, 1, 1), (Some("app"), 3, 2)]);
+
+            let worst = report
+                .worst_messages
+                .iter()
+                .map(|m| (m.nonce, m.payment_to_cost_ratio))
+                .collect::<Vec<_>>();
+            assert_eq!(worst, vec![(1, 0.5), (2, 1.0)]);
+        })
+        .await;
+    }
+}
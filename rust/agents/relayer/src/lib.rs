@@ -1,3 +1,5 @@
+mod config_reload;
+mod gas_report;
 mod merkle_tree;
 mod msg;
 mod processor;
@@ -6,5 +8,6 @@ mod relayer;
 mod server;
 mod settings;
 
+pub use gas_report::{print_gas_report, DEFAULT_WORST_MESSAGES, GAS_REPORT_COMMAND};
 pub use msg::GAS_EXPENDITURE_LOG_MESSAGE;
 pub use relayer::*;
@@ -7,13 +7,24 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
-use eyre::Result;
+use eyre::{Context, Result};
 
 use hyperlane_base::agent_main;
 
-use relayer::Relayer;
+use relayer::{print_gas_report, Relayer, DEFAULT_WORST_MESSAGES, GAS_REPORT_COMMAND};
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 20)]
 async fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some(GAS_REPORT_COMMAND) {
+        // `relayer gas_report [number of worst paying messages to list]`
+        let worst_messages = match args.next().filter(|arg| !arg.starts_with("--")) {
+            Some(count) => count
+                .parse()
+                .context("Invalid number of worst paying messages")?,
+            None => DEFAULT_WORST_MESSAGES,
+        };
+        return print_gas_report(worst_messages);
+    }
     agent_main::<Relayer>().await
 }
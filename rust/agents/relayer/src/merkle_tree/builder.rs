@@ -1,9 +1,9 @@
 use std::fmt::Display;
 
 use eyre::{Context, Result};
-use tracing::{debug, error, instrument};
+use tracing::{debug, error, info, instrument, warn};
 
-use hyperlane_base::db::DbError;
+use hyperlane_base::db::{DbError, HyperlaneRocksDB, MerkleTreeSnapshot};
 use hyperlane_core::{
     accumulator::{incremental::IncrementalMerkle, merkle::Proof},
     ChainCommunicationError, H256,
@@ -11,11 +11,21 @@ use hyperlane_core::{
 
 use crate::prover::{Prover, ProverError};
 
+/// Number of leaves ingested between snapshots. A new snapshot is taken every
+/// time the tree reaches a multiple of it.
+pub const SNAPSHOT_INTERVAL: u32 = 1024;
+
 /// Struct to sync prover.
 #[derive(Debug)]
 pub struct MerkleTreeBuilder {
     prover: Prover,
     incremental: IncrementalMerkle,
+    /// Number of leaves covered by the latest snapshot
+    snapshot_count: u32,
+    /// Number of leaves that were restored from a snapshot on startup
+    restored_count: u32,
+    /// Root of the tree as it was restored from the snapshot
+    restored_root: H256,
 }
 
 impl Display for MerkleTreeBuilder {
@@ -58,15 +68,153 @@ pub enum MerkleTreeBuilderError {
     /// DB Error
     #[error("{0}")]
     DbError(#[from] DbError),
+    /// A leaf restored from a snapshot can't be proven because an insertion
+    /// is missing from the db
+    #[error("Merkle tree insertion with leaf index {0} is missing from the db")]
+    MissingInsertion(u32),
 }
 
 impl MerkleTreeBuilder {
     pub fn new() -> Self {
         let prover = Prover::default();
         let incremental = IncrementalMerkle::default();
+        let restored_root = incremental.root();
         Self {
             prover,
             incremental,
+            snapshot_count: 0,
+            restored_count: 0,
+            restored_root,
+        }
+    }
+
+    /// Restores the tree from the latest snapshot in the db, falling back to
+    /// an empty tree if there is no usable snapshot. Leaves inserted after
+    /// the snapshot still need to be ingested.
+    pub fn from_snapshot(db: &HyperlaneRocksDB) -> Result<Self, MerkleTreeBuilderError> {
+        let Some(snapshot) = db.retrieve_merkle_tree_snapshot()? else {
+            return Ok(Self::new());
+        };
+        if snapshot.version != MerkleTreeSnapshot::VERSION {
+            warn!(
+                version = snapshot.version,
+                expected_version = MerkleTreeSnapshot::VERSION,
+                "Ignoring merkle tree snapshot with unsupported version"
+            );
+            return Ok(Self::new());
+        }
+
+        let prover = Prover::from_branch(&snapshot.branch, snapshot.count as usize);
+        let incremental = IncrementalMerkle::new(snapshot.branch, snapshot.count as usize);
+        if prover.root() != snapshot.root || incremental.root() != snapshot.root {
+            warn!(
+                snapshot_root = ?snapshot.root,
+                prover_root = ?prover.root(),
+                incremental_root = ?incremental.root(),
+                "Merkle tree snapshot is inconsistent, rebuilding from scratch"
+            );
+            return Ok(Self::new());
+        }
+
+        info!(count = snapshot.count, root = ?snapshot.root, "Restored merkle tree from snapshot");
+        Ok(Self {
+            prover,
+            incremental,
+            snapshot_count: snapshot.count,
+            restored_count: snapshot.count,
+            restored_root: snapshot.root,
+        })
+    }
+
+    /// Persists a new snapshot if the tree has reached a multiple of
+    /// `SNAPSHOT_INTERVAL` since the previous one. Returns whether a snapshot
+    /// was taken.
+    pub fn snapshot_if_due(
+        &mut self,
+        db: &HyperlaneRocksDB,
+    ) -> Result<bool, MerkleTreeBuilderError> {
+        let count = self.count();
+        if count == self.snapshot_count || count % SNAPSHOT_INTERVAL != 0 {
+            return Ok(false);
+        }
+        db.store_merkle_tree_snapshot(&MerkleTreeSnapshot {
+            version: MerkleTreeSnapshot::VERSION,
+            count,
+            root: self.prover.root(),
+            branch: self.incremental.branch,
+        })?;
+        self.snapshot_count = count;
+        debug!(count, "Persisted merkle tree snapshot");
+        Ok(true)
+    }
+
+    /// Number of leaves that were restored from a snapshot rather than
+    /// ingested since startup
+    pub fn restored_count(&self) -> u32 {
+        self.restored_count
+    }
+
+    /// Number of leaves restored from a snapshot that can't be proven, as
+    /// the snapshot only holds the branch of the incremental tree. They can
+    /// be proven again once `restore_pruned_leaves` is called.
+    pub fn pruned_count(&self) -> u32 {
+        self.prover.pruned_count() as u32
+    }
+
+    /// Rebuilds a prover of the first `count` leaves from the indexed
+    /// insertions. This reads every one of these insertions from the db, so
+    /// it's meant to be run on a blocking task, without holding a lock on the
+    /// builder.
+    pub fn rebuild_prover(
+        db: &HyperlaneRocksDB,
+        count: u32,
+    ) -> Result<Prover, MerkleTreeBuilderError> {
+        let leaves = (0..count)
+            .map(|leaf_index| {
+                db.retrieve_merkle_tree_insertion_by_leaf_index(&leaf_index)?
+                    .map(|insertion| insertion.message_id())
+                    .ok_or(MerkleTreeBuilderError::MissingInsertion(leaf_index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Prover::from(leaves))
+    }
+
+    /// Replaces the pruned prover with `prover`, rebuilt by `rebuild_prover`,
+    /// so that the leaves restored from the snapshot can be proven. Leaves
+    /// ingested while it was being rebuilt are read from the db.
+    pub fn restore_pruned_leaves(
+        &mut self,
+        mut prover: Prover,
+        db: &HyperlaneRocksDB,
+    ) -> Result<(), MerkleTreeBuilderError> {
+        for leaf_index in prover.count() as u32..self.count() {
+            let insertion = db
+                .retrieve_merkle_tree_insertion_by_leaf_index(&leaf_index)?
+                .ok_or(MerkleTreeBuilderError::MissingInsertion(leaf_index))?;
+            prover.ingest(insertion.message_id())?;
+        }
+        if prover.count() != self.prover.count() || prover.root() != self.prover.root() {
+            return Err(MerkleTreeBuilderError::MismatchedRoots {
+                prover_root: prover.root(),
+                incremental_root: self.incremental.root(),
+            });
+        }
+        self.prover = prover;
+        Ok(())
+    }
+
+    /// Root of the tree as it was when it had `count` leaves, if this tree
+    /// has at least that many and they aren't pruned.
+    pub fn root_at_count(&self, count: u32) -> Option<H256> {
+        match count {
+            0 => Some(IncrementalMerkle::default().root()),
+            count if count > self.count() => None,
+            count if count == self.restored_count => Some(self.restored_root),
+            count => self
+                .prover
+                .prove_against_previous(count as usize - 1, count as usize - 1)
+                .ok()
+                .map(|proof| proof.root()),
         }
     }
 
@@ -100,3 +248,92 @@ impl MerkleTreeBuilder {
         .context(CTX)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hyperlane_base::db::test_utils;
+    use hyperlane_core::{HyperlaneDomain, MerkleTreeInsertion};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn resumes_from_snapshot() {
+        test_utils::run_test_db(|db| async move {
+            let domain = HyperlaneDomain::new_test_domain("test_merkle_tree_snapshot");
+            let db = HyperlaneRocksDB::new(&domain, db);
+            let leaves: Vec<H256> = (0..2 * SNAPSHOT_INTERVAL + 5)
+                .map(|_| H256::random())
+                .collect();
+
+            let mut builder = MerkleTreeBuilder::from_snapshot(&db).unwrap();
+            assert_eq!(builder.count(), 0);
+            for (leaf_index, leaf) in leaves.iter().enumerate() {
+                db.process_tree_insertion(&MerkleTreeInsertion::new(leaf_index as u32, *leaf), 0)
+                    .unwrap();
+                builder.ingest_message_id(*leaf).await.unwrap();
+                builder.snapshot_if_due(&db).unwrap();
+            }
+
+            // Leaves since the latest snapshot are ingested again
+            let mut restored = MerkleTreeBuilder::from_snapshot(&db).unwrap();
+            assert_eq!(restored.count(), 2 * SNAPSHOT_INTERVAL);
+            assert_eq!(restored.restored_count(), 2 * SNAPSHOT_INTERVAL);
+            assert_eq!(
+                restored.root_at_count(restored.count()),
+                builder.root_at_count(restored.count())
+            );
+            for leaf in &leaves[restored.count() as usize..] {
+                restored.ingest_message_id(*leaf).await.unwrap();
+            }
+            assert_eq!(restored.prover.root(), builder.prover.root());
+
+            // Leaves ingested after restoring are proven by the restored tree
+            let latest = builder.count() - 1;
+            assert_eq!(
+                restored.get_proof(latest - 1, latest).unwrap(),
+                builder.get_proof(latest - 1, latest).unwrap()
+            );
+            // but the restored leaves only once they are rebuilt from the indexed
+            // insertions
+            assert_eq!(restored.pruned_count(), 2 * SNAPSHOT_INTERVAL);
+            assert!(matches!(
+                restored.get_proof(3, latest),
+                Err(MerkleTreeBuilderError::ProverError(
+                    ProverError::PrunedProof { .. }
+                ))
+            ));
+            assert_eq!(restored.root_at_count(5), None);
+            let prover = MerkleTreeBuilder::rebuild_prover(&db, restored.pruned_count()).unwrap();
+            restored.restore_pruned_leaves(prover, &db).unwrap();
+            assert_eq!(restored.pruned_count(), 0);
+            assert_eq!(
+                restored.get_proof(3, latest).unwrap(),
+                builder.get_proof(3, latest).unwrap()
+            );
+            assert_eq!(restored.root_at_count(5), builder.root_at_count(5));
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn fails_to_rebuild_restored_leaves_without_insertion() {
+        test_utils::run_test_db(|db| async move {
+            let domain = HyperlaneDomain::new_test_domain("test_merkle_tree_snapshot");
+            let db = HyperlaneRocksDB::new(&domain, db);
+
+            let mut builder = MerkleTreeBuilder::new();
+            for _ in 0..SNAPSHOT_INTERVAL {
+                builder.ingest_message_id(H256::random()).await.unwrap();
+            }
+            assert!(builder.snapshot_if_due(&db).unwrap());
+            assert!(!builder.snapshot_if_due(&db).unwrap());
+
+            let restored = MerkleTreeBuilder::from_snapshot(&db).unwrap();
+            assert!(matches!(
+                MerkleTreeBuilder::rebuild_prover(&db, restored.pruned_count()),
+                Err(MerkleTreeBuilderError::MissingInsertion(0))
+            ));
+        })
+        .await;
+    }
+}
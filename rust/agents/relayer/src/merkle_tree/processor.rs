@@ -1,5 +1,6 @@
 use std::{
     fmt::{Debug, Formatter},
+    num::NonZeroU64,
     sync::Arc,
     time::Duration,
 };
@@ -7,15 +8,15 @@ use std::{
 use async_trait::async_trait;
 use derive_new::new;
 use eyre::Result;
-use hyperlane_base::db::HyperlaneRocksDB;
-use hyperlane_core::{HyperlaneDomain, MerkleTreeInsertion};
+use hyperlane_base::db::{HyperlaneRocksDB, MerkleTreeSnapshot};
+use hyperlane_core::{HyperlaneDomain, MerkleTreeHook, MerkleTreeInsertion, H256};
 use prometheus::IntGauge;
-use tokio::sync::RwLock;
-use tracing::trace;
+use tokio::{sync::RwLock, task::JoinHandle};
+use tracing::{debug, error, info, trace, warn};
 
-use crate::processor::ProcessorExt;
+use crate::{processor::ProcessorExt, prover::Prover};
 
-use super::builder::MerkleTreeBuilder;
+use super::builder::{MerkleTreeBuilder, MerkleTreeBuilderError};
 
 /// Finds unprocessed merkle tree insertions and adds them to the prover sync
 #[derive(new)]
@@ -23,8 +24,29 @@ pub struct MerkleTreeProcessor {
     db: HyperlaneRocksDB,
     metrics: MerkleTreeProcessorMetrics,
     prover_sync: Arc<RwLock<MerkleTreeBuilder>>,
+    merkle_tree_hook: Arc<dyn MerkleTreeHook>,
+    reorg_period: Option<NonZeroU64>,
     #[new(default)]
     leaf_index: u32,
+    #[new(default)]
+    started: bool,
+    #[new(default)]
+    consistency_check: ConsistencyCheck,
+    /// Rebuilds the prover of the leaves restored from the snapshot
+    #[new(default)]
+    pruned_leaves: Option<JoinHandle<Result<Prover, MerkleTreeBuilderError>>>,
+}
+
+/// Progress of the one-off check of the local tree against the onchain one
+#[derive(Debug, Default)]
+enum ConsistencyCheck {
+    /// The onchain tree hasn't been fetched yet
+    #[default]
+    Pending,
+    /// Waiting for the local tree to catch up to the onchain leaf count
+    AwaitingLeaves { count: u32, root: H256 },
+    /// The check either passed or couldn't be performed
+    Done,
 }
 
 impl Debug for MerkleTreeProcessor {
@@ -47,13 +69,34 @@ impl ProcessorExt for MerkleTreeProcessor {
     /// One round of processing, extracted from infinite work loop for
     /// testing purposes.
     async fn tick(&mut self) -> Result<()> {
+        if !self.started {
+            // Resume after the leaves restored from the snapshot
+            let prover_sync = self.prover_sync.read().await;
+            self.leaf_index = prover_sync.count();
+            let pruned_count = prover_sync.pruned_count();
+            drop(prover_sync);
+            if pruned_count > 0 {
+                // Rebuilding the restored leaves reads every insertion up to the
+                // snapshot, so it's done on a blocking task without holding the
+                // prover sync lock. Meanwhile proofs of these leaves fail.
+                let db = self.db.clone();
+                self.pruned_leaves = Some(tokio::task::spawn_blocking(move || {
+                    MerkleTreeBuilder::rebuild_prover(&db, pruned_count)
+                }));
+            }
+            self.started = true;
+        }
+        self.restore_pruned_leaves().await;
+        self.check_consistency().await?;
+
         if let Some(insertion) = self.next_unprocessed_leaf()? {
             // Feed the message to the prover sync
-            self.prover_sync
-                .write()
-                .await
+            let mut prover_sync = self.prover_sync.write().await;
+            prover_sync
                 .ingest_message_id(insertion.message_id())
                 .await?;
+            prover_sync.snapshot_if_due(&self.db)?;
+            drop(prover_sync);
 
             // Increase the leaf index to move on to the next leaf
             self.leaf_index += 1;
@@ -65,6 +108,103 @@ impl ProcessorExt for MerkleTreeProcessor {
 }
 
 impl MerkleTreeProcessor {
+    /// Swaps in the prover of the leaves restored from the snapshot once it
+    /// has been rebuilt.
+    async fn restore_pruned_leaves(&mut self) {
+        if !self
+            .pruned_leaves
+            .as_ref()
+            .map_or(false, JoinHandle::is_finished)
+        {
+            return;
+        }
+        let Some(pruned_leaves) = self.pruned_leaves.take() else {
+            return;
+        };
+        let result = match pruned_leaves.await {
+            Ok(Ok(prover)) => self
+                .prover_sync
+                .write()
+                .await
+                .restore_pruned_leaves(prover, &self.db),
+            Ok(Err(err)) => Err(err),
+            Err(err) => {
+                error!(?err, "Rebuilding the restored merkle tree leaves panicked");
+                return;
+            }
+        };
+        match result {
+            Ok(()) => info!("Rebuilt the merkle tree leaves restored from the snapshot"),
+            Err(err) => error!(
+                ?err,
+                "Failed to rebuild the merkle tree leaves restored from the snapshot, they can't be proven"
+            ),
+        }
+    }
+
+    /// Compares the local tree against the onchain one once it has caught up
+    /// to the onchain leaf count. A mismatching tree restored from a
+    /// snapshot is discarded and rebuilt from the indexed insertions.
+    async fn check_consistency(&mut self) -> Result<()> {
+        let (count, onchain_root) = match self.consistency_check {
+            ConsistencyCheck::Done => return Ok(()),
+            ConsistencyCheck::AwaitingLeaves { count, root } => (count, root),
+            ConsistencyCheck::Pending => {
+                match self.merkle_tree_hook.tree(self.reorg_period).await {
+                    Ok(tree) => {
+                        let (count, root) = (tree.count() as u32, tree.root());
+                        self.consistency_check = ConsistencyCheck::AwaitingLeaves { count, root };
+                        (count, root)
+                    }
+                    Err(err) => {
+                        warn!(
+                            ?err,
+                            "Failed to fetch onchain merkle tree, skipping consistency check"
+                        );
+                        self.consistency_check = ConsistencyCheck::Done;
+                        return Ok(());
+                    }
+                }
+            }
+        };
+
+        let prover_sync = self.prover_sync.read().await;
+        let Some(local_root) = prover_sync.root_at_count(count) else {
+            // Not caught up to the onchain tree yet
+            return Ok(());
+        };
+        if local_root == onchain_root {
+            debug!(count, root=?local_root, "Local merkle tree matches onchain tree");
+            self.consistency_check = ConsistencyCheck::Done;
+            return Ok(());
+        }
+
+        if prover_sync.restored_count() == 0 {
+            error!(
+                count,
+                ?local_root,
+                ?onchain_root,
+                "Local merkle tree does not match onchain tree"
+            );
+            self.consistency_check = ConsistencyCheck::Done;
+            return Ok(());
+        }
+        error!(
+            count,
+            ?local_root,
+            ?onchain_root,
+            restored_count = prover_sync.restored_count(),
+            "Merkle tree restored from snapshot does not match onchain tree, discarding snapshot"
+        );
+        drop(prover_sync);
+        self.db
+            .store_merkle_tree_snapshot(&MerkleTreeSnapshot::empty())?;
+        *self.prover_sync.write().await = MerkleTreeBuilder::new();
+        self.pruned_leaves = None;
+        self.leaf_index = 0;
+        Ok(())
+    }
+
     fn next_unprocessed_leaf(&mut self) -> Result<Option<MerkleTreeInsertion>> {
         let leaf = if let Some(insertion) = self
             .db
@@ -0,0 +1,206 @@
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use hyperlane_core::{HyperlaneDomain, HyperlaneProvider, U256};
+use prometheus::IntGauge;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use tracing::{info, info_span, instrument::Instrumented, warn, Instrument};
+
+/// How often the signer balance is checked against the minimum
+const BALANCE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Shared flag telling the submitter for a destination whether it may submit
+/// transactions. Cloning it yields a handle to the same flag.
+#[derive(Debug, Clone, Default)]
+pub struct SubmissionPause(Arc<AtomicBool>);
+
+impl SubmissionPause {
+    /// Whether submissions are currently paused
+    pub fn is_paused(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Sets the pause state, returning the previous one
+    pub(crate) fn set_paused(&self, paused: bool) -> bool {
+        self.0.swap(paused, Ordering::Relaxed)
+    }
+}
+
+/// Periodically checks the balance of the relayer's signer on a destination
+/// chain, pausing submissions to that chain while the balance is below
+/// `minimum_balance` and resuming them once it has been topped up.
+pub struct SignerBalanceGuard {
+    domain: HyperlaneDomain,
+    provider: Box<dyn HyperlaneProvider>,
+    signer_address: String,
+    minimum_balance: U256,
+    pause: SubmissionPause,
+    paused_gauge: IntGauge,
+}
+
+impl Debug for SignerBalanceGuard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SignerBalanceGuard {{ domain: {}, signer_address: {}, minimum_balance: {} }}",
+            self.domain, self.signer_address, self.minimum_balance
+        )
+    }
+}
+
+impl SignerBalanceGuard {
+    pub fn new(
+        domain: HyperlaneDomain,
+        provider: Box<dyn HyperlaneProvider>,
+        signer_address: String,
+        minimum_balance: U256,
+        pause: SubmissionPause,
+        paused_gauge: IntGauge,
+    ) -> Self {
+        Self {
+            domain,
+            provider,
+            signer_address,
+            minimum_balance,
+            pause,
+            paused_gauge,
+        }
+    }
+
+    pub fn spawn(self) -> Instrumented<JoinHandle<()>> {
+        let span = info_span!("SignerBalanceGuard", destination=%self.domain);
+        tokio::spawn(async move { self.run().await }).instrument(span)
+    }
+
+    async fn run(self) {
+        let mut interval = tokio::time::interval(BALANCE_CHECK_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            self.check_balance().await;
+        }
+    }
+
+    async fn check_balance(&self) {
+        let balance = match self.provider.get_balance(self.signer_address.clone()).await {
+            Ok(balance) => balance,
+            Err(err) => {
+                // Keep the current state rather than pausing or resuming on a flaky RPC
+                warn!(
+                    ?err,
+                    signer = self.signer_address,
+                    "Failed to fetch signer balance"
+                );
+                return;
+            }
+        };
+        let paused = balance < self.minimum_balance;
+        let was_paused = self.pause.set_paused(paused);
+        self.paused_gauge.set(paused as i64);
+        match (was_paused, paused) {
+            (false, true) => warn!(
+                %balance,
+                minimum_balance = %self.minimum_balance,
+                signer = self.signer_address,
+                "Signer balance is below the minimum, pausing submissions"
+            ),
+            (true, false) => info!(
+                %balance,
+                minimum_balance = %self.minimum_balance,
+                signer = self.signer_address,
+                "Signer balance is above the minimum again, resuming submissions"
+            ),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use hyperlane_core::{
+        BlockInfo, ChainInfo, ChainResult, HyperlaneChain, HyperlaneDomain, TxnInfo, H256,
+    };
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct MockBalanceProvider {
+        domain: HyperlaneDomain,
+        balance: Mutex<U256>,
+    }
+
+    impl HyperlaneChain for MockBalanceProvider {
+        fn domain(&self) -> &HyperlaneDomain {
+            &self.domain
+        }
+
+        fn provider(&self) -> Box<dyn HyperlaneProvider> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl HyperlaneProvider for MockBalanceProvider {
+        async fn get_block_by_hash(&self, _hash: &H256) -> ChainResult<BlockInfo> {
+            unimplemented!()
+        }
+
+        async fn get_txn_by_hash(&self, _hash: &H256) -> ChainResult<TxnInfo> {
+            unimplemented!()
+        }
+
+        async fn is_contract(&self, _address: &H256) -> ChainResult<bool> {
+            unimplemented!()
+        }
+
+        async fn get_balance(&self, _address: String) -> ChainResult<U256> {
+            Ok(*self.balance.lock().unwrap())
+        }
+
+        async fn get_nonce(&self, _address: String) -> ChainResult<Option<u64>> {
+            unimplemented!()
+        }
+
+        async fn get_gas_price(&self) -> ChainResult<Option<U256>> {
+            unimplemented!()
+        }
+
+        async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn pauses_below_minimum_and_resumes_when_funded() {
+        let domain = HyperlaneDomain::new_test_domain("test_signer_balance_guard");
+        let provider = Arc::new(MockBalanceProvider {
+            domain: domain.clone(),
+            balance: Mutex::new(U256::from(5)),
+        });
+        let pause = SubmissionPause::default();
+        let guard = SignerBalanceGuard::new(
+            domain,
+            Box::new(provider.clone()),
+            "signer".to_owned(),
+            U256::from(10),
+            pause.clone(),
+            IntGauge::new("dummy_submitter_paused", "help string").unwrap(),
+        );
+
+        guard.check_balance().await;
+        assert!(pause.is_paused());
+        assert_eq!(guard.paused_gauge.get(), 1);
+
+        *provider.balance.lock().unwrap() = U256::from(10);
+        guard.check_balance().await;
+        assert!(!pause.is_paused());
+        assert_eq!(guard.paused_gauge.get(), 0);
+    }
+}
@@ -1,4 +1,7 @@
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    sync::{Arc, RwLock},
+};
 
 use async_trait::async_trait;
 use eyre::Result;
@@ -41,10 +44,14 @@ pub struct GasPaymentEnforcer {
     /// use a wild-card white list to ensure all messages fall into one
     /// policy or another. If a message matches multiple policies'
     /// whitelists, then whichever is first in the list will be used.
-    policies: Vec<(Box<dyn GasPaymentPolicy>, MatchingList)>,
+    /// The policies can be replaced while the relayer is running, see
+    /// `set_policies`.
+    policies: RwLock<Arc<GasPaymentPolicies>>,
     db: HyperlaneRocksDB,
 }
 
+type GasPaymentPolicies = Vec<(Box<dyn GasPaymentPolicy>, MatchingList)>;
+
 impl GasPaymentEnforcer {
     /// Note that `policy_configs` should not be empty. In the settings,
     /// a default of vec![GasPaymentEnforcementConf::default()] is used.
@@ -52,7 +59,29 @@ impl GasPaymentEnforcer {
         policy_configs: impl IntoIterator<Item = GasPaymentEnforcementConf>,
         db: HyperlaneRocksDB,
     ) -> Self {
-        let policies = policy_configs
+        Self {
+            policies: RwLock::new(Arc::new(Self::build_policies(policy_configs))),
+            db,
+        }
+    }
+
+    /// Replace the policies, e.g. after the relayer config is reloaded.
+    /// Messages already being evaluated keep using the previous policies.
+    pub fn set_policies(
+        &self,
+        policy_configs: impl IntoIterator<Item = GasPaymentEnforcementConf>,
+    ) {
+        let policies = Arc::new(Self::build_policies(policy_configs));
+        *self
+            .policies
+            .write()
+            .expect("Gas payment policies lock poisoned") = policies;
+    }
+
+    fn build_policies(
+        policy_configs: impl IntoIterator<Item = GasPaymentEnforcementConf>,
+    ) -> GasPaymentPolicies {
+        policy_configs
             .into_iter()
             .map(|cfg| {
                 let p: Box<dyn GasPaymentPolicy> = match cfg.policy {
@@ -67,9 +96,7 @@ impl GasPaymentEnforcer {
                 };
                 (p, cfg.matching_list)
             })
-            .collect();
-
-        Self { policies, db }
+            .collect()
     }
 }
 
@@ -91,7 +118,12 @@ impl GasPaymentEnforcer {
             .retrieve_gas_payment_by_gas_payment_key(gas_payment_key)?;
         let current_expenditure = self.db.retrieve_gas_expenditure_by_message_id(msg_id)?;
 
-        for (policy, whitelist) in &self.policies {
+        let policies = self
+            .policies
+            .read()
+            .expect("Gas payment policies lock poisoned")
+            .clone();
+        for (policy, whitelist) in policies.iter() {
             if !whitelist.msg_matches(message, true) {
                 trace!(
                     msg=%message,
@@ -127,7 +159,7 @@ impl GasPaymentEnforcer {
 
         error!(
             msg=%message,
-            policies=?self.policies,
+            ?policies,
             "No gas payment policy matched for message; consider adding a default policy to the end of the policies array which uses a wildcard whitelist."
         );
         Ok(None)
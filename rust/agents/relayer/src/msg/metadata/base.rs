@@ -8,6 +8,7 @@ use std::{
 };
 
 use crate::{
+    config_reload::Reloadable,
     merkle_tree::builder::MerkleTreeBuilder,
     msg::metadata::{
         multisig::{MerkleRootMultisigMetadataBuilder, MessageIdMultisigMetadataBuilder},
@@ -113,7 +114,7 @@ pub struct IsmAwareAppContextClassifier {
 impl IsmAwareAppContextClassifier {
     pub fn new(
         destination_mailbox: Arc<dyn Mailbox>,
-        app_matching_lists: Vec<(MatchingList, String)>,
+        app_matching_lists: Reloadable<Vec<(MatchingList, String)>>,
     ) -> Self {
         Self {
             default_ism: DefaultIsmCache::new(destination_mailbox),
@@ -141,7 +142,7 @@ impl IsmAwareAppContextClassifier {
 /// Classifies messages into an app context if they have one.
 #[derive(Debug, new)]
 pub struct AppContextClassifier {
-    app_matching_lists: Vec<(MatchingList, String)>,
+    app_matching_lists: Reloadable<Vec<(MatchingList, String)>>,
 }
 
 impl AppContextClassifier {
@@ -156,7 +157,7 @@ impl AppContextClassifier {
         // Give priority to the matching list. If the app from the matching list happens
         // to use the default ISM, it's preferable to use the app context from the matching
         // list.
-        for (matching_list, app_context) in self.app_matching_lists.iter() {
+        for (matching_list, app_context) in self.app_matching_lists.load().iter() {
             if matching_list.msg_matches(message, false) {
                 return Ok(Some(app_context.clone()));
             }
@@ -272,6 +273,7 @@ impl MessageMetadataBuilder {
 #[derive(new)]
 pub struct BaseMetadataBuilder {
     origin_domain: HyperlaneDomain,
+    origin_mailbox_address: H256,
     destination_chain_setup: ChainConf,
     origin_prover_sync: Arc<RwLock<MerkleTreeBuilder>>,
     origin_validator_announce: Arc<dyn ValidatorAnnounce>,
@@ -399,6 +401,22 @@ impl BaseMetadataBuilder {
 
                 match config.build(None).await {
                     Ok(checkpoint_syncer) => {
+                        if is_revoked(
+                            checkpoint_syncer.as_ref(),
+                            validator,
+                            storage_location,
+                            &self.origin_domain,
+                            self.origin_mailbox_address,
+                        )
+                        .await
+                        {
+                            debug!(
+                                ?config,
+                                ?validator,
+                                "Ignoring storage location revoked by validator"
+                            );
+                            continue;
+                        }
                         // found the syncer for this validator
                         checkpoint_syncers.insert(validator.into(), checkpoint_syncer.into());
                         break;
@@ -432,3 +450,124 @@ impl BaseMetadataBuilder {
         ))
     }
 }
+
+/// Whether the validator has written a valid revocation of
+/// `storage_location` for the origin mailbox to the checkpoint syncer built
+/// from it.
+async fn is_revoked(
+    checkpoint_syncer: &dyn CheckpointSyncer,
+    validator: H256,
+    storage_location: &str,
+    origin_domain: &HyperlaneDomain,
+    origin_mailbox_address: H256,
+) -> bool {
+    match checkpoint_syncer.fetch_revocation().await {
+        Ok(Some(revocation)) => {
+            revocation.value.storage_location == storage_location
+                && revocation.value.mailbox_domain == origin_domain.id()
+                && revocation.value.mailbox_address == origin_mailbox_address
+                && revocation.verify(validator.into()).is_ok()
+        }
+        Ok(None) => false,
+        Err(err) => {
+            debug!(
+                error=%err,
+                ?validator,
+                ?storage_location,
+                "Error when fetching storage location revocation; assuming it is not revoked"
+            );
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ethers::signers::LocalWallet;
+    use hyperlane_base::LocalStorage;
+    use hyperlane_core::{HyperlaneSigner, HyperlaneSignerExt, StorageLocationRevocation};
+    use hyperlane_ethereum::Signers;
+
+    use super::*;
+
+    const STORAGE_LOCATION: &str = "file:///revoked";
+
+    fn validator_signer(key: &str) -> Signers {
+        key.parse::<LocalWallet>().unwrap().into()
+    }
+
+    fn revocation(
+        signer: &Signers,
+        mailbox_domain: u32,
+        mailbox_address: H256,
+    ) -> StorageLocationRevocation {
+        StorageLocationRevocation {
+            validator: signer.eth_address(),
+            mailbox_address,
+            mailbox_domain,
+            storage_location: STORAGE_LOCATION.to_owned(),
+            replacement_location: "file:///replacement".to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_is_revoked() {
+        let dir = std::env::temp_dir().join(format!("relayer_is_revoked_{}", std::process::id()));
+        let storage = LocalStorage::new(dir.clone(), None).unwrap();
+        let origin = HyperlaneDomain::new_test_domain("test_is_revoked");
+        let mailbox = H256::repeat_byte(1);
+        let signer =
+            validator_signer("1111111111111111111111111111111111111111111111111111111111111111");
+        let validator: H256 = signer.eth_address().into();
+
+        let revoked = |storage: &LocalStorage| {
+            let storage = storage.clone();
+            let origin = origin.clone();
+            async move { is_revoked(&storage, validator, STORAGE_LOCATION, &origin, mailbox).await }
+        };
+
+        // Never revoked
+        assert!(!revoked(&storage).await);
+
+        let signed = signer
+            .sign(revocation(&signer, origin.id(), mailbox))
+            .await
+            .unwrap();
+        storage.write_revocation(Some(&signed)).await.unwrap();
+        assert!(revoked(&storage).await);
+        // Only for the revoked location
+        assert!(!is_revoked(&storage, validator, "file:///other", &origin, mailbox).await);
+
+        // A revocation for another mailbox
+        let signed = signer
+            .sign(revocation(&signer, origin.id(), H256::repeat_byte(2)))
+            .await
+            .unwrap();
+        storage.write_revocation(Some(&signed)).await.unwrap();
+        assert!(!revoked(&storage).await);
+
+        // A revocation for another origin
+        let signed = signer
+            .sign(revocation(&signer, origin.id() + 1, mailbox))
+            .await
+            .unwrap();
+        storage.write_revocation(Some(&signed)).await.unwrap();
+        assert!(!revoked(&storage).await);
+
+        // A revocation signed by someone other than the validator
+        let other =
+            validator_signer("2222222222222222222222222222222222222222222222222222222222222222");
+        let signed = other
+            .sign(revocation(&signer, origin.id(), mailbox))
+            .await
+            .unwrap();
+        storage.write_revocation(Some(&signed)).await.unwrap();
+        assert!(!revoked(&storage).await);
+
+        // An overwritten revocation
+        storage.write_revocation(None).await.unwrap();
+        assert!(!revoked(&storage).await);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
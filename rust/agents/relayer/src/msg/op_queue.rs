@@ -171,7 +171,11 @@ mod test {
 
         /// Submit this operation to the blockchain and report if it was successful
         /// or not.
-        async fn submit(&mut self) {
+        async fn submit(&mut self) -> PendingOperationResult {
+            todo!()
+        }
+
+        fn set_signer_index(&mut self, _signer_index: usize) {
             todo!()
         }
 
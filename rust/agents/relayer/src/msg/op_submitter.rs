@@ -24,7 +24,17 @@ use hyperlane_core::{
 use crate::msg::pending_message::CONFIRM_DELAY;
 use crate::server::MessageRetryRequest;
 
-use super::op_queue::OpQueue;
+use super::{balance_guard::SubmissionPause, op_queue::OpQueue};
+
+/// How long a signer stops pulling operations from the submit queue after
+/// all of its submissions failed, leaving them to the other signers in the
+/// pool. Signers of a pool of one never back off, as there is no one to take
+/// over.
+pub const SIGNER_FAILURE_BACKOFF: Duration = if cfg!(any(test, feature = "test-utils")) {
+    Duration::from_millis(500)
+} else {
+    Duration::from_secs(30)
+};
 
 /// SerialSubmitter accepts operations over a channel. It is responsible for
 /// executing the right strategy to deliver those messages to the destination
@@ -87,6 +97,11 @@ pub struct SerialSubmitter {
     max_batch_size: u32,
     /// tokio task monitor
     task_monitor: TaskMonitor,
+    /// One entry per signer in the destination's signer pool. Each signer
+    /// gets its own submit task, which pulls from the shared submit queue
+    /// and stops doing so while its pause is set, e.g. because the signer's
+    /// balance is too low. Operations keep being prepared in the meantime.
+    signer_pauses: Vec<SubmissionPause>,
 }
 
 impl SerialSubmitter {
@@ -107,6 +122,7 @@ impl SerialSubmitter {
             retry_tx,
             max_batch_size,
             task_monitor,
+            signer_pauses,
         } = self;
         let prepare_queue = OpQueue::new(
             metrics.submitter_queue_length.clone(),
@@ -124,6 +140,27 @@ impl SerialSubmitter {
             Arc::new(Mutex::new(retry_tx.subscribe())),
         );
 
+        let backoff_on_failure = signer_pauses.len() > 1;
+        let submit_tasks = signer_pauses
+            .into_iter()
+            .enumerate()
+            .map(|(signer_index, pause)| {
+                tokio::spawn(TaskMonitor::instrument(
+                    &task_monitor,
+                    submit_task(
+                        domain.clone(),
+                        signer_index,
+                        submit_queue.clone(),
+                        confirm_queue.clone(),
+                        max_batch_size,
+                        metrics.clone(),
+                        pause,
+                        backoff_on_failure,
+                    ),
+                ))
+            })
+            .collect::<Vec<_>>();
+
         let tasks = [
             tokio::spawn(TaskMonitor::instrument(
                 &task_monitor,
@@ -140,16 +177,6 @@ impl SerialSubmitter {
                     metrics.clone(),
                 ),
             )),
-            tokio::spawn(TaskMonitor::instrument(
-                &task_monitor,
-                submit_task(
-                    domain.clone(),
-                    submit_queue,
-                    confirm_queue.clone(),
-                    max_batch_size,
-                    metrics.clone(),
-                ),
-            )),
             tokio::spawn(TaskMonitor::instrument(
                 &task_monitor,
                 confirm_task(
@@ -162,7 +189,7 @@ impl SerialSubmitter {
             )),
         ];
 
-        if let Err(err) = try_join_all(tasks).await {
+        if let Err(err) = try_join_all(tasks.into_iter().chain(submit_tasks)).await {
             tracing::error!(
                 error=?err,
                 ?domain,
@@ -256,19 +283,30 @@ async fn prepare_task(
     }
 }
 
-#[instrument(skip_all, fields(%domain))]
+#[instrument(skip_all, fields(%domain, signer_index))]
 async fn submit_task(
     domain: HyperlaneDomain,
+    signer_index: usize,
     mut submit_queue: OpQueue,
     mut confirm_queue: OpQueue,
     max_batch_size: u32,
     metrics: SerialSubmitterMetrics,
+    pause: SubmissionPause,
+    backoff_on_failure: bool,
 ) {
     let recv_limit = max_batch_size as usize;
     loop {
+        if pause.is_paused() {
+            // Leave the operations in the queue so they keep their order once submissions resume
+            sleep(Duration::from_secs(1)).await;
+            continue;
+        }
         let mut batch = submit_queue.pop_many(recv_limit).await;
+        for op in batch.iter_mut() {
+            op.set_signer_index(signer_index);
+        }
 
-        match batch.len().cmp(&1) {
+        let result = match batch.len().cmp(&1) {
             std::cmp::Ordering::Less => {
                 // The queue is empty, so give some time before checking again to prevent burning CPU
                 sleep(Duration::from_millis(100)).await;
@@ -276,13 +314,22 @@ async fn submit_task(
             }
             std::cmp::Ordering::Equal => {
                 let op = batch.pop().unwrap();
-                submit_single_operation(op, &mut confirm_queue, &metrics).await;
+                submit_single_operation(op, &mut confirm_queue, &metrics).await
             }
             std::cmp::Ordering::Greater => {
                 OperationBatch::new(batch, domain.clone())
                     .submit(&mut confirm_queue, &metrics)
-                    .await;
+                    .await
             }
+        };
+        if backoff_on_failure && matches!(result, PendingOperationResult::Reprepare) {
+            // The failed operations are retried as usual, but most likely by another
+            // signer, so that a broken signer doesn't hold up the rest of the pool
+            warn!(
+                backoff = ?SIGNER_FAILURE_BACKOFF,
+                "Submissions failed for signer, backing off"
+            );
+            sleep(SIGNER_FAILURE_BACKOFF).await;
         }
     }
 }
@@ -292,9 +339,9 @@ async fn submit_single_operation(
     mut op: QueueOperation,
     confirm_queue: &mut OpQueue,
     metrics: &SerialSubmitterMetrics,
-) {
+) -> PendingOperationResult {
     let destination = op.destination_domain().clone();
-    op.submit().await;
+    let result = op.submit().await;
     debug!(?op, "Operation submitted");
     op.set_next_attempt_after(CONFIRM_DELAY);
     confirm_queue.push(op).await;
@@ -309,6 +356,7 @@ async fn submit_single_operation(
         // to lose liveness.
         sleep(Duration::from_secs(1)).await;
     }
+    result
 }
 
 #[instrument(skip_all, fields(%domain))]
@@ -426,7 +474,12 @@ struct OperationBatch {
 }
 
 impl OperationBatch {
-    async fn submit(self, confirm_queue: &mut OpQueue, metrics: &SerialSubmitterMetrics) {
+    /// Returns `Reprepare` if none of the operations could be submitted.
+    async fn submit(
+        self,
+        confirm_queue: &mut OpQueue,
+        metrics: &SerialSubmitterMetrics,
+    ) -> PendingOperationResult {
         match self.try_submit_as_batch(metrics).await {
             Ok(outcome) => {
                 info!(outcome=?outcome, batch_size=self.operations.len(), batch=?self.operations, "Submitted transaction batch");
@@ -436,13 +489,13 @@ impl OperationBatch {
                     op.set_next_attempt_after(CONFIRM_DELAY);
                     confirm_queue.push(op).await;
                 }
-                return;
+                return PendingOperationResult::Success;
             }
             Err(e) => {
                 warn!(error=?e, batch=?self.operations, "Error when submitting batch. Falling back to serial submission.");
             }
         }
-        self.submit_serially(confirm_queue, metrics).await;
+        self.submit_serially(confirm_queue, metrics).await
     }
 
     #[instrument(skip(metrics), ret, level = "debug")]
@@ -467,9 +520,208 @@ impl OperationBatch {
         Ok(outcome)
     }
 
-    async fn submit_serially(self, confirm_queue: &mut OpQueue, metrics: &SerialSubmitterMetrics) {
+    async fn submit_serially(
+        self,
+        confirm_queue: &mut OpQueue,
+        metrics: &SerialSubmitterMetrics,
+    ) -> PendingOperationResult {
+        let mut result = PendingOperationResult::Reprepare;
         for op in self.operations.into_iter() {
-            submit_single_operation(op, confirm_queue, metrics).await;
+            if matches!(
+                submit_single_operation(op, confirm_queue, metrics).await,
+                PendingOperationResult::Success
+            ) {
+                result = PendingOperationResult::Success;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{sync::Mutex as StdMutex, time::Instant};
+
+    use hyperlane_core::{KnownHyperlaneDomain, PendingOperation, TryBatchAs, H256, U256};
+    use prometheus::Opts;
+    use tokio::time::timeout;
+
+    use super::*;
+
+    /// The signer whose submissions always fail
+    const FAILING_SIGNER: usize = 0;
+
+    /// Records which signer submitted it and whether that succeeded
+    #[derive(Debug)]
+    struct MockOperation {
+        id: H256,
+        nonce: u32,
+        destination: HyperlaneDomain,
+        signer_index: usize,
+        submissions: Arc<StdMutex<Vec<(u32, usize, bool)>>>,
+    }
+
+    impl TryBatchAs<HyperlaneMessage> for MockOperation {}
+
+    #[async_trait::async_trait]
+    impl PendingOperation for MockOperation {
+        fn id(&self) -> H256 {
+            self.id
+        }
+
+        fn priority(&self) -> u32 {
+            self.nonce
+        }
+
+        fn origin_domain_id(&self) -> u32 {
+            0
+        }
+
+        fn destination_domain(&self) -> &HyperlaneDomain {
+            &self.destination
+        }
+
+        fn app_context(&self) -> Option<String> {
+            None
         }
+
+        async fn prepare(&mut self) -> PendingOperationResult {
+            unimplemented!()
+        }
+
+        async fn submit(&mut self) -> PendingOperationResult {
+            let succeeded = self.signer_index != FAILING_SIGNER;
+            self.submissions
+                .lock()
+                .unwrap()
+                .push((self.nonce, self.signer_index, succeeded));
+            if succeeded {
+                PendingOperationResult::Success
+            } else {
+                PendingOperationResult::Reprepare
+            }
+        }
+
+        fn set_signer_index(&mut self, signer_index: usize) {
+            self.signer_index = signer_index;
+        }
+
+        fn set_submission_outcome(&mut self, _outcome: TxOutcome) {
+            unimplemented!()
+        }
+
+        fn get_tx_cost_estimate(&self) -> Option<U256> {
+            None
+        }
+
+        async fn confirm(&mut self) -> PendingOperationResult {
+            unimplemented!()
+        }
+
+        fn set_operation_outcome(
+            &mut self,
+            _submission_outcome: TxOutcome,
+            _submission_estimated_cost: U256,
+        ) {
+            unimplemented!()
+        }
+
+        fn next_attempt_after(&self) -> Option<Instant> {
+            None
+        }
+
+        fn set_next_attempt_after(&mut self, _delay: Duration) {}
+
+        fn reset_attempts(&mut self) {}
+
+        fn set_retries(&mut self, _retries: u32) {}
+    }
+
+    fn dummy_metrics() -> SerialSubmitterMetrics {
+        let counter = |name| IntCounter::new(name, "help string").unwrap();
+        SerialSubmitterMetrics {
+            submitter_queue_length: IntGaugeVec::new(
+                Opts::new("submitter_queue_length", "help string"),
+                &["destination", "queue_metrics_label", "app_context"],
+            )
+            .unwrap(),
+            ops_prepared: counter("ops_prepared"),
+            ops_submitted: counter("ops_submitted"),
+            ops_confirmed: counter("ops_confirmed"),
+            ops_failed: counter("ops_failed"),
+            ops_dropped: counter("ops_dropped"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failing_signer_does_not_stop_the_pool() {
+        const OPERATIONS: u32 = 10;
+        let destination = HyperlaneDomain::Known(KnownHyperlaneDomain::Test1);
+        let metrics = dummy_metrics();
+        let (retry_tx, _retry_rx) = tokio::sync::broadcast::channel(1);
+        let queue = |label: &str| {
+            OpQueue::new(
+                metrics.submitter_queue_length.clone(),
+                label.to_owned(),
+                Arc::new(Mutex::new(retry_tx.subscribe())),
+            )
+        };
+        let (submit_queue, confirm_queue) = (queue("submit_queue"), queue("confirm_queue"));
+
+        let submissions = Arc::new(StdMutex::new(vec![]));
+        for nonce in 0..OPERATIONS {
+            submit_queue
+                .push(Box::new(MockOperation {
+                    id: H256::random(),
+                    nonce,
+                    destination: destination.clone(),
+                    signer_index: 0,
+                    submissions: submissions.clone(),
+                }))
+                .await;
+        }
+
+        let submit_tasks = (0..2)
+            .map(|signer_index| {
+                tokio::spawn(submit_task(
+                    destination.clone(),
+                    signer_index,
+                    submit_queue.clone(),
+                    confirm_queue.clone(),
+                    1,
+                    metrics.clone(),
+                    SubmissionPause::default(),
+                    true,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let all_submitted = async {
+            while submissions.lock().unwrap().len() < OPERATIONS as usize {
+                sleep(Duration::from_millis(10)).await;
+            }
+        };
+        timeout(Duration::from_secs(5), all_submitted)
+            .await
+            .expect("the healthy signer stopped submitting");
+        submit_tasks.iter().for_each(|task| task.abort());
+
+        let submissions = submissions.lock().unwrap();
+        let failed = submissions
+            .iter()
+            .filter(|(_, _, succeeded)| !succeeded)
+            .collect::<Vec<_>>();
+        // The failing signer backs off after its first failure, leaving the
+        // rest of the queue to the healthy signer
+        assert!(!failed.is_empty() && failed.len() < 3, "{failed:?}");
+        assert!(failed
+            .iter()
+            .all(|(_, signer_index, _)| *signer_index == FAILING_SIGNER));
+        let mut nonces = submissions
+            .iter()
+            .map(|(nonce, _, _)| *nonce)
+            .collect::<Vec<_>>();
+        nonces.sort();
+        assert_eq!(nonces, (0..OPERATIONS).collect::<Vec<_>>());
     }
 }
@@ -7,13 +7,17 @@ use std::{
 use async_trait::async_trait;
 use derive_new::new;
 use eyre::Result;
-use hyperlane_base::{db::HyperlaneRocksDB, CoreMetrics};
+use hyperlane_base::{
+    db::{unix_timestamp_now, HyperlaneRocksDB, MessageLifecycle},
+    CoreMetrics,
+};
 use hyperlane_core::{
     gas_used_by_operation, make_op_try, BatchItem, ChainCommunicationError, ChainResult,
-    HyperlaneChain, HyperlaneDomain, HyperlaneMessage, Mailbox, MessageSubmissionData,
-    PendingOperation, PendingOperationResult, TryBatchAs, TxOutcome, H256, U256,
+    HyperlaneChain, HyperlaneDomain, HyperlaneMessage, HyperlaneProvider, Mailbox,
+    MessageSubmissionData, PendingOperation, PendingOperationResult, TryBatchAs, TxOutcome, H256,
+    U256,
 };
-use prometheus::{IntCounter, IntGauge};
+use prometheus::{HistogramVec, IntCounter, IntGauge};
 use tracing::{debug, error, info, instrument, trace, warn};
 
 use super::{
@@ -34,8 +38,16 @@ pub const CONFIRM_DELAY: Duration = if cfg!(any(test, feature = "test-utils")) {
 pub struct MessageContext {
     /// Mailbox on the destination chain.
     pub destination_mailbox: Arc<dyn Mailbox>,
+    /// Mailboxes on the destination chain bound to each signer in the
+    /// destination's signer pool, indexed by signer. Falls back to
+    /// `destination_mailbox` for indices that are out of range.
+    pub submission_mailboxes: Vec<Arc<dyn Mailbox>>,
     /// Origin chain database to verify gas payments.
     pub origin_db: HyperlaneRocksDB,
+    /// Origin chain provider used to look up the timestamp of the block a
+    /// message was dispatched in. Only set for Ethereum origins, the other
+    /// protocols don't support looking up blocks by hash.
+    pub origin_provider: Option<Arc<dyn HyperlaneProvider>>,
     /// Used to construct the ISM metadata needed to verify a message from the
     /// origin.
     pub metadata_builder: Arc<BaseMetadataBuilder>,
@@ -66,6 +78,13 @@ pub struct PendingMessage {
     next_attempt_after: Option<Instant>,
     #[new(default)]
     submission_outcome: Option<TxOutcome>,
+    #[new(default)]
+    lifecycle: MessageLifecycle,
+    #[new(default)]
+    signer_index: usize,
+    /// The signer the gas limit in `submission_data` was estimated for.
+    #[new(default)]
+    prepared_signer_index: usize,
 }
 
 impl Debug for PendingMessage {
@@ -108,7 +127,7 @@ impl TryBatchAs<HyperlaneMessage> for PendingMessage {
             Some(data) => Ok(BatchItem::new(
                 self.message.clone(),
                 data.as_ref().clone(),
-                self.ctx.destination_mailbox.clone(),
+                self.submission_mailbox().clone(),
             )),
         }
     }
@@ -149,10 +168,7 @@ impl PendingOperation for PendingMessage {
         // already processed, then mark it as already-processed, and move on to
         // the next tick.
         let is_already_delivered = op_try!(
-            self.ctx
-                .destination_mailbox
-                .delivered(self.message.id())
-                .await,
+            self.submission_mailbox().delivered(self.message.id()).await,
             "checking message delivery status"
         );
         if is_already_delivered {
@@ -162,7 +178,7 @@ impl PendingOperation for PendingMessage {
             return PendingOperationResult::Confirm;
         }
 
-        let provider = self.ctx.destination_mailbox.provider();
+        let provider = self.submission_mailbox().provider();
 
         // We cannot deliver to an address that is not a contract so check and drop if it isn't.
         let is_contract = op_try!(
@@ -178,8 +194,7 @@ impl PendingOperation for PendingMessage {
         }
 
         let ism_address = op_try!(
-            self.ctx
-                .destination_mailbox
+            self.submission_mailbox()
                 .recipient_ism(self.message.recipient)
                 .await,
             "fetching ISM address. Potentially malformed recipient ISM address."
@@ -209,76 +224,73 @@ impl PendingOperation for PendingMessage {
         // likely that gas estimation has failed because the message is
         // reverting. This is defined behavior, so we just log the error and
         // move onto the next tick.
-        let tx_cost_estimate = op_try!(
-            self.ctx
-                .destination_mailbox
-                .process_estimate_costs(&self.message, &metadata)
-                .await,
-            "estimating costs for process call"
-        );
-
-        // If the gas payment requirement hasn't been met, move to the next tick.
         let Some(gas_limit) = op_try!(
-            self.ctx
-                .origin_gas_payment_enforcer
-                .message_meets_gas_payment_requirement(&self.message, &tx_cost_estimate)
-                .await,
-            "checking if message meets gas payment requirement"
+            self.estimate_gas_limit(&metadata).await,
+            "estimating the gas limit of the process call"
         ) else {
-            warn!(?tx_cost_estimate, "Gas payment requirement not met yet");
             return self.on_reprepare();
         };
 
-        // Go ahead and attempt processing of message to destination chain.
-        debug!(
-            ?gas_limit,
-            ?tx_cost_estimate,
-            "Gas payment requirement met, ready to process message"
-        );
-
-        if let Some(max_limit) = self.ctx.transaction_gas_limit {
-            if gas_limit > max_limit {
-                info!("Message delivery estimated gas exceeds max gas limit");
-                return self.on_reprepare();
-            }
-        }
-
         self.submission_data = Some(Box::new(MessageSubmissionData {
             metadata,
             gas_limit,
         }));
+        self.prepared_signer_index = self.signer_index;
+        self.record_lifecycle_stage(|lifecycle| &mut lifecycle.first_prepared_at);
         PendingOperationResult::Success
     }
 
     #[instrument]
-    async fn submit(&mut self) {
+    async fn submit(&mut self) -> PendingOperationResult {
         if self.submitted {
             // this message has already been submitted, possibly not by us
-            return;
+            return PendingOperationResult::Success;
         }
 
-        let state = self
+        let mut state = self
             .submission_data
             .clone()
             .expect("Pending message must be prepared before it can be submitted");
 
+        // The signer is picked once the message is prepared, so the estimate is
+        // redone if the message is submitted by another signer than the one it
+        // was estimated for.
+        if self.signer_index != self.prepared_signer_index {
+            match self.estimate_gas_limit(&state.metadata).await {
+                Ok(Some(gas_limit)) => state.gas_limit = gas_limit,
+                Ok(None) => return self.on_reprepare(),
+                Err(e) => {
+                    warn!(error=?e, "Error when estimating the gas limit of the process call");
+                    return self.on_reprepare();
+                }
+            }
+            self.submission_data = Some(state.clone());
+            self.prepared_signer_index = self.signer_index;
+        }
+
         // We use the estimated gas limit from the prior call to
         // `process_estimate_costs` to avoid a second gas estimation.
         let tx_outcome = self
-            .ctx
-            .destination_mailbox
+            .submission_mailbox()
             .process(&self.message, &state.metadata, Some(state.gas_limit))
             .await;
         match tx_outcome {
             Ok(outcome) => {
                 self.set_operation_outcome(outcome, state.gas_limit);
+                self.record_lifecycle_stage(|lifecycle| &mut lifecycle.first_submitted_at);
+                PendingOperationResult::Success
             }
             Err(e) => {
                 error!(error=?e, "Error when processing message");
+                PendingOperationResult::Reprepare
             }
         }
     }
 
+    fn set_signer_index(&mut self, signer_index: usize) {
+        self.signer_index = signer_index;
+    }
+
     fn set_submission_outcome(&mut self, outcome: TxOutcome) {
         self.submission_outcome = Some(outcome);
     }
@@ -311,6 +323,7 @@ impl PendingOperation for PendingMessage {
                 critical: self.record_message_process_success(),
                 "recording message process success"
             );
+            self.record_delivery_latency().await;
             info!(
                 submission=?self.submission_outcome,
                 "Message successfully processed"
@@ -388,7 +401,8 @@ impl PendingOperation for PendingMessage {
 }
 
 impl PendingMessage {
-    /// Constructor that tries reading the retry count from the HyperlaneDB in order to recompute the `next_attempt_after`.
+    /// Constructor that tries reading the retry count from the HyperlaneDB in order to recompute the `next_attempt_after`,
+    /// along with the lifecycle timestamps recorded so far.
     /// In case of failure, behaves like `Self::new(...)`.
     pub fn from_persisted_retries(
         message: HyperlaneMessage,
@@ -411,9 +425,65 @@ impl PendingMessage {
                 trace!(message_id = ?pm.message.id(), result = ?r, "Failed to read retry count from HyperlaneDB for message.")
             }
         }
+        match pm
+            .ctx
+            .origin_db
+            .retrieve_message_lifecycle_by_message_id(&pm.message.id())
+        {
+            Ok(Some(lifecycle)) => pm.lifecycle = lifecycle,
+            r => {
+                trace!(message_id = ?pm.message.id(), result = ?r, "Failed to read lifecycle from HyperlaneDB for message.")
+            }
+        }
         pm
     }
 
+    /// The destination mailbox bound to the signer this message is submitted
+    /// with.
+    fn submission_mailbox(&self) -> &Arc<dyn Mailbox> {
+        self.ctx
+            .submission_mailboxes
+            .get(self.signer_index)
+            .unwrap_or(&self.ctx.destination_mailbox)
+    }
+
+    /// Estimates the gas limit of processing the message with the mailbox of
+    /// the signer it's submitted with, so that the call is simulated from the
+    /// submitting account. Returns `None` if the gas payment requirement isn't
+    /// met yet or the estimate exceeds the transaction gas limit.
+    async fn estimate_gas_limit(&self, metadata: &[u8]) -> Result<Option<U256>> {
+        let tx_cost_estimate = self
+            .submission_mailbox()
+            .process_estimate_costs(&self.message, metadata)
+            .await?;
+
+        // If the gas payment requirement hasn't been met, move to the next tick.
+        let Some(gas_limit) = self
+            .ctx
+            .origin_gas_payment_enforcer
+            .message_meets_gas_payment_requirement(&self.message, &tx_cost_estimate)
+            .await?
+        else {
+            warn!(?tx_cost_estimate, "Gas payment requirement not met yet");
+            return Ok(None);
+        };
+
+        // Go ahead and attempt processing of message to destination chain.
+        debug!(
+            ?gas_limit,
+            ?tx_cost_estimate,
+            "Gas payment requirement met, ready to process message"
+        );
+
+        if let Some(max_limit) = self.ctx.transaction_gas_limit {
+            if gas_limit > max_limit {
+                info!("Message delivery estimated gas exceeds max gas limit");
+                return Ok(None);
+            }
+        }
+        Ok(Some(gas_limit))
+    }
+
     fn on_reprepare(&mut self) -> PendingOperationResult {
         self.inc_attempts();
         self.submitted = false;
@@ -442,6 +512,88 @@ impl PendingMessage {
         Ok(())
     }
 
+    /// Record the current time for the lifecycle stage selected by `stage`,
+    /// unless that stage was already reached before.
+    fn record_lifecycle_stage(&mut self, stage: fn(&mut MessageLifecycle) -> &mut Option<u64>) {
+        if stage(&mut self.lifecycle).is_none() {
+            self.update_lifecycle(|lifecycle| {
+                stage(lifecycle).get_or_insert_with(unix_timestamp_now);
+            });
+        }
+    }
+
+    fn update_lifecycle(&mut self, update: impl FnOnce(&mut MessageLifecycle)) {
+        match self
+            .ctx
+            .origin_db
+            .update_message_lifecycle(&self.message.id(), update)
+        {
+            Ok(lifecycle) => self.lifecycle = lifecycle,
+            Err(e) => {
+                warn!(message_id = ?self.message.id(), err = %e, "Persisting the lifecycle failed for message")
+            }
+        }
+    }
+
+    /// Record that the message was delivered and observe how long each
+    /// lifecycle stage took to reach, measured from the message's dispatch.
+    /// Without an origin provider the dispatch time is unknown, so only the
+    /// confirmation is recorded.
+    /// `pub(crate)` for testing purposes
+    pub(crate) async fn record_delivery_latency(&mut self) {
+        if self.lifecycle.dispatched_at.is_none() {
+            if let Some(dispatched_at) = self.fetch_dispatch_timestamp().await {
+                self.update_lifecycle(|lifecycle| {
+                    lifecycle.dispatched_at.get_or_insert(dispatched_at);
+                });
+            }
+        }
+        self.record_lifecycle_stage(|lifecycle| &mut lifecycle.confirmed_at);
+
+        let Some(dispatched_at) = self.lifecycle.dispatched_at else {
+            debug!("Dispatch timestamp of message is unknown, not recording its latency");
+            return;
+        };
+        let app_context = self.app_context.as_deref().unwrap_or("Unknown");
+        let stages = [
+            ("indexed", self.lifecycle.indexed_at),
+            ("prepared", self.lifecycle.first_prepared_at),
+            ("submitted", self.lifecycle.first_submitted_at),
+            ("confirmed", self.lifecycle.confirmed_at),
+        ];
+        for (stage, reached_at) in stages {
+            if let Some(reached_at) = reached_at {
+                self.ctx.metrics.observe_lifecycle_latency(
+                    app_context,
+                    stage,
+                    reached_at.saturating_sub(dispatched_at),
+                );
+            }
+        }
+    }
+
+    async fn fetch_dispatch_timestamp(&self) -> Option<u64> {
+        let provider = self.ctx.origin_provider.as_ref()?;
+        let block_hash = match self
+            .ctx
+            .origin_db
+            .retrieve_dispatched_block_hash_by_message_id(&self.message.id())
+        {
+            Ok(block_hash) => block_hash?,
+            Err(e) => {
+                warn!(message_id = ?self.message.id(), err = %e, "Reading the dispatch block hash failed for message");
+                return None;
+            }
+        };
+        match provider.get_block_by_hash(&block_hash).await {
+            Ok(block) => Some(block.timestamp),
+            Err(e) => {
+                warn!(message_id = ?self.message.id(), ?block_hash, err = %e, "Fetching the dispatch block failed for message");
+                None
+            }
+        }
+    }
+
     fn reset_attempts(&mut self) {
         self.set_retries(0);
         self.next_attempt_after = None;
@@ -495,6 +647,9 @@ pub struct MessageSubmissionMetrics {
     // Fields are public for testing purposes
     pub last_known_nonce: IntGauge,
     pub messages_processed: IntCounter,
+    pub message_lifecycle_latency: HistogramVec,
+    pub origin: String,
+    pub destination: String,
 }
 
 impl MessageSubmissionMetrics {
@@ -514,9 +669,18 @@ impl MessageSubmissionMetrics {
             messages_processed: metrics
                 .messages_processed_count()
                 .with_label_values(&[origin, destination]),
+            message_lifecycle_latency: metrics.message_lifecycle_latency(),
+            origin: origin.to_owned(),
+            destination: destination.to_owned(),
         }
     }
 
+    fn observe_lifecycle_latency(&self, app_context: &str, stage: &str, seconds: u64) {
+        self.message_lifecycle_latency
+            .with_label_values(&[&self.origin, &self.destination, app_context, stage])
+            .observe(seconds as f64);
+    }
+
     fn update_nonce(&self, msg: &HyperlaneMessage) {
         // this is technically a race condition between `.get` and `.set` but worst case
         // the gauge should get corrected on the next update and is not an issue
@@ -525,3 +689,95 @@ impl MessageSubmissionMetrics {
             .set(std::cmp::max(self.last_known_nonce.get(), msg.nonce as i64));
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hyperlane_base::db::test_utils;
+    use hyperlane_core::{FixedPointNumber, TxCostEstimate, H512};
+    use hyperlane_test::mocks::MockMailboxContract;
+
+    use super::*;
+    use crate::{
+        msg::processor::test::{
+            dummy_domain, dummy_hyperlane_message, dummy_metadata_builder, dummy_submission_metrics,
+        },
+        settings::GasPaymentEnforcementConf,
+    };
+
+    fn cost_estimate(gas_limit: u32) -> TxCostEstimate {
+        TxCostEstimate {
+            gas_limit: gas_limit.into(),
+            gas_price: FixedPointNumber::zero(),
+            l2_gas_limit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_estimates_gas_with_the_submitting_signer() {
+        test_utils::run_test_db(|db| async move {
+            let origin_domain = dummy_domain(0, "dummy_origin_domain");
+            let destination_domain = dummy_domain(1, "dummy_destination_domain");
+            let db = HyperlaneRocksDB::new(&origin_domain, db);
+
+            // The message was prepared with the first signer, but is submitted by
+            // the second one, so only the second signer's mailbox may be used
+            let mut first_mailbox = MockMailboxContract::new();
+            first_mailbox.expect_process_estimate_costs().never();
+            first_mailbox.expect_process().never();
+            let mut second_mailbox = MockMailboxContract::new();
+            second_mailbox
+                .expect_process_estimate_costs()
+                .times(1)
+                .returning(|_, _| Ok(cost_estimate(150_000)));
+            second_mailbox
+                .expect_process()
+                .withf(|_, _, gas_limit| *gas_limit == Some(150_000.into()))
+                .times(1)
+                .returning(|_, _, _| {
+                    Ok(TxOutcome {
+                        transaction_id: H512::zero(),
+                        executed: true,
+                        gas_used: 120_000.into(),
+                        gas_price: FixedPointNumber::zero(),
+                    })
+                });
+            let first_mailbox: Arc<dyn Mailbox> = Arc::new(first_mailbox);
+            let ctx = Arc::new(MessageContext {
+                destination_mailbox: first_mailbox.clone(),
+                submission_mailboxes: vec![first_mailbox, Arc::new(second_mailbox)],
+                origin_db: db.clone(),
+                origin_provider: None,
+                metadata_builder: Arc::new(dummy_metadata_builder(
+                    &origin_domain,
+                    &destination_domain,
+                    &db,
+                )),
+                origin_gas_payment_enforcer: Arc::new(GasPaymentEnforcer::new(
+                    [GasPaymentEnforcementConf::default()],
+                    db.clone(),
+                )),
+                transaction_gas_limit: None,
+                metrics: dummy_submission_metrics(),
+            });
+
+            let mut pending_message =
+                PendingMessage::new(dummy_hyperlane_message(&destination_domain, 0), ctx, None);
+            pending_message.submission_data = Some(Box::new(MessageSubmissionData {
+                metadata: vec![],
+                gas_limit: 100_000.into(),
+            }));
+            pending_message.set_signer_index(1);
+
+            assert!(matches!(
+                pending_message.submit().await,
+                PendingOperationResult::Success
+            ));
+            assert_eq!(pending_message.prepared_signer_index, 1);
+            assert_eq!(
+                pending_message.submission_data.unwrap().gas_limit,
+                150_000.into()
+            );
+        })
+        .await;
+    }
+}
@@ -1,6 +1,6 @@
 use std::{
     cmp::max,
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt::{Debug, Formatter},
     sync::Arc,
     time::Duration,
@@ -19,22 +19,32 @@ use tokio::sync::mpsc::UnboundedSender;
 use tracing::{debug, instrument, trace};
 
 use super::{metadata::AppContextClassifier, pending_message::*};
-use crate::{processor::ProcessorExt, settings::matching_list::MatchingList};
+use crate::{
+    config_reload::Reloadable, processor::ProcessorExt, settings::matching_list::MatchingList,
+};
 
 /// Finds unprocessed messages from an origin and submits then through a channel
 /// for to the appropriate destination.
 #[allow(clippy::too_many_arguments)]
 pub struct MessageProcessor {
-    whitelist: Arc<MatchingList>,
-    blacklist: Arc<MatchingList>,
+    whitelist: Reloadable<MatchingList>,
+    blacklist: Reloadable<MatchingList>,
     metrics: MessageProcessorMetrics,
     /// channel for each destination chain to send operations (i.e. message
     /// submissions) to
     send_channels: HashMap<u32, UnboundedSender<QueueOperation>>,
     /// Needed context to send a message for each destination chain
     destination_ctxs: HashMap<u32, Arc<MessageContext>>,
-    metric_app_contexts: Vec<(MatchingList, String)>,
+    metric_app_contexts: Reloadable<Vec<(MatchingList, String)>>,
     nonce_iterator: ForwardBackwardIterator,
+    /// The white and blacklist the last tick filtered messages with
+    matching_lists: Option<(Arc<MatchingList>, Arc<MatchingList>)>,
+    /// Nonces of the messages skipped by the white or blacklist. They are
+    /// revisited when either list is reloaded, since the nonce iterator
+    /// never goes back to them.
+    skipped_nonces: NonceRanges,
+    /// Skipped nonces left to revisit after a reload, highest first
+    revisit_nonces: NonceRanges,
 }
 
 #[derive(Debug)]
@@ -213,6 +223,63 @@ enum MessageStatus<T> {
     Processed,
 }
 
+/// A set of nonces kept as disjoint inclusive ranges, so that long runs of
+/// skipped messages, e.g. all messages of a blacklisted sender, take up
+/// constant space.
+#[derive(Debug, Default)]
+struct NonceRanges(BTreeMap<u32, u32>);
+
+impl NonceRanges {
+    fn insert(&mut self, nonce: u32) {
+        self.insert_range(nonce, nonce);
+    }
+
+    /// Inserts the nonces from `start` to `end` inclusive, merging them with
+    /// the overlapping and adjacent ranges.
+    fn insert_range(&mut self, mut start: u32, mut end: u32) {
+        let merged: Vec<(u32, u32)> = self
+            .0
+            .range(..=end.saturating_add(1))
+            .rev()
+            .take_while(|(_, range_end)| range_end.saturating_add(1) >= start)
+            .map(|(range_start, range_end)| (*range_start, *range_end))
+            .collect();
+        for (range_start, range_end) in merged {
+            self.0.remove(&range_start);
+            start = start.min(range_start);
+            end = end.max(range_end);
+        }
+        self.0.insert(start, end);
+    }
+
+    fn extend(&mut self, other: NonceRanges) {
+        for (start, end) in other.0 {
+            self.insert_range(start, end);
+        }
+    }
+
+    /// Removes and returns the highest nonce
+    fn pop_highest(&mut self) -> Option<u32> {
+        let (start, end) = self.0.pop_last()?;
+        if start < end {
+            self.0.insert(start, end - 1);
+        }
+        Some(end)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The number of nonces
+    fn len(&self) -> u64 {
+        self.0
+            .iter()
+            .map(|(start, end)| u64::from(end - start) + 1)
+            .sum()
+    }
+}
+
 impl Debug for MessageProcessor {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -237,8 +304,16 @@ impl ProcessorExt for MessageProcessor {
         // satisfied or the message is disqualified, push the message onto
         // self.tx_msg and then continue the scan at the next highest
         // nonce.
+        let whitelist = self.whitelist.load();
+        let blacklist = self.blacklist.load();
+        self.revisit_skipped_if_reloaded(&whitelist, &blacklist);
+
         // Scan until we find next nonce without delivery confirmation.
-        if let Some(msg) = self.try_get_unprocessed_message().await? {
+        let msg = match self.try_get_revisited_message()? {
+            Some(msg) => Some(msg),
+            None => self.try_get_unprocessed_message().await?,
+        };
+        if let Some(msg) = msg {
             debug!(
                 ?msg,
                 cursor = ?self.nonce_iterator,
@@ -247,14 +322,16 @@ impl ProcessorExt for MessageProcessor {
             let destination = msg.destination;
 
             // Skip if not whitelisted.
-            if !self.whitelist.msg_matches(&msg, true) {
-                debug!(?msg, whitelist=?self.whitelist, "Message not whitelisted, skipping");
+            if !whitelist.msg_matches(&msg, true) {
+                debug!(?msg, ?whitelist, "Message not whitelisted, skipping");
+                self.skipped_nonces.insert(msg.nonce);
                 return Ok(());
             }
 
             // Skip if the message is blacklisted
-            if self.blacklist.msg_matches(&msg, false) {
-                debug!(?msg, blacklist=?self.blacklist, "Message blacklisted, skipping");
+            if blacklist.msg_matches(&msg, false) {
+                debug!(?msg, ?blacklist, "Message blacklisted, skipping");
+                self.skipped_nonces.insert(msg.nonce);
                 return Ok(());
             }
 
@@ -293,12 +370,12 @@ impl ProcessorExt for MessageProcessor {
 impl MessageProcessor {
     pub fn new(
         db: HyperlaneRocksDB,
-        whitelist: Arc<MatchingList>,
-        blacklist: Arc<MatchingList>,
+        whitelist: Reloadable<MatchingList>,
+        blacklist: Reloadable<MatchingList>,
         metrics: MessageProcessorMetrics,
         send_channels: HashMap<u32, UnboundedSender<QueueOperation>>,
         destination_ctxs: HashMap<u32, Arc<MessageContext>>,
-        metric_app_contexts: Vec<(MatchingList, String)>,
+        metric_app_contexts: Reloadable<Vec<(MatchingList, String)>>,
     ) -> Self {
         Self {
             whitelist,
@@ -308,9 +385,50 @@ impl MessageProcessor {
             destination_ctxs,
             metric_app_contexts,
             nonce_iterator: ForwardBackwardIterator::new(Arc::new(db) as Arc<dyn ProcessMessage>),
+            matching_lists: None,
+            skipped_nonces: NonceRanges::default(),
+            revisit_nonces: NonceRanges::default(),
         }
     }
 
+    /// Queues the skipped messages to be filtered again if the white or
+    /// blacklist were reloaded since the last tick.
+    fn revisit_skipped_if_reloaded(
+        &mut self,
+        whitelist: &Arc<MatchingList>,
+        blacklist: &Arc<MatchingList>,
+    ) {
+        let reloaded = match &self.matching_lists {
+            Some((last_whitelist, last_blacklist)) => {
+                !Arc::ptr_eq(last_whitelist, whitelist) || !Arc::ptr_eq(last_blacklist, blacklist)
+            }
+            None => false,
+        };
+        self.matching_lists = Some((whitelist.clone(), blacklist.clone()));
+        if reloaded && !self.skipped_nonces.is_empty() {
+            debug!(
+                skipped = self.skipped_nonces.len(),
+                "Matching lists reloaded, revisiting skipped messages"
+            );
+            self.revisit_nonces
+                .extend(std::mem::take(&mut self.skipped_nonces));
+        }
+    }
+
+    /// The next skipped message to revisit that hasn't been processed since
+    fn try_get_revisited_message(&mut self) -> Result<Option<HyperlaneMessage>> {
+        let db = &self.nonce_iterator.high_nonce_iter.db;
+        while let Some(nonce) = self.revisit_nonces.pop_highest() {
+            if db.retrieve_processed_by_nonce(nonce)?.unwrap_or(false) {
+                continue;
+            }
+            if let Some(msg) = db.retrieve_message_by_nonce(nonce)? {
+                return Ok(Some(msg));
+            }
+        }
+        Ok(None)
+    }
+
     async fn try_get_unprocessed_message(&mut self) -> Result<Option<HyperlaneMessage>> {
         trace!(nonce_iterator=?self.nonce_iterator, "Trying to get the next processor message");
         let next_message = self
@@ -361,7 +479,7 @@ impl MessageProcessorMetrics {
 }
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
     use std::time::Instant;
 
     use crate::{
@@ -378,8 +496,9 @@ mod test {
         db::{test_utils, DbResult, HyperlaneRocksDB},
         settings::{ChainConf, ChainConnectionConf, Settings},
     };
+    use hyperlane_core::H256;
     use hyperlane_test::mocks::{MockMailboxContract, MockValidatorAnnounceContract};
-    use prometheus::{IntCounter, Registry};
+    use prometheus::{HistogramOpts, HistogramVec, IntCounter, Registry};
     use tokio::{
         sync::{
             mpsc::{self, UnboundedReceiver},
@@ -403,10 +522,17 @@ mod test {
         }
     }
 
-    fn dummy_submission_metrics() -> MessageSubmissionMetrics {
+    pub(crate) fn dummy_submission_metrics() -> MessageSubmissionMetrics {
         MessageSubmissionMetrics {
             last_known_nonce: IntGauge::new("last_known_nonce_gauge", "help string").unwrap(),
             messages_processed: IntCounter::new("message_processed_gauge", "help string").unwrap(),
+            message_lifecycle_latency: HistogramVec::new(
+                HistogramOpts::new("message_lifecycle_latency_histogram", "help string"),
+                &["origin", "remote", "app_context", "stage"],
+            )
+            .unwrap(),
+            origin: "dummy_origin".to_owned(),
+            destination: "dummy_destination".to_owned(),
         }
     }
 
@@ -414,7 +540,9 @@ mod test {
         ChainConf {
             domain: domain.clone(),
             signer: Default::default(),
+            additional_signers: Default::default(),
             reorg_period: Default::default(),
+            finality: Default::default(),
             addresses: Default::default(),
             connection: ChainConnectionConf::Ethereum(hyperlane_ethereum::ConnectionConf {
                 rpc_connection: hyperlane_ethereum::RpcConnectionConf::Http {
@@ -422,13 +550,14 @@ mod test {
                 },
                 transaction_overrides: Default::default(),
                 operation_batch: Default::default(),
+                subscription_url: None,
             }),
             metrics_conf: Default::default(),
             index: Default::default(),
         }
     }
 
-    fn dummy_metadata_builder(
+    pub(crate) fn dummy_metadata_builder(
         origin_domain: &HyperlaneDomain,
         destination_domain: &HyperlaneDomain,
         db: &HyperlaneRocksDB,
@@ -446,30 +575,44 @@ mod test {
         let core_metrics = CoreMetrics::new("dummy_relayer", 37582, Registry::new()).unwrap();
         BaseMetadataBuilder::new(
             origin_domain.clone(),
+            H256::zero(),
             destination_chain_conf.clone(),
             Arc::new(RwLock::new(MerkleTreeBuilder::new())),
             Arc::new(MockValidatorAnnounceContract::default()),
             false,
             Arc::new(core_metrics),
             db.clone(),
-            IsmAwareAppContextClassifier::new(Arc::new(MockMailboxContract::default()), vec![]),
+            IsmAwareAppContextClassifier::new(
+                Arc::new(MockMailboxContract::default()),
+                Default::default(),
+            ),
         )
     }
 
-    fn dummy_message_processor(
+    fn dummy_message_context(
         origin_domain: &HyperlaneDomain,
         destination_domain: &HyperlaneDomain,
         db: &HyperlaneRocksDB,
-    ) -> (MessageProcessor, UnboundedReceiver<QueueOperation>) {
+    ) -> Arc<MessageContext> {
         let base_metadata_builder = dummy_metadata_builder(origin_domain, destination_domain, db);
-        let message_context = Arc::new(MessageContext {
+        Arc::new(MessageContext {
             destination_mailbox: Arc::new(MockMailboxContract::default()),
+            submission_mailboxes: vec![],
             origin_db: db.clone(),
+            origin_provider: None,
             metadata_builder: Arc::new(base_metadata_builder),
             origin_gas_payment_enforcer: Arc::new(GasPaymentEnforcer::new([], db.clone())),
             transaction_gas_limit: Default::default(),
             metrics: dummy_submission_metrics(),
-        });
+        })
+    }
+
+    fn dummy_message_processor(
+        origin_domain: &HyperlaneDomain,
+        destination_domain: &HyperlaneDomain,
+        db: &HyperlaneRocksDB,
+    ) -> (MessageProcessor, UnboundedReceiver<QueueOperation>) {
+        let message_context = dummy_message_context(origin_domain, destination_domain, db);
 
         let (send_channel, receive_channel) = mpsc::unbounded_channel::<QueueOperation>();
         (
@@ -480,13 +623,16 @@ mod test {
                 dummy_processor_metrics(origin_domain.id()),
                 HashMap::from([(destination_domain.id(), send_channel)]),
                 HashMap::from([(destination_domain.id(), message_context)]),
-                vec![],
+                Default::default(),
             ),
             receive_channel,
         )
     }
 
-    fn dummy_hyperlane_message(destination: &HyperlaneDomain, nonce: u32) -> HyperlaneMessage {
+    pub(crate) fn dummy_hyperlane_message(
+        destination: &HyperlaneDomain,
+        nonce: u32,
+    ) -> HyperlaneMessage {
         HyperlaneMessage {
             version: Default::default(),
             nonce,
@@ -507,7 +653,7 @@ mod test {
         }
     }
 
-    fn dummy_domain(domain_id: u32, name: &str) -> HyperlaneDomain {
+    pub(crate) fn dummy_domain(domain_id: u32, name: &str) -> HyperlaneDomain {
         let test_domain = HyperlaneDomain::new_test_domain(name);
         HyperlaneDomain::Unknown {
             domain_id,
@@ -579,6 +725,38 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_nonce_ranges() {
+        let mut nonces = NonceRanges::default();
+        for nonce in [5, 3, 4, 10, 4, 12, 11, 0, u32::MAX] {
+            nonces.insert(nonce);
+        }
+        assert_eq!(
+            nonces.0,
+            BTreeMap::from([(0, 0), (3, 5), (10, 12), (u32::MAX, u32::MAX)])
+        );
+        assert_eq!(nonces.len(), 8);
+
+        let mut other = NonceRanges::default();
+        other.insert_range(1, 2);
+        other.insert_range(7, 9);
+        nonces.extend(other);
+        assert_eq!(
+            nonces.0,
+            BTreeMap::from([(0, 5), (7, 12), (u32::MAX, u32::MAX)])
+        );
+
+        let mut popped = vec![];
+        while let Some(nonce) = nonces.pop_highest() {
+            popped.push(nonce);
+        }
+        assert_eq!(
+            popped,
+            vec![u32::MAX, 12, 11, 10, 9, 8, 7, 5, 4, 3, 2, 1, 0]
+        );
+        assert!(nonces.is_empty());
+    }
+
     #[tokio::test]
     async fn test_full_pending_message_persistence_flow() {
         test_utils::run_test_db(|db| async move {
@@ -634,6 +812,84 @@ mod test {
         .await;
     }
 
+    #[tokio::test]
+    async fn test_delivery_latency_without_origin_provider() {
+        test_utils::run_test_db(|db| async move {
+            let origin_domain = dummy_domain(0, "dummy_origin_domain");
+            let destination_domain = dummy_domain(1, "dummy_destination_domain");
+            let db = HyperlaneRocksDB::new(&origin_domain, db);
+            // Like for non-Ethereum origins, there is no origin provider to
+            // look up the dispatch block with
+            let ctx = dummy_message_context(&origin_domain, &destination_domain, &db);
+            assert!(ctx.origin_provider.is_none());
+            let message = dummy_hyperlane_message(&destination_domain, 0);
+            add_db_entry(&db, &message, 0);
+            let confirmed_latency = || {
+                ctx.metrics.message_lifecycle_latency.with_label_values(&[
+                    "dummy_origin",
+                    "dummy_destination",
+                    "Unknown",
+                    "confirmed",
+                ])
+            };
+
+            let mut pm = PendingMessage::from_persisted_retries(message.clone(), ctx.clone(), None);
+            pm.record_delivery_latency().await;
+            let lifecycle = db
+                .retrieve_message_lifecycle_by_message_id(&message.id())
+                .unwrap()
+                .unwrap();
+            assert!(lifecycle.confirmed_at.is_some());
+            assert_eq!(lifecycle.dispatched_at, None);
+            assert_eq!(confirmed_latency().get_sample_count(), 0);
+
+            // Once the dispatch time is known, the latency is observed
+            db.update_message_lifecycle(&message.id(), |lifecycle| {
+                lifecycle.dispatched_at = lifecycle.confirmed_at.map(|t| t - 10);
+            })
+            .unwrap();
+            let mut pm = PendingMessage::from_persisted_retries(message, ctx.clone(), None);
+            pm.record_delivery_latency().await;
+            assert_eq!(confirmed_latency().get_sample_count(), 1);
+            assert_eq!(confirmed_latency().get_sample_sum(), 10.);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_revisits_skipped_messages_after_reload() {
+        test_utils::run_test_db(|db| async move {
+            let origin_domain = dummy_domain(0, "dummy_origin_domain");
+            let destination_domain = dummy_domain(1, "dummy_destination_domain");
+            let db = HyperlaneRocksDB::new(&origin_domain, db);
+            let message_context = dummy_message_context(&origin_domain, &destination_domain, &db);
+            let (send_channel, mut receive_channel) = mpsc::unbounded_channel::<QueueOperation>();
+            let whitelist: Reloadable<MatchingList> =
+                Reloadable::new(serde_json::from_str(r#"[{"destinationdomain": 2}]"#).unwrap());
+            let mut processor = MessageProcessor::new(
+                db.clone(),
+                whitelist.clone(),
+                Default::default(),
+                dummy_processor_metrics(origin_domain.id()),
+                HashMap::from([(destination_domain.id(), send_channel)]),
+                HashMap::from([(destination_domain.id(), message_context)]),
+                Default::default(),
+            );
+            let message = dummy_hyperlane_message(&destination_domain, 0);
+            add_db_entry(&db, &message, 0);
+
+            processor.tick().await.unwrap();
+            assert!(receive_channel.try_recv().is_err());
+
+            // Widening the whitelist sends the skipped message, although the
+            // nonce iterator has moved past it
+            whitelist.store(MatchingList::default());
+            processor.tick().await.unwrap();
+            assert_eq!(receive_channel.try_recv().unwrap().id(), message.id());
+        })
+        .await;
+    }
+
     #[tokio::test]
     async fn test_forward_backward_iterator() {
         let mut mock_db = MockDb::new();
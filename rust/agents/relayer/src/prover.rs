@@ -15,6 +15,9 @@ use tracing::{error, instrument};
 pub struct Prover {
     count: usize,
     tree: MerkleTree,
+    /// Number of leaves that are only known by the hashes of their subtrees,
+    /// see `from_branch`
+    pruned_count: usize,
 }
 
 /// Prover Errors
@@ -31,6 +34,16 @@ pub enum ProverError {
         /// The number of leaves
         count: usize,
     },
+    /// Requested proof for a leaf that was pruned from the tree
+    #[error(
+        "Requested proof for a pruned leaf. Requested: {index}. Tree has pruned: {pruned_count}"
+    )]
+    PrunedProof {
+        /// The index requested
+        index: usize,
+        /// The number of pruned leaves
+        pruned_count: usize,
+    },
     /// Bubbled up from underlying
     #[error(transparent)]
     MerkleTreeError(#[from] MerkleTreeError),
@@ -51,11 +64,23 @@ impl Default for Prover {
         Self {
             count: 0,
             tree: full,
+            pruned_count: 0,
         }
     }
 }
 
 impl Prover {
+    /// Restore a tree of `count` leaves from the branch of an incremental
+    /// merkle tree of the same size. The restored leaves are pruned, so only
+    /// leaves ingested afterwards can be proven.
+    pub fn from_branch(branch: &[H256; TREE_DEPTH], count: usize) -> Self {
+        Self {
+            count,
+            tree: MerkleTree::from_branch(branch, count, TREE_DEPTH),
+            pruned_count: count,
+        }
+    }
+
     /// Push a leaf to the tree. Appends it to the first unoccupied slot
     ///
     /// This will fail if the underlying tree is full.
@@ -75,6 +100,11 @@ impl Prover {
         self.count
     }
 
+    /// Return the number of leaves that were pruned, which can't be proven
+    pub fn pruned_count(&self) -> usize {
+        self.pruned_count
+    }
+
     /// Create a proof of a leaf in this tree.
     #[instrument(err, skip(self), fields(prover_msg_count=self.count()))]
     pub fn prove_against_previous(
@@ -92,6 +122,12 @@ impl Prover {
                 count,
             });
         }
+        if leaf_index < self.pruned_count {
+            return Err(ProverError::PrunedProof {
+                index: leaf_index,
+                pruned_count: self.pruned_count,
+            });
+        }
         Ok(self.tree.prove_against_previous(leaf_index, root_index))
     }
 
@@ -117,6 +153,7 @@ where
         Self {
             count: slice.len(),
             tree: MerkleTree::create(slice, TREE_DEPTH),
+            pruned_count: 0,
         }
     }
 }
@@ -143,7 +180,7 @@ impl std::iter::Extend<H256> for Prover {
 mod test {
     use ethers::utils::hash_message;
 
-    use hyperlane_core::test_utils;
+    use hyperlane_core::{accumulator::incremental::IncrementalMerkle, test_utils};
 
     use super::*;
 
@@ -177,4 +214,37 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn it_proves_leaves_after_restoring_from_branch() {
+        let leaves: Vec<H256> = (0..20).map(|_| H256::random()).collect();
+        let full = Prover::from(&leaves);
+
+        let mut incremental = IncrementalMerkle::default();
+        leaves[..11]
+            .iter()
+            .for_each(|leaf| incremental.ingest(*leaf));
+        let mut restored = Prover::from_branch(&incremental.branch, 11);
+        assert_eq!(restored.root(), incremental.root());
+        restored.extend(leaves[11..].iter().copied());
+        assert_eq!(restored.root(), full.root());
+
+        for leaf_index in 11..leaves.len() {
+            for root_index in leaf_index..leaves.len() {
+                assert_eq!(
+                    restored
+                        .prove_against_previous(leaf_index, root_index)
+                        .unwrap(),
+                    full.prove_against_previous(leaf_index, root_index).unwrap()
+                );
+            }
+        }
+        assert!(matches!(
+            restored.prove_against_previous(10, 19),
+            Err(ProverError::PrunedProof {
+                index: 10,
+                pruned_count: 11
+            })
+        ));
+    }
 }
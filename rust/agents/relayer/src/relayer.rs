@@ -1,7 +1,9 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt::{Debug, Formatter},
+    num::NonZeroU64,
     sync::Arc,
+    time::Duration,
 };
 
 use async_trait::async_trait;
@@ -16,8 +18,8 @@ use hyperlane_base::{
     SyncOptions,
 };
 use hyperlane_core::{
-    HyperlaneDomain, HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion, QueueOperation,
-    H512, U256,
+    HyperlaneDomain, HyperlaneDomainProtocol, HyperlaneMessage, InterchainGasPayment,
+    MerkleTreeHook, MerkleTreeInsertion, QueueOperation, H512, U256,
 };
 use tokio::{
     sync::{
@@ -31,15 +33,17 @@ use tokio_metrics::TaskMonitor;
 use tracing::{error, info, info_span, instrument::Instrumented, warn, Instrument};
 
 use crate::{
+    config_reload::{ConfigReloader, Reloadable},
     merkle_tree::builder::MerkleTreeBuilder,
     msg::{
+        balance_guard::{SignerBalanceGuard, SubmissionPause},
         gas_payment::GasPaymentEnforcer,
         metadata::{BaseMetadataBuilder, IsmAwareAppContextClassifier},
         op_submitter::{SerialSubmitter, SerialSubmitterMetrics},
         pending_message::{MessageContext, MessageSubmissionMetrics},
         processor::{MessageProcessor, MessageProcessorMetrics},
     },
-    server::{self as relayer_server, MessageRetryRequest},
+    server::{self as relayer_server, MerkleProofSource, MessageRetryRequest},
     settings::{matching_list::MatchingList, RelayerSettings},
 };
 use crate::{
@@ -69,13 +73,24 @@ pub struct Relayer {
     msg_ctxs: HashMap<ContextKey, Arc<MessageContext>>,
     prover_syncs: HashMap<HyperlaneDomain, Arc<RwLock<MerkleTreeBuilder>>>,
     merkle_tree_hook_syncs: HashMap<HyperlaneDomain, Arc<dyn ContractSyncer<MerkleTreeInsertion>>>,
+    /// Used to check the locally built merkle trees against the onchain ones
+    merkle_tree_hooks: HashMap<HyperlaneDomain, Arc<dyn MerkleTreeHook>>,
     dbs: HashMap<HyperlaneDomain, HyperlaneRocksDB>,
-    whitelist: Arc<MatchingList>,
-    blacklist: Arc<MatchingList>,
+    whitelist: Reloadable<MatchingList>,
+    blacklist: Reloadable<MatchingList>,
     transaction_gas_limit: Option<U256>,
     skip_transaction_gas_limit_for: HashSet<u32>,
     allow_local_checkpoint_syncers: bool,
-    metric_app_contexts: Vec<(MatchingList, String)>,
+    metric_app_contexts: Reloadable<Vec<(MatchingList, String)>>,
+    /// Swaps reloaded settings into the whitelist, blacklist, metric app
+    /// contexts and gas payment enforcers
+    config_reloader: Arc<ConfigReloader>,
+    config_reload_interval: Option<Duration>,
+    admin_token: Option<String>,
+    /// Pause state of each signer in the signer pool of each destination chain
+    submission_pauses: HashMap<HyperlaneDomain, Vec<SubmissionPause>>,
+    /// Guards pausing submissions while a destination's signer balance is low
+    signer_balance_guards: Vec<SignerBalanceGuard>,
     core_metrics: Arc<CoreMetrics>,
     // TODO: decide whether to consolidate `agent_metrics` and `chain_metrics` into a single struct
     // or move them in `core_metrics`, like the validator metrics
@@ -132,6 +147,20 @@ impl BaseAgent for Relayer {
         let validator_announces = settings
             .build_validator_announces(settings.origin_chains.iter(), &core_metrics)
             .await?;
+        let merkle_tree_hooks = settings
+            .build_merkle_tree_hooks(settings.origin_chains.iter(), &core_metrics)
+            .await?;
+        // Only used to look up dispatch block timestamps for message lifecycle
+        // metrics, which other protocols' providers don't support yet.
+        let origin_providers = settings
+            .build_providers(
+                settings
+                    .origin_chains
+                    .iter()
+                    .filter(|origin| origin.domain_protocol() == HyperlaneDomainProtocol::Ethereum),
+                &core_metrics,
+            )
+            .await?;
 
         let contract_sync_metrics = Arc::new(ContractSyncMetrics::new(&core_metrics));
 
@@ -177,8 +206,9 @@ impl BaseAgent for Relayer {
             .map(|(k, v)| (k, v as _))
             .collect();
 
-        let whitelist = Arc::new(settings.whitelist);
-        let blacklist = Arc::new(settings.blacklist);
+        let whitelist = Reloadable::new(settings.whitelist.clone());
+        let blacklist = Reloadable::new(settings.blacklist.clone());
+        let metric_app_contexts = Reloadable::new(settings.metric_app_contexts.clone());
         let skip_transaction_gas_limit_for = settings.skip_transaction_gas_limit_for;
         let transaction_gas_limit = settings.transaction_gas_limit;
 
@@ -190,17 +220,15 @@ impl BaseAgent for Relayer {
             "Whitelist configuration"
         );
 
-        // provers by origin chain
+        // provers by origin chain, resumed from their latest snapshots
         let prover_syncs = settings
             .origin_chains
             .iter()
             .map(|origin| {
-                (
-                    origin.clone(),
-                    Arc::new(RwLock::new(MerkleTreeBuilder::new())),
-                )
+                let builder = MerkleTreeBuilder::from_snapshot(&dbs[origin])?;
+                Ok((origin.clone(), Arc::new(RwLock::new(builder))))
             })
-            .collect::<HashMap<_, _>>();
+            .collect::<Result<HashMap<_, _>>>()?;
 
         info!(gas_enforcement_policies=?settings.gas_payment_enforcement, "Gas enforcement configuration");
 
@@ -220,6 +248,65 @@ impl BaseAgent for Relayer {
             })
             .collect();
 
+        let config_reloader = Arc::new(ConfigReloader::new(
+            &settings,
+            whitelist.clone(),
+            blacklist.clone(),
+            metric_app_contexts.clone(),
+            gas_payment_enforcers.values().cloned().collect(),
+        ));
+
+        for destination in settings.minimum_signer_balances.keys() {
+            if !settings.destination_chains.contains(destination) {
+                warn!(%destination, "Minimum signer balance configured for a chain that isn't relayed to");
+            }
+        }
+        // Each destination's signer pool gets a mailbox and a pause flag per signer, so
+        // that every signer can submit, and be paused, independently of the others.
+        let mut submission_mailboxes = HashMap::new();
+        let mut submission_pauses = HashMap::new();
+        let mut signer_balance_guards = vec![];
+        for destination in &settings.destination_chains {
+            let chain_conf = core.settings.chain_setup(destination)?;
+            let minimum_balance = settings.minimum_signer_balances.get(destination);
+            let mut pool_mailboxes = vec![];
+            let mut pool_pauses = vec![];
+            for (signer_index, signer_conf) in chain_conf.signer_pool().iter().enumerate() {
+                // The destination mailbox already signs with the chain's primary signer
+                if (signer_index == 0 && chain_conf.signer.is_some())
+                    || signer_conf.signer.is_none()
+                {
+                    pool_mailboxes.push(mailboxes[destination].clone());
+                } else {
+                    pool_mailboxes.push(signer_conf.build_mailbox(&core_metrics).await?.into());
+                }
+                let pause = SubmissionPause::default();
+                pool_pauses.push(pause.clone());
+                if let Some(minimum_balance) = minimum_balance {
+                    let Some(signer_address) = signer_conf
+                        .agent_metrics_conf(Self::AGENT_NAME.to_string())
+                        .await?
+                        .address
+                    else {
+                        warn!(%destination, "Minimum signer balance configured for a chain without a signer");
+                        continue;
+                    };
+                    signer_balance_guards.push(SignerBalanceGuard::new(
+                        destination.clone(),
+                        signer_conf.build_provider(&core_metrics).await?,
+                        signer_address.clone(),
+                        *minimum_balance,
+                        pause,
+                        core_metrics
+                            .submitter_paused()
+                            .with_label_values(&[destination.name(), &signer_address]),
+                    ));
+                }
+            }
+            submission_mailboxes.insert(destination.clone(), pool_mailboxes);
+            submission_pauses.insert(destination.clone(), pool_pauses);
+        }
+
         let mut msg_ctxs = HashMap::new();
         let mut destination_chains = HashMap::new();
         for destination in &settings.destination_chains {
@@ -236,6 +323,7 @@ impl BaseAgent for Relayer {
                 let db = dbs.get(origin).unwrap().clone();
                 let metadata_builder = BaseMetadataBuilder::new(
                     origin.clone(),
+                    core.settings.chain_setup(origin).unwrap().addresses.mailbox,
                     destination_chain_setup.clone(),
                     prover_syncs[origin].clone(),
                     validator_announces[origin].clone(),
@@ -244,7 +332,7 @@ impl BaseAgent for Relayer {
                     db,
                     IsmAwareAppContextClassifier::new(
                         mailboxes[destination].clone(),
-                        settings.metric_app_contexts.clone(),
+                        metric_app_contexts.clone(),
                     ),
                 );
 
@@ -255,7 +343,9 @@ impl BaseAgent for Relayer {
                     },
                     Arc::new(MessageContext {
                         destination_mailbox: mailboxes[destination].clone(),
+                        submission_mailboxes: submission_mailboxes[destination].clone(),
                         origin_db: dbs.get(origin).unwrap().clone(),
+                        origin_provider: origin_providers.get(origin).cloned(),
                         metadata_builder: Arc::new(metadata_builder),
                         origin_gas_payment_enforcer: gas_payment_enforcers[origin].clone(),
                         transaction_gas_limit,
@@ -275,12 +365,18 @@ impl BaseAgent for Relayer {
             interchain_gas_payment_syncs,
             prover_syncs,
             merkle_tree_hook_syncs,
+            merkle_tree_hooks,
             whitelist,
             blacklist,
             transaction_gas_limit,
             skip_transaction_gas_limit_for,
             allow_local_checkpoint_syncers: settings.allow_local_checkpoint_syncers,
-            metric_app_contexts: settings.metric_app_contexts,
+            metric_app_contexts,
+            config_reloader,
+            config_reload_interval: settings.config_reload_interval,
+            admin_token: settings.admin_token,
+            submission_pauses,
+            signer_balance_guards,
             core_metrics,
             agent_metrics,
             chain_metrics,
@@ -306,7 +402,33 @@ impl BaseAgent for Relayer {
 
         // run server
         let sender = Sender::<MessageRetryRequest>::new(ENDPOINT_MESSAGES_QUEUE_SIZE);
-        let custom_routes = relayer_server::routes(sender.clone());
+        let submission_pauses = self
+            .submission_pauses
+            .iter()
+            .map(|(destination, pauses)| (destination.name().to_owned(), pauses.clone()))
+            .collect::<BTreeMap<_, _>>();
+        let merkle_proof_sources = self
+            .prover_syncs
+            .iter()
+            .map(|(origin, prover_sync)| {
+                (
+                    origin.id(),
+                    MerkleProofSource::new(self.dbs[origin].clone(), prover_sync.clone()),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let config_reload = self
+            .admin_token
+            .clone()
+            .map(|admin_token| (self.config_reloader.clone(), admin_token));
+        let custom_routes = relayer_server::routes(
+            sender.clone(),
+            submission_pauses,
+            merkle_proof_sources,
+            self.dbs.values().cloned().collect(),
+            self.metric_app_contexts.clone(),
+            config_reload,
+        );
 
         let server = self
             .core
@@ -318,6 +440,14 @@ impl BaseAgent for Relayer {
             .instrument(info_span!("Relayer server"));
         tasks.push(server_task);
 
+        if let Some(interval) = self.config_reload_interval {
+            tasks.push(self.config_reloader.clone().spawn_watcher(interval));
+        }
+
+        for guard in std::mem::take(&mut self.signer_balance_guards) {
+            tasks.push(guard.spawn());
+        }
+
         // send channels by destination chain
         let mut send_channels = HashMap::with_capacity(self.destination_chains.len());
         for (dest_domain, dest_conf) in &self.destination_chains {
@@ -339,16 +469,19 @@ impl BaseAgent for Relayer {
                 ),
             );
 
-            let metrics_updater = MetricsUpdater::new(
-                dest_conf,
-                self.core_metrics.clone(),
-                self.agent_metrics.clone(),
-                self.chain_metrics.clone(),
-                Self::AGENT_NAME.to_string(),
-            )
-            .await
-            .unwrap();
-            tasks.push(metrics_updater.spawn());
+            // Report the balance and nonce of every signer in the pool
+            for signer_conf in dest_conf.signer_pool() {
+                let metrics_updater = MetricsUpdater::new(
+                    &signer_conf,
+                    self.core_metrics.clone(),
+                    self.agent_metrics.clone(),
+                    self.chain_metrics.clone(),
+                    Self::AGENT_NAME.to_string(),
+                )
+                .await
+                .unwrap();
+                tasks.push(metrics_updater.spawn());
+            }
         }
 
         for origin in &self.origin_chains {
@@ -511,6 +644,8 @@ impl Relayer {
             self.dbs.get(origin).unwrap().clone(),
             metrics,
             self.prover_syncs[origin].clone(),
+            self.merkle_tree_hooks[origin].clone(),
+            NonZeroU64::new(self.core.settings.chains[origin.name()].reorg_period as u64),
         );
 
         let span = info_span!("MerkleTreeProcessor", origin=%merkle_tree_processor.domain());
@@ -535,6 +670,7 @@ impl Relayer {
             SerialSubmitterMetrics::new(&self.core.metrics, destination),
             batch_size,
             task_monitor.clone(),
+            self.submission_pauses[destination].clone(),
         );
         let span = info_span!("SerialSubmitter", destination=%destination);
         let destination = destination.clone();
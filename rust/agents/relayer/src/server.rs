@@ -1,22 +1,55 @@
 use axum::{
     extract::{Query, State},
-    routing, Router,
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+    routing, Json, Router,
 };
 use derive_new::new;
-use hyperlane_core::{ChainCommunicationError, QueueOperation, H256};
-use serde::Deserialize;
-use std::str::FromStr;
-use tokio::sync::broadcast::Sender;
+use hyperlane_base::db::HyperlaneRocksDB;
+use hyperlane_core::{accumulator::merkle::Proof, ChainCommunicationError, QueueOperation, H256};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+use tokio::sync::{broadcast::Sender, RwLock};
+
+use crate::{
+    config_reload::{ConfigReloader, Reloadable},
+    gas_report::{gas_report, GasReport, DEFAULT_WORST_MESSAGES},
+    merkle_tree::builder::MerkleTreeBuilder,
+    msg::balance_guard::SubmissionPause,
+    settings::matching_list::MatchingList,
+};
 
 const MESSAGE_RETRY_API_BASE: &str = "/message_retry";
+const SUBMITTER_STATUS_API_BASE: &str = "/submitter_status";
+const MERKLE_PROOF_API_BASE: &str = "/merkle_proof";
+const CONFIG_RELOAD_API_BASE: &str = "/config_reload";
+const GAS_REPORT_API_BASE: &str = "/gas_report";
 pub const ENDPOINT_MESSAGES_QUEUE_SIZE: usize = 1_000;
 
 /// Returns a vector of agent-specific endpoint routes to be served.
 /// Can be extended with additional routes and feature flags to enable/disable individually.
-pub fn routes(tx: Sender<MessageRetryRequest>) -> Vec<(&'static str, Router)> {
+pub fn routes(
+    tx: Sender<MessageRetryRequest>,
+    submission_pauses: BTreeMap<String, Vec<SubmissionPause>>,
+    merkle_proof_sources: BTreeMap<u32, MerkleProofSource>,
+    origin_dbs: Vec<HyperlaneRocksDB>,
+    metric_app_contexts: Reloadable<Vec<(MatchingList, String)>>,
+    config_reload: Option<(Arc<ConfigReloader>, String)>,
+) -> Vec<(&'static str, Router)> {
     let message_retry_api = MessageRetryApi::new(tx);
+    let submitter_status_api = SubmitterStatusApi::new(submission_pauses);
+    let merkle_proof_api = MerkleProofApi::new(merkle_proof_sources);
 
-    vec![message_retry_api.get_route()]
+    let mut routes = vec![
+        message_retry_api.get_route(),
+        submitter_status_api.get_route(),
+        merkle_proof_api.get_route(),
+    ];
+    // The admin endpoints are only served when an admin token is configured
+    if let Some((reloader, admin_token)) = config_reload {
+        routes.push(ConfigReloadApi::new(reloader, admin_token.clone()).get_route());
+        routes.push(GasReportApi::new(origin_dbs, metric_app_contexts, admin_token).get_route());
+    }
+    routes
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -102,11 +135,279 @@ impl MessageRetryApi {
     }
 }
 
+/// Reports, per destination chain name, whether its submitter is paused
+#[derive(new, Clone)]
+pub struct SubmitterStatusApi {
+    submission_pauses: BTreeMap<String, Vec<SubmissionPause>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct SubmitterStatus {
+    /// Whether any of the destination's signers is paused. With a single
+    /// signer, this means the destination's submissions are paused.
+    paused: bool,
+    /// Pause state of each signer in the destination's signer pool
+    signers_paused: Vec<bool>,
+}
+
+async fn submitter_status(
+    State(submission_pauses): State<BTreeMap<String, Vec<SubmissionPause>>>,
+) -> Json<BTreeMap<String, SubmitterStatus>> {
+    Json(
+        submission_pauses
+            .iter()
+            .map(|(destination, pauses)| {
+                let signers_paused = pauses.iter().map(|p| p.is_paused()).collect::<Vec<_>>();
+                (
+                    destination.clone(),
+                    SubmitterStatus {
+                        paused: signers_paused.iter().any(|paused| *paused),
+                        signers_paused,
+                    },
+                )
+            })
+            .collect(),
+    )
+}
+
+impl SubmitterStatusApi {
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/", routing::get(submitter_status))
+            .with_state(self.submission_pauses.clone())
+    }
+
+    pub fn get_route(&self) -> (&'static str, Router) {
+        (SUBMITTER_STATUS_API_BASE, self.router())
+    }
+}
+
+/// Where the merkle proofs for messages from an origin chain come from
+#[derive(new, Clone)]
+pub struct MerkleProofSource {
+    db: HyperlaneRocksDB,
+    prover_sync: Arc<RwLock<MerkleTreeBuilder>>,
+}
+
+/// Serves merkle proofs of dispatched messages from the relayer's local
+/// merkle trees, keyed by origin domain id
+#[derive(new, Clone)]
+pub struct MerkleProofApi {
+    sources: BTreeMap<u32, MerkleProofSource>,
+}
+
+#[derive(Deserialize)]
+struct RawMerkleProofRequest {
+    origin_domain: u32,
+    message_id: String,
+    /// Index of the last leaf of the tree to prove against, defaults to the
+    /// latest leaf ingested by the relayer
+    root_index: Option<u32>,
+}
+
+/// Same layout as `vectors/proof.json`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct MerkleProofResponse {
+    proof: Proof,
+    root: H256,
+}
+
+async fn merkle_proof(
+    State(sources): State<BTreeMap<u32, MerkleProofSource>>,
+    Query(request): Query<RawMerkleProofRequest>,
+) -> Result<Json<MerkleProofResponse>, (StatusCode, String)> {
+    let source = sources.get(&request.origin_domain).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            format!("Unknown origin domain {}", request.origin_domain),
+        )
+    })?;
+    let message_id = H256::from_str(&request.message_id).map_err(|err| {
+        (
+            StatusCode::BAD_REQUEST,
+            format!("Failed to parse message id: {}", err),
+        )
+    })?;
+    let leaf_index = source
+        .db
+        .retrieve_merkle_leaf_index_by_message_id(&message_id)
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                format!(
+                    "No merkle tree insertion found for message {:?}",
+                    message_id
+                ),
+            )
+        })?;
+
+    let prover_sync = source.prover_sync.read().await;
+    let count = prover_sync.count();
+    if leaf_index >= count {
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!(
+                "Leaf {} has not been added to the local merkle tree yet, it has {} leaves",
+                leaf_index, count
+            ),
+        ));
+    }
+    let root_index = request.root_index.unwrap_or(count - 1);
+    if root_index < leaf_index || root_index >= count {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Root index must be between the leaf index {} and the latest index {}",
+                leaf_index,
+                count - 1
+            ),
+        ));
+    }
+    let proof = prover_sync
+        .get_proof(leaf_index, root_index)
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?;
+
+    Ok(Json(MerkleProofResponse {
+        root: proof.root(),
+        proof,
+    }))
+}
+
+impl MerkleProofApi {
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/", routing::get(merkle_proof))
+            .with_state(self.sources.clone())
+    }
+
+    pub fn get_route(&self) -> (&'static str, Router) {
+        (MERKLE_PROOF_API_BASE, self.router())
+    }
+}
+
+/// Serves the gas payment reconciliation report of the relayer's database, see
+/// [`gas_report`]. Building the report reads every message from the database,
+/// so requests must carry the admin token as a bearer token.
+#[derive(new, Clone)]
+pub struct GasReportApi {
+    dbs: Vec<HyperlaneRocksDB>,
+    app_contexts: Reloadable<Vec<(MatchingList, String)>>,
+    admin_token: String,
+}
+
+#[derive(Deserialize)]
+struct RawGasReportRequest {
+    /// Number of worst paying messages to list
+    limit: Option<usize>,
+}
+
+async fn get_gas_report(
+    State(api): State<GasReportApi>,
+    headers: HeaderMap,
+    Query(request): Query<RawGasReportRequest>,
+) -> Result<Json<GasReport>, (StatusCode, String)> {
+    authorize(&headers, &api.admin_token)?;
+    let limit = request.limit.unwrap_or(DEFAULT_WORST_MESSAGES);
+    // The report reads every message from the db, so keep it off the runtime
+    let report =
+        tokio::task::spawn_blocking(move || gas_report(&api.dbs, &api.app_contexts.load(), limit))
+            .await
+            .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?
+            .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?;
+    Ok(Json(report))
+}
+
+impl GasReportApi {
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/", routing::get(get_gas_report))
+            .with_state(self.clone())
+    }
+
+    pub fn get_route(&self) -> (&'static str, Router) {
+        (GAS_REPORT_API_BASE, self.router())
+    }
+}
+
+/// Reloads the relayer's reloadable settings from its config on request.
+/// Requests must carry the admin token as a bearer token.
+#[derive(new, Clone)]
+pub struct ConfigReloadApi {
+    reloader: Arc<ConfigReloader>,
+    admin_token: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct ConfigReloadResponse {
+    /// Names of the settings that changed
+    updated: Vec<String>,
+}
+
+async fn config_reload(
+    State(api): State<ConfigReloadApi>,
+    headers: HeaderMap,
+) -> Result<Json<ConfigReloadResponse>, (StatusCode, String)> {
+    authorize(&headers, &api.admin_token)?;
+
+    let updated = api
+        .reloader
+        .reload()
+        .await
+        .map_err(|err| (StatusCode::BAD_REQUEST, format!("Invalid config: {err}")))?;
+    Ok(Json(ConfigReloadResponse {
+        updated: updated.into_iter().map(str::to_owned).collect(),
+    }))
+}
+
+/// Errors unless the request carries the admin token as a bearer token
+fn authorize(headers: &HeaderMap, admin_token: &str) -> Result<(), (StatusCode, String)> {
+    let authorized = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map_or(false, |token| tokens_match(token, admin_token));
+    if !authorized {
+        return Err((
+            StatusCode::UNAUTHORIZED,
+            "Missing or invalid admin token".to_owned(),
+        ));
+    }
+    Ok(())
+}
+
+/// Compares the tokens in constant time, so response times don't leak how
+/// much of a guessed token is correct
+fn tokens_match(token: &str, expected: &str) -> bool {
+    token.len() == expected.len()
+        && token
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+impl ConfigReloadApi {
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/", routing::post(config_reload))
+            .with_state(self.clone())
+    }
+
+    pub fn get_route(&self) -> (&'static str, Router) {
+        (CONFIG_RELOAD_API_BASE, self.router())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use axum::http::StatusCode;
     use ethers::utils::hex::ToHex;
+    use hyperlane_base::db::test_utils;
+    use hyperlane_core::{
+        accumulator::{merkle::verify_merkle_proof, TREE_DEPTH},
+        HyperlaneDomain,
+    };
     use std::net::SocketAddr;
     use tokio::sync::broadcast::{Receiver, Sender};
 
@@ -174,4 +475,202 @@ mod tests {
             MessageRetryRequest::DestinationDomain(destination_domain)
         );
     }
+
+    async fn get_submitter_status(
+        submission_pauses: BTreeMap<String, Vec<SubmissionPause>>,
+    ) -> BTreeMap<String, SubmitterStatus> {
+        let submitter_status_api = SubmitterStatusApi::new(submission_pauses);
+        let (path, router) = submitter_status_api.get_route();
+        let app = Router::new().nest(path, router);
+        let server =
+            axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+        let addr = server.local_addr();
+        tokio::spawn(server);
+
+        let response = reqwest::get(format!("http://{}{}", addr, SUBMITTER_STATUS_API_BASE))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        serde_json::from_str(&response.text().await.unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_submitter_status() {
+        let pause = SubmissionPause::default();
+        pause.set_paused(true);
+        let status =
+            get_submitter_status(BTreeMap::from([("test1".to_owned(), vec![pause])])).await;
+        assert_eq!(
+            status["test1"],
+            SubmitterStatus {
+                paused: true,
+                signers_paused: vec![true],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submitter_status_of_signer_pool() {
+        let pause = SubmissionPause::default();
+        let pool = vec![pause.clone(), SubmissionPause::default()];
+        let status =
+            get_submitter_status(BTreeMap::from([("test1".to_owned(), pool.clone())])).await;
+        assert_eq!(
+            status["test1"],
+            SubmitterStatus {
+                paused: false,
+                signers_paused: vec![false, false],
+            }
+        );
+
+        // A single paused signer is reported, even though the rest of the pool
+        // keeps submitting
+        pause.set_paused(true);
+        let status = get_submitter_status(BTreeMap::from([("test1".to_owned(), pool)])).await;
+        assert_eq!(
+            status["test1"],
+            SubmitterStatus {
+                paused: true,
+                signers_paused: vec![true, false],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merkle_proof() {
+        test_utils::run_test_db(|db| async move {
+            let domain = HyperlaneDomain::new_test_domain("test_merkle_proof");
+            let db = HyperlaneRocksDB::new(&domain, db);
+            let mut builder = MerkleTreeBuilder::new();
+            let leaves: Vec<H256> = (0..4).map(|_| H256::random()).collect();
+            for (index, leaf) in leaves.iter().enumerate() {
+                builder.ingest_message_id(*leaf).await.unwrap();
+                db.store_merkle_leaf_index_by_message_id(leaf, &(index as u32))
+                    .unwrap();
+            }
+            let historical_root = builder.root_at_count(3).unwrap();
+
+            let merkle_proof_api = MerkleProofApi::new(BTreeMap::from([(
+                domain.id(),
+                MerkleProofSource::new(db, Arc::new(RwLock::new(builder))),
+            )]));
+            let (path, router) = merkle_proof_api.get_route();
+            let app = Router::new().nest(path, router);
+            let server =
+                axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+            let addr = server.local_addr();
+            tokio::spawn(server);
+
+            let response = reqwest::get(format!(
+                "http://{}{}?origin_domain={}&message_id={}&root_index=2",
+                addr,
+                MERKLE_PROOF_API_BASE,
+                domain.id(),
+                leaves[1].encode_hex::<String>()
+            ))
+            .await
+            .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let response: MerkleProofResponse =
+                serde_json::from_str(&response.text().await.unwrap()).unwrap();
+            assert_eq!(response.proof.index, 1);
+            assert_eq!(response.proof.leaf, leaves[1]);
+            assert_eq!(response.root, historical_root);
+            assert!(verify_merkle_proof(
+                response.proof.leaf,
+                &response.proof.path,
+                TREE_DEPTH,
+                response.proof.index,
+                response.root,
+            ));
+
+            let response = reqwest::get(format!(
+                "http://{}{}?origin_domain={}&message_id={}",
+                addr,
+                MERKLE_PROOF_API_BASE,
+                domain.id(),
+                H256::random().encode_hex::<String>()
+            ))
+            .await
+            .unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_config_reload_requires_admin_token() {
+        let config_reload_api =
+            ConfigReloadApi::new(Arc::new(ConfigReloader::empty()), "secret".to_owned());
+        let (path, router) = config_reload_api.get_route();
+        let app = Router::new().nest(path, router);
+        let server =
+            axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+        let addr = server.local_addr();
+        tokio::spawn(server);
+
+        let url = format!("http://{}{}", addr, CONFIG_RELOAD_API_BASE);
+        let client = reqwest::Client::new();
+        let response = client.post(&url).send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        for token in ["Bearer secreT", "Bearer secret2", "secret"] {
+            let response = client
+                .post(&url)
+                .header(AUTHORIZATION, token)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        // With the admin token, the request gets to reloading the config
+        let response = client
+            .post(&url)
+            .header(AUTHORIZATION, "Bearer secret")
+            .send()
+            .await
+            .unwrap();
+        assert_ne!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_gas_report_requires_admin_token() {
+        test_utils::run_test_db(|db| async move {
+            let db =
+                HyperlaneRocksDB::new(&HyperlaneDomain::new_test_domain("test_gas_report"), db);
+            let gas_report_api =
+                GasReportApi::new(vec![db], Reloadable::default(), "secret".to_owned());
+            let (path, router) = gas_report_api.get_route();
+            let app = Router::new().nest(path, router);
+            let server =
+                axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+            let addr = server.local_addr();
+            tokio::spawn(server);
+
+            let url = format!("http://{}{}", addr, GAS_REPORT_API_BASE);
+            let client = reqwest::Client::new();
+            let response = client.get(&url).send().await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+            let response = client
+                .get(&url)
+                .header(AUTHORIZATION, "Bearer secret")
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let report: GasReport = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+            assert!(report.routes.is_empty());
+        })
+        .await;
+    }
+
+    #[test]
+    fn test_tokens_match() {
+        assert!(tokens_match("secret", "secret"));
+        assert!(!tokens_match("secreT", "secret"));
+        assert!(!tokens_match("secre", "secret"));
+        assert!(!tokens_match("", "secret"));
+    }
 }
@@ -4,7 +4,11 @@
 //! and validations it defines are not applied here, we should mirror them.
 //! ANY CHANGES HERE NEED TO BE REFLECTED IN THE TYPESCRIPT SDK.
 
-use std::{collections::HashSet, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    time::Duration,
+};
 
 use convert_case::Case;
 use derive_more::{AsMut, AsRef, Deref, DerefMut};
@@ -56,6 +60,16 @@ pub struct RelayerSettings {
     pub allow_local_checkpoint_syncers: bool,
     /// App contexts used for metrics.
     pub metric_app_contexts: Vec<(MatchingList, String)>,
+    /// Minimum balance of the relayer's signer on a destination chain, in the
+    /// chain's smallest denomination. Submissions to that chain are paused
+    /// while the balance is below it.
+    pub minimum_signer_balances: HashMap<HyperlaneDomain, U256>,
+    /// How often to check the config files for changes to the reloadable
+    /// settings. `None` disables watching the config files.
+    pub config_reload_interval: Option<Duration>,
+    /// Bearer token for the admin endpoints of the relayer API. The admin
+    /// endpoints are disabled when this is not set.
+    pub admin_token: Option<String>,
 }
 
 /// Config for gas payment enforcement
@@ -210,6 +224,28 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             .parse_bool()
             .unwrap_or(false);
 
+        let config_reload_interval = p
+            .chain(&mut err)
+            .get_opt_key("configReloadInterval")
+            .parse_u64()
+            .unwrap_or(30);
+        let config_reload_interval =
+            (config_reload_interval > 0).then(|| Duration::from_secs(config_reload_interval));
+
+        let admin_token = p
+            .chain(&mut err)
+            .get_opt_key("adminToken")
+            .parse_string()
+            .end()
+            .map(str::to_owned);
+
+        let raw_minimum_signer_balances: Vec<(String, ValueParser)> = p
+            .chain(&mut err)
+            .get_opt_key("minimumSignerBalances")
+            .into_obj_iter()
+            .map(|v| v.collect())
+            .unwrap_or_default();
+
         cfg_unwrap_all!(cwp, err: [base]);
 
         let skip_transaction_gas_limit_for = skip_transaction_gas_limit_for_names
@@ -223,6 +259,19 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             .map(|d| d.id())
             .collect();
 
+        let minimum_signer_balances = raw_minimum_signer_balances
+            .into_iter()
+            .filter_map(|(key, balance)| {
+                let domain = base
+                    .lookup_domain(&key)
+                    .context("Missing configuration for a chain in `minimumSignerBalances`")
+                    .into_config_result(|| cwp + "minimum_signer_balances" + &key)
+                    .take_config_err(&mut err)?;
+                let balance = balance.chain(&mut err).parse_u256().end()?;
+                Some((domain, balance))
+            })
+            .collect();
+
         let relay_chains: HashSet<HyperlaneDomain> = relay_chain_names
             .unwrap_or_default()
             .into_iter()
@@ -272,6 +321,9 @@ impl FromRawConf<RawRelayerSettings> for RelayerSettings {
             skip_transaction_gas_limit_for,
             allow_local_checkpoint_syncers,
             metric_app_contexts,
+            minimum_signer_balances,
+            config_reload_interval,
+            admin_token,
         })
     }
 }
@@ -13,6 +13,9 @@ mod m20230309_000003_create_table_transaction;
 mod m20230309_000004_create_table_delivered_message;
 mod m20230309_000004_create_table_gas_payment;
 mod m20230309_000005_create_table_message;
+mod m20261019_000001_create_table_merkle_tree_insertion;
+mod m20261019_000002_create_table_validator_announcement;
+mod m20261019_000003_add_cursor_event;
 
 pub struct Migrator;
 
@@ -30,6 +33,70 @@ impl MigratorTrait for Migrator {
             Box::new(m20230309_000004_create_table_gas_payment::Migration),
             Box::new(m20230309_000004_create_table_delivered_message::Migration),
             Box::new(m20230309_000005_create_table_message::Migration),
+            Box::new(m20261019_000001_create_table_merkle_tree_insertion::Migration),
+            Box::new(m20261019_000002_create_table_validator_announcement::Migration),
+            Box::new(m20261019_000003_add_cursor_event::Migration),
         ]
     }
 }
+
+#[cfg(test)]
+mod test {
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    use super::*;
+
+    /// Runs `migration` up and then down against a mock database, returning
+    /// the SQL of the `statements` it executed
+    async fn migration_sql(migration: &dyn MigrationTrait, statements: usize) -> String {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(
+                (0..statements)
+                    .map(|_| MockExecResult {
+                        last_insert_id: 0,
+                        rows_affected: 0,
+                    })
+                    .collect(),
+            )
+            .into_connection();
+        {
+            let manager = SchemaManager::new(&db);
+            migration.up(&manager).await.unwrap();
+            migration.down(&manager).await.unwrap();
+        }
+        format!("{:?}", db.into_transaction_log()).replace("\\\"", "\"")
+    }
+
+    #[tokio::test]
+    async fn test_create_table_merkle_tree_insertion() {
+        let sql = migration_sql(
+            &m20261019_000001_create_table_merkle_tree_insertion::Migration,
+            3,
+        )
+        .await;
+        assert!(sql.contains(r#"CREATE TABLE IF NOT EXISTS "merkle_tree_insertion""#));
+        assert!(sql.contains(r#"UNIQUE ("domain", "merkle_tree_hook", "leaf_index")"#));
+        assert!(sql.contains(r#"CREATE INDEX "merkle_tree_insertion_msg_id_idx""#));
+        assert!(sql.contains(r#"DROP TABLE "merkle_tree_insertion""#));
+    }
+
+    #[tokio::test]
+    async fn test_create_table_validator_announcement() {
+        let sql = migration_sql(
+            &m20261019_000002_create_table_validator_announcement::Migration,
+            3,
+        )
+        .await;
+        assert!(sql.contains(r#"CREATE TABLE IF NOT EXISTS "validator_announcement""#));
+        assert!(sql.contains(r#"UNIQUE ("tx_id", "log_index")"#));
+        assert!(sql.contains(r#"CREATE INDEX "validator_announcement_validator_idx""#));
+        assert!(sql.contains(r#"DROP TABLE "validator_announcement""#));
+    }
+
+    #[tokio::test]
+    async fn test_add_cursor_event() {
+        let sql = migration_sql(&m20261019_000003_add_cursor_event::Migration, 2).await;
+        assert!(sql.contains(r#"ALTER TABLE "cursor" ADD COLUMN "event" text"#));
+        assert!(sql.contains(r#"ALTER TABLE "cursor" DROP COLUMN "event""#));
+    }
+}
@@ -7,14 +7,20 @@ use hyperlane_base::{
     metrics::AgentMetrics, settings::IndexSettings, BaseAgent, ChainMetrics, ContractSyncMetrics,
     ContractSyncer, CoreMetrics, HyperlaneAgentCore, MetricsUpdater, SyncOptions,
 };
-use hyperlane_core::{Delivery, HyperlaneDomain, HyperlaneMessage, InterchainGasPayment, H512};
+use hyperlane_core::{
+    Delivery, HyperlaneDomain, HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion,
+    ValidatorAnnouncement, H512,
+};
 use tokio::{
     sync::broadcast::{Receiver, Sender},
     task::JoinHandle,
 };
-use tracing::{info_span, instrument::Instrumented, trace, Instrument};
+use tracing::{info_span, instrument::Instrumented, trace, warn, Instrument};
 
-use crate::{chain_scraper::HyperlaneSqlDb, db::ScraperDb, settings::ScraperSettings};
+use crate::{
+    chain_scraper::HyperlaneSqlDb, db::ScraperDb, server as scraper_server,
+    settings::ScraperSettings,
+};
 
 /// A message explorer scraper agent
 #[derive(Debug, AsRef)]
@@ -24,6 +30,7 @@ pub struct Scraper {
     core: HyperlaneAgentCore,
     contract_sync_metrics: Arc<ContractSyncMetrics>,
     scrapers: HashMap<u32, ChainScraper>,
+    db: ScraperDb,
     settings: ScraperSettings,
     core_metrics: Arc<CoreMetrics>,
     agent_metrics: AgentMetrics,
@@ -62,7 +69,7 @@ impl BaseAgent for Scraper {
             let chain_setup = settings.chain_setup(domain).expect("Missing chain config");
             let db = HyperlaneSqlDb::new(
                 db.clone(),
-                chain_setup.addresses.mailbox,
+                &chain_setup.addresses,
                 domain.clone(),
                 settings
                     .build_provider(domain, &metrics.clone())
@@ -87,6 +94,7 @@ impl BaseAgent for Scraper {
             core,
             contract_sync_metrics,
             scrapers,
+            db,
             settings,
             core_metrics: metrics,
             agent_metrics,
@@ -104,7 +112,14 @@ impl BaseAgent for Scraper {
             .settings
             .server(self.core_metrics.clone())
             .expect("Failed to create server");
-        let server_task = server.run().instrument(info_span!("Relayer server"));
+        let custom_routes = if self.settings.serve_query_api {
+            scraper_server::routes(self.db.clone())
+        } else {
+            vec![]
+        };
+        let server_task = server
+            .run_with_custom_routes(custom_routes)
+            .instrument(info_span!("Relayer server"));
         tasks.push(server_task);
 
         for (domain, scraper) in self.scrapers.iter() {
@@ -137,7 +152,7 @@ impl Scraper {
         let index_settings = scraper.index_settings.clone();
         let domain = scraper.domain.clone();
 
-        let mut tasks = Vec::with_capacity(2);
+        let mut tasks = Vec::with_capacity(5);
         let (message_indexer, maybe_broadcaster) = self
             .build_message_indexer(
                 domain.clone(),
@@ -161,15 +176,38 @@ impl Scraper {
         );
         tasks.push(
             self.build_interchain_gas_payment_indexer(
-                domain,
+                domain.clone(),
                 self.core_metrics.clone(),
                 self.contract_sync_metrics.clone(),
-                db,
+                db.clone(),
+                index_settings.clone(),
+                maybe_broadcaster.clone().map(|b| b.subscribe()),
+            )
+            .await,
+        );
+        tasks.push(
+            self.build_merkle_tree_insertion_indexer(
+                domain.clone(),
+                self.core_metrics.clone(),
+                self.contract_sync_metrics.clone(),
+                db.clone(),
                 index_settings.clone(),
                 maybe_broadcaster.map(|b| b.subscribe()),
             )
             .await,
         );
+        if let Some(task) = self
+            .build_validator_announcement_indexer(
+                domain,
+                self.core_metrics.clone(),
+                self.contract_sync_metrics.clone(),
+                db,
+                index_settings.clone(),
+            )
+            .await
+        {
+            tasks.push(task);
+        }
 
         tokio::spawn(async move {
             // If any of the tasks panic, we want to propagate it, so we unwrap
@@ -267,4 +305,71 @@ impl Scraper {
         })
         .instrument(info_span!("ChainContractSync", chain=%domain.name(), event=label))
     }
+
+    async fn build_merkle_tree_insertion_indexer(
+        &self,
+        domain: HyperlaneDomain,
+        metrics: Arc<CoreMetrics>,
+        contract_sync_metrics: Arc<ContractSyncMetrics>,
+        db: HyperlaneSqlDb,
+        index_settings: IndexSettings,
+        tx_id_receiver: Option<Receiver<H512>>,
+    ) -> Instrumented<JoinHandle<()>> {
+        let label = "merkle_tree_insertion";
+        let db = db.with_event_cursor(label, &index_settings).await.unwrap();
+        let sync = self
+            .as_ref()
+            .settings
+            .contract_sync::<MerkleTreeInsertion, _>(
+                &domain,
+                &metrics.clone(),
+                &contract_sync_metrics.clone(),
+                Arc::new(db),
+            )
+            .await
+            .unwrap();
+
+        let cursor = sync.cursor(index_settings.clone()).await;
+        tokio::spawn(async move {
+            sync.sync(label, SyncOptions::new(Some(cursor), tx_id_receiver))
+                .await
+        })
+        .instrument(info_span!("ChainContractSync", chain=%domain.name(), event=label))
+    }
+
+    /// Announcements can only be indexed on some protocols, so no task is
+    /// returned if the indexer can't be built.
+    async fn build_validator_announcement_indexer(
+        &self,
+        domain: HyperlaneDomain,
+        metrics: Arc<CoreMetrics>,
+        contract_sync_metrics: Arc<ContractSyncMetrics>,
+        db: HyperlaneSqlDb,
+        index_settings: IndexSettings,
+    ) -> Option<Instrumented<JoinHandle<()>>> {
+        let label = "validator_announcement";
+        let db = db.with_event_cursor(label, &index_settings).await.unwrap();
+        let sync = match self
+            .as_ref()
+            .settings
+            .watermark_contract_sync::<ValidatorAnnouncement, _>(
+                &domain,
+                &metrics.clone(),
+                &contract_sync_metrics.clone(),
+                Arc::new(db),
+            )
+            .await
+        {
+            Ok(sync) => sync,
+            Err(err) => {
+                warn!(domain=%domain.name(), ?err, "Not indexing validator announcements");
+                return None;
+            }
+        };
+
+        let cursor = sync.cursor(index_settings.clone()).await;
+        let task = tokio::spawn(async move { sync.sync(label, cursor.into()).await })
+            .instrument(info_span!("ChainContractSync", chain=%domain.name(), event=label));
+        Some(task)
+    }
 }
@@ -0,0 +1,426 @@
+//! Re-index a block, nonce or gas payment range of a single chain into the
+//! database, e.g. to recover events that were missed by the contract syncs. The
+//! contract syncs only move forward, so this is the only way to fill such gaps
+//! short of wiping the database.
+//!
+//! Backfilling is idempotent: logs are stored with the same `HyperlaneLogStore`
+//! implementations the contract syncs use, which ignore rows that are already
+//! present, and the block cursor of the chain is left untouched.
+
+use std::{collections::HashSet, fmt::Display, ops::RangeInclusive};
+
+use eyre::{bail, eyre, Context, Result};
+use hyperlane_base::{settings::ChainConf, BaseAgent, CoreMetrics, LoadableFromSettings};
+use hyperlane_core::{
+    HyperlaneLogStore, HyperlaneMessage, HyperlaneSequenceAwareIndexerStoreReader, IndexMode,
+    Indexer, SequenceAwareIndexer,
+};
+use tracing::info;
+
+use crate::{
+    agent::Scraper, chain_scraper::HyperlaneSqlDb, db::ScraperDb, settings::ScraperSettings,
+};
+
+pub use hyperlane_base::settings::loader::BACKFILL_COMMAND;
+
+const USAGE: &str =
+    "usage: scraper backfill <chain> <blocks|nonces|payments> <from> <to> [config args]";
+
+/// The inclusive range to backfill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackfillRange {
+    /// Re-index every event type emitted in these blocks
+    Blocks(RangeInclusive<u32>),
+    /// Re-index the messages dispatched with these nonces. On block indexed
+    /// chains, every event type emitted in the blocks of the messages is
+    /// re-indexed. On sequence indexed chains, the range is also taken as the
+    /// merkle tree leaf indices to re-index.
+    Nonces(RangeInclusive<u32>),
+    /// Re-index the gas payments with these sequence numbers, which are
+    /// unrelated to message nonces. Only valid on sequence indexed chains.
+    Payments(RangeInclusive<u32>),
+}
+
+/// A request to backfill a range of a single chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackfillRequest {
+    pub chain: String,
+    pub range: BackfillRange,
+}
+
+impl BackfillRequest {
+    /// Parse a backfill request from the program arguments, without the
+    /// executable path. Returns `None` if the scraper was not started with the
+    /// `backfill` command.
+    pub fn from_args(args: &[String]) -> Result<Option<Self>> {
+        if args.first().map(String::as_str) != Some(BACKFILL_COMMAND) {
+            return Ok(None);
+        }
+        let positional = args[1..]
+            .iter()
+            .take_while(|arg| !arg.starts_with("--"))
+            .collect::<Vec<_>>();
+        let [chain, kind, from, to] = positional.as_slice() else {
+            bail!(USAGE);
+        };
+        let parse_bound = |name: &str, value: &str| {
+            value
+                .parse::<u32>()
+                .with_context(|| format!("Invalid {name} `{value}`, {USAGE}"))
+        };
+        let (from, to) = (parse_bound("from", from)?, parse_bound("to", to)?);
+        if from > to {
+            bail!("Backfill range start {from} is after its end {to}");
+        }
+        let range = match kind.as_str() {
+            "blocks" => BackfillRange::Blocks(from..=to),
+            "nonces" => BackfillRange::Nonces(from..=to),
+            "payments" => BackfillRange::Payments(from..=to),
+            _ => bail!("Invalid range kind `{kind}`, {USAGE}"),
+        };
+        Ok(Some(Self {
+            chain: chain.to_string(),
+            range,
+        }))
+    }
+}
+
+/// The number of logs of one event type found in the backfilled range.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BackfillCount {
+    /// Logs that were missing from the database
+    pub added: u64,
+    /// Logs that were already in the database
+    pub present: u64,
+}
+
+impl Display for BackfillCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} added, {} already present", self.added, self.present)
+    }
+}
+
+/// Backfill the requested range and print how many logs of each event type
+/// were added.
+pub async fn run(request: BackfillRequest) -> Result<()> {
+    let settings = ScraperSettings::load()?;
+    let metrics = settings.metrics(Scraper::AGENT_NAME)?;
+    let _tokio_console_server = settings.tracing.start_tracing(&metrics)?;
+
+    let domain = settings.lookup_domain(&request.chain)?;
+    let chain_setup = settings.chain_setup(&domain)?;
+    let db = HyperlaneSqlDb::new(
+        ScraperDb::connect(&settings.db).await?,
+        &chain_setup.addresses,
+        domain.clone(),
+        settings.build_provider(&domain, &metrics).await?.into(),
+        &chain_setup.index,
+    )
+    .await?;
+    let chunk_size = chain_setup.index.chunk_size;
+    let message_indexer = chain_setup.build_message_indexer(&metrics).await?;
+
+    let mut counts = vec![];
+    match (request.range, chain_setup.index.mode) {
+        (BackfillRange::Blocks(_), IndexMode::Sequence) => {
+            bail!("{domain} is indexed by sequence, backfill a nonce range instead")
+        }
+        (BackfillRange::Blocks(range), IndexMode::Block) => {
+            counts = backfill_blocks(&db, &*message_indexer, chain_setup, &metrics, range).await?;
+        }
+        (BackfillRange::Nonces(range), IndexMode::Sequence) => {
+            counts.push((
+                "message_dispatch",
+                backfill(&db, &*message_indexer, range.clone(), chunk_size).await?,
+            ));
+            // Leaf indices are message nonces
+            let indexer = chain_setup.build_merkle_tree_hook_indexer(&metrics).await?;
+            counts.push((
+                "merkle_tree_insertion",
+                backfill(&db, &*indexer, range, chunk_size).await?,
+            ));
+            info!(
+                "Deliveries aren't indexed on sequence indexed chains and gas payments have a \
+                 sequence of their own, not backfilling them"
+            );
+        }
+        (BackfillRange::Payments(range), IndexMode::Sequence) => {
+            let indexer = chain_setup
+                .build_interchain_gas_payment_indexer(&metrics)
+                .await?;
+            counts.push((
+                "gas_payment",
+                backfill(&db, &*indexer, range, chunk_size).await?,
+            ));
+        }
+        (BackfillRange::Payments(_), IndexMode::Block) => {
+            bail!("{domain} is indexed by block, backfill a block range instead")
+        }
+        (BackfillRange::Nonces(range), IndexMode::Block) => {
+            let blocks =
+                nonces_to_blocks(&db, &*message_indexer, &range, chain_setup.index.from).await?;
+            info!(nonces=?range, ?blocks, "Backfilling the blocks around the nonce range");
+            counts = backfill_blocks(&db, &*message_indexer, chain_setup, &metrics, blocks).await?;
+        }
+    }
+
+    for (label, count) in counts {
+        println!("{domain} {label}: {count}");
+    }
+    Ok(())
+}
+
+/// Backfill every event type emitted in the `blocks` of a block indexed chain.
+async fn backfill_blocks(
+    db: &HyperlaneSqlDb,
+    message_indexer: &dyn SequenceAwareIndexer<HyperlaneMessage>,
+    chain_setup: &ChainConf,
+    metrics: &CoreMetrics,
+    blocks: RangeInclusive<u32>,
+) -> Result<Vec<(&'static str, BackfillCount)>> {
+    let chunk_size = chain_setup.index.chunk_size;
+    let mut counts = vec![];
+    counts.push((
+        "message_dispatch",
+        backfill(db, message_indexer, blocks.clone(), chunk_size).await?,
+    ));
+    let indexer = chain_setup.build_delivery_indexer(metrics).await?;
+    counts.push((
+        "message_delivery",
+        backfill(db, &*indexer, blocks.clone(), chunk_size).await?,
+    ));
+    let indexer = chain_setup
+        .build_interchain_gas_payment_indexer(metrics)
+        .await?;
+    counts.push((
+        "gas_payment",
+        backfill(db, &*indexer, blocks.clone(), chunk_size).await?,
+    ));
+    let indexer = chain_setup.build_merkle_tree_hook_indexer(metrics).await?;
+    counts.push((
+        "merkle_tree_insertion",
+        backfill(db, &*indexer, blocks.clone(), chunk_size).await?,
+    ));
+    match chain_setup.build_validator_announce_indexer(metrics).await {
+        Ok(indexer) => counts.push((
+            "validator_announcement",
+            backfill(db, &*indexer, blocks, chunk_size).await?,
+        )),
+        Err(err) => info!(?err, "Not backfilling validator announcements"),
+    }
+    Ok(counts)
+}
+
+/// The messages in `nonces` may be missing from the database, so the blocks
+/// they were dispatched in are bounded by the stored messages right before and
+/// after the range. Ranges starting at nonce 0 start at the configured
+/// `index.from` block and without a message after the range, it ends at the
+/// finalized tip.
+async fn nonces_to_blocks<I>(
+    db: &HyperlaneSqlDb,
+    indexer: &I,
+    nonces: &RangeInclusive<u32>,
+    index_from: u32,
+) -> Result<RangeInclusive<u32>>
+where
+    I: Indexer<HyperlaneMessage> + ?Sized,
+{
+    let from = match nonces.start().checked_sub(1) {
+        Some(nonce) => db
+            .retrieve_log_block_number_by_sequence(nonce)
+            .await?
+            .ok_or_else(|| {
+                eyre!("Message with nonce {nonce} is not stored, backfill a block range instead")
+            })?
+            .try_into()?,
+        None => index_from,
+    };
+    let to = match nonces.end().checked_add(1) {
+        Some(nonce) => db.retrieve_log_block_number_by_sequence(nonce).await?,
+        None => None,
+    };
+    let to = match to {
+        Some(to) => to.try_into()?,
+        None => indexer.get_finalized_block_number().await?,
+    };
+    Ok(from..=to)
+}
+
+/// Fetch the logs in `range` chunk by chunk and store them.
+async fn backfill<T, I, S>(
+    db: &S,
+    indexer: &I,
+    range: RangeInclusive<u32>,
+    chunk_size: u32,
+) -> Result<BackfillCount>
+where
+    T: Send + Sync + 'static,
+    I: Indexer<T> + ?Sized,
+    S: HyperlaneLogStore<T>,
+{
+    let mut count = BackfillCount::default();
+    for chunk in chunks(range, chunk_size) {
+        let mut logs = indexer.fetch_logs_in_range(chunk.clone()).await?;
+        // Providers may return the same log more than once
+        let mut seen = HashSet::new();
+        logs.retain(|(_, meta)| seen.insert(meta.clone()));
+
+        let added = db.store_logs(&logs).await? as u64;
+        count.added += added;
+        count.present += (logs.len() as u64).saturating_sub(added);
+        info!(range=?chunk, logs = logs.len(), added, "Backfilled range");
+    }
+    Ok(count)
+}
+
+/// Split `range` into consecutive ranges of at most `chunk_size` elements.
+fn chunks(range: RangeInclusive<u32>, chunk_size: u32) -> Vec<RangeInclusive<u32>> {
+    let step = chunk_size.max(1) - 1;
+    let mut chunks = vec![];
+    let mut from = *range.start();
+    loop {
+        let to = from.saturating_add(step).min(*range.end());
+        chunks.push(from..=to);
+        if to == *range.end() {
+            return chunks;
+        }
+        from = to + 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use hyperlane_core::{ChainResult, Indexed, LogMeta, H256};
+
+    use super::*;
+
+    /// Returns the messages dispatched in the requested blocks, with each
+    /// message returned twice like some providers do
+    #[derive(Debug)]
+    struct MockIndexer(Vec<(Indexed<HyperlaneMessage>, LogMeta)>);
+
+    #[async_trait]
+    impl Indexer<HyperlaneMessage> for MockIndexer {
+        async fn fetch_logs_in_range(
+            &self,
+            range: RangeInclusive<u32>,
+        ) -> ChainResult<Vec<(Indexed<HyperlaneMessage>, LogMeta)>> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|(_, meta)| range.contains(&(meta.block_number as u32)))
+                .flat_map(|log| [log.clone(), log.clone()])
+                .collect())
+        }
+
+        async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+            Ok(100)
+        }
+    }
+
+    /// Stores the nonces of messages, ignoring the ones already stored
+    #[derive(Debug, Default)]
+    struct MockStore(Mutex<HashSet<u32>>);
+
+    #[async_trait]
+    impl HyperlaneLogStore<HyperlaneMessage> for MockStore {
+        async fn store_logs(&self, logs: &[(Indexed<HyperlaneMessage>, LogMeta)]) -> Result<u32> {
+            let mut stored = self.0.lock().unwrap();
+            Ok(logs
+                .iter()
+                .filter(|(message, _)| stored.insert(message.inner().nonce))
+                .count() as u32)
+        }
+    }
+
+    fn dispatch(nonce: u32, block_number: u64) -> (Indexed<HyperlaneMessage>, LogMeta) {
+        let message = HyperlaneMessage {
+            nonce,
+            ..HyperlaneMessage::default()
+        };
+        let meta = LogMeta {
+            block_number,
+            transaction_id: H256::from_low_u64_be(nonce.into()).into(),
+            ..LogMeta::default()
+        };
+        (message.into(), meta)
+    }
+
+    #[tokio::test]
+    async fn test_backfill_counts_added_and_present_logs() {
+        let indexer = MockIndexer(vec![
+            dispatch(0, 1),
+            dispatch(1, 5),
+            dispatch(2, 12),
+            dispatch(3, 30),
+        ]);
+        let store = MockStore::default();
+        store.0.lock().unwrap().insert(1);
+
+        let count = backfill(&store, &indexer, 0..=20, 10).await.unwrap();
+        assert_eq!(
+            count,
+            BackfillCount {
+                added: 2,
+                present: 1
+            }
+        );
+
+        // Backfilling again only finds logs that are already present
+        let count = backfill(&store, &indexer, 0..=30, 10).await.unwrap();
+        assert_eq!(
+            count,
+            BackfillCount {
+                added: 1,
+                present: 3
+            }
+        );
+    }
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_parse_request() {
+        assert_eq!(BackfillRequest::from_args(&args("--db foo")).unwrap(), None);
+        assert_eq!(
+            BackfillRequest::from_args(&args("backfill ethereum blocks 10 20 --db foo")).unwrap(),
+            Some(BackfillRequest {
+                chain: "ethereum".to_owned(),
+                range: BackfillRange::Blocks(10..=20),
+            })
+        );
+        assert_eq!(
+            BackfillRequest::from_args(&args("backfill solana nonces 0 5")).unwrap(),
+            Some(BackfillRequest {
+                chain: "solana".to_owned(),
+                range: BackfillRange::Nonces(0..=5),
+            })
+        );
+        assert_eq!(
+            BackfillRequest::from_args(&args("backfill solana payments 3 8")).unwrap(),
+            Some(BackfillRequest {
+                chain: "solana".to_owned(),
+                range: BackfillRange::Payments(3..=8),
+            })
+        );
+        assert!(BackfillRequest::from_args(&args("backfill ethereum blocks 10")).is_err());
+        assert!(BackfillRequest::from_args(&args("backfill ethereum blocks 20 10")).is_err());
+        assert!(BackfillRequest::from_args(&args("backfill ethereum slots 10 20")).is_err());
+    }
+
+    #[test]
+    fn test_chunks() {
+        assert_eq!(chunks(5..=5, 10), vec![5..=5]);
+        assert_eq!(chunks(0..=24, 10), vec![0..=9, 10..=19, 20..=24]);
+        assert_eq!(chunks(0..=1, 0), vec![0..=0, 1..=1]);
+        assert_eq!(
+            chunks(u32::MAX - 1..=u32::MAX, 10),
+            vec![u32::MAX - 1..=u32::MAX]
+        );
+    }
+}
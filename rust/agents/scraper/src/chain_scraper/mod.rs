@@ -5,18 +5,19 @@ use std::{collections::HashMap, sync::Arc};
 
 use async_trait::async_trait;
 use eyre::Result;
-use hyperlane_base::settings::IndexSettings;
+use hyperlane_base::settings::{CoreContractAddresses, IndexSettings};
 use hyperlane_core::{
     unwrap_or_none_result, BlockInfo, Delivery, HyperlaneDomain, HyperlaneLogStore,
     HyperlaneMessage, HyperlaneProvider, HyperlaneSequenceAwareIndexerStoreReader,
-    HyperlaneWatermarkedLogStore, Indexed, InterchainGasPayment, LogMeta, H256,
+    HyperlaneWatermarkedLogStore, Indexed, InterchainGasPayment, LogMeta, MerkleTreeInsertion,
+    ValidatorAnnouncement, H256,
 };
 use itertools::Itertools;
 use tracing::trace;
 
 use crate::db::{
-    BasicBlock, BlockCursor, ScraperDb, StorableDelivery, StorableMessage, StorablePayment,
-    StorableTxn,
+    BasicBlock, BlockCursor, ScraperDb, StorableDelivery, StorableMerkleTreeInsertion,
+    StorableMessage, StorablePayment, StorableTxn, StorableValidatorAnnouncement,
 };
 
 /// Maximum number of records to query at a time. This came about because when a
@@ -30,6 +31,8 @@ const CHUNK_SIZE: usize = 50;
 #[derive(Clone, Debug)]
 pub struct HyperlaneSqlDb {
     mailbox_address: H256,
+    merkle_tree_hook_address: H256,
+    validator_announce_address: H256,
     domain: HyperlaneDomain,
     db: ScraperDb,
     provider: Arc<dyn HyperlaneProvider>,
@@ -40,24 +43,44 @@ pub struct HyperlaneSqlDb {
 impl HyperlaneSqlDb {
     pub async fn new(
         db: ScraperDb,
-        mailbox_address: H256,
+        addresses: &CoreContractAddresses,
         domain: HyperlaneDomain,
         provider: Arc<dyn HyperlaneProvider>,
         index_settings: &IndexSettings,
     ) -> Result<Self> {
         let cursor = Arc::new(
-            db.block_cursor(domain.id(), index_settings.from as u64)
+            db.block_cursor(domain.id(), None, index_settings.from as u64)
                 .await?,
         );
         Ok(Self {
             db,
             domain,
             provider,
-            mailbox_address,
+            mailbox_address: addresses.mailbox,
+            merkle_tree_hook_address: addresses.merkle_tree_hook,
+            validator_announce_address: addresses.validator_announce,
             cursor,
         })
     }
 
+    /// The same db with its own block cursor for `event`, so that an indexer
+    /// added after the others were run starts from the configured index
+    /// height rather than from where the others are at.
+    pub async fn with_event_cursor(
+        &self,
+        event: &'static str,
+        index_settings: &IndexSettings,
+    ) -> Result<Self> {
+        let cursor = self
+            .db
+            .block_cursor(self.domain.id(), Some(event), index_settings.from as u64)
+            .await?;
+        Ok(Self {
+            cursor: Arc::new(cursor),
+            ..self.clone()
+        })
+    }
+
     pub fn domain(&self) -> &HyperlaneDomain {
         &self.domain
     }
@@ -68,6 +91,19 @@ impl HyperlaneSqlDb {
             .await
     }
 
+    /// Looks up the database id of the transaction each log was emitted in,
+    /// inserting the transactions and their blocks if they are missing.
+    async fn txn_ids_by_hash(
+        &self,
+        log_meta: impl Iterator<Item = &LogMeta>,
+    ) -> Result<HashMap<H256, i64>> {
+        Ok(self
+            .ensure_blocks_and_txns(log_meta)
+            .await?
+            .map(|t| (t.hash, t.id))
+            .collect())
+    }
+
     /// Takes a list of txn and block hashes and ensure they are all in the
     /// database. If any are not it will fetch the data and insert them.
     ///
@@ -372,6 +408,77 @@ impl HyperlaneLogStore<InterchainGasPayment> for HyperlaneSqlDb {
     }
 }
 
+#[async_trait]
+impl HyperlaneLogStore<MerkleTreeInsertion> for HyperlaneSqlDb {
+    async fn store_logs(
+        &self,
+        insertions: &[(Indexed<MerkleTreeInsertion>, LogMeta)],
+    ) -> Result<u32> {
+        if insertions.is_empty() {
+            return Ok(0);
+        }
+        let txn_ids = self
+            .txn_ids_by_hash(insertions.iter().map(|r| &r.1))
+            .await?;
+        let storable = insertions.iter().map(|(insertion, meta)| {
+            let txn_hash: H256 = meta
+                .transaction_id
+                .try_into()
+                .expect("256-bit transaction ids are the maximum supported at this time");
+            StorableMerkleTreeInsertion {
+                insertion: insertion.inner(),
+                txn_id: txn_ids[&txn_hash],
+            }
+        });
+
+        let stored = self
+            .db
+            .store_merkle_tree_insertions(
+                self.domain().id(),
+                &self.merkle_tree_hook_address,
+                storable,
+            )
+            .await?;
+        Ok(stored as u32)
+    }
+}
+
+#[async_trait]
+impl HyperlaneLogStore<ValidatorAnnouncement> for HyperlaneSqlDb {
+    async fn store_logs(
+        &self,
+        announcements: &[(Indexed<ValidatorAnnouncement>, LogMeta)],
+    ) -> Result<u32> {
+        if announcements.is_empty() {
+            return Ok(0);
+        }
+        let txn_ids = self
+            .txn_ids_by_hash(announcements.iter().map(|r| &r.1))
+            .await?;
+        let storable = announcements.iter().map(|(announcement, meta)| {
+            let txn_hash: H256 = meta
+                .transaction_id
+                .try_into()
+                .expect("256-bit transaction ids are the maximum supported at this time");
+            StorableValidatorAnnouncement {
+                announcement: announcement.inner(),
+                meta,
+                txn_id: txn_ids[&txn_hash],
+            }
+        });
+
+        let stored = self
+            .db
+            .store_validator_announcements(
+                self.domain().id(),
+                &self.validator_announce_address,
+                storable,
+            )
+            .await?;
+        Ok(stored as u32)
+    }
+}
+
 #[async_trait]
 impl HyperlaneSequenceAwareIndexerStoreReader<HyperlaneMessage> for HyperlaneSqlDb {
     /// Gets a message by its nonce.
@@ -395,6 +502,37 @@ impl HyperlaneSequenceAwareIndexerStoreReader<HyperlaneMessage> for HyperlaneSql
     }
 }
 
+#[async_trait]
+impl HyperlaneSequenceAwareIndexerStoreReader<MerkleTreeInsertion> for HyperlaneSqlDb {
+    /// Gets a merkle tree insertion by its leaf index.
+    async fn retrieve_by_sequence(&self, sequence: u32) -> Result<Option<MerkleTreeInsertion>> {
+        let insertion = self
+            .db
+            .retrieve_merkle_tree_insertion_by_leaf_index(
+                self.domain().id(),
+                &self.merkle_tree_hook_address,
+                sequence,
+            )
+            .await?;
+        Ok(insertion.map(|(insertion, _)| insertion))
+    }
+
+    /// Gets the block number at which the log occurred.
+    async fn retrieve_log_block_number_by_sequence(&self, sequence: u32) -> Result<Option<u64>> {
+        let (_, tx_id) = unwrap_or_none_result!(
+            self.db
+                .retrieve_merkle_tree_insertion_by_leaf_index(
+                    self.domain().id(),
+                    &self.merkle_tree_hook_address,
+                    sequence,
+                )
+                .await?
+        );
+        let block_id = unwrap_or_none_result!(self.db.retrieve_block_id(tx_id).await?);
+        Ok(self.db.retrieve_block_number(block_id).await?)
+    }
+}
+
 #[async_trait]
 impl<T> HyperlaneWatermarkedLogStore<T> for HyperlaneSqlDb
 where
@@ -37,3 +37,12 @@ pub fn u256_to_decimal(v: U256) -> BigDecimal {
     v.to_little_endian(&mut buf);
     BigDecimal::from(BigInt::from_bytes_le(Sign::Plus, &buf as &[u8]))
 }
+
+pub fn decimal_to_u256(v: &BigDecimal) -> eyre::Result<U256> {
+    let (int, _) = v.with_scale(0).into_bigint_and_exponent();
+    let (sign, bytes) = int.to_bytes_be();
+    if sign == Sign::Minus || bytes.len() > 32 {
+        return Err(eyre::eyre!("Decimal {v} is out of range for a U256"));
+    }
+    Ok(U256::from_big_endian(&bytes))
+}
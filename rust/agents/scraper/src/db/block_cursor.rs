@@ -27,11 +27,19 @@ pub struct BlockCursor {
     db: DbConn,
     /// The hyperlane domain this block cursor is for.
     domain: u32,
+    /// The event this block cursor is for, unset for the cursor shared by
+    /// message dispatches, deliveries and gas payments.
+    event: Option<&'static str>,
     inner: RwLock<BlockCursorInner>,
 }
 
 impl BlockCursor {
-    async fn new(db: DbConn, domain: u32, default_height: u64) -> Result<Self> {
+    async fn new(
+        db: DbConn,
+        domain: u32,
+        event: Option<&'static str>,
+        default_height: u64,
+    ) -> Result<Self> {
         #[derive(Copy, Clone, Debug, EnumIter, DeriveColumn)]
         enum QueryAs {
             Height,
@@ -39,6 +47,10 @@ impl BlockCursor {
 
         let height = (cursor::Entity::find())
             .filter(cursor::Column::Domain.eq(domain))
+            .filter(match event {
+                Some(event) => cursor::Column::Event.eq(event),
+                None => cursor::Column::Event.is_null(),
+            })
             .order_by(cursor::Column::Height, Order::Desc)
             .select_only()
             .column_as(cursor::Column::Height, QueryAs::Height)
@@ -54,11 +66,16 @@ impl BlockCursor {
                 "Cursor height loaded from the database is lower than the default height!"
             )
         } else {
-            info!(height, "Restored current cursor position from database")
+            info!(
+                height,
+                ?event,
+                "Restored current cursor position from database"
+            )
         }
         Ok(Self {
             db,
             domain,
+            event,
             inner: RwLock::new(BlockCursorInner {
                 height,
                 last_saved_at: Instant::now(),
@@ -88,6 +105,7 @@ impl BlockCursor {
                 domain: ActiveValue::Set(self.domain as i32),
                 time_created: ActiveValue::NotSet,
                 height: ActiveValue::Set(height as i64),
+                event: ActiveValue::Set(self.event.map(str::to_owned)),
             };
             debug!(?model, "Inserting cursor");
             if let Err(e) = Insert::one(model).exec(&self.db).await {
@@ -100,7 +118,14 @@ impl BlockCursor {
 }
 
 impl ScraperDb {
-    pub async fn block_cursor(&self, domain: u32, default_height: u64) -> Result<BlockCursor> {
-        BlockCursor::new(self.0.clone(), domain, default_height).await
+    /// The block cursor of `domain` for `event`, or the cursor shared by
+    /// message dispatches, deliveries and gas payments if unset.
+    pub async fn block_cursor(
+        &self,
+        domain: u32,
+        event: Option<&'static str>,
+        default_height: u64,
+    ) -> Result<BlockCursor> {
+        BlockCursor::new(self.0.clone(), domain, event, default_height).await
     }
 }
@@ -3,6 +3,7 @@
 pub use super::{
     block::Entity as Block, cursor::Entity as Cursor,
     delivered_message::Entity as DeliveredMessage, domain::Entity as Domain,
-    gas_payment::Entity as GasPayment, message::Entity as Message,
-    transaction::Entity as Transaction,
+    gas_payment::Entity as GasPayment, merkle_tree_insertion::Entity as MerkleTreeInsertion,
+    message::Entity as Message, transaction::Entity as Transaction,
+    validator_announcement::Entity as ValidatorAnnouncement,
 };
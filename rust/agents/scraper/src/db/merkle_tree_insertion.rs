@@ -0,0 +1,215 @@
+use eyre::Result;
+use itertools::Itertools;
+use sea_orm::{prelude::*, ActiveValue::*, Insert};
+use tracing::{debug, instrument, trace};
+
+use hyperlane_core::{MerkleTreeInsertion, H256};
+use migration::OnConflict;
+
+use crate::conversions::{address_to_bytes, h256_to_bytes};
+use crate::date_time;
+use crate::db::ScraperDb;
+
+use super::generated::merkle_tree_insertion;
+
+pub struct StorableMerkleTreeInsertion<'a> {
+    pub insertion: &'a MerkleTreeInsertion,
+    /// The database id of the transaction the insertion was made in
+    pub txn_id: i64,
+}
+
+impl ScraperDb {
+    #[instrument(skip_all)]
+    pub async fn store_merkle_tree_insertions(
+        &self,
+        domain: u32,
+        merkle_tree_hook: &H256,
+        insertions: impl Iterator<Item = StorableMerkleTreeInsertion<'_>>,
+    ) -> Result<u64> {
+        let insertion_count_before = self
+            .merkle_tree_insertions_count(domain, merkle_tree_hook)
+            .await?;
+        let models = insertions
+            .map(|storable| merkle_tree_insertion::ActiveModel {
+                id: NotSet,
+                time_created: Set(date_time::now()),
+                domain: Unchanged(domain as i32),
+                merkle_tree_hook: Unchanged(address_to_bytes(merkle_tree_hook)),
+                leaf_index: Unchanged(storable.insertion.index() as i32),
+                msg_id: Set(h256_to_bytes(&storable.insertion.message_id())),
+                tx_id: Set(storable.txn_id),
+            })
+            .collect_vec();
+
+        debug_assert!(!models.is_empty());
+        trace!(?models, "Writing merkle tree insertions to database");
+
+        Insert::many(models)
+            .on_conflict(
+                OnConflict::columns([
+                    merkle_tree_insertion::Column::Domain,
+                    merkle_tree_insertion::Column::MerkleTreeHook,
+                    merkle_tree_insertion::Column::LeafIndex,
+                ])
+                .update_columns([
+                    merkle_tree_insertion::Column::TimeCreated,
+                    merkle_tree_insertion::Column::MsgId,
+                    merkle_tree_insertion::Column::TxId,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.0)
+            .await?;
+        let insertion_count_after = self
+            .merkle_tree_insertions_count(domain, merkle_tree_hook)
+            .await?;
+        let difference = insertion_count_after.saturating_sub(insertion_count_before);
+        if difference > 0 {
+            debug!(
+                insertions = difference,
+                "Wrote new merkle tree insertions to database"
+            );
+        }
+        Ok(difference)
+    }
+
+    /// Get the merkle tree insertion at a leaf index of a merkle tree hook,
+    /// along with the database id of the transaction it was made in
+    #[instrument(skip(self))]
+    pub async fn retrieve_merkle_tree_insertion_by_leaf_index(
+        &self,
+        domain: u32,
+        merkle_tree_hook: &H256,
+        leaf_index: u32,
+    ) -> Result<Option<(MerkleTreeInsertion, i64)>> {
+        let Some(insertion) = merkle_tree_insertion::Entity::find()
+            .filter(merkle_tree_insertion::Column::Domain.eq(domain))
+            .filter(
+                merkle_tree_insertion::Column::MerkleTreeHook
+                    .eq(address_to_bytes(merkle_tree_hook)),
+            )
+            .filter(merkle_tree_insertion::Column::LeafIndex.eq(leaf_index))
+            .one(&self.0)
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(Some((
+            MerkleTreeInsertion::new(
+                insertion.leaf_index as u32,
+                H256::from_slice(&insertion.msg_id),
+            ),
+            insertion.tx_id,
+        )))
+    }
+
+    async fn merkle_tree_insertions_count(
+        &self,
+        domain: u32,
+        merkle_tree_hook: &H256,
+    ) -> Result<u64> {
+        Ok(merkle_tree_insertion::Entity::find()
+            .filter(merkle_tree_insertion::Column::Domain.eq(domain))
+            .filter(
+                merkle_tree_insertion::Column::MerkleTreeHook
+                    .eq(address_to_bytes(merkle_tree_hook)),
+            )
+            .count(&self.0)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{DatabaseBackend, MockDatabase, Value};
+
+    use super::*;
+
+    fn row(column: &str, value: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([(column.to_owned(), value.into())])
+    }
+
+    /// A mock database where the count of insertions goes from `before` to
+    /// `after` around each insert
+    fn mock_db(inserts: &[(i64, i64)]) -> ScraperDb {
+        let mut db = MockDatabase::new(DatabaseBackend::Postgres);
+        for (before, after) in inserts {
+            db = db
+                .append_query_results(vec![vec![row("num_items", *before)]])
+                .append_query_results(vec![vec![row("id", 1)]])
+                .append_query_results(vec![vec![row("num_items", *after)]]);
+        }
+        ScraperDb(db.into_connection())
+    }
+
+    #[tokio::test]
+    async fn test_store_merkle_tree_insertions() {
+        let db = mock_db(&[(1, 3), (3, 3)]);
+        let merkle_tree_hook = H256::from_low_u64_be(5);
+        let insertions = [
+            MerkleTreeInsertion::new(1, H256::from_low_u64_be(1)),
+            MerkleTreeInsertion::new(2, H256::from_low_u64_be(2)),
+        ];
+        let storable = || {
+            insertions
+                .iter()
+                .map(|insertion| StorableMerkleTreeInsertion {
+                    insertion,
+                    txn_id: 1,
+                })
+        };
+
+        let stored = db
+            .store_merkle_tree_insertions(1, &merkle_tree_hook, storable())
+            .await
+            .unwrap();
+        assert_eq!(stored, 2);
+        // Storing the same insertions again updates them in place
+        let stored = db
+            .store_merkle_tree_insertions(1, &merkle_tree_hook, storable())
+            .await
+            .unwrap();
+        assert_eq!(stored, 0);
+
+        let sql = format!("{:?}", db.0.into_transaction_log()).replace("\\\"", "\"");
+        assert!(
+            sql.contains(r#"ON CONFLICT ("domain", "merkle_tree_hook", "leaf_index") DO UPDATE"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retrieve_merkle_tree_insertion_by_leaf_index() {
+        let merkle_tree_hook = H256::from_low_u64_be(5);
+        let message_id = H256::from_low_u64_be(2);
+        let model = merkle_tree_insertion::Model {
+            id: 1,
+            time_created: date_time::now(),
+            domain: 1,
+            merkle_tree_hook: address_to_bytes(&merkle_tree_hook),
+            leaf_index: 2,
+            msg_id: h256_to_bytes(&message_id),
+            tx_id: 7,
+        };
+        let db = ScraperDb(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results(vec![vec![model]])
+                .append_query_results(vec![Vec::<merkle_tree_insertion::Model>::new()])
+                .into_connection(),
+        );
+
+        let (insertion, tx_id) = db
+            .retrieve_merkle_tree_insertion_by_leaf_index(1, &merkle_tree_hook, 2)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(insertion, MerkleTreeInsertion::new(2, message_id));
+        assert_eq!(tx_id, 7);
+        assert!(db
+            .retrieve_merkle_tree_insertion_by_leaf_index(1, &merkle_tree_hook, 3)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -1,11 +1,14 @@
 pub use block::*;
 pub use block_cursor::BlockCursor;
 use eyre::Result;
+pub use merkle_tree_insertion::*;
 pub use message::*;
 pub use payment::*;
+pub use query::*;
 use sea_orm::{Database, DbConn};
 use tracing::instrument;
 pub use txn::*;
+pub use validator_announcement::*;
 
 #[allow(clippy::all)]
 mod generated;
@@ -13,9 +16,12 @@ mod generated;
 // These modules implement additional functionality for the ScraperDb
 mod block;
 mod block_cursor;
+mod merkle_tree_insertion;
 mod message;
 mod payment;
+mod query;
 mod txn;
+mod validator_announcement;
 
 /// Database interface to the message explorer database for the scraper. This is
 /// focused on writing data to the database.
@@ -29,3 +35,9 @@ impl ScraperDb {
         Ok(Self(db))
     }
 }
+
+impl From<DbConn> for ScraperDb {
+    fn from(db: DbConn) -> Self {
+        Self(db)
+    }
+}
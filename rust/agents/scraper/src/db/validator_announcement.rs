@@ -0,0 +1,153 @@
+use eyre::Result;
+use itertools::Itertools;
+use sea_orm::{prelude::*, ActiveValue::*, Insert};
+use tracing::{debug, instrument, trace};
+
+use hyperlane_core::{LogMeta, ValidatorAnnouncement, H160, H256};
+use migration::OnConflict;
+
+use crate::conversions::address_to_bytes;
+use crate::date_time;
+use crate::db::ScraperDb;
+
+use super::generated::validator_announcement;
+
+pub struct StorableValidatorAnnouncement<'a> {
+    pub announcement: &'a ValidatorAnnouncement,
+    pub meta: &'a LogMeta,
+    /// The database id of the transaction the announcement was made in
+    pub txn_id: i64,
+}
+
+impl ScraperDb {
+    #[instrument(skip_all)]
+    pub async fn store_validator_announcements(
+        &self,
+        domain: u32,
+        validator_announce: &H256,
+        announcements: impl Iterator<Item = StorableValidatorAnnouncement<'_>>,
+    ) -> Result<u64> {
+        let announcement_count_before = self.validator_announcements_count(domain).await?;
+        let models = announcements
+            .map(|storable| validator_announcement::ActiveModel {
+                id: NotSet,
+                time_created: Set(date_time::now()),
+                domain: Unchanged(domain as i32),
+                validator_announce: Unchanged(address_to_bytes(validator_announce)),
+                validator: Unchanged(address_to_bytes(&storable.announcement.validator.into())),
+                storage_location: Set(storable.announcement.storage_location.clone()),
+                tx_id: Unchanged(storable.txn_id),
+                log_index: Unchanged(storable.meta.log_index.as_u64() as i64),
+            })
+            .collect_vec();
+
+        debug_assert!(!models.is_empty());
+        trace!(?models, "Writing validator announcements to database");
+
+        Insert::many(models)
+            .on_conflict(
+                OnConflict::columns([
+                    // don't need domain because TxId includes it
+                    validator_announcement::Column::TxId,
+                    validator_announcement::Column::LogIndex,
+                ])
+                .update_columns([
+                    validator_announcement::Column::TimeCreated,
+                    validator_announcement::Column::StorageLocation,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.0)
+            .await?;
+        let announcement_count_after = self.validator_announcements_count(domain).await?;
+        let difference = announcement_count_after.saturating_sub(announcement_count_before);
+        if difference > 0 {
+            debug!(
+                announcements = difference,
+                "Wrote new validator announcements to database"
+            );
+        }
+        Ok(difference)
+    }
+
+    async fn validator_announcements_count(&self, domain: u32) -> Result<u64> {
+        Ok(validator_announcement::Entity::find()
+            .filter(validator_announcement::Column::Domain.eq(domain))
+            .count(&self.0)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{DatabaseBackend, MockDatabase, Value};
+
+    use super::*;
+
+    fn row(column: &str, value: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([(column.to_owned(), value.into())])
+    }
+
+    /// A mock database where the count of announcements goes from `before` to
+    /// `after` around each insert
+    fn mock_db(inserts: &[(i64, i64)]) -> ScraperDb {
+        let mut db = MockDatabase::new(DatabaseBackend::Postgres);
+        for (before, after) in inserts {
+            db = db
+                .append_query_results(vec![vec![row("num_items", *before)]])
+                .append_query_results(vec![vec![row("id", 1)]])
+                .append_query_results(vec![vec![row("num_items", *after)]]);
+        }
+        ScraperDb(db.into_connection())
+    }
+
+    #[tokio::test]
+    async fn test_store_validator_announcements() {
+        let db = mock_db(&[(1, 3), (3, 3)]);
+        let validator_announce = H256::from_low_u64_be(5);
+        let announcements = [
+            ValidatorAnnouncement {
+                validator: H160::from_low_u64_be(1),
+                storage_location: "s3://bucket/region".to_owned(),
+            },
+            ValidatorAnnouncement {
+                validator: H160::from_low_u64_be(2),
+                storage_location: "file:///tmp/checkpoints".to_owned(),
+            },
+        ];
+        let metas = [
+            LogMeta::default(),
+            LogMeta {
+                log_index: 1.into(),
+                ..LogMeta::default()
+            },
+        ];
+        let storable = || {
+            announcements
+                .iter()
+                .zip(&metas)
+                .map(|(announcement, meta)| StorableValidatorAnnouncement {
+                    announcement,
+                    meta,
+                    txn_id: 1,
+                })
+        };
+
+        let stored = db
+            .store_validator_announcements(1, &validator_announce, storable())
+            .await
+            .unwrap();
+        assert_eq!(stored, 2);
+        // Storing the same announcements again updates them in place
+        let stored = db
+            .store_validator_announcements(1, &validator_announce, storable())
+            .await
+            .unwrap();
+        assert_eq!(stored, 0);
+
+        let sql = format!("{:?}", db.0.into_transaction_log()).replace("\\\"", "\"");
+        assert!(sql.contains(r#"ON CONFLICT ("tx_id", "log_index") DO UPDATE"#));
+    }
+}
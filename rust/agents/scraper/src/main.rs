@@ -14,18 +14,25 @@
 #![warn(missing_docs)]
 
 use agent::Scraper;
+use backfill::BackfillRequest;
 use eyre::Result;
 use hyperlane_base::agent_main;
 
 mod db;
 
 mod agent;
+mod backfill;
 mod chain_scraper;
 mod conversions;
 mod date_time;
+mod server;
 mod settings;
 
 #[tokio::main(flavor = "current_thread")]
 async fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    if let Some(request) = BackfillRequest::from_args(&args)? {
+        return backfill::run(request).await;
+    }
     agent_main::<Scraper>().await
 }
@@ -0,0 +1,259 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing, Json, Router,
+};
+use derive_new::new;
+use hyperlane_core::H256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    conversions::bytes_to_address,
+    db::{MessageFilter, MessageInfo, ScraperDb, MAX_MESSAGES_PAGE_SIZE},
+};
+
+const MESSAGES_API_BASE: &str = "/messages";
+const DEFAULT_MESSAGES_PAGE_SIZE: u64 = 50;
+
+/// Returns the routes of the read only query API over the scraper database.
+pub fn routes(db: ScraperDb) -> Vec<(&'static str, Router)> {
+    vec![MessagesApi::new(db).get_route()]
+}
+
+/// Serves the scraped messages along with their delivery status and the gas
+/// paid for them
+#[derive(new, Clone)]
+pub struct MessagesApi {
+    db: ScraperDb,
+}
+
+#[derive(Deserialize)]
+struct RawMessagesRequest {
+    origin_domain: Option<u32>,
+    destination_domain: Option<u32>,
+    sender: Option<String>,
+    recipient: Option<String>,
+    origin_tx_hash: Option<String>,
+    /// Cursor of the last message of the previous page
+    after: Option<i64>,
+    limit: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct MessagesResponse {
+    messages: Vec<MessageInfo>,
+    /// Cursor to request the next page with, unset on the last page
+    next: Option<i64>,
+}
+
+type ApiError = (StatusCode, String);
+
+fn internal_error(err: eyre::Report) -> ApiError {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+/// Parse a 20 or 32 byte hex address
+fn parse_address(name: &str, address: &str) -> Result<H256, ApiError> {
+    ethers::utils::hex::decode(address.trim_start_matches("0x"))
+        .map_err(eyre::Report::from)
+        .and_then(bytes_to_address)
+        .map_err(|err| {
+            (
+                StatusCode::BAD_REQUEST,
+                format!("Failed to parse {name}: {err}"),
+            )
+        })
+}
+
+async fn message(
+    State(db): State<ScraperDb>,
+    Path(message_id): Path<String>,
+) -> Result<Json<MessageInfo>, ApiError> {
+    let message_id = message_id.parse::<H256>().map_err(|err| {
+        (
+            StatusCode::BAD_REQUEST,
+            format!("Failed to parse message id: {err}"),
+        )
+    })?;
+    db.query_message(&message_id)
+        .await
+        .map_err(internal_error)?
+        .map(Json)
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                format!("No message found with id {message_id:?}"),
+            )
+        })
+}
+
+async fn messages(
+    State(db): State<ScraperDb>,
+    Query(request): Query<RawMessagesRequest>,
+) -> Result<Json<MessagesResponse>, ApiError> {
+    let filter = MessageFilter {
+        origin: request.origin_domain,
+        destination: request.destination_domain,
+        sender: request
+            .sender
+            .map(|sender| parse_address("sender", &sender))
+            .transpose()?,
+        recipient: request
+            .recipient
+            .map(|recipient| parse_address("recipient", &recipient))
+            .transpose()?,
+        origin_tx_hash: request
+            .origin_tx_hash
+            .map(|hash| {
+                hash.parse::<H256>().map_err(|err| {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        format!("Failed to parse origin tx hash: {err}"),
+                    )
+                })
+            })
+            .transpose()?,
+    };
+    // Clamped here rather than only in the query, so that a full page of a
+    // larger limit still gets a next cursor
+    let limit = request
+        .limit
+        .unwrap_or(DEFAULT_MESSAGES_PAGE_SIZE)
+        .min(MAX_MESSAGES_PAGE_SIZE);
+    let messages = db
+        .query_messages(&filter, request.after, limit)
+        .await
+        .map_err(internal_error)?;
+    let next = (messages.len() as u64 >= limit)
+        .then(|| messages.last().map(|m| m.cursor))
+        .flatten();
+    Ok(Json(MessagesResponse { messages, next }))
+}
+
+impl MessagesApi {
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/", routing::get(messages))
+            .route("/:message_id", routing::get(message))
+            .with_state(self.db.clone())
+    }
+
+    pub fn get_route(&self) -> (&'static str, Router) {
+        (MESSAGES_API_BASE, self.router())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use sea_orm::{DatabaseBackend, MockDatabase, Value};
+
+    use super::*;
+    use crate::date_time;
+
+    fn setup_test_server(db: ScraperDb) -> std::net::SocketAddr {
+        let (path, router) = MessagesApi::new(db).get_route();
+        let app = Router::new().nest(path, router);
+        let server =
+            axum::Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app.into_make_service());
+        let addr = server.local_addr();
+        tokio::spawn(server);
+        addr
+    }
+
+    #[tokio::test]
+    async fn test_unknown_message() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results::<BTreeMap<String, Value>>(vec![vec![]])
+            .into_connection();
+        let addr = setup_test_server(ScraperDb::from(db));
+
+        let response = reqwest::get(format!(
+            "http://{}{}/{:?}",
+            addr,
+            MESSAGES_API_BASE,
+            H256::random()
+        ))
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_sender() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let addr = setup_test_server(ScraperDb::from(db));
+
+        let response = reqwest::get(format!(
+            "http://{}{}?sender=0x1234",
+            addr, MESSAGES_API_BASE
+        ))
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    fn message_row(id: i64) -> BTreeMap<String, Value> {
+        BTreeMap::from([
+            ("id".to_owned(), id.into()),
+            ("time_created".to_owned(), date_time::now().into()),
+            (
+                "msg_id".to_owned(),
+                H256::from_low_u64_be(id as u64).as_bytes().to_vec().into(),
+            ),
+            ("origin".to_owned(), 1i32.into()),
+            ("destination".to_owned(), 2i32.into()),
+            ("nonce".to_owned(), (id as i32).into()),
+            ("sender".to_owned(), vec![1u8; 20].into()),
+            ("recipient".to_owned(), vec![2u8; 20].into()),
+            ("msg_body".to_owned(), Some(vec![0xabu8]).into()),
+            ("origin_mailbox".to_owned(), vec![3u8; 20].into()),
+            ("origin_tx_id".to_owned(), 1i64.into()),
+        ])
+    }
+
+    /// Appends the results of listing the messages with the given ids, which
+    /// have no deliveries, payments or known transactions
+    fn append_messages(db: MockDatabase, ids: impl Iterator<Item = i64>) -> MockDatabase {
+        db.append_query_results(vec![ids.map(message_row).collect::<Vec<_>>()])
+            .append_query_results::<BTreeMap<String, Value>>(vec![vec![]])
+            .append_query_results::<BTreeMap<String, Value>>(vec![vec![]])
+            .append_query_results::<BTreeMap<String, Value>>(vec![vec![]])
+    }
+
+    async fn get_messages_page(addr: std::net::SocketAddr, query: &str) -> (usize, Option<i64>) {
+        let response = reqwest::get(format!("http://{}{}?{}", addr, MESSAGES_API_BASE, query))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let page: serde_json::Value =
+            serde_json::from_str(&response.text().await.unwrap()).unwrap();
+        (
+            page["messages"].as_array().unwrap().len(),
+            page["next"].as_i64(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_messages_pagination() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres);
+        let db = append_messages(db, 1..=2);
+        let db = append_messages(db, 3..=3);
+        let db = append_messages(db, 1..=MAX_MESSAGES_PAGE_SIZE as i64);
+        let addr = setup_test_server(ScraperDb::from(db.into_connection()));
+
+        assert_eq!(get_messages_page(addr, "limit=2").await, (2, Some(2)));
+        // A partial page is the last one
+        assert_eq!(get_messages_page(addr, "limit=2&after=2").await, (1, None));
+        // A full page of a limit above the maximum page size isn't mistaken
+        // for the last one
+        assert_eq!(
+            get_messages_page(addr, "limit=100000").await,
+            (
+                MAX_MESSAGES_PAGE_SIZE as usize,
+                Some(MAX_MESSAGES_PAGE_SIZE as i64)
+            )
+        );
+    }
+}
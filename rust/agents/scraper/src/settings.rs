@@ -30,6 +30,8 @@ pub struct ScraperSettings {
 
     pub db: String,
     pub chains_to_scrape: Vec<HyperlaneDomain>,
+    /// Whether to serve the read only query API over the scraped data
+    pub serve_query_api: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -69,6 +71,12 @@ impl FromRawConf<RawScraperSettings> for ScraperSettings {
             .end()
             .map(|v| v.to_owned());
 
+        let serve_query_api = p
+            .chain(&mut err)
+            .get_opt_key("serveQueryApi")
+            .parse_bool()
+            .unwrap_or(false);
+
         let chains_to_scrape = if let (Some(base), Some(chains)) = (&base, chains_names_to_scrape) {
             chains
                 .into_iter()
@@ -89,6 +97,7 @@ impl FromRawConf<RawScraperSettings> for ScraperSettings {
             base,
             db,
             chains_to_scrape,
+            serve_query_api,
         })
     }
 }
@@ -40,6 +40,9 @@ pub struct ValidatorSettings {
     pub reorg_period: u64,
     /// How frequently to check for new checkpoints
     pub interval: Duration,
+    /// Whether to write a signed revocation to previously announced storage
+    /// locations once the configured checkpoint syncer has been announced
+    pub revoke_previous_storage_locations: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -114,6 +117,12 @@ impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
             .map(Duration::from_secs)
             .unwrap_or(Duration::from_secs(5));
 
+        let revoke_previous_storage_locations = p
+            .chain(&mut err)
+            .get_opt_key("revokePreviousStorageLocations")
+            .parse_bool()
+            .unwrap_or(false);
+
         cfg_unwrap_all!(cwp, err: [origin_chain_name]);
 
         let reorg_period = p
@@ -143,6 +152,7 @@ impl FromRawConf<RawValidatorSettings> for ValidatorSettings {
             checkpoint_syncer,
             reorg_period,
             interval,
+            revoke_previous_storage_locations,
         })
     }
 }
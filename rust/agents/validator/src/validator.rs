@@ -1,4 +1,4 @@
-use std::{num::NonZeroU64, sync::Arc, time::Duration};
+use std::{num::NonZeroU64, str::FromStr, sync::Arc, time::Duration};
 
 use crate::server as validator_server;
 use async_trait::async_trait;
@@ -12,15 +12,15 @@ use tracing::{error, info, info_span, instrument::Instrumented, warn, Instrument
 use hyperlane_base::{
     db::{HyperlaneRocksDB, DB},
     metrics::AgentMetrics,
-    settings::ChainConf,
+    settings::{ChainConf, CheckpointSyncerConf},
     BaseAgent, ChainMetrics, CheckpointSyncer, ContractSyncMetrics, ContractSyncer, CoreMetrics,
     HyperlaneAgentCore, MetricsUpdater, SequencedDataContractSync,
 };
 
 use hyperlane_core::{
     Announcement, ChainResult, HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneSigner,
-    HyperlaneSignerExt, Mailbox, MerkleTreeHook, MerkleTreeInsertion, TxOutcome, ValidatorAnnounce,
-    H256, U256,
+    HyperlaneSignerExt, Mailbox, MerkleTreeHook, MerkleTreeInsertion, StorageLocationRevocation,
+    TxOutcome, ValidatorAnnounce, H256, U256,
 };
 use hyperlane_ethereum::{SingletonSigner, SingletonSignerHandle};
 
@@ -46,6 +46,7 @@ pub struct Validator {
     signer_instance: Option<Box<SingletonSigner>>,
     reorg_period: u64,
     interval: Duration,
+    revoke_previous_storage_locations: bool,
     checkpoint_syncer: Arc<dyn CheckpointSyncer>,
     core_metrics: Arc<CoreMetrics>,
     agent_metrics: AgentMetrics,
@@ -119,6 +120,7 @@ impl BaseAgent for Validator {
             signer_instance: Some(Box::new(signer_instance)),
             reorg_period: settings.reorg_period,
             interval: settings.interval,
+            revoke_previous_storage_locations: settings.revoke_previous_storage_locations,
             checkpoint_syncer,
             agent_metrics,
             chain_metrics,
@@ -305,6 +307,7 @@ impl Validator {
         self.checkpoint_syncer
             .write_announcement(&signed_announcement)
             .await?;
+        self.clear_stale_revocation().await?;
 
         // Ensure that the validator has announced themselves before we enter
         // the main validator submit loop. This is to avoid a situation in
@@ -325,6 +328,10 @@ impl Validator {
                         ?announcement_location,
                         "Validator has announced signature storage location"
                     );
+                    if self.revoke_previous_storage_locations {
+                        self.revoke_storage_locations(locations, &announcement_location)
+                            .await;
+                    }
                     break;
                 }
                 info!(
@@ -366,4 +373,63 @@ impl Validator {
         }
         Ok(())
     }
+
+    /// Writes a signed revocation to each previously announced storage location
+    /// other than `current_location`, so that relayers stop reading from them.
+    async fn revoke_storage_locations(&self, announced: &[String], current_location: &str) {
+        for location in announced.iter().filter(|l| l.as_str() != current_location) {
+            match self
+                .revoke_storage_location(location, current_location)
+                .await
+            {
+                Ok(()) => info!(
+                    revoked_location = %location,
+                    ?current_location,
+                    "Revoked previously announced storage location"
+                ),
+                Err(err) => warn!(
+                    ?err,
+                    revoked_location = %location,
+                    "Failed to revoke previously announced storage location"
+                ),
+            }
+        }
+    }
+
+    async fn revoke_storage_location(&self, location: &str, current_location: &str) -> Result<()> {
+        let checkpoint_syncer = CheckpointSyncerConf::from_str(location)?
+            .build(None)
+            .await?;
+        let revocation = StorageLocationRevocation {
+            validator: self.signer.eth_address(),
+            mailbox_address: self.mailbox.address(),
+            mailbox_domain: self.mailbox.domain().id(),
+            storage_location: location.to_owned(),
+            replacement_location: current_location.to_owned(),
+        };
+        let signed_revocation = self.signer.sign(revocation).await?;
+        checkpoint_syncer
+            .write_revocation(Some(&signed_revocation))
+            .await
+    }
+
+    /// Overwrites a revocation of the current storage location, which would
+    /// have relayers skip it, e.g. if the validator moved back to a location it
+    /// previously revoked.
+    async fn clear_stale_revocation(&self) -> Result<()> {
+        match self.checkpoint_syncer.fetch_revocation().await {
+            Ok(None) => return Ok(()),
+            Ok(Some(revocation)) => {
+                info!(
+                    ?revocation,
+                    "Overwriting revocation of the current storage location"
+                )
+            }
+            Err(err) => warn!(
+                ?err,
+                "Failed to fetch revocation of the current storage location, overwriting it"
+            ),
+        }
+        self.checkpoint_syncer.write_revocation(None).await
+    }
 }
@@ -3,7 +3,8 @@ use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use hyperlane_core::{
     ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract,
     HyperlaneDomain, HyperlaneProvider, Indexed, Indexer, InterchainGasPaymaster,
-    InterchainGasPayment, LogMeta, SequenceAwareIndexer, H256, U256,
+    InterchainGasPayment, LogMeta, OracleGasData, RemoteGasDataConfig, SequenceAwareIndexer,
+    TxOutcome, H256, U256,
 };
 use once_cell::sync::Lazy;
 use std::ops::RangeInclusive;
@@ -44,7 +45,23 @@ impl HyperlaneChain for CosmosInterchainGasPaymaster {
     }
 }
 
-impl InterchainGasPaymaster for CosmosInterchainGasPaymaster {}
+#[async_trait]
+impl InterchainGasPaymaster for CosmosInterchainGasPaymaster {
+    async fn remote_gas_data(&self, _remote_domain: u32) -> ChainResult<Option<OracleGasData>> {
+        Err(ChainCommunicationError::from_other_str(
+            "Reading gas oracles is not supported on Cosmos",
+        ))
+    }
+
+    async fn set_remote_gas_data(
+        &self,
+        _configs: Vec<RemoteGasDataConfig>,
+    ) -> ChainResult<TxOutcome> {
+        Err(ChainCommunicationError::from_other_str(
+            "Setting gas oracles is not supported on Cosmos",
+        ))
+    }
+}
 
 impl CosmosInterchainGasPaymaster {
     /// create new Cosmos InterchainGasPaymaster agent
@@ -258,9 +258,7 @@ impl WasmGrpcProvider {
             .await?;
 
         let signer = self.get_signer()?;
-        let tx_signed = sign_doc
-            .sign(&signer.signing_key()?)
-            .map_err(Into::<HyperlaneCosmosError>::into)?;
+        let tx_signed = signer.sign(sign_doc).await?;
         Ok((
             tx_signed
                 .to_bytes()
@@ -100,6 +100,17 @@ impl HyperlaneProvider for CosmosProvider {
             .await?)
     }
 
+    async fn get_nonce(&self, address: String) -> ChainResult<Option<u64>> {
+        let account = self.grpc_client.account_query(address).await?;
+        Ok(Some(account.sequence))
+    }
+
+    async fn get_gas_price(&self) -> ChainResult<Option<U256>> {
+        // The configured minimum gas price, which transactions are priced at
+        let gas_price: U256 = self.grpc_client.gas_price().ceil_to_integer().try_into()?;
+        Ok(Some(gas_price))
+    }
+
     async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
         Ok(None)
     }
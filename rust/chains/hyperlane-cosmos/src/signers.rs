@@ -1,8 +1,34 @@
-use cosmrs::crypto::{secp256k1::SigningKey, PublicKey};
-use hyperlane_core::ChainResult;
+use std::{fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+use cosmrs::{
+    crypto::{secp256k1::SigningKey, PublicKey},
+    proto::cosmos::tx::v1beta1::TxRaw,
+    tx::{Raw, SignDoc},
+};
+use hyperlane_core::{ChainCommunicationError, ChainResult};
+use sha2::{Digest, Sha256};
 
 use crate::{address::CosmosAddress, HyperlaneCosmosError};
 
+/// Signs secp256k1 digests with a key that is held outside of the agent, e.g.
+/// in a KMS.
+#[async_trait]
+pub trait RemoteSecp256k1Signer: Debug + Send + Sync {
+    /// The SEC1 encoded public key of the signer
+    fn public_key(&self) -> Vec<u8>;
+
+    /// Sign the sha256 `digest`, returning the 64 byte `r || s` signature with
+    /// a normalized (low) `s`
+    async fn sign_digest(&self, digest: [u8; 32]) -> ChainResult<[u8; 64]>;
+}
+
+#[derive(Clone, Debug)]
+enum SignerKey {
+    Local(Vec<u8>),
+    Remote(Arc<dyn RemoteSecp256k1Signer>),
+}
+
 #[derive(Clone, Debug)]
 /// Signer for cosmos chain
 pub struct Signer {
@@ -13,7 +39,7 @@ pub struct Signer {
     pub address: String,
     /// address prefix
     pub prefix: String,
-    private_key: Vec<u8>,
+    key: SignerKey,
 }
 
 impl Signer {
@@ -28,7 +54,25 @@ impl Signer {
         let public_key = signing_key.public_key();
         Ok(Self {
             public_key,
-            private_key,
+            key: SignerKey::Local(private_key),
+            address,
+            prefix,
+        })
+    }
+
+    /// create new signer whose key is held outside of the agent
+    ///
+    /// # Arguments
+    /// * `remote` - the signer holding the key
+    /// * `prefix` - prefix for signer address
+    pub fn new_remote(remote: Arc<dyn RemoteSecp256k1Signer>, prefix: String) -> ChainResult<Self> {
+        let public_key = PublicKey::from_raw_secp256k1(&remote.public_key()).ok_or_else(|| {
+            ChainCommunicationError::from_other_str("Invalid secp256k1 public key")
+        })?;
+        let address = CosmosAddress::from_pubkey(public_key, &prefix)?.address();
+        Ok(Self {
+            public_key,
+            key: SignerKey::Remote(remote),
             address,
             prefix,
         })
@@ -36,8 +80,39 @@ impl Signer {
 
     /// Build a SigningKey from a private key. This cannot be
     /// precompiled and stored in `Signer`, because `SigningKey` is not `Sync`.
+    /// Errors if the key is held outside of the agent.
     pub fn signing_key(&self) -> ChainResult<SigningKey> {
-        Self::build_signing_key(&self.private_key)
+        match &self.key {
+            SignerKey::Local(private_key) => Self::build_signing_key(private_key),
+            SignerKey::Remote(_) => Err(ChainCommunicationError::from_other_str(
+                "Signer key is held remotely",
+            )),
+        }
+    }
+
+    /// Sign `sign_doc`, returning the signed transaction
+    pub async fn sign(&self, sign_doc: SignDoc) -> ChainResult<Raw> {
+        match &self.key {
+            SignerKey::Local(private_key) => Ok(sign_doc
+                .sign(&Self::build_signing_key(private_key)?)
+                .map_err(Into::<HyperlaneCosmosError>::into)?),
+            SignerKey::Remote(remote) => {
+                let body_bytes = sign_doc.body_bytes.clone();
+                let auth_info_bytes = sign_doc.auth_info_bytes.clone();
+                let sign_doc_bytes = sign_doc
+                    .into_bytes()
+                    .map_err(Into::<HyperlaneCosmosError>::into)?;
+                let signature = remote
+                    .sign_digest(Sha256::digest(sign_doc_bytes).into())
+                    .await?;
+                Ok(TxRaw {
+                    body_bytes,
+                    auth_info_bytes,
+                    signatures: vec![signature.to_vec()],
+                }
+                .into())
+            }
+        }
     }
 
     fn build_signing_key(private_key: &Vec<u8>) -> ChainResult<SigningKey> {
@@ -35,6 +35,9 @@ pub struct ConnectionConf {
     pub transaction_overrides: TransactionOverrides,
     /// Operation batching configuration
     pub operation_batch: OperationBatchConfig,
+    /// Optional websocket url used to subscribe to logs as they are emitted,
+    /// in addition to polling for them.
+    pub subscription_url: Option<Url>,
 }
 
 /// Ethereum transaction overrides.
@@ -8,9 +8,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use ethers::prelude::Middleware;
 use hyperlane_core::{
-    ChainCommunicationError, ChainResult, ContractLocator, HyperlaneAbi, HyperlaneChain,
-    HyperlaneContract, HyperlaneDomain, HyperlaneProvider, Indexed, Indexer,
-    InterchainGasPaymaster, InterchainGasPayment, LogMeta, SequenceAwareIndexer, H160, H256, H512,
+    BlockFinality, ChainCommunicationError, ChainResult, ContractLocator, HyperlaneAbi,
+    HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneProvider, Indexed, Indexer,
+    InterchainGasPaymaster, InterchainGasPayment, LogMeta, OracleGasData, RemoteGasData,
+    RemoteGasDataConfig, SequenceAwareIndexer, TxOutcome, H160, H256, H512, U256,
 };
 use tracing::instrument;
 
@@ -19,6 +20,11 @@ use crate::interfaces::i_interchain_gas_paymaster::{
     GasPaymentFilter, IInterchainGasPaymaster as EthereumInterchainGasPaymasterInternal,
     IINTERCHAINGASPAYMASTER_ABI,
 };
+use crate::interfaces::interchain_gas_paymaster::InterchainGasPaymaster as EthereumInterchainGasPaymasterContract;
+use crate::interfaces::storage_gas_oracle::{
+    RemoteGasDataConfig as EthereumRemoteGasDataConfig, StorageGasOracle,
+};
+use crate::tx::{fill_tx_gas_params, get_finalized_block_number, report_tx};
 use crate::{BuildableWithProvider, ConnectionConf, EthereumProvider};
 
 impl<M> Display for EthereumInterchainGasPaymasterInternal<M>
@@ -33,6 +39,7 @@ where
 pub struct InterchainGasPaymasterIndexerBuilder {
     pub mailbox_address: H160,
     pub reorg_period: u32,
+    pub finality: BlockFinality,
 }
 
 #[async_trait]
@@ -49,6 +56,7 @@ impl BuildableWithProvider for InterchainGasPaymasterIndexerBuilder {
             Arc::new(provider),
             locator,
             self.reorg_period,
+            self.finality,
         ))
     }
 }
@@ -62,6 +70,7 @@ where
     contract: Arc<EthereumInterchainGasPaymasterInternal<M>>,
     provider: Arc<M>,
     reorg_period: u32,
+    finality: BlockFinality,
 }
 
 impl<M> EthereumInterchainGasPaymasterIndexer<M>
@@ -69,7 +78,12 @@ where
     M: Middleware + 'static,
 {
     /// Create new EthereumInterchainGasPaymasterIndexer
-    pub fn new(provider: Arc<M>, locator: &ContractLocator, reorg_period: u32) -> Self {
+    pub fn new(
+        provider: Arc<M>,
+        locator: &ContractLocator,
+        reorg_period: u32,
+        finality: BlockFinality,
+    ) -> Self {
         Self {
             contract: Arc::new(EthereumInterchainGasPaymasterInternal::new(
                 locator.address,
@@ -77,6 +91,7 @@ where
             )),
             provider,
             reorg_period,
+            finality,
         }
     }
 }
@@ -118,13 +133,7 @@ where
 
     #[instrument(level = "debug", err, ret, skip(self))]
     async fn get_finalized_block_number(&self) -> ChainResult<u32> {
-        Ok(self
-            .provider
-            .get_block_number()
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .as_u32()
-            .saturating_sub(self.reorg_period))
+        get_finalized_block_number(&*self.provider, self.finality, self.reorg_period).await
     }
 
     async fn fetch_logs_by_tx_hash(
@@ -180,11 +189,12 @@ impl BuildableWithProvider for InterchainGasPaymasterBuilder {
     async fn build_with_provider<M: Middleware + 'static>(
         &self,
         provider: M,
-        _conn: &ConnectionConf,
+        conn: &ConnectionConf,
         locator: &ContractLocator,
     ) -> Self::Output {
         Box::new(EthereumInterchainGasPaymaster::new(
             Arc::new(provider),
+            conn,
             locator,
         ))
     }
@@ -196,8 +206,10 @@ pub struct EthereumInterchainGasPaymaster<M>
 where
     M: Middleware,
 {
-    contract: Arc<EthereumInterchainGasPaymasterInternal<M>>,
+    contract: Arc<EthereumInterchainGasPaymasterContract<M>>,
     domain: HyperlaneDomain,
+    provider: Arc<M>,
+    conn: ConnectionConf,
 }
 
 impl<M> EthereumInterchainGasPaymaster<M>
@@ -206,15 +218,36 @@ where
 {
     /// Create a reference to a mailbox at a specific Ethereum address on some
     /// chain
-    pub fn new(provider: Arc<M>, locator: &ContractLocator) -> Self {
+    pub fn new(provider: Arc<M>, conn: &ConnectionConf, locator: &ContractLocator) -> Self {
         Self {
-            contract: Arc::new(EthereumInterchainGasPaymasterInternal::new(
+            contract: Arc::new(EthereumInterchainGasPaymasterContract::new(
                 locator.address,
-                provider,
+                provider.clone(),
             )),
             domain: locator.domain.clone(),
+            provider,
+            conn: conn.clone(),
         }
     }
+
+    /// Gets the storage gas oracle the IGP uses for the remote domain, if one is set.
+    async fn storage_gas_oracle(
+        &self,
+        remote_domain: u32,
+    ) -> ChainResult<Option<StorageGasOracle<M>>> {
+        let (gas_oracle, _gas_overhead) = self
+            .contract
+            .destination_gas_configs(remote_domain)
+            .call()
+            .await?;
+        if gas_oracle.is_zero() {
+            return Ok(None);
+        }
+        Ok(Some(StorageGasOracle::new(
+            gas_oracle,
+            self.provider.clone(),
+        )))
+    }
 }
 
 impl<M> HyperlaneChain for EthereumInterchainGasPaymaster<M>
@@ -243,7 +276,77 @@ where
 }
 
 #[async_trait]
-impl<M> InterchainGasPaymaster for EthereumInterchainGasPaymaster<M> where M: Middleware + 'static {}
+impl<M> InterchainGasPaymaster for EthereumInterchainGasPaymaster<M>
+where
+    M: Middleware + 'static,
+{
+    #[instrument(err, ret, skip(self))]
+    async fn remote_gas_data(&self, remote_domain: u32) -> ChainResult<Option<OracleGasData>> {
+        let Some(gas_oracle) = self.storage_gas_oracle(remote_domain).await? else {
+            return Ok(None);
+        };
+        let (token_exchange_rate, gas_price) =
+            gas_oracle.remote_gas_data(remote_domain).call().await?;
+        if token_exchange_rate == 0 && gas_price == 0 {
+            return Ok(None);
+        }
+        Ok(Some(OracleGasData::new(RemoteGasData {
+            token_exchange_rate: token_exchange_rate.into(),
+            gas_price: gas_price.into(),
+            token_decimals: None,
+        })))
+    }
+
+    #[instrument(err, ret, skip(self))]
+    async fn set_remote_gas_data(
+        &self,
+        configs: Vec<RemoteGasDataConfig>,
+    ) -> ChainResult<TxOutcome> {
+        let Some(first_config) = configs.first() else {
+            return Err(ChainCommunicationError::from_other_str(
+                "No remote gas data to set",
+            ));
+        };
+        let gas_oracle = self
+            .storage_gas_oracle(first_config.remote_domain)
+            .await?
+            .ok_or_else(|| {
+                ChainCommunicationError::from_other_str("No gas oracle set for remote domain")
+            })?;
+
+        let mut gas_oracle_configs = Vec::with_capacity(configs.len());
+        for config in configs {
+            // All configs are expected to be for the same storage gas oracle.
+            let remote_gas_oracle = self.storage_gas_oracle(config.remote_domain).await?;
+            if remote_gas_oracle.map(|o| o.address()) != Some(gas_oracle.address()) {
+                return Err(ChainCommunicationError::from_other_str(
+                    "Remote domains use different gas oracles",
+                ));
+            }
+            gas_oracle_configs.push(EthereumRemoteGasDataConfig {
+                remote_domain: config.remote_domain,
+                token_exchange_rate: u256_to_u128(config.remote_gas_data.token_exchange_rate)?,
+                gas_price: u256_to_u128(config.remote_gas_data.gas_price)?,
+            });
+        }
+
+        let contract_call = fill_tx_gas_params(
+            gas_oracle.set_remote_gas_data_configs(gas_oracle_configs),
+            self.provider.clone(),
+            &self.conn.transaction_overrides,
+        )
+        .await?;
+        let receipt = report_tx(contract_call).await?;
+        Ok(receipt.into())
+    }
+}
+
+/// Converts a U256 to the u128 expected by the storage gas oracle.
+fn u256_to_u128(value: U256) -> ChainResult<u128> {
+    value
+        .try_into()
+        .map_err(|_| ChainCommunicationError::from_other_str("Value does not fit in a uint128"))
+}
 
 pub struct EthereumInterchainGasPaymasterAbi;
 
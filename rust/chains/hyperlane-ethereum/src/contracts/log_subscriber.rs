@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use ethers::{
+    abi::RawLog,
+    prelude::{Middleware, Provider, Ws},
+    types::{Filter, H160 as EthersH160, H256 as EthersH256},
+};
+use ethers_contract::{EthEvent, LogMeta as EthersLogMeta};
+use futures_util::{stream, StreamExt};
+use hyperlane_core::{
+    BlockFinality, ChainCommunicationError, ChainResult, ContractLocator, HyperlaneMessage,
+    Indexed, InterchainGasPayment, LogMeta, LogSubscriber, MerkleTreeInsertion, SubscribedLog,
+    H256,
+};
+use tokio::sync::mpsc::Sender;
+use tracing::{debug, instrument, warn};
+use url::Url;
+
+use crate::interfaces::i_interchain_gas_paymaster::GasPaymentFilter;
+use crate::interfaces::mailbox::DispatchFilter;
+use crate::interfaces::merkle_tree_hook::InsertedIntoTreeFilter;
+use crate::tx::get_finalized_block_number;
+use crate::EthereumProviderConnectionError;
+
+/// How many recent block timestamps to keep around for logs that are released
+/// after their block's head was received
+const BLOCK_TIMESTAMP_CACHE_SIZE: u64 = 256;
+
+enum SubscriptionEvent<L, B> {
+    Log(L),
+    Head(B),
+}
+
+/// Subscribes to the logs of a single event over a websocket connection.
+/// Logs are held back until they are final according to `finality`, and are
+/// dropped if the node reports them as removed by a reorg in the meantime.
+#[derive(Debug)]
+pub struct EthereumLogSubscriber<T> {
+    url: Url,
+    address: EthersH160,
+    topic: EthersH256,
+    reorg_period: u32,
+    finality: BlockFinality,
+    decode: fn(&RawLog) -> Option<Indexed<T>>,
+}
+
+impl EthereumLogSubscriber<HyperlaneMessage> {
+    /// Subscribe to `Dispatch` logs of the mailbox at `locator`
+    pub fn dispatch(
+        url: Url,
+        locator: &ContractLocator,
+        reorg_period: u32,
+        finality: BlockFinality,
+    ) -> Self {
+        Self {
+            url,
+            address: locator.address.into(),
+            topic: DispatchFilter::signature(),
+            reorg_period,
+            finality,
+            decode: |raw_log| {
+                DispatchFilter::decode_log(raw_log)
+                    .ok()
+                    .map(|event| HyperlaneMessage::from(event.message.to_vec()).into())
+            },
+        }
+    }
+}
+
+impl EthereumLogSubscriber<MerkleTreeInsertion> {
+    /// Subscribe to `InsertedIntoTree` logs of the merkle tree hook at `locator`
+    pub fn inserted_into_tree(
+        url: Url,
+        locator: &ContractLocator,
+        reorg_period: u32,
+        finality: BlockFinality,
+    ) -> Self {
+        Self {
+            url,
+            address: locator.address.into(),
+            topic: InsertedIntoTreeFilter::signature(),
+            reorg_period,
+            finality,
+            decode: |raw_log| {
+                InsertedIntoTreeFilter::decode_log(raw_log)
+                    .ok()
+                    .map(|event| {
+                        MerkleTreeInsertion::new(event.index, H256::from(event.message_id)).into()
+                    })
+            },
+        }
+    }
+}
+
+impl EthereumLogSubscriber<InterchainGasPayment> {
+    /// Subscribe to `GasPayment` logs of the IGP at `locator`
+    pub fn gas_payment(
+        url: Url,
+        locator: &ContractLocator,
+        reorg_period: u32,
+        finality: BlockFinality,
+    ) -> Self {
+        Self {
+            url,
+            address: locator.address.into(),
+            topic: GasPaymentFilter::signature(),
+            reorg_period,
+            finality,
+            decode: |raw_log| {
+                GasPaymentFilter::decode_log(raw_log).ok().map(|event| {
+                    Indexed::new(InterchainGasPayment {
+                        message_id: H256::from(event.message_id),
+                        destination: event.destination_domain,
+                        payment: event.payment.into(),
+                        gas_amount: event.gas_amount.into(),
+                    })
+                })
+            },
+        }
+    }
+}
+
+impl<T> EthereumLogSubscriber<T> {
+    /// The latest final block, given the head the node just announced
+    async fn finalized_block_number<M>(&self, provider: &M, head: u64) -> ChainResult<u64>
+    where
+        M: Middleware + 'static,
+    {
+        match self.finality {
+            BlockFinality::BlockLag => Ok(head.saturating_sub(self.reorg_period.into())),
+            BlockFinality::Safe | BlockFinality::Finalized => {
+                get_finalized_block_number(provider, self.finality, self.reorg_period)
+                    .await
+                    .map(Into::into)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<T> LogSubscriber<T> for EthereumLogSubscriber<T>
+where
+    T: Send + Sync + std::fmt::Debug + 'static,
+{
+    #[instrument(err, skip(self, sender), fields(url = %self.url, address = ?self.address))]
+    async fn subscribe(&self, sender: Sender<SubscribedLog<T>>) -> ChainResult<()> {
+        let ws = Ws::connect(&self.url)
+            .await
+            .map_err(EthereumProviderConnectionError::from)?;
+        let provider = Provider::new(ws);
+        let filter = Filter::new().address(self.address).topic0(self.topic);
+        let logs = provider
+            .subscribe_logs(&filter)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        let heads = provider
+            .subscribe_blocks()
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        debug!("Subscribed to logs");
+
+        let mut events = stream::select(
+            logs.map(SubscriptionEvent::Log),
+            heads.map(SubscriptionEvent::Head),
+        );
+        let mut pending = PendingLogs::default();
+
+        while let Some(event) = events.next().await {
+            match event {
+                SubscriptionEvent::Log(log) => {
+                    let meta: LogMeta = EthersLogMeta::from(&log).into();
+                    if log.removed == Some(true) {
+                        pending.remove(&meta);
+                        continue;
+                    }
+                    let raw_log = RawLog {
+                        topics: log.topics.clone(),
+                        data: log.data.to_vec(),
+                    };
+                    match (self.decode)(&raw_log) {
+                        Some(item) => pending.push(item, meta),
+                        None => warn!(?meta, "Failed to decode subscribed log"),
+                    }
+                }
+                SubscriptionEvent::Head(head) => {
+                    let Some(number) = head.number.map(|n| n.as_u64()) else {
+                        continue;
+                    };
+                    pending.add_block_timestamp(number, head.timestamp.as_u64());
+                    let finalized = self.finalized_block_number(&provider, number).await?;
+                    for subscribed in pending.release(finalized) {
+                        if sender.send(subscribed).await.is_err() {
+                            // The receiving end is gone, so there is no one left to push to
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+        }
+        Err(ChainCommunicationError::from_other_str(
+            "Websocket log subscription ended",
+        ))
+    }
+}
+
+/// Logs that were received but are not final yet, and the timestamps of the
+/// recent blocks they may have been emitted in.
+#[derive(Debug)]
+struct PendingLogs<T> {
+    logs: Vec<(Indexed<T>, LogMeta)>,
+    block_timestamps: BTreeMap<u64, u64>,
+}
+
+impl<T> Default for PendingLogs<T> {
+    fn default() -> Self {
+        Self {
+            logs: vec![],
+            block_timestamps: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> PendingLogs<T> {
+    fn push(&mut self, log: Indexed<T>, meta: LogMeta) {
+        self.logs.push((log, meta));
+    }
+
+    /// Drop a log that was removed by a reorg
+    fn remove(&mut self, meta: &LogMeta) {
+        self.logs.retain(|(_, pending_meta)| pending_meta != meta);
+    }
+
+    fn add_block_timestamp(&mut self, block_number: u64, timestamp: u64) {
+        self.block_timestamps.insert(block_number, timestamp);
+    }
+
+    /// Take the logs emitted at or below the `finalized` block
+    fn release(&mut self, finalized: u64) -> Vec<SubscribedLog<T>> {
+        let (ready, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.logs)
+            .into_iter()
+            .partition(|(_, meta)| meta.block_number <= finalized);
+        self.logs = pending;
+        let released = ready
+            .into_iter()
+            .map(|(log, meta)| SubscribedLog {
+                block_timestamp: self.block_timestamps.get(&meta.block_number).copied(),
+                log,
+                meta,
+            })
+            .collect();
+        self.block_timestamps = self
+            .block_timestamps
+            .split_off(&finalized.saturating_sub(BLOCK_TIMESTAMP_CACHE_SIZE));
+        released
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn meta(block_number: u64, log_index: u64) -> LogMeta {
+        LogMeta {
+            block_number,
+            log_index: log_index.into(),
+            ..Default::default()
+        }
+    }
+
+    fn released_blocks(released: Vec<SubscribedLog<u32>>) -> Vec<(u32, u64, Option<u64>)> {
+        released
+            .into_iter()
+            .map(|s| (*s.log.inner(), s.meta.block_number, s.block_timestamp))
+            .collect()
+    }
+
+    #[test]
+    fn test_logs_are_held_until_final() {
+        let mut pending = PendingLogs::default();
+        pending.push(Indexed::new(1), meta(10, 0));
+        pending.push(Indexed::new(2), meta(12, 0));
+        pending.add_block_timestamp(10, 1000);
+        pending.add_block_timestamp(12, 1024);
+
+        assert!(pending.release(9).is_empty());
+        assert_eq!(
+            released_blocks(pending.release(11)),
+            vec![(1, 10, Some(1000))]
+        );
+        assert!(pending.release(11).is_empty());
+        assert_eq!(
+            released_blocks(pending.release(12)),
+            vec![(2, 12, Some(1024))]
+        );
+    }
+
+    #[test]
+    fn test_removed_logs_are_dropped() {
+        let mut pending = PendingLogs::default();
+        pending.push(Indexed::new(1), meta(10, 0));
+        pending.push(Indexed::new(2), meta(10, 1));
+        pending.remove(&meta(10, 0));
+
+        assert_eq!(released_blocks(pending.release(10)), vec![(2, 10, None)]);
+    }
+}
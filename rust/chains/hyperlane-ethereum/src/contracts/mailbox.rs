@@ -15,10 +15,11 @@ use hyperlane_core::H512;
 use tracing::instrument;
 
 use hyperlane_core::{
-    utils::bytes_to_hex, BatchItem, ChainCommunicationError, ChainResult, ContractLocator,
-    HyperlaneAbi, HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneMessage,
-    HyperlaneProtocolError, HyperlaneProvider, Indexed, Indexer, LogMeta, Mailbox,
-    RawHyperlaneMessage, SequenceAwareIndexer, TxCostEstimate, TxOutcome, H160, H256, U256,
+    utils::bytes_to_hex, BatchItem, BlockFinality, ChainCommunicationError, ChainResult,
+    ContractLocator, HyperlaneAbi, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    HyperlaneMessage, HyperlaneProtocolError, HyperlaneProvider, Indexed, Indexer, LogMeta,
+    Mailbox, RawHyperlaneMessage, SequenceAwareIndexer, TxCostEstimate, TxOutcome, H160, H256,
+    U256,
 };
 
 use crate::error::HyperlaneEthereumError;
@@ -27,7 +28,7 @@ use crate::interfaces::i_mailbox::{
     IMailbox as EthereumMailboxInternal, ProcessCall, IMAILBOX_ABI,
 };
 use crate::interfaces::mailbox::DispatchFilter;
-use crate::tx::{call_with_lag, fill_tx_gas_params, report_tx};
+use crate::tx::{call_with_lag, fill_tx_gas_params, get_finalized_block_number, report_tx};
 use crate::{BuildableWithProvider, ConnectionConf, EthereumProvider, TransactionOverrides};
 
 use super::multicall::{self, build_multicall};
@@ -44,6 +45,7 @@ where
 
 pub struct SequenceIndexerBuilder {
     pub reorg_period: u32,
+    pub finality: BlockFinality,
 }
 
 #[async_trait]
@@ -60,12 +62,14 @@ impl BuildableWithProvider for SequenceIndexerBuilder {
             Arc::new(provider),
             locator,
             self.reorg_period,
+            self.finality,
         ))
     }
 }
 
 pub struct DeliveryIndexerBuilder {
     pub reorg_period: u32,
+    pub finality: BlockFinality,
 }
 
 #[async_trait]
@@ -82,6 +86,7 @@ impl BuildableWithProvider for DeliveryIndexerBuilder {
             Arc::new(provider),
             locator,
             self.reorg_period,
+            self.finality,
         ))
     }
 }
@@ -95,6 +100,7 @@ where
     contract: Arc<EthereumMailboxInternal<M>>,
     provider: Arc<M>,
     reorg_period: u32,
+    finality: BlockFinality,
 }
 
 impl<M> EthereumMailboxIndexer<M>
@@ -102,7 +108,12 @@ where
     M: Middleware + 'static,
 {
     /// Create new EthereumMailboxIndexer
-    pub fn new(provider: Arc<M>, locator: &ContractLocator, reorg_period: u32) -> Self {
+    pub fn new(
+        provider: Arc<M>,
+        locator: &ContractLocator,
+        reorg_period: u32,
+        finality: BlockFinality,
+    ) -> Self {
         let contract = Arc::new(EthereumMailboxInternal::new(
             locator.address,
             provider.clone(),
@@ -111,18 +122,13 @@ where
             contract,
             provider,
             reorg_period,
+            finality,
         }
     }
 
     #[instrument(level = "debug", err, ret, skip(self))]
     async fn get_finalized_block_number(&self) -> ChainResult<u32> {
-        Ok(self
-            .provider
-            .get_block_number()
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .as_u32()
-            .saturating_sub(self.reorg_period))
+        get_finalized_block_number(&*self.provider, self.finality, self.reorg_period).await
     }
 }
 
@@ -237,7 +243,9 @@ where
     }
 }
 
-pub struct MailboxBuilder {}
+pub struct MailboxBuilder {
+    pub finality: BlockFinality,
+}
 
 #[async_trait]
 impl BuildableWithProvider for MailboxBuilder {
@@ -249,7 +257,12 @@ impl BuildableWithProvider for MailboxBuilder {
         conn: &ConnectionConf,
         locator: &ContractLocator,
     ) -> Self::Output {
-        Box::new(EthereumMailbox::new(Arc::new(provider), conn, locator))
+        Box::new(EthereumMailbox::new(
+            Arc::new(provider),
+            conn,
+            locator,
+            self.finality,
+        ))
     }
 }
 
@@ -264,6 +277,7 @@ where
     provider: Arc<M>,
     arbitrum_node_interface: Option<Arc<ArbitrumNodeInterface<M>>>,
     conn: ConnectionConf,
+    finality: BlockFinality,
 }
 
 impl<M> EthereumMailbox<M>
@@ -272,7 +286,12 @@ where
 {
     /// Create a reference to a mailbox at a specific Ethereum address on some
     /// chain
-    pub fn new(provider: Arc<M>, conn: &ConnectionConf, locator: &ContractLocator) -> Self {
+    pub fn new(
+        provider: Arc<M>,
+        conn: &ConnectionConf,
+        locator: &ContractLocator,
+        finality: BlockFinality,
+    ) -> Self {
         // Arbitrum Nitro based chains are a special case for transaction cost estimation.
         // The gas amount that eth_estimateGas returns considers both L1 and L2 gas costs.
         // We use the NodeInterface, found at address(0xC8), to isolate the L2 gas costs.
@@ -293,6 +312,7 @@ where
             provider,
             arbitrum_node_interface,
             conn: conn.clone(),
+            finality,
         }
     }
 
@@ -362,7 +382,13 @@ where
 {
     #[instrument(skip(self))]
     async fn count(&self, maybe_lag: Option<NonZeroU64>) -> ChainResult<u32> {
-        let call = call_with_lag(self.contract.nonce(), &self.provider, maybe_lag).await?;
+        let call = call_with_lag(
+            self.contract.nonce(),
+            &self.provider,
+            maybe_lag,
+            self.finality,
+        )
+        .await?;
         let nonce = call.call().await?;
         Ok(nonce)
     }
@@ -529,6 +555,7 @@ mod test {
             },
             transaction_overrides: Default::default(),
             operation_batch: Default::default(),
+            subscription_url: None,
         };
 
         let mailbox = EthereumMailbox::new(
@@ -540,6 +567,7 @@ mod test {
                 // Address doesn't matter because we're using a MockProvider
                 address: H256::default(),
             },
+            Default::default(),
         );
 
         let message = HyperlaneMessage::default();
@@ -9,15 +9,15 @@ use hyperlane_core::accumulator::incremental::IncrementalMerkle;
 use tracing::instrument;
 
 use hyperlane_core::{
-    ChainCommunicationError, ChainResult, Checkpoint, ContractLocator, HyperlaneChain,
-    HyperlaneContract, HyperlaneDomain, HyperlaneProvider, Indexed, Indexer, LogMeta,
-    MerkleTreeHook, MerkleTreeInsertion, SequenceAwareIndexer, H256, H512,
+    BlockFinality, ChainResult, Checkpoint, ContractLocator, HyperlaneChain, HyperlaneContract,
+    HyperlaneDomain, HyperlaneProvider, Indexed, Indexer, LogMeta, MerkleTreeHook,
+    MerkleTreeInsertion, SequenceAwareIndexer, H256, H512,
 };
 
 use crate::interfaces::merkle_tree_hook::{
     InsertedIntoTreeFilter, MerkleTreeHook as MerkleTreeHookContract, Tree,
 };
-use crate::tx::call_with_lag;
+use crate::tx::{call_with_lag, get_finalized_block_number};
 use crate::{BuildableWithProvider, ConnectionConf, EthereumProvider};
 
 use super::utils::fetch_raw_logs_and_log_meta;
@@ -39,7 +39,9 @@ impl Into<IncrementalMerkle> for Tree {
     }
 }
 
-pub struct MerkleTreeHookBuilder {}
+pub struct MerkleTreeHookBuilder {
+    pub finality: BlockFinality,
+}
 
 #[async_trait]
 impl BuildableWithProvider for MerkleTreeHookBuilder {
@@ -51,12 +53,17 @@ impl BuildableWithProvider for MerkleTreeHookBuilder {
         _conn: &ConnectionConf,
         locator: &ContractLocator,
     ) -> Self::Output {
-        Box::new(EthereumMerkleTreeHook::new(Arc::new(provider), locator))
+        Box::new(EthereumMerkleTreeHook::new(
+            Arc::new(provider),
+            locator,
+            self.finality,
+        ))
     }
 }
 
 pub struct MerkleTreeHookIndexerBuilder {
     pub reorg_period: u32,
+    pub finality: BlockFinality,
 }
 
 #[async_trait]
@@ -73,6 +80,7 @@ impl BuildableWithProvider for MerkleTreeHookIndexerBuilder {
             Arc::new(provider),
             locator,
             self.reorg_period,
+            self.finality,
         ))
     }
 }
@@ -86,6 +94,7 @@ where
     contract: Arc<MerkleTreeHookContract<M>>,
     provider: Arc<M>,
     reorg_period: u32,
+    finality: BlockFinality,
 }
 
 impl<M> EthereumMerkleTreeHookIndexer<M>
@@ -93,7 +102,12 @@ where
     M: Middleware + 'static,
 {
     /// Create new EthereumMerkleTreeHookIndexer
-    pub fn new(provider: Arc<M>, locator: &ContractLocator, reorg_period: u32) -> Self {
+    pub fn new(
+        provider: Arc<M>,
+        locator: &ContractLocator,
+        reorg_period: u32,
+        finality: BlockFinality,
+    ) -> Self {
         Self {
             contract: Arc::new(MerkleTreeHookContract::new(
                 locator.address,
@@ -101,6 +115,7 @@ where
             )),
             provider,
             reorg_period,
+            finality,
         }
     }
 }
@@ -138,13 +153,7 @@ where
 
     #[instrument(level = "debug", err, skip(self))]
     async fn get_finalized_block_number(&self) -> ChainResult<u32> {
-        Ok(self
-            .provider
-            .get_block_number()
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .as_u32()
-            .saturating_sub(self.reorg_period))
+        get_finalized_block_number(&*self.provider, self.finality, self.reorg_period).await
     }
 
     async fn fetch_logs_by_tx_hash(
@@ -193,6 +202,7 @@ where
     contract: Arc<MerkleTreeHookContract<M>>,
     domain: HyperlaneDomain,
     provider: Arc<M>,
+    finality: BlockFinality,
 }
 
 impl<M> EthereumMerkleTreeHook<M>
@@ -201,7 +211,7 @@ where
 {
     /// Create a reference to a mailbox at a specific Ethereum address on some
     /// chain
-    pub fn new(provider: Arc<M>, locator: &ContractLocator) -> Self {
+    pub fn new(provider: Arc<M>, locator: &ContractLocator, finality: BlockFinality) -> Self {
         Self {
             contract: Arc::new(MerkleTreeHookContract::new(
                 locator.address,
@@ -209,6 +219,7 @@ where
             )),
             domain: locator.domain.clone(),
             provider,
+            finality,
         }
     }
 }
@@ -245,8 +256,13 @@ where
 {
     #[instrument(skip(self))]
     async fn latest_checkpoint(&self, maybe_lag: Option<NonZeroU64>) -> ChainResult<Checkpoint> {
-        let call =
-            call_with_lag(self.contract.latest_checkpoint(), &self.provider, maybe_lag).await?;
+        let call = call_with_lag(
+            self.contract.latest_checkpoint(),
+            &self.provider,
+            maybe_lag,
+            self.finality,
+        )
+        .await?;
 
         let (root, index) = call.call().await?;
         Ok(Checkpoint {
@@ -260,14 +276,26 @@ where
     #[instrument(skip(self))]
     #[allow(clippy::needless_range_loop)]
     async fn tree(&self, maybe_lag: Option<NonZeroU64>) -> ChainResult<IncrementalMerkle> {
-        let call = call_with_lag(self.contract.tree(), &self.provider, maybe_lag).await?;
+        let call = call_with_lag(
+            self.contract.tree(),
+            &self.provider,
+            maybe_lag,
+            self.finality,
+        )
+        .await?;
 
         Ok(call.call().await?.into())
     }
 
     #[instrument(skip(self))]
     async fn count(&self, maybe_lag: Option<NonZeroU64>) -> ChainResult<u32> {
-        let call = call_with_lag(self.contract.count(), &self.provider, maybe_lag).await?;
+        let call = call_with_lag(
+            self.contract.count(),
+            &self.provider,
+            maybe_lag,
+            self.finality,
+        )
+        .await?;
         let count = call.call().await?;
         Ok(count)
     }
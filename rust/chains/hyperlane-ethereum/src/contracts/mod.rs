@@ -1,6 +1,9 @@
-pub use {interchain_gas::*, mailbox::*, merkle_tree_hook::*, validator_announce::*};
+pub use {
+    interchain_gas::*, log_subscriber::*, mailbox::*, merkle_tree_hook::*, validator_announce::*,
+};
 
 mod interchain_gas;
+mod log_subscriber;
 mod mailbox;
 mod merkle_tree_hook;
 mod multicall;
@@ -1,22 +1,26 @@
 #![allow(clippy::enum_variant_names)]
 #![allow(missing_docs)]
 
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, ops::RangeInclusive, sync::Arc};
 
 use async_trait::async_trait;
 use ethers::providers::Middleware;
 use ethers_contract::builders::ContractCall;
 use hyperlane_core::{
-    Announcement, ChainResult, ContractLocator, HyperlaneAbi, HyperlaneChain, HyperlaneContract,
-    HyperlaneDomain, HyperlaneProvider, SignedType, TxOutcome, ValidatorAnnounce, H160, H256, U256,
+    Announcement, BlockFinality, ChainResult, ContractLocator, HyperlaneAbi, HyperlaneChain,
+    HyperlaneContract, HyperlaneDomain, HyperlaneProvider, Indexed, Indexer, LogMeta,
+    SequenceAwareIndexer, SignedType, TxOutcome, ValidatorAnnounce, ValidatorAnnouncement, H160,
+    H256, H512, U256,
 };
 use tracing::{instrument, log::trace};
 
+use super::utils::fetch_raw_logs_and_log_meta;
 use crate::{
     interfaces::i_validator_announce::{
-        IValidatorAnnounce as EthereumValidatorAnnounceInternal, IVALIDATORANNOUNCE_ABI,
+        IValidatorAnnounce as EthereumValidatorAnnounceInternal, ValidatorAnnouncementFilter,
+        IVALIDATORANNOUNCE_ABI,
     },
-    tx::{fill_tx_gas_params, report_tx},
+    tx::{fill_tx_gas_params, get_finalized_block_number, report_tx},
     BuildableWithProvider, ConnectionConf, EthereumProvider,
 };
 
@@ -169,6 +173,135 @@ where
     }
 }
 
+pub struct ValidatorAnnounceIndexerBuilder {
+    pub reorg_period: u32,
+    pub finality: BlockFinality,
+}
+
+#[async_trait]
+impl BuildableWithProvider for ValidatorAnnounceIndexerBuilder {
+    type Output = Box<dyn SequenceAwareIndexer<ValidatorAnnouncement>>;
+
+    async fn build_with_provider<M: Middleware + 'static>(
+        &self,
+        provider: M,
+        _conn: &ConnectionConf,
+        locator: &ContractLocator,
+    ) -> Self::Output {
+        Box::new(EthereumValidatorAnnounceIndexer::new(
+            Arc::new(provider),
+            locator,
+            self.reorg_period,
+            self.finality,
+        ))
+    }
+}
+
+#[derive(Debug)]
+/// Struct that retrieves storage location announcements from an Ethereum
+/// ValidatorAnnounce contract
+pub struct EthereumValidatorAnnounceIndexer<M>
+where
+    M: Middleware,
+{
+    contract: Arc<EthereumValidatorAnnounceInternal<M>>,
+    provider: Arc<M>,
+    reorg_period: u32,
+    finality: BlockFinality,
+}
+
+impl<M> EthereumValidatorAnnounceIndexer<M>
+where
+    M: Middleware + 'static,
+{
+    /// Create new EthereumValidatorAnnounceIndexer
+    pub fn new(
+        provider: Arc<M>,
+        locator: &ContractLocator,
+        reorg_period: u32,
+        finality: BlockFinality,
+    ) -> Self {
+        Self {
+            contract: Arc::new(EthereumValidatorAnnounceInternal::new(
+                locator.address,
+                provider.clone(),
+            )),
+            provider,
+            reorg_period,
+            finality,
+        }
+    }
+}
+
+impl From<ValidatorAnnouncementFilter> for ValidatorAnnouncement {
+    fn from(log: ValidatorAnnouncementFilter) -> Self {
+        ValidatorAnnouncement {
+            validator: log.validator.into(),
+            storage_location: log.storage_location,
+        }
+    }
+}
+
+#[async_trait]
+impl<M> Indexer<ValidatorAnnouncement> for EthereumValidatorAnnounceIndexer<M>
+where
+    M: Middleware + 'static,
+{
+    /// Note: This call may return duplicates depending on the provider used
+    #[instrument(err, skip(self))]
+    async fn fetch_logs_in_range(
+        &self,
+        range: RangeInclusive<u32>,
+    ) -> ChainResult<Vec<(Indexed<ValidatorAnnouncement>, LogMeta)>> {
+        let events = self
+            .contract
+            .validator_announcement_filter()
+            .from_block(*range.start())
+            .to_block(*range.end())
+            .query_with_meta()
+            .await?;
+
+        Ok(events
+            .into_iter()
+            .map(|(log, log_meta)| (Indexed::new(log.into()), log_meta.into()))
+            .collect())
+    }
+
+    #[instrument(level = "debug", err, ret, skip(self))]
+    async fn get_finalized_block_number(&self) -> ChainResult<u32> {
+        get_finalized_block_number(&*self.provider, self.finality, self.reorg_period).await
+    }
+
+    async fn fetch_logs_by_tx_hash(
+        &self,
+        tx_hash: H512,
+    ) -> ChainResult<Vec<(Indexed<ValidatorAnnouncement>, LogMeta)>> {
+        let logs = fetch_raw_logs_and_log_meta::<ValidatorAnnouncementFilter, M>(
+            tx_hash,
+            self.provider.clone(),
+            self.contract.address(),
+        )
+        .await?
+        .into_iter()
+        .map(|(log, log_meta)| (Indexed::new(log.into()), log_meta))
+        .collect();
+        Ok(logs)
+    }
+}
+
+#[async_trait]
+impl<M> SequenceAwareIndexer<ValidatorAnnouncement> for EthereumValidatorAnnounceIndexer<M>
+where
+    M: Middleware + 'static,
+{
+    async fn latest_sequence_count_and_tip(&self) -> ChainResult<(Option<u32>, u32)> {
+        // Announcements are not sequenced, they are indexed by block using a
+        // watermark cursor.
+        let tip = self.get_finalized_block_number().await?;
+        Ok((None, tip))
+    }
+}
+
 pub struct EthereumValidatorAnnounceAbi;
 
 impl HyperlaneAbi for EthereumValidatorAnnounceAbi {
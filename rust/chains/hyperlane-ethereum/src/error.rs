@@ -18,6 +18,10 @@ pub enum HyperlaneEthereumError {
     /// Some details from a queried block are missing
     #[error("Some details from a queried block are missing")]
     MissingBlockDetails,
+
+    /// The RPC did not return a block for a finality block tag
+    #[error("RPC does not support the `{0}` block tag")]
+    UnsupportedBlockTag(&'static str),
 }
 
 impl From<HyperlaneEthereumError> for ChainCommunicationError {
@@ -12,10 +12,12 @@ use tokio::time::sleep;
 use tracing::instrument;
 
 use hyperlane_core::{
-    BlockInfo, ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain,
-    HyperlaneDomain, HyperlaneProvider, HyperlaneProviderError, TxnInfo, TxnReceiptInfo, H256,
+    BlockFinality, BlockInfo, ChainCommunicationError, ChainResult, ContractLocator,
+    HyperlaneChain, HyperlaneDomain, HyperlaneProvider, HyperlaneProviderError, TxnInfo,
+    TxnReceiptInfo, H256,
 };
 
+use crate::tx::get_finalized_block_number;
 use crate::{BuildableWithProvider, ConnectionConf};
 
 /// Connection to an ethereum provider. Useful for querying information about
@@ -117,6 +119,29 @@ where
         Ok(balance.into())
     }
 
+    #[instrument(err, skip(self))]
+    async fn get_nonce(&self, address: String) -> ChainResult<Option<u64>> {
+        let addr: Address = address.parse()?;
+        let nonce = self
+            .provider
+            .get_transaction_count(addr, None)
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        Ok(Some(nonce.as_u64()))
+    }
+
+    #[instrument(err, skip(self))]
+    async fn get_gas_price(&self) -> ChainResult<Option<U256>> {
+        // `eth_gasPrice` includes the base fee and a suggested priority fee
+        // on EIP-1559 chains
+        let gas_price = self
+            .provider
+            .get_gas_price()
+            .await
+            .map_err(ChainCommunicationError::from_other)?;
+        Ok(Some(gas_price.into()))
+    }
+
     async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
         let Some(block) = self
             .provider
@@ -184,6 +209,29 @@ impl BuildableWithProvider for HyperlaneProviderBuilder {
     }
 }
 
+/// Builder that queries the latest final block once, to check that the RPC
+/// supports the block tag of `finality` before any indexer relies on it.
+pub struct BlockFinalityProbe {
+    /// How the latest final block is determined
+    pub finality: BlockFinality,
+    /// The reorg period of the chain
+    pub reorg_period: u32,
+}
+
+#[async_trait]
+impl BuildableWithProvider for BlockFinalityProbe {
+    type Output = ChainResult<u32>;
+
+    async fn build_with_provider<M: Middleware + 'static>(
+        &self,
+        provider: M,
+        _conn: &ConnectionConf,
+        _locator: &ContractLocator,
+    ) -> Self::Output {
+        get_finalized_block_number(&provider, self.finality, self.reorg_period).await
+    }
+}
+
 /// Call a get function that returns a Result<Option<T>> and retry if the inner
 /// option is None. This can happen because the provider has not discovered the
 /// object we are looking for yet.
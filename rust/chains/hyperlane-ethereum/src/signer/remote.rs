@@ -0,0 +1,237 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use ethers::core::types::{
+    transaction::{eip2718::TypedTransaction, eip712::Eip712},
+    Address, Signature, SignatureError, H256,
+};
+use ethers::core::utils::keccak256;
+use ethers_signers::{to_eip155_v, Signer};
+use reqwest::Client;
+use serde::Serialize;
+use thiserror::Error;
+use url::Url;
+
+/// A signer that forwards signing requests to a remote signing service over
+/// HTTP, using the web3signer eth1 API. The service is expected to sign the
+/// keccak256 hash of the data it is sent.
+#[derive(Clone)]
+pub struct RemoteSigner {
+    client: Client,
+    url: Url,
+    address: Address,
+    chain_id: u64,
+}
+
+impl fmt::Debug for RemoteSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteSigner")
+            .field("url", &self.url.as_str())
+            .field("address", &self.address)
+            .field("chain_id", &self.chain_id)
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
+struct SignRequest {
+    data: String,
+}
+
+impl RemoteSigner {
+    /// Create a signer for the key of `address` held by the signing service
+    /// at `url`. Timeouts and TLS are configured through `client`.
+    pub fn new(client: Client, url: Url, address: Address) -> Self {
+        Self {
+            client,
+            url,
+            address,
+            chain_id: 1,
+        }
+    }
+
+    /// Have the remote service sign `data`, checking the signature is by the
+    /// expected key.
+    async fn sign_data(&self, data: &[u8]) -> Result<Signature, RemoteSignerError> {
+        let url = self
+            .url
+            .join(&format!("api/v1/eth1/sign/{:?}", self.address))?;
+        let response = self
+            .client
+            .post(url)
+            .json(&SignRequest {
+                data: format!("0x{}", hex::encode(data)),
+            })
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        let mut signature: Signature = response.trim().trim_matches('"').parse()?;
+        if signature.v < 27 {
+            signature.v += 27;
+        }
+        let signer = signature.recover(H256::from(keccak256(data)))?;
+        if signer != self.address {
+            return Err(RemoteSignerError::UnexpectedSigner(signer));
+        }
+        Ok(signature)
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    type Error = RemoteSignerError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        let message = message.as_ref();
+        let mut data = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+        data.extend_from_slice(message);
+        self.sign_data(&data).await
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+        let mut tx = tx.clone();
+        let chain_id = tx.chain_id().map(|id| id.as_u64()).unwrap_or(self.chain_id);
+        tx.set_chain_id(chain_id);
+        let mut signature = self.sign_data(&tx.rlp()).await?;
+        signature.v = to_eip155_v(signature.v as u8 - 27, chain_id);
+        Ok(signature)
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        // The keccak256 hash of this preimage is the EIP-712 signing hash
+        let mut data = vec![0x19, 0x01];
+        data.extend_from_slice(
+            &payload
+                .domain_separator()
+                .map_err(|err| RemoteSignerError::Eip712(err.to_string()))?,
+        );
+        data.extend_from_slice(
+            &payload
+                .struct_hash()
+                .map_err(|err| RemoteSignerError::Eip712(err.to_string()))?,
+        );
+        self.sign_data(&data).await
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        self.chain_id = chain_id.into();
+        self
+    }
+}
+
+/// An error incurred by the remote signer
+#[derive(Error, Debug)]
+pub enum RemoteSignerError {
+    /// The request to the signing service failed
+    #[error("Remote signer request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    /// The signing service url could not be built
+    #[error("Invalid remote signer url: {0}")]
+    Url(#[from] url::ParseError),
+    /// The signing service returned an invalid signature
+    #[error("Invalid signature from remote signer: {0}")]
+    Signature(#[from] SignatureError),
+    /// The signing service signed with a key other than the configured one
+    #[error("Remote signer signed with unexpected key {0:?}")]
+    UnexpectedSigner(Address),
+    /// The typed data could not be encoded
+    #[error("Unable to encode typed data: {0}")]
+    Eip712(String),
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::SocketAddr;
+
+    use axum::{routing::post, Json, Router};
+    use ethers::core::types::TransactionRequest;
+    use ethers_signers::LocalWallet;
+    use hyperlane_core::{
+        Checkpoint, CheckpointWithMessageId, HyperlaneSigner, HyperlaneSignerExt,
+    };
+    use serde_json::Value;
+
+    use super::*;
+    use crate::Signers;
+
+    const KEY: &str = "1111111111111111111111111111111111111111111111111111111111111111";
+
+    /// Signs the keccak256 hash of the request data with `KEY`, the way the
+    /// web3signer eth1 API does.
+    async fn mock_signer(Json(request): Json<Value>) -> String {
+        let wallet: LocalWallet = KEY.parse().unwrap();
+        let data = hex::decode(request["data"].as_str().unwrap().trim_start_matches("0x")).unwrap();
+        let signature = wallet.sign_hash(keccak256(data).into()).unwrap();
+        format!("0x{signature}")
+    }
+
+    async fn remote_signer() -> RemoteSigner {
+        let server = axum::Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(
+            Router::new()
+                .route("/api/v1/eth1/sign/:identifier", post(mock_signer))
+                .into_make_service(),
+        );
+        let url = format!("http://{}", server.local_addr()).parse().unwrap();
+        tokio::spawn(server);
+        let address = KEY.parse::<LocalWallet>().unwrap().address();
+        RemoteSigner::new(Client::new(), url, address)
+    }
+
+    #[tokio::test]
+    async fn test_sign_checkpoint() {
+        let signer: Signers = remote_signer().await.into();
+        let message = CheckpointWithMessageId {
+            checkpoint: Checkpoint {
+                merkle_tree_hook_address: H256::repeat_byte(2),
+                mailbox_domain: 5,
+                root: H256::repeat_byte(1),
+                index: 123,
+            },
+            message_id: H256::repeat_byte(3),
+        };
+
+        let signed = signer.sign(message).await.expect("!sign");
+        signed.verify(signer.eth_address()).expect("!verify");
+    }
+
+    #[tokio::test]
+    async fn test_sign_transaction() {
+        let signer = remote_signer().await.with_chain_id(5u64);
+        let tx: TypedTransaction = TransactionRequest::new()
+            .to(Address::repeat_byte(1))
+            .value(100)
+            .nonce(0)
+            .gas(21000)
+            .gas_price(1)
+            .into();
+
+        let signature = signer.sign_transaction(&tx).await.unwrap();
+        assert!(signature.v == 45 || signature.v == 46);
+        let mut tx = tx;
+        tx.set_chain_id(5u64);
+        assert_eq!(signature.recover(tx.sighash()).unwrap(), signer.address());
+    }
+
+    #[tokio::test]
+    async fn test_wrong_key_is_rejected() {
+        let signer = remote_signer().await;
+        let signer = RemoteSigner::new(signer.client, signer.url, Address::repeat_byte(9));
+        assert!(signer.sign_message(b"hello").await.is_err());
+    }
+}
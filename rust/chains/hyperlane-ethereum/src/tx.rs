@@ -16,9 +16,12 @@ use ethers_core::{
         EIP1559_FEE_ESTIMATION_REWARD_PERCENTILE,
     },
 };
-use hyperlane_core::{utils::bytes_to_hex, ChainCommunicationError, ChainResult, H256, U256};
+use hyperlane_core::{
+    utils::bytes_to_hex, BlockFinality, ChainCommunicationError, ChainResult, H256, U256,
+};
 use tracing::{error, info};
 
+use crate::error::HyperlaneEthereumError;
 use crate::{Middleware, TransactionOverrides};
 
 /// An amount of gas to add to the estimated gas
@@ -192,24 +195,135 @@ where
     Ok((base_fee_per_gas, max_fee_per_gas, max_priority_fee_per_gas))
 }
 
+/// Returns the latest block number that is final according to `finality`.
+/// When a block tag is used, an error is returned if the RPC does not support it.
+pub(crate) async fn get_finalized_block_number<M>(
+    provider: &M,
+    finality: BlockFinality,
+    reorg_period: u32,
+) -> ChainResult<u32>
+where
+    M: Middleware + 'static,
+{
+    let (tag_name, tag) = match finality {
+        BlockFinality::BlockLag => {
+            return Ok(provider
+                .get_block_number()
+                .await
+                .map_err(ChainCommunicationError::from_other)?
+                .as_u32()
+                .saturating_sub(reorg_period))
+        }
+        BlockFinality::Safe => ("safe", BlockNumber::Safe),
+        BlockFinality::Finalized => ("finalized", BlockNumber::Finalized),
+    };
+    let block = provider.get_block(tag).await.map_err(|e| {
+        error!(tag = tag_name, error = ?e, "failed to get block by finality tag");
+        HyperlaneEthereumError::UnsupportedBlockTag(tag_name)
+    })?;
+    block
+        .and_then(|b| b.number)
+        .map(|n| n.as_u32())
+        .ok_or_else(|| HyperlaneEthereumError::UnsupportedBlockTag(tag_name).into())
+}
+
+/// Pins `call` to a past block if `maybe_lag` is set. With a block tag
+/// finality, the tagged block is used instead of lagging by `maybe_lag` blocks.
 pub(crate) async fn call_with_lag<M, T>(
     call: ethers::contract::builders::ContractCall<M, T>,
     provider: &M,
     maybe_lag: Option<NonZeroU64>,
+    finality: BlockFinality,
 ) -> ChainResult<ethers::contract::builders::ContractCall<M, T>>
 where
     M: Middleware + 'static,
     T: Detokenize,
 {
-    if let Some(lag) = maybe_lag {
-        let fixed_block_number: BlockNumber = provider
+    let Some(lag) = maybe_lag else {
+        return Ok(call);
+    };
+    let fixed_block_number: BlockNumber = match finality {
+        BlockFinality::BlockLag => provider
             .get_block_number()
             .await
             .map_err(ChainCommunicationError::from_other)?
             .saturating_sub(lag.get().into())
-            .into();
-        Ok(call.block(fixed_block_number))
-    } else {
-        Ok(call)
+            .into(),
+        BlockFinality::Safe | BlockFinality::Finalized => {
+            u64::from(get_finalized_block_number(provider, finality, 0).await?).into()
+        }
+    };
+    Ok(call.block(fixed_block_number))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use ethers::{
+        providers::{MockProvider, Provider},
+        types::{Block, H256 as EthersH256, U64},
+    };
+    use hyperlane_core::BlockFinality;
+
+    use super::get_finalized_block_number;
+
+    fn mock_provider() -> (Arc<MockProvider>, Provider<Arc<MockProvider>>) {
+        let mock = Arc::new(MockProvider::new());
+        (mock.clone(), Provider::new(mock))
+    }
+
+    fn block(number: u64) -> Block<EthersH256> {
+        Block {
+            number: Some(number.into()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_block_lag_finality() {
+        let (mock, provider) = mock_provider();
+        mock.push(U64::from(100)).unwrap();
+        let block = get_finalized_block_number(&provider, BlockFinality::BlockLag, 10)
+            .await
+            .unwrap();
+        assert_eq!(block, 90);
+
+        mock.push(U64::from(5)).unwrap();
+        let block = get_finalized_block_number(&provider, BlockFinality::BlockLag, 10)
+            .await
+            .unwrap();
+        assert_eq!(block, 0);
+    }
+
+    #[tokio::test]
+    async fn test_block_tag_finality() {
+        for finality in [BlockFinality::Safe, BlockFinality::Finalized] {
+            let (mock, provider) = mock_provider();
+            mock.push(block(95)).unwrap();
+            // The reorg period is ignored in favor of the tagged block
+            let block = get_finalized_block_number(&provider, finality, 10)
+                .await
+                .unwrap();
+            assert_eq!(block, 95);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_block_tag() {
+        for finality in [BlockFinality::Safe, BlockFinality::Finalized] {
+            // No block is returned for the tag
+            let (mock, provider) = mock_provider();
+            mock.push::<Option<Block<EthersH256>>, _>(None).unwrap();
+            assert!(get_finalized_block_number(&provider, finality, 10)
+                .await
+                .is_err());
+
+            // The RPC rejects the tag
+            let (_mock, provider) = mock_provider();
+            assert!(get_finalized_block_number(&provider, finality, 10)
+                .await
+                .is_err());
+        }
     }
 }
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 
 use hyperlane_core::{
     ChainResult, HyperlaneChain, HyperlaneContract, Indexed, Indexer, InterchainGasPaymaster,
+    OracleGasData, RemoteGasDataConfig, TxOutcome,
 };
 use hyperlane_core::{HyperlaneDomain, HyperlaneProvider, InterchainGasPayment, LogMeta, H256};
 
@@ -27,7 +28,19 @@ impl HyperlaneChain for FuelInterchainGasPaymaster {
     }
 }
 
-impl InterchainGasPaymaster for FuelInterchainGasPaymaster {}
+#[async_trait]
+impl InterchainGasPaymaster for FuelInterchainGasPaymaster {
+    async fn remote_gas_data(&self, remote_domain: u32) -> ChainResult<Option<OracleGasData>> {
+        todo!()
+    }
+
+    async fn set_remote_gas_data(
+        &self,
+        configs: Vec<RemoteGasDataConfig>,
+    ) -> ChainResult<TxOutcome> {
+        todo!()
+    }
+}
 
 /// Struct that retrieves event data for a Fuel IGP contract
 #[derive(Debug)]
@@ -37,6 +37,14 @@ impl HyperlaneProvider for FuelProvider {
         todo!()
     }
 
+    async fn get_nonce(&self, address: String) -> ChainResult<Option<u64>> {
+        todo!()
+    }
+
+    async fn get_gas_price(&self) -> ChainResult<Option<U256>> {
+        Ok(None)
+    }
+
     async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
         Ok(None)
     }
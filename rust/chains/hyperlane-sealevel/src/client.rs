@@ -1,22 +1,58 @@
-use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_sdk::commitment_config::CommitmentConfig;
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use hyperlane_core::{
+    rpc_clients::{BlockNumberGetter, FallbackProvider, RpcClientError},
+    ChainCommunicationError, ChainResult,
+};
+use jsonrpc_core::Value;
+use serde::Deserialize;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_client::GetConfirmedSignaturesForAddress2Config,
+    rpc_config::{RpcProgramAccountsConfig, RpcTransactionConfig},
+    rpc_request::RpcRequest,
+    rpc_response::{RpcConfirmedTransactionStatusWithSignature, RpcSimulateTransactionResult},
+};
+use solana_sdk::{
+    account::Account, commitment_config::CommitmentConfig, hash::Hash, pubkey::Pubkey,
+    signature::Signature, transaction::Transaction,
+};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use url::Url;
+
+use crate::ConnectionConf;
+
+type RpcFuture<V> = Pin<Box<dyn Future<Output = ChainResult<V>> + Send>>;
+
+/// An entry of the `getRecentPrioritizationFees` response, which solana-client
+/// 1.14 has no typed method for.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecentPrioritizationFee {
+    prioritization_fee: u64,
+}
 
 /// Kludge to implement Debug for RpcClient.
-pub struct RpcClientWithDebug(RpcClient);
+#[derive(Clone)]
+pub struct RpcClientWithDebug {
+    client: Arc<RpcClient>,
+    url: Url,
+}
 
 impl RpcClientWithDebug {
-    pub fn new(rpc_endpoint: String) -> Self {
-        Self(RpcClient::new(rpc_endpoint))
-    }
-
-    pub fn new_with_commitment(rpc_endpoint: String, commitment: CommitmentConfig) -> Self {
-        Self(RpcClient::new_with_commitment(rpc_endpoint, commitment))
+    pub fn new_with_commitment(url: Url, commitment: CommitmentConfig) -> Self {
+        Self {
+            client: Arc::new(RpcClient::new_with_commitment(url.to_string(), commitment)),
+            url,
+        }
     }
 }
 
 impl std::fmt::Debug for RpcClientWithDebug {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("RpcClient { ... }")
+        write!(f, "RpcClient {{ url: {} }}", self.url)
     }
 }
 
@@ -24,6 +60,459 @@ impl std::ops::Deref for RpcClientWithDebug {
     type Target = RpcClient;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.client
+    }
+}
+
+#[async_trait]
+impl BlockNumberGetter for RpcClientWithDebug {
+    async fn get_block_number(&self) -> Result<u64, ChainCommunicationError> {
+        self.client
+            .get_slot_with_commitment(CommitmentConfig::processed())
+            .await
+            .map_err(ChainCommunicationError::from_other)
+    }
+}
+
+/// An RPC client that sends requests to the configured urls in order of
+/// priority, falling back to the next one when a request fails. In quorum
+/// mode, the account reads validators sign checkpoints from are sent to all
+/// urls and a majority must agree.
+#[derive(Clone, Debug)]
+pub struct SealevelRpcClient {
+    provider: FallbackProvider<RpcClientWithDebug, RpcClientWithDebug>,
+    quorum: bool,
+}
+
+impl SealevelRpcClient {
+    /// Create a client for the urls in `conf` using the given commitment
+    pub fn new(conf: &ConnectionConf, commitment: CommitmentConfig) -> Self {
+        let clients = conf
+            .urls
+            .iter()
+            .map(|url| RpcClientWithDebug::new_with_commitment(url.clone(), commitment));
+        Self {
+            provider: FallbackProvider::new(clients),
+            quorum: conf.quorum,
+        }
+    }
+
+    async fn call<V>(&self, f: impl FnMut(RpcClientWithDebug) -> RpcFuture<V>) -> ChainResult<V> {
+        self.provider.call(f).await
+    }
+
+    /// Sends the request to every client when in quorum mode, returning the
+    /// response a majority of them agree on according to `same`. Otherwise
+    /// behaves like `call`.
+    async fn quorum_call<V>(
+        &self,
+        f: impl FnMut(RpcClientWithDebug) -> RpcFuture<V>,
+        same: impl Fn(&V, &V) -> bool,
+    ) -> ChainResult<V> {
+        if !self.quorum {
+            return self.call(f).await;
+        }
+        quorum_call(&self.provider.inner.providers, f, same).await
+    }
+
+    /// Get the account at `pubkey`, if it exists
+    pub async fn get_account_with_commitment(
+        &self,
+        pubkey: &Pubkey,
+        commitment: CommitmentConfig,
+    ) -> ChainResult<Option<Account>> {
+        let pubkey = *pubkey;
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .get_account_with_commitment(&pubkey, commitment)
+                    .await
+                    .map(|response| response.value)
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the account at `pubkey`, if it exists. In quorum mode, a majority
+    /// of the urls must agree on the account's owner and data, which is what
+    /// validators sign checkpoints from. Other fields, e.g. the lamports and
+    /// rent epoch, are not compared, as nodes may report them differently.
+    pub async fn get_quorum_account_with_commitment(
+        &self,
+        pubkey: &Pubkey,
+        commitment: CommitmentConfig,
+    ) -> ChainResult<Option<Account>> {
+        let pubkey = *pubkey;
+        self.quorum_call(
+            move |client| {
+                Box::pin(async move {
+                    client
+                        .get_account_with_commitment(&pubkey, commitment)
+                        .await
+                        .map(|response| response.value)
+                        .map_err(ChainCommunicationError::from_other)
+                })
+            },
+            same_account_data,
+        )
+        .await
+    }
+
+    /// Get the accounts at `pubkeys`, with `None` for the ones that don't exist
+    pub async fn get_multiple_accounts_with_commitment(
+        &self,
+        pubkeys: &[Pubkey],
+        commitment: CommitmentConfig,
+    ) -> ChainResult<Vec<Option<Account>>> {
+        let pubkeys = pubkeys.to_vec();
+        self.call(move |client| {
+            let pubkeys = pubkeys.clone();
+            Box::pin(async move {
+                client
+                    .get_multiple_accounts_with_commitment(&pubkeys, commitment)
+                    .await
+                    .map(|response| response.value)
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the accounts owned by `program_id` that match `config`
+    pub async fn get_program_accounts_with_config(
+        &self,
+        program_id: &Pubkey,
+        config: RpcProgramAccountsConfig,
+    ) -> ChainResult<Vec<(Pubkey, Account)>> {
+        let program_id = *program_id;
+        self.call(move |client| {
+            let config = config.clone();
+            Box::pin(async move {
+                client
+                    .get_program_accounts_with_config(&program_id, config)
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the signatures of the transactions involving `address`, newest
+    /// first, starting before the transaction with signature `before` if set
+    pub async fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+        before: Option<Signature>,
+        commitment: CommitmentConfig,
+    ) -> ChainResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        let address = *address;
+        self.call(move |client| {
+            Box::pin(async move {
+                let config = GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until: None,
+                    limit: None,
+                    commitment: Some(commitment),
+                };
+                client
+                    .get_signatures_for_address_with_config(&address, config)
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the transaction with `signature`
+    pub async fn get_transaction_with_config(
+        &self,
+        signature: &Signature,
+        config: RpcTransactionConfig,
+    ) -> ChainResult<EncodedConfirmedTransactionWithStatusMeta> {
+        let signature = *signature;
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .get_transaction_with_config(&signature, config)
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the account at `pubkey`, erroring if it doesn't exist
+    pub async fn get_account(&self, pubkey: &Pubkey) -> ChainResult<Account> {
+        let pubkey = *pubkey;
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .get_account(&pubkey)
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the balance of `pubkey` in lamports
+    pub async fn get_balance(&self, pubkey: &Pubkey) -> ChainResult<u64> {
+        let pubkey = *pubkey;
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .get_balance(&pubkey)
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the prioritization fees paid in recent slots, in micro-lamports
+    /// per compute unit
+    pub async fn get_recent_prioritization_fees(&self) -> ChainResult<Vec<u64>> {
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .send::<Vec<RecentPrioritizationFee>>(
+                        RpcRequest::Custom {
+                            method: "getRecentPrioritizationFees",
+                        },
+                        Value::Array(vec![]),
+                    )
+                    .await
+                    .map(|fees| fees.into_iter().map(|f| f.prioritization_fee).collect())
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the current block height
+    pub async fn get_block_height(&self) -> ChainResult<u64> {
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .get_block_height()
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the current slot
+    pub async fn get_slot_with_commitment(&self, commitment: CommitmentConfig) -> ChainResult<u64> {
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .get_slot_with_commitment(commitment)
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Get the latest blockhash and the last block height at which it is valid
+    pub async fn get_latest_blockhash_with_commitment(
+        &self,
+        commitment: CommitmentConfig,
+    ) -> ChainResult<(Hash, u64)> {
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .get_latest_blockhash_with_commitment(commitment)
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Simulate `transaction`
+    pub async fn simulate_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> ChainResult<RpcSimulateTransactionResult> {
+        let transaction = transaction.clone();
+        self.call(move |client| {
+            let transaction = transaction.clone();
+            Box::pin(async move {
+                client
+                    .simulate_transaction(&transaction)
+                    .await
+                    .map(|response| response.value)
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Send `transaction` and wait for it to be confirmed
+    pub async fn send_and_confirm_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> ChainResult<Signature> {
+        let transaction = transaction.clone();
+        self.call(move |client| {
+            let transaction = transaction.clone();
+            Box::pin(async move {
+                client
+                    .send_and_confirm_transaction(&transaction)
+                    .await
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+
+    /// Whether the transaction with `signature` has reached `commitment`
+    pub async fn confirm_transaction_with_commitment(
+        &self,
+        signature: &Signature,
+        commitment: CommitmentConfig,
+    ) -> ChainResult<bool> {
+        let signature = *signature;
+        self.call(move |client| {
+            Box::pin(async move {
+                client
+                    .confirm_transaction_with_commitment(&signature, commitment)
+                    .await
+                    .map(|response| response.value)
+                    .map_err(ChainCommunicationError::from_other)
+            })
+        })
+        .await
+    }
+}
+
+/// Sends the request to every client, returning the response a majority of
+/// them agree on according to `same`.
+async fn quorum_call<C: Clone, V>(
+    clients: &[C],
+    f: impl FnMut(C) -> RpcFuture<V>,
+    same: impl Fn(&V, &V) -> bool,
+) -> ChainResult<V> {
+    let threshold = clients.len() / 2 + 1;
+    let responses = join_all(clients.iter().cloned().map(f)).await;
+
+    let mut tallies: Vec<(V, usize)> = Vec::new();
+    let mut errors = Vec::new();
+    for response in responses {
+        match response {
+            Ok(value) => match tallies.iter_mut().find(|(v, _)| same(v, &value)) {
+                Some((_, count)) => *count += 1,
+                None => tallies.push((value, 1)),
+            },
+            Err(err) => errors.push(err),
+        }
+    }
+    tallies
+        .into_iter()
+        .find(|(_, count)| *count >= threshold)
+        .map(|(value, _)| value)
+        .ok_or_else(|| RpcClientError::QuorumNotReached { threshold, errors }.into())
+}
+
+/// Whether both reads found the same account owner and data.
+fn same_account_data(a: &Option<Account>, b: &Option<Account>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.owner == b.owner && a.data == b.data,
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    /// A client that always returns `response`, or errors if it's `None`.
+    #[derive(Clone, Debug)]
+    struct MockClient {
+        response: Option<Option<Account>>,
+    }
+
+    #[async_trait]
+    impl BlockNumberGetter for MockClient {
+        async fn get_block_number(&self) -> Result<u64, ChainCommunicationError> {
+            Ok(0)
+        }
+    }
+
+    fn account(data: &[u8], lamports: u64) -> Option<Account> {
+        Some(Account {
+            lamports,
+            data: data.to_vec(),
+            owner: Pubkey::new_from_array([1; 32]),
+            executable: false,
+            rent_epoch: lamports,
+        })
+    }
+
+    fn ok(response: Option<Account>) -> MockClient {
+        MockClient {
+            response: Some(response),
+        }
+    }
+
+    fn failing() -> MockClient {
+        MockClient { response: None }
+    }
+
+    fn get_account(client: MockClient) -> RpcFuture<Option<Account>> {
+        Box::pin(async move {
+            client
+                .response
+                .ok_or_else(|| ChainCommunicationError::from_other_str("request failed"))
+        })
+    }
+
+    fn quorum_get_account(clients: &[MockClient]) -> ChainResult<Option<Account>> {
+        block_on(quorum_call(clients, get_account, same_account_data))
+    }
+
+    #[test]
+    fn test_fallback() {
+        let provider: FallbackProvider<MockClient, MockClient> = FallbackProvider::new([
+            failing(),
+            ok(account(b"second", 1)),
+            ok(account(b"third", 1)),
+        ]);
+        assert_eq!(
+            block_on(provider.call(get_account)).unwrap(),
+            account(b"second", 1)
+        );
+    }
+
+    #[test]
+    fn test_quorum_reached() {
+        // Lamports and rent epochs may differ between nodes
+        let clients = [
+            ok(account(b"data", 1)),
+            ok(account(b"data", 2)),
+            ok(account(b"stale", 1)),
+        ];
+        assert_eq!(quorum_get_account(&clients).unwrap(), account(b"data", 1));
+
+        // Failing clients count against the quorum
+        let clients = [ok(None), failing(), ok(None)];
+        assert_eq!(quorum_get_account(&clients).unwrap(), None);
+    }
+
+    #[test]
+    fn test_quorum_not_reached() {
+        let clients = [ok(account(b"data", 1)), ok(account(b"stale", 1)), failing()];
+        let err = quorum_get_account(&clients).unwrap_err();
+        assert!(err.to_string().contains("Fewer than 2 providers agreed"));
+
+        // Half of the clients is not a majority
+        let clients = [ok(account(b"data", 1)), ok(None)];
+        assert!(quorum_get_account(&clients).is_err());
     }
 }
@@ -2,11 +2,16 @@ use async_trait::async_trait;
 use hyperlane_core::{
     config::StrOrIntParseError, ChainCommunicationError, ChainResult, ContractLocator,
     HyperlaneChain, HyperlaneContract, HyperlaneDomain, HyperlaneProvider, Indexed, Indexer,
-    InterchainGasPaymaster, InterchainGasPayment, LogMeta, SequenceAwareIndexer, H256, H512,
+    InterchainGasPaymaster, InterchainGasPayment, LogMeta, OracleGasData, RemoteGasData,
+    RemoteGasDataConfig, SequenceAwareIndexer, TxOutcome, H256, H512, U256,
 };
 use hyperlane_sealevel_igp::{
-    accounts::{GasPaymentAccount, ProgramDataAccount},
+    accounts::{
+        GasOracle, GasPaymentAccount, GasPrice, GasPriceAccount, Igp, IgpAccount,
+        ProgramDataAccount, RemoteGasData as SealevelRemoteGasData,
+    },
     igp_gas_payment_pda_seeds, igp_program_data_pda_seeds,
+    instruction::{set_gas_oracle_configs_instruction, set_gas_price_instruction, GasOracleConfig},
 };
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_client::{
@@ -17,7 +22,8 @@ use std::ops::RangeInclusive;
 use tracing::{info, instrument};
 
 use crate::{
-    client::RpcClientWithDebug, utils::get_finalized_block_number, ConnectionConf, SealevelProvider,
+    client::SealevelRpcClient, utils::get_finalized_block_number, ConnectionConf, SealevelProvider,
+    SealevelSigner,
 };
 use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 
@@ -36,6 +42,7 @@ pub struct SealevelInterchainGasPaymaster {
     domain: HyperlaneDomain,
     igp_account: H256,
     provider: SealevelProvider,
+    payer: Option<SealevelSigner>,
 }
 
 impl SealevelInterchainGasPaymaster {
@@ -43,6 +50,7 @@ impl SealevelInterchainGasPaymaster {
     pub async fn new(
         conf: &ConnectionConf,
         igp_account_locator: &ContractLocator<'_>,
+        payer: Option<SealevelSigner>,
     ) -> ChainResult<Self> {
         let provider = SealevelProvider::new(igp_account_locator.domain.clone(), conf);
         let program_id =
@@ -56,11 +64,46 @@ impl SealevelInterchainGasPaymaster {
             domain: igp_account_locator.domain.clone(),
             igp_account: igp_account_locator.address,
             provider,
+            payer,
         })
     }
 
+    fn igp_account_pubkey(&self) -> Pubkey {
+        Pubkey::from(<[u8; 32]>::from(self.igp_account))
+    }
+
+    async fn fetch_igp(&self) -> ChainResult<Igp> {
+        let account = self
+            .provider
+            .rpc()
+            .get_account_with_commitment(&self.igp_account_pubkey(), CommitmentConfig::finalized())
+            .await?
+            .ok_or_else(|| {
+                ChainCommunicationError::from_other_str("Could not find IGP account for pubkey")
+            })?;
+        Ok(IgpAccount::fetch(&mut &account.data[..])
+            .map_err(ChainCommunicationError::from_other)?
+            .into_inner()
+            .data)
+    }
+
+    async fn fetch_gas_price(&self, price_account: &Pubkey) -> ChainResult<GasPrice> {
+        let account = self
+            .provider
+            .rpc()
+            .get_account_with_commitment(price_account, CommitmentConfig::finalized())
+            .await?
+            .ok_or_else(|| {
+                ChainCommunicationError::from_other_str("Could not find gas oracle price account")
+            })?;
+        Ok(GasPriceAccount::fetch(&mut &account.data[..])
+            .map_err(ChainCommunicationError::from_other)?
+            .into_inner()
+            .data)
+    }
+
     async fn determine_igp_program_id(
-        rpc_client: &RpcClientWithDebug,
+        rpc_client: &SealevelRpcClient,
         igp_account_pubkey: &H256,
     ) -> ChainResult<Pubkey> {
         let account = rpc_client
@@ -68,9 +111,7 @@ impl SealevelInterchainGasPaymaster {
                 &Pubkey::from(<[u8; 32]>::from(*igp_account_pubkey)),
                 CommitmentConfig::finalized(),
             )
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .value
+            .await?
             .ok_or_else(|| {
                 ChainCommunicationError::from_other_str("Could not find IGP account for pubkey")
             })?;
@@ -94,12 +135,150 @@ impl HyperlaneChain for SealevelInterchainGasPaymaster {
     }
 }
 
-impl InterchainGasPaymaster for SealevelInterchainGasPaymaster {}
+#[async_trait]
+impl InterchainGasPaymaster for SealevelInterchainGasPaymaster {
+    #[instrument(err, ret, skip(self))]
+    /// Price account gas oracles also report the slot their price account
+    /// was last updated in, as the IGP rejects gas prices older than the
+    /// oracle's `max_staleness_slots`.
+    async fn remote_gas_data(&self, remote_domain: u32) -> ChainResult<Option<OracleGasData>> {
+        let igp = self.fetch_igp().await?;
+
+        match igp.gas_oracles.get(&remote_domain) {
+            Some(GasOracle::RemoteGasData(remote_gas_data)) => {
+                Ok(Some(OracleGasData::new(RemoteGasData {
+                    token_exchange_rate: remote_gas_data.token_exchange_rate.into(),
+                    gas_price: remote_gas_data.gas_price.into(),
+                    token_decimals: Some(remote_gas_data.token_decimals),
+                })))
+            }
+            Some(GasOracle::PriceAccount(oracle)) => {
+                let gas_price = self.fetch_gas_price(&oracle.price_account).await?;
+                let current_slot = self
+                    .provider
+                    .rpc()
+                    .get_slot_with_commitment(CommitmentConfig::finalized())
+                    .await?;
+                Ok(Some(OracleGasData {
+                    remote_gas_data: RemoteGasData {
+                        token_exchange_rate: gas_price.token_exchange_rate.into(),
+                        gas_price: gas_price.gas_price.into(),
+                        token_decimals: Some(oracle.token_decimals),
+                    },
+                    last_updated_slot: Some(gas_price.last_updated_slot),
+                    current_slot: Some(current_slot),
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Domains whose gas oracle reads from a price account owned by the IGP
+    /// program have their price account written, with the payer expected to
+    /// be its updater. Other domains have their gas oracle set, with the payer
+    /// expected to be the owner of the IGP account.
+    #[instrument(err, ret, skip(self))]
+    async fn set_remote_gas_data(
+        &self,
+        configs: Vec<RemoteGasDataConfig>,
+    ) -> ChainResult<TxOutcome> {
+        let payer = self
+            .payer
+            .as_ref()
+            .ok_or_else(|| ChainCommunicationError::SignerUnavailable)?;
+
+        let igp = self.fetch_igp().await?;
+
+        let mut instructions = vec![];
+        let mut gas_oracle_configs = vec![];
+        for config in configs {
+            let remote_gas_data = config.remote_gas_data;
+            let token_exchange_rate = u256_to_u128(remote_gas_data.token_exchange_rate)?;
+            let gas_price = u256_to_u128(remote_gas_data.gas_price)?;
+
+            match igp.gas_oracles.get(&config.remote_domain) {
+                Some(GasOracle::PriceAccount(oracle)) => {
+                    if oracle.price_account_owner != self.program_id {
+                        return Err(ChainCommunicationError::from_other_str(
+                            "Price account of gas oracle is not owned by the IGP program",
+                        ));
+                    }
+                    instructions.push(
+                        set_gas_price_instruction(
+                            self.program_id,
+                            oracle.price_account,
+                            payer.pubkey(),
+                            token_exchange_rate,
+                            gas_price,
+                        )
+                        .map_err(ChainCommunicationError::from_other)?,
+                    );
+                }
+                _ => gas_oracle_configs.push(GasOracleConfig {
+                    domain: config.remote_domain,
+                    gas_oracle: Some(GasOracle::RemoteGasData(SealevelRemoteGasData {
+                        token_exchange_rate,
+                        gas_price,
+                        token_decimals: remote_gas_data.token_decimals.ok_or_else(|| {
+                            ChainCommunicationError::from_other_str(
+                                "Token decimals are required for Sealevel gas oracles",
+                            )
+                        })?,
+                    })),
+                }),
+            }
+        }
+
+        if !gas_oracle_configs.is_empty() {
+            instructions.push(
+                set_gas_oracle_configs_instruction(
+                    self.program_id,
+                    self.igp_account_pubkey(),
+                    payer.pubkey(),
+                    gas_oracle_configs,
+                )
+                .map_err(ChainCommunicationError::from_other)?,
+            );
+        }
+
+        let commitment = CommitmentConfig::processed();
+        let (recent_blockhash, _) = self
+            .provider
+            .rpc()
+            .get_latest_blockhash_with_commitment(commitment)
+            .await?;
+        let txn = payer
+            .sign_transaction(&instructions, recent_blockhash)
+            .await?;
+
+        let signature = self
+            .provider
+            .rpc()
+            .send_and_confirm_transaction(&txn)
+            .await?;
+
+        info!(?signature, "Set Sealevel IGP remote gas data");
+
+        Ok(TxOutcome {
+            transaction_id: signature.into(),
+            executed: true,
+            gas_price: U256::zero().try_into()?,
+            gas_used: U256::zero(),
+        })
+    }
+}
+
+/// Converts a U256 to the u128 used by the IGP's gas oracles.
+fn u256_to_u128(value: U256) -> ChainResult<u128> {
+    value
+        .try_into()
+        .map_err(|_| ChainCommunicationError::from_other_str("Value does not fit in a u128"))
+}
 
 /// Struct that retrieves event data for a Sealevel IGP contract
 #[derive(Debug)]
 pub struct SealevelInterchainGasPaymasterIndexer {
-    rpc_client: RpcClientWithDebug,
+    rpc_client: SealevelRpcClient,
     igp: SealevelInterchainGasPaymaster,
 }
 
@@ -118,12 +297,9 @@ impl SealevelInterchainGasPaymasterIndexer {
         igp_account_locator: ContractLocator<'_>,
     ) -> ChainResult<Self> {
         // Set the `processed` commitment at rpc level
-        let rpc_client = RpcClientWithDebug::new_with_commitment(
-            conf.url.to_string(),
-            CommitmentConfig::processed(),
-        );
+        let rpc_client = SealevelRpcClient::new(conf, CommitmentConfig::processed());
 
-        let igp = SealevelInterchainGasPaymaster::new(conf, &igp_account_locator).await?;
+        let igp = SealevelInterchainGasPaymaster::new(conf, &igp_account_locator, None).await?;
         Ok(Self { rpc_client, igp })
     }
 
@@ -169,8 +345,7 @@ impl SealevelInterchainGasPaymasterIndexer {
         let accounts = self
             .rpc_client
             .get_program_accounts_with_config(&self.igp.program_id, config)
-            .await
-            .map_err(ChainCommunicationError::from_other)?;
+            .await?;
 
         tracing::debug!(accounts=?accounts, "Fetched program accounts");
 
@@ -203,9 +378,7 @@ impl SealevelInterchainGasPaymasterIndexer {
         let account = self
             .rpc_client
             .get_account_with_commitment(&valid_payment_pda_pubkey, CommitmentConfig::finalized())
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .value
+            .await?
             .ok_or_else(|| {
                 ChainCommunicationError::from_other_str("Could not find account data")
             })?;
@@ -283,9 +456,7 @@ impl SequenceAwareIndexer<InterchainGasPayment> for SealevelInterchainGasPaymast
         let program_data_account = self
             .rpc_client
             .get_account_with_commitment(&self.igp.data_pda_pubkey, CommitmentConfig::finalized())
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .value
+            .await?
             .ok_or_else(|| {
                 ChainCommunicationError::from_other_str("Could not find account data")
             })?;
@@ -5,12 +5,13 @@
 #![deny(warnings)]
 
 pub use crate::multisig_ism::*;
-pub(crate) use client::RpcClientWithDebug;
+pub(crate) use client::SealevelRpcClient;
 pub use interchain_gas::*;
 pub use interchain_security_module::*;
 pub use mailbox::*;
 pub use merkle_tree_hook::*;
 pub use provider::*;
+pub use signer::*;
 pub use solana_sdk::signer::keypair::Keypair;
 pub use trait_builder::*;
 pub use validator_announce::*;
@@ -22,6 +23,7 @@ mod mailbox;
 mod merkle_tree_hook;
 mod multisig_ism;
 mod provider;
+mod signer;
 mod trait_builder;
 mod utils;
 
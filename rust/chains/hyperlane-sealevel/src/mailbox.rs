@@ -18,7 +18,7 @@ use hyperlane_sealevel_interchain_security_module_interface::{
     InterchainSecurityModuleInstruction, VerifyInstruction,
 };
 use hyperlane_sealevel_mailbox::{
-    accounts::{DispatchedMessageAccount, InboxAccount, OutboxAccount},
+    accounts::{DispatchedMessage, DispatchedMessageAccount, InboxAccount, OutboxAccount},
     instruction::InboxProcess,
     mailbox_dispatched_message_pda_seeds, mailbox_inbox_pda_seeds, mailbox_outbox_pda_seeds,
     mailbox_process_authority_pda_seeds, mailbox_processed_message_pda_seeds,
@@ -30,33 +30,30 @@ use serializable_account_meta::SimulationReturnData;
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_client::{
     nonblocking::rpc_client::RpcClient,
-    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSendTransactionConfig},
+    rpc_config::{
+        RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSendTransactionConfig,
+        RpcTransactionConfig,
+    },
     rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    rpc_response::RpcConfirmedTransactionStatusWithSignature,
 };
 use solana_sdk::{
-    account::Account,
-    commitment_config::CommitmentConfig,
-    compute_budget::ComputeBudgetInstruction,
-    hash::Hash,
-    instruction::AccountMeta,
-    instruction::Instruction,
-    message::Message,
-    pubkey::Pubkey,
-    signature::Signature,
-    signer::{keypair::Keypair, Signer as _},
-    transaction::{Transaction, VersionedTransaction},
+    account::Account, bs58, commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction, hash::Hash, instruction::AccountMeta,
+    instruction::Instruction, message::Message, pubkey::Pubkey, signature::Signature,
+    transaction::VersionedTransaction,
 };
 use solana_transaction_status::{
     EncodedConfirmedBlock, EncodedTransaction, EncodedTransactionWithStatusMeta,
     UiInnerInstructions, UiInstruction, UiMessage, UiParsedInstruction, UiReturnDataEncoding,
-    UiTransaction, UiTransactionReturnData, UiTransactionStatusMeta,
+    UiTransaction, UiTransactionEncoding, UiTransactionReturnData, UiTransactionStatusMeta,
 };
 
-use crate::RpcClientWithDebug;
 use crate::{
     utils::{get_account_metas, get_finalized_block_number, simulate_instruction},
     ConnectionConf, SealevelProvider,
 };
+use crate::{SealevelRpcClient, SealevelSigner};
 
 const SYSTEM_PROGRAM: &str = "11111111111111111111111111111111";
 const SPL_NOOP: &str = "noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV";
@@ -71,7 +68,7 @@ pub struct SealevelMailbox {
     inbox: (Pubkey, u8),
     pub(crate) outbox: (Pubkey, u8),
     pub(crate) provider: SealevelProvider,
-    payer: Option<Keypair>,
+    payer: Option<SealevelSigner>,
 }
 
 impl SealevelMailbox {
@@ -79,7 +76,7 @@ impl SealevelMailbox {
     pub fn new(
         conf: &ConnectionConf,
         locator: ContractLocator,
-        payer: Option<Keypair>,
+        payer: Option<SealevelSigner>,
     ) -> ChainResult<Self> {
         let provider = SealevelProvider::new(locator.domain.clone(), conf);
         let program_id = Pubkey::from(<[u8; 32]>::from(locator.address));
@@ -108,7 +105,7 @@ impl SealevelMailbox {
         self.outbox
     }
 
-    pub fn rpc(&self) -> &RpcClientWithDebug {
+    pub fn rpc(&self) -> &SealevelRpcClient {
         self.provider.rpc()
     }
 
@@ -300,19 +297,14 @@ impl Mailbox for SealevelMailbox {
                 &processed_message_account_key,
                 CommitmentConfig::finalized(),
             )
-            .await
-            .map_err(ChainCommunicationError::from_other)?;
+            .await?;
 
-        Ok(account.value.is_some())
+        Ok(account.is_some())
     }
 
     #[instrument(err, ret, skip(self))]
     async fn default_ism(&self) -> ChainResult<H256> {
-        let inbox_account = self
-            .rpc()
-            .get_account(&self.inbox.0)
-            .await
-            .map_err(ChainCommunicationError::from_other)?;
+        let inbox_account = self.rpc().get_account(&self.inbox.0).await?;
         let inbox = InboxAccount::fetch(&mut inbox_account.data.as_ref())
             .map_err(ChainCommunicationError::from_other)?
             .into_inner();
@@ -438,23 +430,15 @@ impl Mailbox for SealevelMailbox {
         let (recent_blockhash, _) = self
             .rpc()
             .get_latest_blockhash_with_commitment(commitment)
-            .await
-            .map_err(ChainCommunicationError::from_other)?;
+            .await?;
 
-        let txn = Transaction::new_signed_with_payer(
-            &instructions,
-            Some(&payer.pubkey()),
-            &[payer],
-            recent_blockhash,
-        );
+        let txn = payer
+            .sign_transaction(&instructions, recent_blockhash)
+            .await?;
 
         tracing::info!(?txn, "Created sealevel transaction to process message");
 
-        let signature = self
-            .rpc()
-            .send_and_confirm_transaction(&txn)
-            .await
-            .map_err(ChainCommunicationError::from_other)?;
+        let signature = self.rpc().send_and_confirm_transaction(&txn).await?;
 
         tracing::info!(?txn, ?signature, "Sealevel transaction sent");
 
@@ -463,7 +447,6 @@ impl Mailbox for SealevelMailbox {
             .confirm_transaction_with_commitment(&signature, commitment)
             .await
             .map_err(|err| warn!("Failed to confirm inbox process transaction: {}", err))
-            .map(|ctx| ctx.value)
             .unwrap_or(false);
         let txid = signature.into();
 
@@ -512,7 +495,7 @@ impl SealevelMailboxIndexer {
         })
     }
 
-    fn rpc(&self) -> &RpcClientWithDebug {
+    fn rpc(&self) -> &SealevelRpcClient {
         &self.mailbox.rpc()
     }
 
@@ -520,8 +503,7 @@ impl SealevelMailboxIndexer {
         let height = self
             .rpc()
             .get_block_height()
-            .await
-            .map_err(ChainCommunicationError::from_other)?
+            .await?
             .try_into()
             // FIXME solana block height is u64...
             .expect("sealevel block height exceeds u32::MAX");
@@ -566,8 +548,7 @@ impl SealevelMailboxIndexer {
         let accounts = self
             .rpc()
             .get_program_accounts_with_config(&self.mailbox.program_id, config)
-            .await
-            .map_err(ChainCommunicationError::from_other)?;
+            .await?;
 
         // Now loop through matching accounts and find the one with a valid account pubkey
         // that proves it's an actual message storage PDA.
@@ -604,9 +585,7 @@ impl SealevelMailboxIndexer {
                 &valid_message_storage_pda_pubkey,
                 CommitmentConfig::finalized(),
             )
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .value
+            .await?
             .ok_or_else(|| {
                 ChainCommunicationError::from_other_str("Could not find account data")
             })?;
@@ -614,8 +593,17 @@ impl SealevelMailboxIndexer {
             DispatchedMessageAccount::fetch(&mut account.data.as_ref())
                 .map_err(ChainCommunicationError::from_other)?
                 .into_inner();
-        let hyperlane_message =
-            HyperlaneMessage::read_from(&mut &dispatched_message_account.encoded_message[..])?;
+        let encoded_message = if dispatched_message_account.encoded_message.is_empty() {
+            // The PDA was closed to reclaim its rent
+            self.get_closed_message(
+                &valid_message_storage_pda_pubkey,
+                &dispatched_message_account,
+            )
+            .await?
+        } else {
+            dispatched_message_account.encoded_message
+        };
+        let hyperlane_message = HyperlaneMessage::read_from(&mut &encoded_message[..])?;
 
         Ok((
             hyperlane_message.into(),
@@ -631,6 +619,95 @@ impl SealevelMailboxIndexer {
             },
         ))
     }
+
+    /// Gets the encoded message of a closed dispatched message PDA, of which only
+    /// the header is left, from the dispatch transaction that logged the full PDA.
+    async fn get_closed_message(
+        &self,
+        dispatched_message_pda: &Pubkey,
+        closed: &DispatchedMessage,
+    ) -> ChainResult<Vec<u8>> {
+        // The dispatch is the oldest transaction involving the PDA
+        let mut signatures = vec![];
+        loop {
+            let before = signatures
+                .last()
+                .map(|status: &RpcConfirmedTransactionStatusWithSignature| {
+                    Signature::from_str(&status.signature)
+                })
+                .transpose()
+                .map_err(ChainCommunicationError::from_other)?;
+            let page = self
+                .rpc()
+                .get_signatures_for_address(
+                    dispatched_message_pda,
+                    before,
+                    CommitmentConfig::finalized(),
+                )
+                .await?;
+            if page.is_empty() {
+                break;
+            }
+            signatures.extend(page);
+        }
+
+        for status in signatures
+            .iter()
+            .rev()
+            .filter(|status| status.err.is_none())
+        {
+            let signature = Signature::from_str(&status.signature)
+                .map_err(ChainCommunicationError::from_other)?;
+            let transaction = self
+                .rpc()
+                .get_transaction_with_config(
+                    &signature,
+                    RpcTransactionConfig {
+                        encoding: Some(UiTransactionEncoding::Json),
+                        commitment: Some(CommitmentConfig::finalized()),
+                        max_supported_transaction_version: Some(0),
+                    },
+                )
+                .await?;
+            let inner_instructions: Option<Vec<UiInnerInstructions>> = transaction
+                .transaction
+                .meta
+                .and_then(|meta| meta.inner_instructions.into());
+            if let Some(logged) =
+                find_logged_dispatched_message(inner_instructions.unwrap_or_default(), closed)
+            {
+                return Ok(logged.encoded_message);
+            }
+        }
+        Err(ChainCommunicationError::from_other_str(
+            "Could not find the dispatch transaction of closed message storage PDA",
+        ))
+    }
+}
+
+/// Finds the dispatched message PDA that a dispatch transaction logged via the
+/// SPL Noop program among its inner instructions, matching the header left in
+/// the `closed` PDA.
+fn find_logged_dispatched_message(
+    inner_instructions: Vec<UiInnerInstructions>,
+    closed: &DispatchedMessage,
+) -> Option<DispatchedMessage> {
+    inner_instructions
+        .into_iter()
+        .flat_map(|inner_instructions| inner_instructions.instructions)
+        .find_map(|instruction| {
+            let UiInstruction::Compiled(instruction) = instruction else {
+                return None;
+            };
+            let data = bs58::decode(&instruction.data).into_vec().ok()?;
+            let logged = DispatchedMessageAccount::fetch(&mut &data[..])
+                .ok()?
+                .into_inner();
+            (logged.nonce == closed.nonce
+                && logged.unique_message_pubkey == closed.unique_message_pubkey
+                && !logged.encoded_message.is_empty())
+            .then_some(*logged)
+        })
 }
 
 #[async_trait]
@@ -702,3 +779,84 @@ impl HyperlaneAbi for SealevelMailboxAbi {
         todo!()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use account_utils::SizedData;
+    use hyperlane_core::{Decode, Encode};
+    use solana_transaction_status::UiCompiledInstruction;
+
+    use crate::merkle_tree_hook::message_to_merkle_tree_insertion;
+
+    use super::*;
+
+    fn noop_log(dispatched_message: DispatchedMessage) -> UiInstruction {
+        let account = DispatchedMessageAccount::from(dispatched_message);
+        let mut data = vec![0; account.size()];
+        account.store_in_slice(&mut data).unwrap();
+        UiInstruction::Compiled(UiCompiledInstruction {
+            program_id_index: 3,
+            accounts: vec![],
+            data: bs58::encode(data).into_string(),
+        })
+    }
+
+    #[test]
+    fn test_closed_message_is_indexed_from_dispatch_logs() {
+        let message = HyperlaneMessage {
+            nonce: 7,
+            body: vec![1, 2, 3],
+            ..Default::default()
+        };
+        let unique_message_pubkey = Pubkey::new_unique();
+        let closed = DispatchedMessage::new(message.nonce, 100, unique_message_pubkey, vec![]);
+
+        let inner_instructions = vec![UiInnerInstructions {
+            index: 0,
+            instructions: vec![
+                // e.g. the creation of the PDA
+                UiInstruction::Compiled(UiCompiledInstruction {
+                    program_id_index: 2,
+                    accounts: vec![0, 1],
+                    data: bs58::encode([0u8; 52]).into_string(),
+                }),
+                // A message with the same nonce, dispatched by another mailbox
+                noop_log(DispatchedMessage::new(
+                    message.nonce,
+                    100,
+                    Pubkey::new_unique(),
+                    vec![4, 5, 6],
+                )),
+                noop_log(DispatchedMessage::new(
+                    message.nonce,
+                    100,
+                    unique_message_pubkey,
+                    message.to_vec(),
+                )),
+            ],
+        }];
+
+        let logged = find_logged_dispatched_message(inner_instructions, &closed).unwrap();
+        let indexed = HyperlaneMessage::read_from(&mut &logged.encoded_message[..]).unwrap();
+        assert_eq!(indexed, message);
+        // The closed message is still inserted into the merkle tree at its nonce
+        let insertion = message_to_merkle_tree_insertion(&indexed);
+        assert_eq!(insertion.index(), message.nonce);
+        assert_eq!(insertion.message_id(), message.id());
+
+        // Closed PDAs are never mistaken for the logged message
+        assert!(find_logged_dispatched_message(
+            vec![UiInnerInstructions {
+                index: 0,
+                instructions: vec![noop_log(DispatchedMessage::new(
+                    message.nonce,
+                    100,
+                    unique_message_pubkey,
+                    vec![],
+                ))],
+            }],
+            &closed
+        )
+        .is_none());
+    }
+}
@@ -24,10 +24,8 @@ impl MerkleTreeHook for SealevelMailbox {
 
         let outbox_account = self
             .rpc()
-            .get_account_with_commitment(&self.outbox.0, CommitmentConfig::finalized())
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .value
+            .get_quorum_account_with_commitment(&self.outbox.0, CommitmentConfig::finalized())
+            .await?
             .ok_or_else(|| {
                 ChainCommunicationError::from_other_str("Could not find account data")
             })?;
@@ -107,7 +105,7 @@ impl SequenceAwareIndexer<MerkleTreeInsertion> for SealevelMerkleTreeHookIndexer
     }
 }
 
-fn message_to_merkle_tree_insertion(message: &HyperlaneMessage) -> MerkleTreeInsertion {
+pub(crate) fn message_to_merkle_tree_insertion(message: &HyperlaneMessage) -> MerkleTreeInsertion {
     let leaf_index = message.nonce;
     let message_id = message.id();
     MerkleTreeInsertion::new(leaf_index, message_id)
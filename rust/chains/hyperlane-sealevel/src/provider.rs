@@ -8,40 +8,33 @@ use hyperlane_core::{
 };
 use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 
-use crate::{client::RpcClientWithDebug, error::HyperlaneSealevelError, ConnectionConf};
+use crate::{error::HyperlaneSealevelError, ConnectionConf, SealevelRpcClient};
 
 /// A wrapper around a Sealevel provider to get generic blockchain information.
 #[derive(Debug)]
 pub struct SealevelProvider {
     domain: HyperlaneDomain,
-    rpc_client: Arc<RpcClientWithDebug>,
+    rpc_client: Arc<SealevelRpcClient>,
 }
 
 impl SealevelProvider {
     /// Create a new Sealevel provider.
     pub fn new(domain: HyperlaneDomain, conf: &ConnectionConf) -> Self {
         // Set the `processed` commitment at rpc level
-        let rpc_client = Arc::new(RpcClientWithDebug::new_with_commitment(
-            conf.url.to_string(),
-            CommitmentConfig::processed(),
-        ));
+        let rpc_client = Arc::new(SealevelRpcClient::new(conf, CommitmentConfig::processed()));
 
         SealevelProvider { domain, rpc_client }
     }
 
     /// Get an rpc client
-    pub fn rpc(&self) -> &RpcClientWithDebug {
+    pub fn rpc(&self) -> &SealevelRpcClient {
         &self.rpc_client
     }
 
     /// Get the balance of an address
     pub async fn get_balance(&self, address: String) -> ChainResult<U256> {
         let pubkey = Pubkey::from_str(&address).map_err(Into::<HyperlaneSealevelError>::into)?;
-        let balance = self
-            .rpc_client
-            .get_balance(&pubkey)
-            .await
-            .map_err(Into::<HyperlaneSealevelError>::into)?;
+        let balance = self.rpc_client.get_balance(&pubkey).await?;
         Ok(balance.into())
     }
 }
@@ -78,7 +71,55 @@ impl HyperlaneProvider for SealevelProvider {
         self.get_balance(address).await
     }
 
+    async fn get_nonce(&self, _address: String) -> ChainResult<Option<u64>> {
+        // Solana transactions are deduplicated by blockhash rather than nonces
+        Ok(None)
+    }
+
+    async fn get_gas_price(&self) -> ChainResult<Option<U256>> {
+        let fees = self.rpc_client.get_recent_prioritization_fees().await?;
+        Ok(median_prioritization_fee_lamports(fees))
+    }
+
     async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>> {
         Ok(None)
     }
 }
+
+/// The number of micro-lamports in a lamport.
+const MICRO_LAMPORTS_PER_LAMPORT: u64 = 1_000_000;
+
+/// The median of the recent prioritization `fees`, which are in micro-lamports
+/// per compute unit, rounded up to lamports per compute unit.
+fn median_prioritization_fee_lamports(mut fees: Vec<u64>) -> Option<U256> {
+    if fees.is_empty() {
+        return None;
+    }
+    fees.sort_unstable();
+    let median = fees[fees.len() / 2];
+    let lamports =
+        median / MICRO_LAMPORTS_PER_LAMPORT + u64::from(median % MICRO_LAMPORTS_PER_LAMPORT != 0);
+    Some(lamports.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_median_prioritization_fee_lamports() {
+        assert_eq!(median_prioritization_fee_lamports(vec![]), None);
+        assert_eq!(
+            median_prioritization_fee_lamports(vec![0, 0, 0]),
+            Some(0.into())
+        );
+        assert_eq!(
+            median_prioritization_fee_lamports(vec![3_000_000, 0, 1_500_000]),
+            Some(2.into())
+        );
+        assert_eq!(
+            median_prioritization_fee_lamports(vec![1, 5_000_000]),
+            Some(5.into())
+        );
+    }
+}
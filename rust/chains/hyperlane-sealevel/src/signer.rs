@@ -0,0 +1,70 @@
+use std::{fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+use hyperlane_core::{ChainCommunicationError, ChainResult};
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    message::Message,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::{presigner::Presigner, Signer as _},
+    transaction::Transaction,
+};
+
+/// Signs ed25519 messages with a key that is held outside of the agent, e.g.
+/// in a KMS.
+#[async_trait]
+pub trait RemoteEd25519Signer: Debug + Send + Sync {
+    /// The public key of the signer
+    fn pubkey(&self) -> Pubkey;
+
+    /// Sign the serialized `message`
+    async fn sign_message(&self, message: &[u8]) -> ChainResult<Signature>;
+}
+
+/// The signer of Sealevel transactions
+#[derive(Clone, Debug)]
+pub enum SealevelSigner {
+    /// A keypair held by the agent
+    Local(Arc<Keypair>),
+    /// A key held outside of the agent
+    Remote(Arc<dyn RemoteEd25519Signer>),
+}
+
+impl From<Keypair> for SealevelSigner {
+    fn from(keypair: Keypair) -> Self {
+        Self::Local(Arc::new(keypair))
+    }
+}
+
+impl SealevelSigner {
+    /// The public key of the signer
+    pub fn pubkey(&self) -> Pubkey {
+        match self {
+            Self::Local(keypair) => keypair.pubkey(),
+            Self::Remote(signer) => signer.pubkey(),
+        }
+    }
+
+    /// Build a transaction of `instructions` paid for and signed by this signer
+    pub async fn sign_transaction(
+        &self,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> ChainResult<Transaction> {
+        let message =
+            Message::new_with_blockhash(instructions, Some(&self.pubkey()), &recent_blockhash);
+        let mut transaction = Transaction::new_unsigned(message);
+        match self {
+            Self::Local(keypair) => transaction.try_sign(&[keypair.as_ref()], recent_blockhash),
+            Self::Remote(signer) => {
+                let signature = signer.sign_message(&transaction.message_data()).await?;
+                let presigner = Presigner::new(&signer.pubkey(), &signature);
+                transaction.try_sign(&[&presigner], recent_blockhash)
+            }
+        }
+        .map_err(ChainCommunicationError::from_other)?;
+        Ok(transaction)
+    }
+}
@@ -4,8 +4,12 @@ use url::Url;
 /// Sealevel connection configuration
 #[derive(Debug, Clone)]
 pub struct ConnectionConf {
-    /// Fully qualified string to connect to
-    pub url: Url,
+    /// Fully qualified strings to connect to, in order of priority
+    pub urls: Vec<Url>,
+    /// Whether the outbox reads validators sign checkpoints from must be
+    /// agreed on by a majority of `urls`, rather than falling back from one
+    /// url to the next
+    pub quorum: bool,
     /// Operation batching configuration
     pub operation_batch: OperationBatchConfig,
 }
@@ -3,41 +3,36 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use hyperlane_core::{ChainCommunicationError, ChainResult};
 
 use serializable_account_meta::{SerializableAccountMeta, SimulationReturnData};
-use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
     instruction::{AccountMeta, Instruction},
     message::Message,
-    signature::{Keypair, Signer},
     transaction::Transaction,
 };
 use solana_transaction_status::UiReturnDataEncoding;
 
-use crate::client::RpcClientWithDebug;
+use crate::{SealevelRpcClient, SealevelSigner};
 
 /// Simulates an instruction, and attempts to deserialize it into a T.
 /// If no return data at all was returned, returns Ok(None).
 /// If some return data was returned but deserialization was unsuccessful,
 /// an Err is returned.
 pub async fn simulate_instruction<T: BorshDeserialize + BorshSerialize>(
-    rpc_client: &RpcClient,
-    payer: &Keypair,
+    rpc_client: &SealevelRpcClient,
+    payer: &SealevelSigner,
     instruction: Instruction,
 ) -> ChainResult<Option<T>> {
     let commitment = CommitmentConfig::finalized();
     let (recent_blockhash, _) = rpc_client
         .get_latest_blockhash_with_commitment(commitment)
-        .await
-        .map_err(ChainCommunicationError::from_other)?;
+        .await?;
     let return_data = rpc_client
         .simulate_transaction(&Transaction::new_unsigned(Message::new_with_blockhash(
             &[instruction],
             Some(&payer.pubkey()),
             &recent_blockhash,
         )))
-        .await
-        .map_err(ChainCommunicationError::from_other)?
-        .value
+        .await?
         .return_data;
 
     if let Some(return_data) = return_data {
@@ -58,8 +53,8 @@ pub async fn simulate_instruction<T: BorshDeserialize + BorshSerialize>(
 
 /// Simulates an Instruction that will return a list of AccountMetas.
 pub async fn get_account_metas(
-    rpc_client: &RpcClient,
-    payer: &Keypair,
+    rpc_client: &SealevelRpcClient,
+    payer: &SealevelSigner,
     instruction: Instruction,
 ) -> ChainResult<Vec<AccountMeta>> {
     // If there's no data at all, default to an empty vec.
@@ -81,11 +76,10 @@ pub async fn get_account_metas(
     Ok(account_metas)
 }
 
-pub async fn get_finalized_block_number(rpc_client: &RpcClientWithDebug) -> ChainResult<u32> {
+pub async fn get_finalized_block_number(rpc_client: &SealevelRpcClient) -> ChainResult<u32> {
     let height = rpc_client
         .get_block_height()
-        .await
-        .map_err(ChainCommunicationError::from_other)?
+        .await?
         .try_into()
         // FIXME solana block height is u64...
         .expect("sealevel block height exceeds u32::MAX");
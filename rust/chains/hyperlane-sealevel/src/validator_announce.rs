@@ -2,13 +2,12 @@ use async_trait::async_trait;
 use tracing::{info, instrument, warn};
 
 use hyperlane_core::{
-    Announcement, ChainCommunicationError, ChainResult, ContractLocator, HyperlaneChain,
-    HyperlaneContract, HyperlaneDomain, SignedType, TxOutcome, ValidatorAnnounce, H160, H256, H512,
-    U256,
+    Announcement, ChainResult, ContractLocator, HyperlaneChain, HyperlaneContract, HyperlaneDomain,
+    SignedType, TxOutcome, ValidatorAnnounce, H160, H256, H512, U256,
 };
 use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 
-use crate::{ConnectionConf, RpcClientWithDebug, SealevelProvider};
+use crate::{ConnectionConf, SealevelProvider, SealevelRpcClient};
 use hyperlane_sealevel_validator_announce::{
     accounts::ValidatorStorageLocationsAccount, validator_storage_locations_pda_seeds,
 };
@@ -33,7 +32,7 @@ impl SealevelValidatorAnnounce {
         }
     }
 
-    fn rpc(&self) -> &RpcClientWithDebug {
+    fn rpc(&self) -> &SealevelRpcClient {
         self.provider.rpc()
     }
 }
@@ -80,9 +79,7 @@ impl ValidatorAnnounce for SealevelValidatorAnnounce {
         let accounts = self
             .rpc()
             .get_multiple_accounts_with_commitment(&account_pubkeys, CommitmentConfig::finalized())
-            .await
-            .map_err(ChainCommunicationError::from_other)?
-            .value;
+            .await?;
 
         // Parse the storage locations from each account.
         // If a validator's account doesn't exist, its storage locations will
@@ -1,14 +1,17 @@
 use std::{env, fmt::Debug, sync::Arc};
 
 use async_trait::async_trait;
-use eyre::Result;
+use eyre::{eyre, Result};
 use hyperlane_core::config::*;
 use tracing::info;
 
 use crate::{
     create_chain_metrics,
     metrics::{create_agent_metrics, AgentMetrics, CoreMetrics},
-    settings::Settings,
+    settings::{
+        loader::{explain_config, EXPLAIN_FLAG, VALIDATE_COMMAND},
+        Settings,
+    },
     ChainMetrics,
 };
 
@@ -57,6 +60,11 @@ pub trait BaseAgent: Send + Sync + Debug {
 /// Call this from `main` to fully initialize and run the agent for its entire
 /// lifecycle. This assumes only a single agent is being run. This will
 /// initialize the metrics server and tracing as well.
+///
+/// When the first program argument is `validate`, the agent's settings are
+/// loaded and fully parsed without connecting to any chain, every config
+/// error is reported and the process exits. With `--explain`, the effective
+/// config and the source of each value is printed too, with secrets redacted.
 pub async fn agent_main<A: BaseAgent>() -> Result<()> {
     if env::var("ONELINE_BACKTRACES")
         .map(|v| v.to_lowercase())
@@ -72,6 +80,11 @@ pub async fn agent_main<A: BaseAgent>() -> Result<()> {
         color_eyre::install()?;
     }
 
+    let mut args = env::args().skip(1);
+    if args.next().as_deref() == Some(VALIDATE_COMMAND) {
+        return validate_settings::<A>(args.any(|arg| arg == EXPLAIN_FLAG));
+    }
+
     let settings = A::Settings::load()?;
     let core_settings: &Settings = settings.as_ref();
 
@@ -93,3 +106,24 @@ pub async fn agent_main<A: BaseAgent>() -> Result<()> {
     info!(agent = A::AGENT_NAME, "Shutting down agent...");
     Ok(())
 }
+
+/// Load and parse the agent's settings, reporting every config error.
+fn validate_settings<A: BaseAgent>(explain: bool) -> Result<()> {
+    if explain {
+        println!("Effective {} config:", A::AGENT_NAME);
+        for value in explain_config()? {
+            println!("  {value}");
+        }
+    }
+
+    match A::Settings::load() {
+        Ok(_) => {
+            println!("{} config is valid", A::AGENT_NAME);
+            Ok(())
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            Err(eyre!("{} config is invalid", A::AGENT_NAME))
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub(crate) mod sequence_aware;
 
 use hyperlane_core::{
     Delivery, HyperlaneDomainProtocol, HyperlaneMessage, InterchainGasPayment, MerkleTreeInsertion,
+    ValidatorAnnouncement,
 };
 pub(crate) use sequence_aware::ForwardBackwardSequenceAwareSyncCursor;
 
@@ -75,3 +76,14 @@ impl Indexable for Delivery {
         }
     }
 }
+
+impl Indexable for ValidatorAnnouncement {
+    fn indexing_cursor(domain: HyperlaneDomainProtocol) -> CursorType {
+        match domain {
+            HyperlaneDomainProtocol::Ethereum => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Fuel => todo!(),
+            HyperlaneDomainProtocol::Sealevel => CursorType::RateLimited,
+            HyperlaneDomainProtocol::Cosmos => CursorType::RateLimited,
+        }
+    }
+}
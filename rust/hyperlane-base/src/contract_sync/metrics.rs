@@ -1,5 +1,5 @@
 use crate::CoreMetrics;
-use prometheus::{IntCounterVec, IntGaugeVec};
+use prometheus::{HistogramVec, IntCounterVec, IntGaugeVec};
 
 /// Struct encapsulating prometheus metrics used by the ContractSync.
 #[derive(Debug, Clone)]
@@ -20,6 +20,21 @@ pub struct ContractSyncMetrics {
 
     /// See `last_known_message_nonce` in CoreMetrics.
     pub message_nonce: IntGaugeVec,
+
+    /// Seconds between a block being produced and a log from that block,
+    /// pushed by a log subscription, being stored.
+    ///
+    /// Labels:
+    /// - `data_type`: the data the indexer is recording. E.g. `messages` or `gas_payments`.
+    /// - `chain`: Chain the indexer is collecting data from.
+    pub subscription_log_latency: HistogramVec,
+
+    /// Number of times a log subscription was (re)established.
+    ///
+    /// Labels:
+    /// - `data_type`: the data the indexer is recording. E.g. `messages` or `gas_payments`.
+    /// - `chain`: Chain the indexer is collecting data from.
+    pub subscription_connects: IntCounterVec,
 }
 
 impl ContractSyncMetrics {
@@ -43,10 +58,29 @@ impl ContractSyncMetrics {
 
         let message_nonce = metrics.last_known_message_nonce();
 
+        let subscription_log_latency = metrics
+            .new_histogram(
+                "contract_sync_subscription_log_latency_seconds",
+                "Seconds from block production until a log pushed by a subscription is stored",
+                &["data_type", "chain"],
+                vec![0.5, 1., 2., 5., 10., 20., 30., 60., 120., 300.],
+            )
+            .expect("failed to register subscription_log_latency metric");
+
+        let subscription_connects = metrics
+            .new_int_counter(
+                "contract_sync_subscription_connects",
+                "Number of times a log subscription was (re)established",
+                &["data_type", "chain"],
+            )
+            .expect("failed to register subscription_connects metric");
+
         ContractSyncMetrics {
             indexed_height,
             stored_events,
             message_nonce,
+            subscription_log_latency,
+            subscription_connects,
         }
     }
 }
@@ -1,5 +1,10 @@
 use std::{
-    collections::HashSet, fmt::Debug, hash::Hash, marker::PhantomData, sync::Arc, time::Duration,
+    collections::HashSet,
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use axum::async_trait;
@@ -7,14 +12,15 @@ use cursors::*;
 use derive_new::new;
 use hyperlane_core::{
     utils::fmt_sync_time, ContractSyncCursor, CursorAction, HyperlaneDomain, HyperlaneLogStore,
-    HyperlaneSequenceAwareIndexerStore, HyperlaneWatermarkedLogStore, Indexer,
-    SequenceAwareIndexer,
+    HyperlaneSequenceAwareIndexerStore, HyperlaneWatermarkedLogStore, Indexer, LogSubscriber,
+    SequenceAwareIndexer, SubscribedLog,
 };
 use hyperlane_core::{Indexed, LogMeta, H512};
 pub use metrics::ContractSyncMetrics;
 use prometheus::core::{AtomicI64, AtomicU64, GenericCounter, GenericGauge};
 use tokio::sync::broadcast::error::TryRecvError;
 use tokio::sync::broadcast::{Receiver as BroadcastReceiver, Sender as BroadcastSender};
+use tokio::sync::mpsc;
 use tokio::time::sleep;
 use tracing::{debug, info, instrument, trace, warn};
 
@@ -28,6 +34,11 @@ use cursors::ForwardBackwardSequenceAwareSyncCursor;
 
 const SLEEP_DURATION: Duration = Duration::from_secs(5);
 
+/// How many logs pushed by a subscription may wait to be stored. Once full,
+/// the subscription waits for the db to catch up instead of buffering logs
+/// without bound.
+const SUBSCRIPTION_CHANNEL_SIZE: usize = 1_000;
+
 /// Entity that drives the syncing of an agent's db with on-chain data.
 /// Extracts chain-specific data (emitted checkpoints, messages, etc) from an
 /// `indexer` and fills the agent's db with this data.
@@ -36,18 +47,27 @@ pub struct ContractSync<T: Indexable, D: HyperlaneLogStore<T>, I: Indexer<T>> {
     domain: HyperlaneDomain,
     db: D,
     indexer: I,
+    log_subscriber: Option<Arc<dyn LogSubscriber<T>>>,
     metrics: ContractSyncMetrics,
     broadcast_sender: Option<BroadcastSender<H512>>,
     _phantom: PhantomData<T>,
 }
 
 impl<T: Indexable, D: HyperlaneLogStore<T>, I: Indexer<T>> ContractSync<T, D, I> {
-    /// Create a new ContractSync
-    pub fn new(domain: HyperlaneDomain, db: D, indexer: I, metrics: ContractSyncMetrics) -> Self {
+    /// Create a new ContractSync. If a `log_subscriber` is provided, logs it
+    /// pushes are stored alongside the ones found by polling the `indexer`.
+    pub fn new(
+        domain: HyperlaneDomain,
+        db: D,
+        indexer: I,
+        log_subscriber: Option<Arc<dyn LogSubscriber<T>>>,
+        metrics: ContractSyncMetrics,
+    ) -> Self {
         Self {
             domain,
             db,
             indexer,
+            log_subscriber,
             metrics,
             broadcast_sender: T::broadcast_channel_size().map(BroadcastSender::new),
             _phantom: PhantomData,
@@ -83,14 +103,98 @@ where
             .stored_events
             .with_label_values(&[label, chain_name]);
 
-        loop {
-            if let Some(rx) = opts.tx_id_receiver.as_mut() {
-                self.fetch_logs_from_receiver(rx, &stored_logs_metric).await;
-            }
-            if let Some(cursor) = opts.cursor.as_mut() {
-                self.fetch_logs_with_cursor(cursor, &stored_logs_metric, &indexed_height_metric)
+        let poll = async {
+            loop {
+                if let Some(rx) = opts.tx_id_receiver.as_mut() {
+                    self.fetch_logs_from_receiver(rx, &stored_logs_metric).await;
+                }
+                if let Some(cursor) = opts.cursor.as_mut() {
+                    self.fetch_logs_with_cursor(
+                        cursor,
+                        &stored_logs_metric,
+                        &indexed_height_metric,
+                    )
                     .await;
+                }
+            }
+        };
+
+        match self.log_subscriber.clone() {
+            // Polling keeps running next to the subscription, filling in any logs
+            // that were missed while the subscription was disconnected
+            Some(subscriber) => {
+                tokio::join!(
+                    poll,
+                    self.sync_from_subscription(label, subscriber, &stored_logs_metric)
+                );
             }
+            None => poll.await,
+        }
+    }
+
+    /// Stores logs pushed by the subscriber, reconnecting whenever the
+    /// subscription ends
+    #[instrument(fields(domain=self.domain().name()), skip(self, subscriber, stored_logs_metric))]
+    async fn sync_from_subscription(
+        &self,
+        label: &'static str,
+        subscriber: Arc<dyn LogSubscriber<T>>,
+        stored_logs_metric: &GenericCounter<AtomicU64>,
+    ) {
+        let chain_name = self.domain.as_ref();
+        let latency_metric = self
+            .metrics
+            .subscription_log_latency
+            .with_label_values(&[label, chain_name]);
+        let connects_metric = self
+            .metrics
+            .subscription_connects
+            .with_label_values(&[label, chain_name]);
+        let (sender, mut receiver) = mpsc::channel::<SubscribedLog<T>>(SUBSCRIPTION_CHANNEL_SIZE);
+        let subscribe =
+            subscribe_with_reconnects(subscriber, sender, connects_metric, SLEEP_DURATION);
+
+        let store = async {
+            while let Some(first) = receiver.recv().await {
+                let mut subscribed = vec![first];
+                while let Ok(next) = receiver.try_recv() {
+                    subscribed.push(next);
+                }
+                let block_timestamps = subscribed
+                    .iter()
+                    .filter_map(|s| s.block_timestamp)
+                    .collect::<Vec<_>>();
+                let logs = subscribed
+                    .into_iter()
+                    .map(|s| (s.log, s.meta))
+                    .collect::<Vec<_>>();
+                let logs = self.dedupe_and_store_logs(logs, stored_logs_metric).await;
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs_f64();
+                for timestamp in block_timestamps {
+                    latency_metric.observe((now - timestamp as f64).max(0.));
+                }
+                debug!(
+                    num_logs = logs.len(),
+                    sequences = ?logs.iter().map(|(log, _)| log.sequence).collect::<Vec<_>>(),
+                    "Found log(s) from subscription"
+                );
+                self.broadcast_tx_ids(&logs);
+            }
+        };
+
+        tokio::join!(subscribe, store);
+    }
+
+    fn broadcast_tx_ids(&self, logs: &[(Indexed<T>, LogMeta)]) {
+        if let Some(tx) = self.broadcast_sender.as_ref() {
+            logs.iter().for_each(|(_, meta)| {
+                if let Err(err) = tx.send(meta.transaction_id) {
+                    trace!(?err, "Error sending txid to receiver");
+                }
+            });
         }
     }
 
@@ -173,13 +277,7 @@ where
                     "Found log(s) in index range"
                 );
 
-                if let Some(tx) = self.broadcast_sender.as_ref() {
-                    logs.iter().for_each(|(_, meta)| {
-                        if let Err(err) = tx.send(meta.transaction_id) {
-                            trace!(?err, "Error sending txid to receiver");
-                        }
-                    });
-                }
+                self.broadcast_tx_ids(&logs);
 
                 // Update cursor
                 if let Err(err) = cursor.update(logs, range).await {
@@ -223,6 +321,27 @@ where
     }
 }
 
+/// Keep `subscriber` pushing logs to `sender`, resubscribing `retry_delay`
+/// after every time the subscription ends. Returns once the receiving end of
+/// `sender` is dropped.
+async fn subscribe_with_reconnects<T>(
+    subscriber: Arc<dyn LogSubscriber<T>>,
+    sender: mpsc::Sender<SubscribedLog<T>>,
+    connects_metric: GenericCounter<AtomicU64>,
+    retry_delay: Duration,
+) {
+    while !sender.is_closed() {
+        connects_metric.inc();
+        if let Err(err) = subscriber.subscribe(sender.clone()).await {
+            warn!(
+                ?err,
+                "Log subscription ended, relying on polling until it reconnects"
+            );
+        }
+        sleep(retry_delay).await;
+    }
+}
+
 /// A ContractSync for syncing events using a SequenceAwareIndexer
 pub type SequenceAwareContractSync<T, U> = ContractSync<T, U, Arc<dyn SequenceAwareIndexer<T>>>;
 
@@ -344,3 +463,70 @@ where
         ContractSync::get_broadcaster(self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use hyperlane_core::{ChainCommunicationError, ChainResult};
+    use prometheus::IntCounter;
+    use tokio::time::timeout;
+
+    use super::*;
+
+    /// Fails its first subscription, pushes a single log on the second one and
+    /// ends every subscription after that
+    #[derive(Debug, Default)]
+    struct FlakySubscriber {
+        attempts: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LogSubscriber<()> for FlakySubscriber {
+        async fn subscribe(&self, sender: mpsc::Sender<SubscribedLog<()>>) -> ChainResult<()> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            if attempt == 1 {
+                let log = SubscribedLog {
+                    log: Indexed::new(()),
+                    meta: LogMeta::default(),
+                    block_timestamp: None,
+                };
+                sender.send(log).await.ok();
+            }
+            Err(ChainCommunicationError::from_other_str(
+                "connection dropped",
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscription_reconnects() {
+        let subscriber = Arc::new(FlakySubscriber::default());
+        let connects = IntCounter::new("connects", "connects").unwrap();
+        let (sender, mut receiver) = mpsc::channel(1);
+        let subscribe = tokio::spawn(subscribe_with_reconnects(
+            subscriber.clone(),
+            sender,
+            connects.clone(),
+            Duration::from_millis(1),
+        ));
+
+        let log = timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .expect("the subscription did not reconnect")
+            .unwrap();
+        assert_eq!(log.meta, LogMeta::default());
+        assert!(subscriber.attempts.load(Ordering::SeqCst) >= 2);
+
+        // Resubscribing stops once no one receives the logs anymore
+        drop(receiver);
+        timeout(Duration::from_secs(5), subscribe)
+            .await
+            .expect("the subscription kept reconnecting")
+            .unwrap();
+        assert_eq!(
+            connects.get(),
+            subscriber.attempts.load(Ordering::SeqCst) as u64
+        );
+    }
+}
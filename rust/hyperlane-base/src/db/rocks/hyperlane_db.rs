@@ -1,3 +1,5 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use async_trait::async_trait;
 use eyre::{bail, Result};
 use paste::paste;
@@ -11,7 +13,10 @@ use hyperlane_core::{
 };
 
 use super::{
-    storage_types::{InterchainGasExpenditureData, InterchainGasPaymentData},
+    storage_types::{
+        InterchainGasExpenditureData, InterchainGasPaymentData, MerkleTreeSnapshot,
+        MessageLifecycle,
+    },
     DbError, TypedDB, DB,
 };
 
@@ -34,6 +39,9 @@ const MERKLE_LEAF_INDEX_BY_MESSAGE_ID: &str = "merkle_leaf_index_by_message_id_"
 const MERKLE_TREE_INSERTION_BLOCK_NUMBER_BY_LEAF_INDEX: &str =
     "merkle_tree_insertion_block_number_by_leaf_index_";
 const LATEST_INDEXED_GAS_PAYMENT_BLOCK: &str = "latest_indexed_gas_payment_block";
+const MESSAGE_DISPATCHED_BLOCK_HASH: &str = "message_dispatched_block_hash_";
+const MESSAGE_LIFECYCLE: &str = "message_lifecycle_";
+const MERKLE_TREE_SNAPSHOT: &str = "merkle_tree_snapshot_";
 
 /// Rocks DB result type
 pub type DbResult<T> = std::result::Result<T, DbError>;
@@ -103,6 +111,21 @@ impl HyperlaneRocksDB {
         Ok(true)
     }
 
+    /// Apply `update` to the lifecycle record of a message and persist the
+    /// result, returning the updated record.
+    pub fn update_message_lifecycle(
+        &self,
+        message_id: &H256,
+        update: impl FnOnce(&mut MessageLifecycle),
+    ) -> DbResult<MessageLifecycle> {
+        let mut lifecycle = self
+            .retrieve_message_lifecycle_by_message_id(message_id)?
+            .unwrap_or_default();
+        update(&mut lifecycle);
+        self.store_message_lifecycle_by_message_id(message_id, &lifecycle)?;
+        Ok(lifecycle)
+    }
+
     /// Retrieve a message by its nonce
     pub fn retrieve_message_by_nonce(&self, nonce: u32) -> DbResult<Option<HyperlaneMessage>> {
         let id = self.retrieve_message_id_by_nonce(&nonce)?;
@@ -128,6 +151,17 @@ impl HyperlaneRocksDB {
         self.retrieve_highest_seen_message_nonce_number(&Default::default())
     }
 
+    /// Store the marker of the latest merkle tree snapshot, replacing any
+    /// previous one
+    pub fn store_merkle_tree_snapshot(&self, snapshot: &MerkleTreeSnapshot) -> DbResult<()> {
+        self.store_latest_merkle_tree_snapshot(&Default::default(), snapshot)
+    }
+
+    /// Retrieve the marker of the latest merkle tree snapshot
+    pub fn retrieve_merkle_tree_snapshot(&self) -> DbResult<Option<MerkleTreeSnapshot>> {
+        self.retrieve_latest_merkle_tree_snapshot(&Default::default())
+    }
+
     /// If the provided gas payment, identified by its metadata, has not been
     /// processed, processes the gas payment and records it as processed.
     /// Returns whether the gas payment was processed for the first time.
@@ -288,6 +322,11 @@ impl HyperlaneLogStore<HyperlaneMessage> for HyperlaneRocksDB {
         for (message, meta) in messages {
             let stored_message = self.store_message(message.inner(), meta.block_number)?;
             if stored_message {
+                let id = message.inner().id();
+                self.store_dispatched_block_hash_by_message_id(&id, &meta.block_hash)?;
+                self.update_message_lifecycle(&id, |lifecycle| {
+                    lifecycle.indexed_at.get_or_insert_with(unix_timestamp_now);
+                })?;
                 stored += 1;
             }
         }
@@ -469,6 +508,14 @@ impl ProcessMessage for HyperlaneRocksDB {
     }
 }
 
+/// Current unix timestamp in seconds
+pub fn unix_timestamp_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
 /// Generate a call to ChainSetup for the given builder
 macro_rules! make_store_and_retrieve {
     ($vis:vis, $name_suffix:ident, $key_prefix: ident, $key_ty:ty, $val_ty:ty$(,)?) => {
@@ -532,6 +579,21 @@ make_store_and_retrieve!(
     u32,
     u64
 );
+make_store_and_retrieve!(
+    pub,
+    dispatched_block_hash_by_message_id,
+    MESSAGE_DISPATCHED_BLOCK_HASH,
+    H256,
+    H256
+);
+make_store_and_retrieve!(
+    pub,
+    message_lifecycle_by_message_id,
+    MESSAGE_LIFECYCLE,
+    H256,
+    MessageLifecycle
+);
 // There's no unit struct Encode/Decode impl, so just use `bool`, have visibility be private (by omitting the first argument), and wrap
 // with a function that always uses the `Default::default()` key
 make_store_and_retrieve!(, highest_seen_message_nonce_number, HIGHEST_SEEN_MESSAGE_NONCE, bool, u32);
+make_store_and_retrieve!(, latest_merkle_tree_snapshot, MERKLE_TREE_SNAPSHOT, bool, MerkleTreeSnapshot);
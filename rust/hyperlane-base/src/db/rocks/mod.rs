@@ -6,6 +6,7 @@ use rocksdb::{Options, DB as Rocks};
 use tracing::info;
 
 pub use hyperlane_db::*;
+pub use storage_types::{MerkleTreeSnapshot, MessageLifecycle};
 pub use typed_db::*;
 
 /// Shared functionality surrounding use of rocksdb
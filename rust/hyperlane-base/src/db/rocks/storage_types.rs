@@ -1,6 +1,7 @@
 use std::io::{Read, Write};
 
 use hyperlane_core::{
+    accumulator::{incremental::IncrementalMerkle, TREE_DEPTH},
     Decode, Encode, HyperlaneProtocolError, InterchainGasExpenditure, InterchainGasPayment, H256,
     U256,
 };
@@ -121,3 +122,118 @@ impl Decode for InterchainGasExpenditureData {
         })
     }
 }
+
+/// Unix timestamps (in seconds) of the points a message reached in its
+/// lifecycle, as observed by this agent. Each stage is only ever recorded
+/// the first time it is reached.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct MessageLifecycle {
+    /// Timestamp of the block the message was dispatched in
+    pub dispatched_at: Option<u64>,
+    /// When the message was written to the database by the indexer
+    pub indexed_at: Option<u64>,
+    /// When the message was first successfully prepared for submission
+    pub first_prepared_at: Option<u64>,
+    /// When a transaction delivering the message was first submitted
+    pub first_submitted_at: Option<u64>,
+    /// When delivery of the message was confirmed
+    pub confirmed_at: Option<u64>,
+}
+
+impl Encode for MessageLifecycle {
+    fn write_to<W>(&self, writer: &mut W) -> std::io::Result<usize>
+    where
+        W: Write,
+    {
+        Ok(self.dispatched_at.write_to(writer)?
+            + self.indexed_at.write_to(writer)?
+            + self.first_prepared_at.write_to(writer)?
+            + self.first_submitted_at.write_to(writer)?
+            + self.confirmed_at.write_to(writer)?)
+    }
+}
+
+impl Decode for MessageLifecycle {
+    fn read_from<R>(reader: &mut R) -> Result<Self, HyperlaneProtocolError>
+    where
+        R: Read,
+        Self: Sized,
+    {
+        Ok(Self {
+            dispatched_at: Option::<u64>::read_from(reader)?,
+            indexed_at: Option::<u64>::read_from(reader)?,
+            first_prepared_at: Option::<u64>::read_from(reader)?,
+            first_submitted_at: Option::<u64>::read_from(reader)?,
+            confirmed_at: Option::<u64>::read_from(reader)?,
+        })
+    }
+}
+
+/// The latest persisted snapshot of a relayer's merkle tree. Only the branch
+/// of the incremental tree is stored, which is enough to keep inserting and
+/// proving leaves from `count` on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTreeSnapshot {
+    /// Format version of the snapshot, snapshots of any other version are
+    /// ignored
+    pub version: u32,
+    /// Number of leaves covered by the snapshot
+    pub count: u32,
+    /// Root of the tree after inserting `count` leaves
+    pub root: H256,
+    /// Branch of the incremental tree after inserting `count` leaves
+    pub branch: [H256; TREE_DEPTH],
+}
+
+impl MerkleTreeSnapshot {
+    /// Current snapshot format version
+    pub const VERSION: u32 = 1;
+
+    /// Snapshot of an empty tree
+    pub fn empty() -> Self {
+        let tree = IncrementalMerkle::default();
+        Self {
+            version: Self::VERSION,
+            count: 0,
+            root: tree.root(),
+            branch: tree.branch,
+        }
+    }
+}
+
+impl Encode for MerkleTreeSnapshot {
+    fn write_to<W>(&self, writer: &mut W) -> std::io::Result<usize>
+    where
+        W: Write,
+    {
+        let mut written = self.version.write_to(writer)?
+            + self.count.write_to(writer)?
+            + self.root.write_to(writer)?;
+        for node in &self.branch {
+            written += node.write_to(writer)?;
+        }
+        Ok(written)
+    }
+}
+
+impl Decode for MerkleTreeSnapshot {
+    fn read_from<R>(reader: &mut R) -> Result<Self, HyperlaneProtocolError>
+    where
+        R: Read,
+        Self: Sized,
+    {
+        let version = u32::read_from(reader)?;
+        let count = u32::read_from(reader)?;
+        let root = H256::read_from(reader)?;
+        let mut branch = [H256::zero(); TREE_DEPTH];
+        for node in branch.iter_mut() {
+            *node = H256::read_from(reader)?;
+        }
+        Ok(Self {
+            version,
+            count,
+            root,
+            branch,
+        })
+    }
+}
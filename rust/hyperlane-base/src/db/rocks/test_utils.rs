@@ -77,4 +77,54 @@ mod test {
         })
         .await;
     }
+
+    #[tokio::test]
+    async fn db_records_message_lifecycle_on_indexing() {
+        run_test_db(|db| async move {
+            let db = HyperlaneRocksDB::new(
+                &HyperlaneDomain::new_test_domain("db_records_message_lifecycle_on_indexing"),
+                db,
+            );
+
+            let m = HyperlaneMessage {
+                nonce: 7,
+                ..Default::default()
+            };
+            let meta = LogMeta {
+                address: H256::from_low_u64_be(1),
+                block_number: 1,
+                block_hash: H256::from_low_u64_be(2),
+                transaction_id: H512::from_low_u64_be(1),
+                transaction_index: 0,
+                log_index: U256::from(0),
+            };
+
+            db.store_logs(&vec![(Indexed::new(m.clone()), meta)])
+                .await
+                .unwrap();
+
+            let id = m.id();
+            assert_eq!(
+                db.retrieve_dispatched_block_hash_by_message_id(&id)
+                    .unwrap(),
+                Some(H256::from_low_u64_be(2))
+            );
+            let indexed = db
+                .retrieve_message_lifecycle_by_message_id(&id)
+                .unwrap()
+                .unwrap();
+            assert!(indexed.indexed_at.is_some());
+            assert!(indexed.first_prepared_at.is_none());
+
+            let updated = db
+                .update_message_lifecycle(&id, |lifecycle| lifecycle.first_prepared_at = Some(42))
+                .unwrap();
+            assert_eq!(updated.indexed_at, indexed.indexed_at);
+            assert_eq!(
+                db.retrieve_message_lifecycle_by_message_id(&id).unwrap(),
+                Some(updated)
+            );
+        })
+        .await;
+    }
 }
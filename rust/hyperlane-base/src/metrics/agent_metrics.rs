@@ -33,6 +33,12 @@ pub const WALLET_BALANCE_LABELS: &[&str] = &[
 pub const WALLET_BALANCE_HELP: &str =
     "Current native token balance for the wallet addresses in the `wallets` set";
 
+/// Expected label names for the `wallet_nonce` metric.
+pub const WALLET_NONCE_LABELS: &[&str] = &["chain", "wallet_address", "wallet_name"];
+/// Help string for the metric.
+pub const WALLET_NONCE_HELP: &str =
+    "Current nonce (number of sent transactions) for the wallet addresses in the `wallets` set";
+
 /// Expected label names for the `block_height` metric.
 pub const BLOCK_HEIGHT_LABELS: &[&str] = &["chain"];
 /// Help string for the metric.
@@ -58,6 +64,15 @@ pub struct AgentMetrics {
     /// - `token_name`: Full name of the token.
     #[builder(setter(into, strip_option), default)]
     wallet_balance: Option<GaugeVec>,
+
+    /// Current nonce of the wallet address, on chains that have account
+    /// nonces.
+    /// - `chain`: the chain name (or chain ID if the name is unknown) of the
+    ///   chain the nonce refers to.
+    /// - `wallet_address`: Address of the wallet.
+    /// - `wallet_name`: Name of the wallet.
+    #[builder(setter(into, strip_option), default)]
+    wallet_nonce: Option<IntGaugeVec>,
 }
 
 pub(crate) fn create_agent_metrics(metrics: &CoreMetrics) -> Result<AgentMetrics> {
@@ -67,6 +82,11 @@ pub(crate) fn create_agent_metrics(metrics: &CoreMetrics) -> Result<AgentMetrics
             WALLET_BALANCE_HELP,
             WALLET_BALANCE_LABELS,
         )?)
+        .wallet_nonce(metrics.new_int_gauge(
+            "wallet_nonce",
+            WALLET_NONCE_HELP,
+            WALLET_NONCE_LABELS,
+        )?)
         .build()?)
 }
 
@@ -170,6 +190,24 @@ impl MetricsUpdater {
             },
             Err(e) => warn!("Metric update failed for wallet {wallet_name} ({wallet_addr}) on chain {chain} balance for native currency; {e}")
         }
+
+        let Some(wallet_nonce_metric) = self.agent_metrics.wallet_nonce.clone() else {
+            return;
+        };
+        match self.provider.get_nonce(wallet_addr.clone()).await {
+            Ok(Some(nonce)) => {
+                trace!("Wallet {wallet_name} ({wallet_addr}) on chain {chain} nonce is {nonce}");
+                wallet_nonce_metric
+                    .with(&hashmap! {
+                        "chain" => chain,
+                        "wallet_address" => wallet_addr.as_str(),
+                        "wallet_name" => wallet_name.as_str(),
+                    })
+                    .set(nonce as i64)
+            }
+            Ok(None) => {}
+            Err(e) => warn!("Metric update failed for wallet {wallet_name} ({wallet_addr}) on chain {chain} nonce; {e}")
+        }
     }
 
     async fn update_block_details(&self) {
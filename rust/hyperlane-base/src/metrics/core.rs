@@ -38,9 +38,11 @@ pub struct CoreMetrics {
     span_events: IntCounterVec,
     last_known_message_nonce: IntGaugeVec,
     submitter_queue_length: IntGaugeVec,
+    submitter_paused: IntGaugeVec,
 
     operations_processed_count: IntCounterVec,
     messages_processed_count: IntCounterVec,
+    message_lifecycle_latency: HistogramVec,
 
     latest_checkpoint: IntGaugeVec,
 
@@ -136,6 +138,16 @@ impl CoreMetrics {
             registry
         )?;
 
+        let submitter_paused = register_int_gauge_vec_with_registry!(
+            opts!(
+                namespaced!("submitter_paused"),
+                "Whether submissions to a remote are paused because the signer balance is too low",
+                const_labels_ref
+            ),
+            &["remote", "signer"],
+            registry
+        )?;
+
         let latest_checkpoint = register_int_gauge_vec_with_registry!(
             opts!(
                 namespaced!("latest_checkpoint"),
@@ -166,6 +178,17 @@ impl CoreMetrics {
             registry
         )?;
 
+        let message_lifecycle_latency = register_histogram_vec_with_registry!(
+            histogram_opts!(
+                namespaced!("message_lifecycle_latency_seconds"),
+                "Seconds from a message's dispatch until it first reached a lifecycle stage, only observed for messages from Ethereum origins",
+                vec![5., 15., 30., 60., 120., 300., 600., 1800., 3600., 7200., 21600., 86400.],
+                const_labels.clone()
+            ),
+            &["origin", "remote", "app_context", "stage"],
+            registry
+        )?;
+
         Ok(Self {
             agent_name: for_agent.into(),
             registry,
@@ -178,9 +201,11 @@ impl CoreMetrics {
             last_known_message_nonce,
 
             submitter_queue_length,
+            submitter_paused,
 
             operations_processed_count,
             messages_processed_count,
+            message_lifecycle_latency,
 
             latest_checkpoint,
 
@@ -327,6 +352,16 @@ impl CoreMetrics {
         self.submitter_queue_length.clone()
     }
 
+    /// Whether submissions to a remote are paused (1) or not (0) because the
+    /// balance of the signer on that chain fell below the configured minimum.
+    ///
+    /// Labels:
+    /// - `remote`: Remote chain the submitter delivers to.
+    /// - `signer`: Address of the signer, as one remote may have several.
+    pub fn submitter_paused(&self) -> IntGaugeVec {
+        self.submitter_paused.clone()
+    }
+
     /// The number of operations successfully submitted by this process during
     /// its lifetime.
     ///
@@ -371,6 +406,22 @@ impl CoreMetrics {
         self.messages_processed_count.clone()
     }
 
+    /// Seconds from the timestamp of the block a message was dispatched in
+    /// until the message first reached each stage of its lifecycle. Observed
+    /// once per message, when its delivery is confirmed.
+    ///
+    /// Only Ethereum providers can look up the dispatch block of a message, so
+    /// this is only observed for messages from Ethereum origins.
+    ///
+    /// Labels:
+    /// - `origin`: Chain the message came from.
+    /// - `remote`: Chain we delivered the message to.
+    /// - `app_context`: App context of the message, or `Unknown`.
+    /// - `stage`: One of `indexed`, `prepared`, `submitted` or `confirmed`.
+    pub fn message_lifecycle_latency(&self) -> HistogramVec {
+        self.message_lifecycle_latency.clone()
+    }
+
     /// Measure of span durations provided by tracing.
     ///
     /// Labels:
@@ -0,0 +1,289 @@
+//! Signers backed by keys held in AWS KMS, for chains that are not signed
+//! through ethers.
+
+use std::fmt::{Debug, Formatter};
+
+use async_trait::async_trait;
+use ethers::core::k256::ecdsa::Signature as K256Signature;
+use hyperlane_core::{ChainCommunicationError, ChainResult};
+use hyperlane_cosmos::RemoteSecp256k1Signer;
+use hyperlane_sealevel::RemoteEd25519Signer;
+use rusoto_kms::{GetPublicKeyRequest, Kms, KmsClient, SignRequest};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+/// DER prefix of an ed25519 `SubjectPublicKeyInfo`, followed by the 32 byte
+/// public key.
+const ED25519_SPKI_PREFIX: [u8; 12] = [
+    0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00,
+];
+
+/// DER prefix of a secp256k1 `SubjectPublicKeyInfo`, followed by the 65 byte
+/// uncompressed SEC1 public key.
+const SECP256K1_SPKI_PREFIX: [u8; 23] = [
+    0x30, 0x56, 0x30, 0x10, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x05, 0x2b,
+    0x81, 0x04, 0x00, 0x0a, 0x03, 0x42, 0x00,
+];
+
+/// Fetch the public key of `key_id`, stripping the `SubjectPublicKeyInfo`
+/// `prefix` expected for the key spec.
+async fn get_public_key(client: &KmsClient, key_id: &str, prefix: &[u8]) -> ChainResult<Vec<u8>> {
+    let der = client
+        .get_public_key(GetPublicKeyRequest {
+            key_id: key_id.to_owned(),
+            ..Default::default()
+        })
+        .await
+        .map_err(ChainCommunicationError::from_other)?
+        .public_key
+        .ok_or_else(|| ChainCommunicationError::from_other_str("KMS returned no public key"))?;
+    der.strip_prefix(prefix)
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| ChainCommunicationError::from_other_str("Unexpected KMS key spec"))
+}
+
+/// Sign `message` with `key_id`, returning the raw signature from KMS.
+async fn sign(
+    client: &KmsClient,
+    key_id: &str,
+    message: Vec<u8>,
+    message_type: &str,
+    signing_algorithm: &str,
+) -> ChainResult<Vec<u8>> {
+    let signature = client
+        .sign(SignRequest {
+            key_id: key_id.to_owned(),
+            message: message.into(),
+            message_type: Some(message_type.to_owned()),
+            signing_algorithm: signing_algorithm.to_owned(),
+            ..Default::default()
+        })
+        .await
+        .map_err(ChainCommunicationError::from_other)?
+        .signature
+        .ok_or_else(|| ChainCommunicationError::from_other_str("KMS returned no signature"))?;
+    Ok(signature.to_vec())
+}
+
+/// An ed25519 key held in AWS KMS, used to sign Sealevel transactions.
+#[derive(Clone)]
+pub(crate) struct AwsKmsEd25519Signer {
+    client: KmsClient,
+    key_id: String,
+    pubkey: Pubkey,
+}
+
+impl AwsKmsEd25519Signer {
+    /// Create a signer for the KMS key `key_id`, fetching its public key.
+    pub async fn new(client: KmsClient, key_id: String) -> ChainResult<Self> {
+        let public_key = get_public_key(&client, &key_id, &ED25519_SPKI_PREFIX).await?;
+        let pubkey =
+            Pubkey::try_from(public_key.as_slice()).map_err(ChainCommunicationError::from_other)?;
+        Ok(Self {
+            client,
+            key_id,
+            pubkey,
+        })
+    }
+}
+
+impl Debug for AwsKmsEd25519Signer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AwsKmsEd25519Signer")
+            .field("key_id", &self.key_id)
+            .field("pubkey", &self.pubkey)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl RemoteEd25519Signer for AwsKmsEd25519Signer {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> ChainResult<Signature> {
+        let signature = sign(
+            &self.client,
+            &self.key_id,
+            message.to_vec(),
+            "RAW",
+            "ED25519_SHA_512",
+        )
+        .await?;
+        Signature::try_from(signature.as_slice()).map_err(ChainCommunicationError::from_other)
+    }
+}
+
+/// A secp256k1 key held in AWS KMS, used to sign Cosmos transactions.
+#[derive(Clone)]
+pub(crate) struct AwsKmsSecp256k1Signer {
+    client: KmsClient,
+    key_id: String,
+    public_key: Vec<u8>,
+}
+
+impl AwsKmsSecp256k1Signer {
+    /// Create a signer for the KMS key `key_id`, fetching its public key.
+    pub async fn new(client: KmsClient, key_id: String) -> ChainResult<Self> {
+        let public_key = get_public_key(&client, &key_id, &SECP256K1_SPKI_PREFIX).await?;
+        Ok(Self {
+            client,
+            key_id,
+            public_key,
+        })
+    }
+}
+
+impl Debug for AwsKmsSecp256k1Signer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AwsKmsSecp256k1Signer")
+            .field("key_id", &self.key_id)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl RemoteSecp256k1Signer for AwsKmsSecp256k1Signer {
+    fn public_key(&self) -> Vec<u8> {
+        self.public_key.clone()
+    }
+
+    async fn sign_digest(&self, digest: [u8; 32]) -> ChainResult<[u8; 64]> {
+        let der = sign(
+            &self.client,
+            &self.key_id,
+            digest.to_vec(),
+            "DIGEST",
+            "ECDSA_SHA_256",
+        )
+        .await?;
+        let signature =
+            K256Signature::from_der(&der).map_err(ChainCommunicationError::from_other)?;
+        let signature = signature.normalize_s().unwrap_or(signature);
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&signature.to_bytes());
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::SocketAddr;
+
+    use axum::{body::Bytes, http::HeaderMap, routing::post, Json, Router};
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use ed25519_dalek::Verifier;
+    use ethers::core::k256::ecdsa::{
+        signature::hazmat::{PrehashSigner, PrehashVerifier},
+        SigningKey, VerifyingKey,
+    };
+    use rusoto_core::{credential::StaticProvider, HttpClient, Region};
+    use serde_json::{json, Value};
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+
+    const ED25519_KEY_ID: &str = "ed25519";
+    const SECP256K1_KEY_ID: &str = "secp256k1";
+
+    fn ed25519_keypair() -> ed25519_dalek::Keypair {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[7u8; 32]).unwrap();
+        ed25519_dalek::Keypair {
+            public: (&secret).into(),
+            secret,
+        }
+    }
+
+    fn secp256k1_key() -> SigningKey {
+        SigningKey::from_slice(&[7u8; 32]).unwrap()
+    }
+
+    /// Handles the subset of the KMS JSON API used by the signers.
+    async fn kms_stand_in(headers: HeaderMap, body: Bytes) -> Json<Value> {
+        let target = headers["x-amz-target"].to_str().unwrap().to_owned();
+        let request: Value = serde_json::from_slice(&body).unwrap();
+        let key_id = request["KeyId"].as_str().unwrap();
+        let response = match (target.as_str(), key_id) {
+            ("TrentService.GetPublicKey", ED25519_KEY_ID) => {
+                let mut der = ED25519_SPKI_PREFIX.to_vec();
+                der.extend_from_slice(ed25519_keypair().public.as_bytes());
+                json!({ "KeyId": key_id, "PublicKey": STANDARD.encode(der) })
+            }
+            ("TrentService.GetPublicKey", SECP256K1_KEY_ID) => {
+                let mut der = SECP256K1_SPKI_PREFIX.to_vec();
+                der.extend_from_slice(
+                    secp256k1_key()
+                        .verifying_key()
+                        .to_encoded_point(false)
+                        .as_bytes(),
+                );
+                json!({ "KeyId": key_id, "PublicKey": STANDARD.encode(der) })
+            }
+            ("TrentService.Sign", ED25519_KEY_ID) => {
+                assert_eq!(request["MessageType"], "RAW");
+                let message = STANDARD
+                    .decode(request["Message"].as_str().unwrap())
+                    .unwrap();
+                let signature = ed25519_dalek::Signer::sign(&ed25519_keypair(), &message);
+                json!({ "KeyId": key_id, "Signature": STANDARD.encode(signature.to_bytes()) })
+            }
+            ("TrentService.Sign", SECP256K1_KEY_ID) => {
+                assert_eq!(request["MessageType"], "DIGEST");
+                let digest = STANDARD
+                    .decode(request["Message"].as_str().unwrap())
+                    .unwrap();
+                let signature: K256Signature = secp256k1_key().sign_prehash(&digest).unwrap();
+                json!({ "KeyId": key_id, "Signature": STANDARD.encode(signature.to_der()) })
+            }
+            other => panic!("Unexpected KMS request {other:?}"),
+        };
+        Json(response)
+    }
+
+    async fn kms_client() -> KmsClient {
+        let server = axum::Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(
+            Router::new()
+                .route("/", post(kms_stand_in))
+                .into_make_service(),
+        );
+        let endpoint = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+        KmsClient::new_with(
+            HttpClient::new().unwrap(),
+            StaticProvider::new_minimal("access_key".into(), "secret_key".into()),
+            Region::Custom {
+                name: "us-east-1".into(),
+                endpoint,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ed25519_signer() {
+        let signer = AwsKmsEd25519Signer::new(kms_client().await, ED25519_KEY_ID.into())
+            .await
+            .unwrap();
+        let keypair = ed25519_keypair();
+        assert_eq!(signer.pubkey().to_bytes(), keypair.public.to_bytes());
+
+        let message = b"hello sealevel";
+        let signature = signer.sign_message(message).await.unwrap();
+        let signature = ed25519_dalek::Signature::try_from(signature.as_ref()).unwrap();
+        keypair.public.verify(message, &signature).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_secp256k1_signer() {
+        let signer = AwsKmsSecp256k1Signer::new(kms_client().await, SECP256K1_KEY_ID.into())
+            .await
+            .unwrap();
+        let verifying_key = VerifyingKey::from_sec1_bytes(&signer.public_key()).unwrap();
+        assert_eq!(&verifying_key, secp256k1_key().verifying_key());
+
+        let digest: [u8; 32] = Sha256::digest(b"hello cosmos").into();
+        let signature = signer.sign_digest(digest).await.unwrap();
+        let signature = K256Signature::from_slice(&signature).unwrap();
+        assert!(signature.normalize_s().is_none());
+        verifying_key.verify_prehash(&digest, &signature).unwrap();
+    }
+}
@@ -16,7 +16,7 @@ use crate::{
     WatermarkLogStore,
 };
 
-use super::TryFromWithMetrics;
+use super::{LogSubscription, TryFromWithMetrics};
 
 /// Settings. Usually this should be treated as a base config and used as
 /// follows:
@@ -162,15 +162,18 @@ impl Settings {
     where
         T: Indexable + Debug,
         SequenceIndexer<T>: TryFromWithMetrics<ChainConf>,
+        LogSubscription<T>: TryFromWithMetrics<ChainConf>,
         D: HyperlaneLogStore<T> + HyperlaneSequenceAwareIndexerStoreReader<T> + 'static,
     {
         let setup = self.chain_setup(domain)?;
         // Currently, all indexers are of the `SequenceIndexer` type
         let indexer = SequenceIndexer::<T>::try_from_with_metrics(setup, metrics).await?;
+        let log_subscriber = LogSubscription::<T>::try_from_with_metrics(setup, metrics).await?;
         Ok(Arc::new(ContractSync::new(
             domain.clone(),
             db.clone() as SequenceAwareLogStore<_>,
             indexer,
+            log_subscriber,
             sync_metrics.clone(),
         )))
     }
@@ -186,19 +189,53 @@ impl Settings {
     where
         T: Indexable + Debug,
         SequenceIndexer<T>: TryFromWithMetrics<ChainConf>,
+        LogSubscription<T>: TryFromWithMetrics<ChainConf>,
         D: HyperlaneLogStore<T> + HyperlaneWatermarkedLogStore<T> + 'static,
     {
         let setup = self.chain_setup(domain)?;
         // Currently, all indexers are of the `SequenceIndexer` type
         let indexer = SequenceIndexer::<T>::try_from_with_metrics(setup, metrics).await?;
+        let log_subscriber = LogSubscription::<T>::try_from_with_metrics(setup, metrics).await?;
         Ok(Arc::new(ContractSync::new(
             domain.clone(),
             db.clone() as WatermarkLogStore<_>,
             indexer,
+            log_subscriber,
             sync_metrics.clone(),
         )))
     }
 
+    /// Build a contract sync for type `T` using log store `D`, choosing
+    /// between a sequenced and a watermark sync based on how `T` is indexed
+    /// on the domain's protocol
+    pub async fn contract_sync<T, D>(
+        &self,
+        domain: &HyperlaneDomain,
+        metrics: &CoreMetrics,
+        sync_metrics: &ContractSyncMetrics,
+        db: Arc<D>,
+    ) -> Result<Arc<dyn ContractSyncer<T>>>
+    where
+        T: Indexable + Debug + Send + Sync + Clone + Eq + Hash + 'static,
+        SequenceIndexer<T>: TryFromWithMetrics<ChainConf>,
+        LogSubscription<T>: TryFromWithMetrics<ChainConf>,
+        D: HyperlaneLogStore<T>
+            + HyperlaneSequenceAwareIndexerStoreReader<T>
+            + HyperlaneWatermarkedLogStore<T>
+            + 'static,
+    {
+        match T::indexing_cursor(domain.domain_protocol()) {
+            CursorType::SequenceAware => self
+                .sequenced_contract_sync(domain, metrics, sync_metrics, db)
+                .await
+                .map(|r| r as Arc<dyn ContractSyncer<T>>),
+            CursorType::RateLimited => self
+                .watermark_contract_sync(domain, metrics, sync_metrics, db)
+                .await
+                .map(|r| r as Arc<dyn ContractSyncer<T>>),
+        }
+    }
+
     /// Build multiple contract syncs.
     /// All contracts have to implement both sequenced and
     /// watermark trait bounds
@@ -212,6 +249,7 @@ impl Settings {
     where
         T: Indexable + Debug + Send + Sync + Clone + Eq + Hash + 'static,
         SequenceIndexer<T>: TryFromWithMetrics<ChainConf>,
+        LogSubscription<T>: TryFromWithMetrics<ChainConf>,
         D: HyperlaneLogStore<T>
             + HyperlaneSequenceAwareIndexerStoreReader<T>
             + HyperlaneWatermarkedLogStore<T>
@@ -220,26 +258,14 @@ impl Settings {
         // TODO: parallelize these calls again
         let mut syncs = vec![];
         for domain in domains {
-            let sync = match T::indexing_cursor(domain.domain_protocol()) {
-                CursorType::SequenceAware => self
-                    .sequenced_contract_sync(
-                        domain,
-                        metrics,
-                        sync_metrics,
-                        dbs.get(domain).unwrap().clone(),
-                    )
-                    .await
-                    .map(|r| r as Arc<dyn ContractSyncer<T>>)?,
-                CursorType::RateLimited => self
-                    .watermark_contract_sync(
-                        domain,
-                        metrics,
-                        sync_metrics,
-                        dbs.get(domain).unwrap().clone(),
-                    )
-                    .await
-                    .map(|r| r as Arc<dyn ContractSyncer<T>>)?,
-            };
+            let sync = self
+                .contract_sync(
+                    domain,
+                    metrics,
+                    sync_metrics,
+                    dbs.get(domain).unwrap().clone(),
+                )
+                .await?;
             syncs.push(sync);
         }
 
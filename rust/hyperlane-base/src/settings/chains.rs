@@ -7,11 +7,11 @@ use eyre::{eyre, Context, Result};
 
 use ethers_prometheus::middleware::{ChainInfo, ContractInfo, PrometheusMiddlewareConf};
 use hyperlane_core::{
-    config::OperationBatchConfig, AggregationIsm, CcipReadIsm, ContractLocator, HyperlaneAbi,
-    HyperlaneDomain, HyperlaneDomainProtocol, HyperlaneMessage, HyperlaneProvider, IndexMode,
-    InterchainGasPaymaster, InterchainGasPayment, InterchainSecurityModule, Mailbox,
-    MerkleTreeHook, MerkleTreeInsertion, MultisigIsm, RoutingIsm, SequenceAwareIndexer,
-    ValidatorAnnounce, H256,
+    config::OperationBatchConfig, AggregationIsm, BlockFinality, CcipReadIsm, ContractLocator,
+    HyperlaneAbi, HyperlaneDomain, HyperlaneDomainProtocol, HyperlaneMessage, HyperlaneProvider,
+    IndexMode, InterchainGasPaymaster, InterchainGasPayment, InterchainSecurityModule,
+    LogSubscriber, Mailbox, MerkleTreeHook, MerkleTreeInsertion, MultisigIsm, RoutingIsm,
+    SequenceAwareIndexer, ValidatorAnnounce, ValidatorAnnouncement, H256,
 };
 use hyperlane_cosmos as h_cosmos;
 use hyperlane_ethereum::{
@@ -20,6 +20,7 @@ use hyperlane_ethereum::{
 };
 use hyperlane_fuel as h_fuel;
 use hyperlane_sealevel as h_sealevel;
+use url::Url;
 
 use crate::{
     metrics::AgentMetricsConf,
@@ -44,8 +45,14 @@ pub struct ChainConf {
     pub domain: HyperlaneDomain,
     /// Signer configuration for this chain
     pub signer: Option<SignerConf>,
+    /// Further signers that transactions may be submitted with concurrently
+    /// to `signer`. Currently only used by the relayer.
+    pub additional_signers: Vec<SignerConf>,
     /// The reorg period of the chain, i.e. the number of blocks until finality
     pub reorg_period: u32,
+    /// How the latest final block is determined. Only the Ethereum indexers
+    /// and contract reads currently honor block tag finality.
+    pub finality: BlockFinality,
     /// Addresses of contracts on the chain
     pub addresses: CoreContractAddresses,
     /// The chain connection details
@@ -70,6 +77,12 @@ pub type IgpIndexer = Arc<dyn SequenceAwareIndexer<InterchainGasPayment>>;
 /// A sequence-aware indexer for merkle tree hooks
 pub type MerkleTreeHookIndexer = Arc<dyn SequenceAwareIndexer<MerkleTreeInsertion>>;
 
+/// A sequence-aware indexer for validator storage location announcements
+pub type ValidatorAnnounceIndexer = Arc<dyn SequenceAwareIndexer<ValidatorAnnouncement>>;
+
+/// An optional subscriber that pushes logs as they are emitted
+pub type LogSubscription<T> = Option<Arc<dyn LogSubscriber<T>>>;
+
 #[async_trait]
 impl TryFromWithMetrics<ChainConf> for MessageIndexer {
     async fn try_from_with_metrics(conf: &ChainConf, metrics: &CoreMetrics) -> Result<Self> {
@@ -102,6 +115,59 @@ impl TryFromWithMetrics<ChainConf> for MerkleTreeHookIndexer {
     }
 }
 
+#[async_trait]
+impl TryFromWithMetrics<ChainConf> for ValidatorAnnounceIndexer {
+    async fn try_from_with_metrics(conf: &ChainConf, metrics: &CoreMetrics) -> Result<Self> {
+        conf.build_validator_announce_indexer(metrics)
+            .await
+            .map(Into::into)
+    }
+}
+
+#[async_trait]
+impl TryFromWithMetrics<ChainConf> for LogSubscription<HyperlaneMessage> {
+    async fn try_from_with_metrics(conf: &ChainConf, _metrics: &CoreMetrics) -> Result<Self> {
+        Ok(conf.build_log_subscriber(
+            conf.addresses.mailbox,
+            h_eth::EthereumLogSubscriber::dispatch,
+        ))
+    }
+}
+
+#[async_trait]
+impl TryFromWithMetrics<ChainConf> for LogSubscription<H256> {
+    async fn try_from_with_metrics(_conf: &ChainConf, _metrics: &CoreMetrics) -> Result<Self> {
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl TryFromWithMetrics<ChainConf> for LogSubscription<InterchainGasPayment> {
+    async fn try_from_with_metrics(conf: &ChainConf, _metrics: &CoreMetrics) -> Result<Self> {
+        Ok(conf.build_log_subscriber(
+            conf.addresses.interchain_gas_paymaster,
+            h_eth::EthereumLogSubscriber::gas_payment,
+        ))
+    }
+}
+
+#[async_trait]
+impl TryFromWithMetrics<ChainConf> for LogSubscription<MerkleTreeInsertion> {
+    async fn try_from_with_metrics(conf: &ChainConf, _metrics: &CoreMetrics) -> Result<Self> {
+        Ok(conf.build_log_subscriber(
+            conf.addresses.merkle_tree_hook,
+            h_eth::EthereumLogSubscriber::inserted_into_tree,
+        ))
+    }
+}
+
+#[async_trait]
+impl TryFromWithMetrics<ChainConf> for LogSubscription<ValidatorAnnouncement> {
+    async fn try_from_with_metrics(_conf: &ChainConf, _metrics: &CoreMetrics) -> Result<Self> {
+        Ok(None)
+    }
+}
+
 /// A connection to _some_ blockchain.
 #[derive(Clone, Debug)]
 pub enum ChainConnectionConf {
@@ -204,8 +270,15 @@ impl ChainConf {
 
         match &self.connection {
             ChainConnectionConf::Ethereum(conf) => {
-                self.build_ethereum(conf, &locator, metrics, h_eth::MailboxBuilder {})
-                    .await
+                self.build_ethereum(
+                    conf,
+                    &locator,
+                    metrics,
+                    h_eth::MailboxBuilder {
+                        finality: self.finality,
+                    },
+                )
+                .await
             }
             ChainConnectionConf::Fuel(conf) => {
                 let wallet = self.fuel_signer().await.context(ctx)?;
@@ -214,8 +287,8 @@ impl ChainConf {
                     .map_err(Into::into)
             }
             ChainConnectionConf::Sealevel(conf) => {
-                let keypair = self.sealevel_signer().await.context(ctx)?;
-                h_sealevel::SealevelMailbox::new(conf, locator, keypair)
+                let signer = self.sealevel_signer().await.context(ctx)?;
+                h_sealevel::SealevelMailbox::new(conf, locator, signer)
                     .map(|m| Box::new(m) as Box<dyn Mailbox>)
                     .map_err(Into::into)
             }
@@ -239,8 +312,15 @@ impl ChainConf {
 
         match &self.connection {
             ChainConnectionConf::Ethereum(conf) => {
-                self.build_ethereum(conf, &locator, metrics, h_eth::MerkleTreeHookBuilder {})
-                    .await
+                self.build_ethereum(
+                    conf,
+                    &locator,
+                    metrics,
+                    h_eth::MerkleTreeHookBuilder {
+                        finality: self.finality,
+                    },
+                )
+                .await
             }
             ChainConnectionConf::Fuel(_conf) => {
                 todo!("Fuel does not support merkle tree hooks yet")
@@ -271,12 +351,13 @@ impl ChainConf {
 
         match &self.connection {
             ChainConnectionConf::Ethereum(conf) => {
-                self.build_ethereum(
+                self.build_ethereum_indexer(
                     conf,
                     &locator,
                     metrics,
                     h_eth::SequenceIndexerBuilder {
                         reorg_period: self.reorg_period,
+                        finality: self.finality,
                     },
                 )
                 .await
@@ -310,12 +391,13 @@ impl ChainConf {
 
         match &self.connection {
             ChainConnectionConf::Ethereum(conf) => {
-                self.build_ethereum(
+                self.build_ethereum_indexer(
                     conf,
                     &locator,
                     metrics,
                     h_eth::DeliveryIndexerBuilder {
                         reorg_period: self.reorg_period,
+                        finality: self.finality,
                     },
                 )
                 .await
@@ -360,8 +442,9 @@ impl ChainConf {
             }
             ChainConnectionConf::Fuel(_) => todo!(),
             ChainConnectionConf::Sealevel(conf) => {
+                let signer = self.sealevel_signer().await.context(ctx)?;
                 let paymaster = Box::new(
-                    h_sealevel::SealevelInterchainGasPaymaster::new(conf, &locator).await?,
+                    h_sealevel::SealevelInterchainGasPaymaster::new(conf, &locator, signer).await?,
                 );
                 Ok(paymaster as Box<dyn InterchainGasPaymaster>)
             }
@@ -388,13 +471,14 @@ impl ChainConf {
 
         match &self.connection {
             ChainConnectionConf::Ethereum(conf) => {
-                self.build_ethereum(
+                self.build_ethereum_indexer(
                     conf,
                     &locator,
                     metrics,
                     h_eth::InterchainGasPaymasterIndexerBuilder {
                         mailbox_address: self.addresses.mailbox.into(),
                         reorg_period: self.reorg_period,
+                        finality: self.finality,
                     },
                 )
                 .await
@@ -428,12 +512,13 @@ impl ChainConf {
 
         match &self.connection {
             ChainConnectionConf::Ethereum(conf) => {
-                self.build_ethereum(
+                self.build_ethereum_indexer(
                     conf,
                     &locator,
                     metrics,
                     h_eth::MerkleTreeHookIndexerBuilder {
                         reorg_period: self.reorg_period,
+                        finality: self.finality,
                     },
                 )
                 .await
@@ -462,6 +547,60 @@ impl ChainConf {
         .context(ctx)
     }
 
+    /// Try to convert the chain settings into a validator announcement
+    /// indexer. Only Ethereum supports indexing announcements.
+    pub async fn build_validator_announce_indexer(
+        &self,
+        metrics: &CoreMetrics,
+    ) -> Result<Box<dyn SequenceAwareIndexer<ValidatorAnnouncement>>> {
+        let ctx = "Building validator announce indexer";
+        let locator = self.locator(self.addresses.validator_announce);
+
+        match &self.connection {
+            ChainConnectionConf::Ethereum(conf) => {
+                self.build_ethereum_indexer(
+                    conf,
+                    &locator,
+                    metrics,
+                    h_eth::ValidatorAnnounceIndexerBuilder {
+                        reorg_period: self.reorg_period,
+                        finality: self.finality,
+                    },
+                )
+                .await
+            }
+            ChainConnectionConf::Fuel(_) => todo!(),
+            ChainConnectionConf::Sealevel(_) | ChainConnectionConf::Cosmos(_) => Err(eyre!(
+                "Indexing validator announcements is not supported on {}",
+                self.domain
+            )),
+        }
+        .context(ctx)
+    }
+
+    /// Build a subscriber for logs of the contract at `address`, if the chain
+    /// is configured with a subscription url. Only Ethereum supports this.
+    fn build_log_subscriber<T>(
+        &self,
+        address: H256,
+        build: fn(Url, &ContractLocator, u32, BlockFinality) -> h_eth::EthereumLogSubscriber<T>,
+    ) -> LogSubscription<T>
+    where
+        h_eth::EthereumLogSubscriber<T>: LogSubscriber<T> + 'static,
+    {
+        match &self.connection {
+            ChainConnectionConf::Ethereum(conf) => conf.subscription_url.clone().map(|url| {
+                Arc::new(build(
+                    url,
+                    &self.locator(address),
+                    self.reorg_period,
+                    self.finality,
+                )) as Arc<dyn LogSubscriber<T>>
+            }),
+            _ => None,
+        }
+    }
+
     /// Try to convert the chain settings into a ValidatorAnnounce
     pub async fn build_validator_announce(
         &self,
@@ -515,9 +654,9 @@ impl ChainConf {
             }
             ChainConnectionConf::Fuel(_) => todo!(),
             ChainConnectionConf::Sealevel(conf) => {
-                let keypair = self.sealevel_signer().await.context(ctx)?;
+                let signer = self.sealevel_signer().await.context(ctx)?;
                 let ism = Box::new(h_sealevel::SealevelInterchainSecurityModule::new(
-                    conf, locator, keypair,
+                    conf, locator, signer,
                 ));
                 Ok(ism as Box<dyn InterchainSecurityModule>)
             }
@@ -549,8 +688,8 @@ impl ChainConf {
 
             ChainConnectionConf::Fuel(_) => todo!(),
             ChainConnectionConf::Sealevel(conf) => {
-                let keypair = self.sealevel_signer().await.context(ctx)?;
-                let ism = Box::new(h_sealevel::SealevelMultisigIsm::new(conf, locator, keypair));
+                let signer = self.sealevel_signer().await.context(ctx)?;
+                let ism = Box::new(h_sealevel::SealevelMultisigIsm::new(conf, locator, signer));
                 Ok(ism as Box<dyn MultisigIsm>)
             }
             ChainConnectionConf::Cosmos(conf) => {
@@ -663,6 +802,33 @@ impl ChainConf {
         .context(ctx)
     }
 
+    /// A copy of this chain config that signs with `signer` and has no
+    /// additional signers.
+    pub fn with_signer(&self, signer: SignerConf) -> Self {
+        Self {
+            signer: Some(signer),
+            additional_signers: vec![],
+            ..self.clone()
+        }
+    }
+
+    /// One chain config per configured signer, starting with the one for
+    /// `signer` if it is set. A chain without any signer has a pool of one
+    /// signer-less config, so that it can still be used to read from.
+    pub fn signer_pool(&self) -> Vec<Self> {
+        let pool = self
+            .signer
+            .iter()
+            .chain(&self.additional_signers)
+            .map(|signer| self.with_signer(signer.clone()))
+            .collect::<Vec<_>>();
+        if pool.is_empty() {
+            vec![self.clone()]
+        } else {
+            pool
+        }
+    }
+
     async fn signer<S: BuildableWithSignerConf>(&self) -> Result<Option<S>> {
         if let Some(conf) = &self.signer {
             Ok(Some(conf.build::<S>().await?))
@@ -680,7 +846,7 @@ impl ChainConf {
                     Box::new(conf.build::<fuels::prelude::WalletUnlocked>().await?)
                 }
                 ChainConnectionConf::Sealevel(_) => {
-                    Box::new(conf.build::<h_sealevel::Keypair>().await?)
+                    Box::new(conf.build::<h_sealevel::SealevelSigner>().await?)
                 }
                 ChainConnectionConf::Cosmos(_) => Box::new(conf.build::<h_cosmos::Signer>().await?),
             };
@@ -700,7 +866,7 @@ impl ChainConf {
         })
     }
 
-    async fn sealevel_signer(&self) -> Result<Option<h_sealevel::Keypair>> {
+    async fn sealevel_signer(&self) -> Result<Option<h_sealevel::SealevelSigner>> {
         self.signer().await
     }
 
@@ -772,6 +938,40 @@ impl ChainConf {
         }
     }
 
+    /// Build an Ethereum indexer, after checking that the RPC supports the
+    /// block tag of the configured finality. Without this check, an
+    /// unsupported tag would only surface as an error on every indexing call.
+    async fn build_ethereum_indexer<B>(
+        &self,
+        conf: &h_eth::ConnectionConf,
+        locator: &ContractLocator<'_>,
+        metrics: &CoreMetrics,
+        builder: B,
+    ) -> Result<B::Output>
+    where
+        B: BuildableWithProvider + Sync,
+    {
+        if self.finality != BlockFinality::BlockLag {
+            self.build_ethereum(
+                conf,
+                locator,
+                metrics,
+                h_eth::BlockFinalityProbe {
+                    finality: self.finality,
+                    reorg_period: self.reorg_period,
+                },
+            )
+            .await?
+            .with_context(|| {
+                format!(
+                    "Checking the {:?} finality of {}, use the `blockLag` finality if the RPC does not support its block tag",
+                    self.finality, self.domain
+                )
+            })?;
+        }
+        self.build_ethereum(conf, locator, metrics, builder).await
+    }
+
     async fn build_ethereum<B>(
         &self,
         conf: &h_eth::ConnectionConf,
@@ -792,3 +992,65 @@ impl ChainConf {
         Ok(res?)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn chain_conf(signer: Option<SignerConf>, additional_signers: Vec<SignerConf>) -> ChainConf {
+        ChainConf {
+            domain: HyperlaneDomain::new_test_domain("test_signer_pool"),
+            signer,
+            additional_signers,
+            reorg_period: Default::default(),
+            finality: Default::default(),
+            addresses: Default::default(),
+            connection: ChainConnectionConf::Ethereum(h_eth::ConnectionConf {
+                rpc_connection: h_eth::RpcConnectionConf::Http {
+                    url: "http://example.com".parse().unwrap(),
+                },
+                transaction_overrides: Default::default(),
+                operation_batch: Default::default(),
+                subscription_url: None,
+            }),
+            metrics_conf: Default::default(),
+            index: Default::default(),
+        }
+    }
+
+    fn key(byte: u64) -> SignerConf {
+        SignerConf::HexKey {
+            key: H256::from_low_u64_be(byte),
+        }
+    }
+
+    fn pool_keys(conf: &ChainConf) -> Vec<Option<H256>> {
+        conf.signer_pool()
+            .iter()
+            .map(|conf| {
+                assert!(conf.additional_signers.is_empty());
+                match conf.signer {
+                    Some(SignerConf::HexKey { key }) => Some(key),
+                    None => None,
+                    _ => unreachable!(),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_signer_pool() {
+        let k = |byte| Some(H256::from_low_u64_be(byte));
+        assert_eq!(pool_keys(&chain_conf(None, vec![])), vec![None]);
+        assert_eq!(pool_keys(&chain_conf(Some(key(1)), vec![])), vec![k(1)]);
+        assert_eq!(
+            pool_keys(&chain_conf(Some(key(1)), vec![key(2), key(3)])),
+            vec![k(1), k(2), k(3)]
+        );
+        // A missing primary signer doesn't leave a signer-less slot in the pool
+        assert_eq!(
+            pool_keys(&chain_conf(None, vec![key(2), key(3)])),
+            vec![k(2), k(3)]
+        );
+    }
+}
@@ -4,6 +4,8 @@ use config::{ConfigError, Map, Source, Value, ValueKind};
 use hyperlane_core::unwrap_or_none_result;
 use itertools::Itertools;
 
+use super::{COMMANDS, EXPLAIN_FLAG, VALIDATE_COMMAND};
+
 /// A source for loading configuration from command line arguments.
 ///
 /// * `--key=value`
@@ -108,13 +110,14 @@ impl ArgumentParser {
 
     /// Creates a parser from [`env::args_os`].
     ///
-    /// The executable path will be removed.
+    /// The executable path will be removed, as will a leading agent command
+    /// (e.g. `validate`), its positional arguments and the `validate` flags.
     ///
     /// [`env::args_os`]: https://doc.rust-lang.org/stable/std/env/fn.args_os.html
     fn from_env() -> Self {
         let mut args: Vec<_> = std::env::args_os().collect();
         args.remove(0);
-        ArgumentParser(args)
+        ArgumentParser(strip_command(args))
     }
 
     /// Returns a list of remaining arguments.
@@ -151,6 +154,29 @@ impl Iterator for ArgumentParser {
     }
 }
 
+/// Remove a leading agent command and its positional arguments, which are
+/// handled by the agent rather than the config. Arguments that don't start
+/// with a known command are left as they are, so a stray positional argument
+/// is still reported as unparsable.
+fn strip_command(mut args: Vec<OsString>) -> Vec<OsString> {
+    let Some(command) = args.first().and_then(|arg| arg.to_str()) else {
+        return args;
+    };
+    if !COMMANDS.contains(&command) {
+        return args;
+    }
+    let is_validate = command == VALIDATE_COMMAND;
+    let command_len = args
+        .iter()
+        .take_while(|arg| !arg.to_string_lossy().starts_with("--"))
+        .count();
+    args.drain(..command_len);
+    if is_validate {
+        args.retain(|arg| arg != EXPLAIN_FLAG);
+    }
+    args
+}
+
 // internal workings
 impl ArgumentParser {
     #[inline(never)]
@@ -331,4 +357,29 @@ mod test {
 
         assert!(config.is_empty());
     }
+
+    #[test]
+    fn strips_known_commands_only() {
+        let strip = |args: &str| {
+            strip_command(args.split_whitespace().map(OsString::from).collect())
+                .into_iter()
+                .map(|arg| arg.into_string().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(strip("validate --explain --db foo"), ["--db", "foo"]);
+        assert_eq!(
+            strip("backfill ethereum blocks 10 20 --db foo"),
+            ["--db", "foo"]
+        );
+        assert_eq!(strip("gas_report 5 --db foo"), ["--db", "foo"]);
+        // `--explain` is only a flag of `validate`
+        assert_eq!(
+            strip("gas_report --explain --db foo"),
+            ["--explain", "--db", "foo"]
+        );
+        // Unknown leading arguments are kept, so they are reported as errors
+        assert_eq!(strip("typo --db foo"), ["typo", "--db", "foo"]);
+        assert_eq!(strip("--db foo"), ["--db", "foo"]);
+    }
 }
@@ -0,0 +1,242 @@
+//! Explain the effective config of an agent: every value after the config
+//! sources have been merged, along with the source it was taken from.
+
+use std::fmt::{Display, Formatter};
+
+use config::{Config, ConfigError, Map, Source, Value, ValueKind};
+use eyre::Context;
+use hyperlane_core::config::*;
+use url::Url;
+
+use super::build_config;
+
+/// Key segments, in flat case, whose values are redacted when explaining the
+/// config. A segment matches if it contains any of these.
+const SECRET_KEY_SEGMENTS: &[&str] = &[
+    "key",
+    "secret",
+    "password",
+    "passphrase",
+    "token",
+    "mnemonic",
+];
+
+/// Key segments, in flat case, that identify keys rather than hold them.
+const NON_SECRET_KEY_SEGMENTS: &[&str] = &["keyid"];
+
+const REDACTED: &str = "<redacted>";
+
+/// A single value of the effective config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplainedValue {
+    /// The dot separated, flat case key of the value, e.g.
+    /// `chains.ethereum.signer.key`
+    pub key: String,
+    /// The value, with secrets redacted
+    pub value: String,
+    /// Where the value was taken from, e.g. a config file path or
+    /// `program environment`
+    pub source: String,
+}
+
+impl Display for ExplainedValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} = {} ({})", self.key, self.value, self.source)
+    }
+}
+
+/// Merge the config sources the same way `load_settings` does and list every
+/// value of the result, sorted by key, with secrets redacted.
+pub fn explain_config() -> ConfigResult<Vec<ExplainedValue>> {
+    let root_path = ConfigPath::default();
+    let (config, _) = build_config(&root_path)?;
+    explain_merged(&config)
+        .context("Failed to merge config sources")
+        .into_config_result(|| root_path.clone())
+}
+
+/// List every value of the merged `config`, sorted by key, with secrets
+/// redacted.
+fn explain_merged(config: &Config) -> Result<Vec<ExplainedValue>, ConfigError> {
+    let mut values = vec![];
+    flatten(String::new(), config.collect()?, false, &mut values);
+    values.sort_by(|a, b| a.key.cmp(&b.key));
+    Ok(values)
+}
+
+/// The merged `config` with secrets redacted, one value per line, for
+/// printing when the config fails to load.
+pub(super) fn redacted_config(config: &Config, line_separator: &str) -> String {
+    match explain_merged(config) {
+        Ok(values) => values
+            .iter()
+            .map(ExplainedValue::to_string)
+            .collect::<Vec<_>>()
+            .join(line_separator),
+        Err(err) => format!("<failed to merge config sources: {err}>"),
+    }
+}
+
+fn flatten(prefix: String, table: Map<String, Value>, secret: bool, out: &mut Vec<ExplainedValue>) {
+    for (segment, value) in table {
+        let key = if prefix.is_empty() {
+            segment.clone()
+        } else {
+            format!("{prefix}.{segment}")
+        };
+        let secret = secret || is_secret_segment(&segment);
+        flatten_value(key, value, secret, out);
+    }
+}
+
+fn flatten_value(key: String, value: Value, secret: bool, out: &mut Vec<ExplainedValue>) {
+    let source = value.origin().unwrap_or("default").to_owned();
+    match value.kind {
+        ValueKind::Table(table) => flatten(key, table, secret, out),
+        ValueKind::Array(array) => {
+            for (index, value) in array.into_iter().enumerate() {
+                flatten_value(format!("{key}.{index}"), value, secret, out);
+            }
+        }
+        kind => {
+            let value = if secret {
+                REDACTED.to_owned()
+            } else {
+                redact_url_credentials(kind.to_string())
+            };
+            out.push(ExplainedValue { key, value, source });
+        }
+    }
+}
+
+fn is_secret_segment(segment: &str) -> bool {
+    let segment = segment.to_lowercase();
+    !NON_SECRET_KEY_SEGMENTS.contains(&segment.as_str())
+        && SECRET_KEY_SEGMENTS
+            .iter()
+            .any(|secret| segment.contains(secret))
+}
+
+/// RPC urls often carry an API key in their path, query or userinfo, so only
+/// the scheme, host and port of urls are shown. Comma separated lists of urls
+/// are redacted individually.
+fn redact_url_credentials(value: String) -> String {
+    if !value.contains("://") {
+        return value;
+    }
+    value
+        .split(',')
+        .map(|part| match Url::parse(part.trim()) {
+            Ok(url) if url.has_host() => {
+                let redacted = !url.username().is_empty()
+                    || url.password().is_some()
+                    || url.path() != "/"
+                    || url.query().is_some();
+                let mut origin = format!("{}://{}", url.scheme(), url.host_str().unwrap_or(""));
+                if let Some(port) = url.port() {
+                    origin.push_str(&format!(":{port}"));
+                }
+                if redacted {
+                    origin.push_str(&format!("/{REDACTED}"));
+                }
+                origin
+            }
+            _ => part.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn value(kind: impl Into<ValueKind>, origin: &str) -> Value {
+        Value::new(Some(&origin.to_owned()), kind)
+    }
+
+    #[test]
+    fn test_flatten_redacts_secrets() {
+        let signer = Map::from([
+            ("type".to_owned(), value("hexKey", "config/test.json")),
+            ("key".to_owned(), value("0x1234", "program environment")),
+        ]);
+        let aws = Map::from([
+            ("type".to_owned(), value("aws", "config/test.json")),
+            (
+                "keyid".to_owned(),
+                value("alias/validator", "config/test.json"),
+            ),
+        ]);
+        let chain = Map::from([
+            ("signer".to_owned(), value(signer, "config/test.json")),
+            ("aws".to_owned(), value(aws, "config/test.json")),
+            (
+                "customrpcurls".to_owned(),
+                value(
+                    "https://rpc.example.com/v1/apikey,http://localhost:8545",
+                    "program argument",
+                ),
+            ),
+        ]);
+        let table = Map::from([
+            (
+                "chains".to_owned(),
+                value(
+                    Map::from([("test".to_owned(), value(chain, "config/test.json"))]),
+                    "config/test.json",
+                ),
+            ),
+            ("db".to_owned(), value("/tmp/db", "program environment")),
+        ]);
+
+        let mut values = vec![];
+        flatten(String::new(), table, false, &mut values);
+        values.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let explained = values
+            .iter()
+            .map(|v| (v.key.as_str(), v.value.as_str(), v.source.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            explained,
+            vec![
+                (
+                    "chains.test.aws.keyid",
+                    "alias/validator",
+                    "config/test.json"
+                ),
+                ("chains.test.aws.type", "aws", "config/test.json"),
+                (
+                    "chains.test.customrpcurls",
+                    "https://rpc.example.com/<redacted>,http://localhost:8545",
+                    "program argument"
+                ),
+                ("chains.test.signer.key", REDACTED, "program environment"),
+                ("chains.test.signer.type", "hexKey", "config/test.json"),
+                ("db", "/tmp/db", "program environment"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_redacted_config() {
+        let config = Config::builder()
+            .set_override("chains.test.signer.key", "0x1234")
+            .unwrap()
+            .set_override("chains.test.signer.type", "hexKey")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let redacted = redacted_config(&config, "\n");
+        assert!(!redacted.contains("0x1234"));
+        assert_eq!(
+            redacted,
+            format!(
+                "chains.test.signer.key = {REDACTED} (default)\n\
+                 chains.test.signer.type = hexKey (default)"
+            )
+        );
+    }
+}
@@ -12,9 +12,45 @@ use crate::settings::loader::{
     arguments::CommandLineArguments, case_adapter::CaseAdapter, environment::Environment,
 };
 
+use explain::redacted_config;
+pub use explain::{explain_config, ExplainedValue};
+
 mod arguments;
 mod case_adapter;
 mod environment;
+mod explain;
+
+/// When given as the first program argument, the agent validates its config
+/// and exits instead of running, see [`crate::agent_main`].
+pub const VALIDATE_COMMAND: &str = "validate";
+/// Program argument that makes the `validate` command also print the
+/// effective config.
+pub const EXPLAIN_FLAG: &str = "--explain";
+/// When given as the first program argument, the scraper backfills a range of
+/// a chain and exits instead of running.
+pub const BACKFILL_COMMAND: &str = "backfill";
+/// When given as the first program argument, the relayer prints the gas
+/// report of its database and exits instead of running.
+pub const GAS_REPORT_COMMAND: &str = "gas_report";
+/// The commands agents accept as their first program argument. These and
+/// their positional arguments are not read as config.
+const COMMANDS: [&str; 3] = [VALIDATE_COMMAND, BACKFILL_COMMAND, GAS_REPORT_COMMAND];
+
+/// The config files settings are loaded from, i.e. the json files in
+/// `./config` followed by the files in `CONFIG_FILES`.
+pub fn config_file_paths() -> Vec<PathBuf> {
+    let default_paths = PathBuf::from("./config")
+        .read_dir()
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && path.extension() == Some("json".as_ref()));
+    let user_paths = env::var("CONFIG_FILES")
+        .map(|s| s.split(',').map(PathBuf::from).collect::<Vec<_>>())
+        .unwrap_or_default();
+    default_paths.chain(user_paths).collect()
+}
 
 /// Deserialize a settings object from the configs.
 pub fn load_settings<T, R>() -> ConfigResult<R>
@@ -23,7 +59,51 @@ where
     R: FromRawConf<T>,
 {
     let root_path = ConfigPath::default();
+    let (config_deserializer, loaded_config_files) = build_config(&root_path)?;
+
+    // The loaded config is printed for debugging when it fails to load, with
+    // secrets redacted since the logs may be collected
+    let formatted_config = || {
+        let line_separator = if env::var("ONELINE_BACKTRACES")
+            .map(|v| v.to_lowercase())
+            .as_deref()
+            == Ok("true")
+        {
+            "\\n"
+        } else {
+            "\n"
+        };
+        redacted_config(&config_deserializer, line_separator)
+    };
+
+    let raw_config = Config::try_deserialize::<T>(config_deserializer.clone())
+        .or_else(|err| {
+            let mut err = if let Some(source_err) = err.source() {
+                let source = format!("Config error source: {source_err}");
+                Err(err).context(source)
+            } else {
+                Err(err.into())
+            };
 
+            for cfg_path in &loaded_config_files {
+                err = err.with_context(|| format!("Config loaded: {cfg_path}"));
+            }
+            eprintln!("Loaded config for debugging: {}", formatted_config());
+            err.context("Config deserialization error, please check the config reference (https://docs.hyperlane.xyz/docs/operators/agent-configuration/configuration-reference)")
+        })
+        .into_config_result(|| root_path.clone())?;
+
+    let res = raw_config.parse_config(&root_path);
+    if res.is_err() {
+        eprintln!("Loaded config for debugging: {}", formatted_config());
+    }
+    res
+}
+
+/// Merge the config sources in order of precedence: the config files, then
+/// the `HYP_` env vars, then the program arguments. Returns the merged config
+/// and the config files that were loaded.
+fn build_config(root_path: &ConfigPath) -> ConfigResult<(Config, Vec<String>)> {
     let mut base_config_sources = vec![];
     let mut builder = Config::builder();
 
@@ -91,39 +171,11 @@ where
         .context("Failed to load config sources")
         .into_config_result(|| root_path.clone())?;
 
-    let formatted_config = {
-        let f = format!("{config_deserializer:#?}");
-        if env::var("ONELINE_BACKTRACES")
-            .map(|v| v.to_lowercase())
-            .as_deref()
-            == Ok("true")
-        {
-            f.replace('\n', "\\n")
-        } else {
-            f
-        }
-    };
-
-    let raw_config = Config::try_deserialize::<T>(config_deserializer)
-        .or_else(|err| {
-            let mut err = if let Some(source_err) = err.source() {
-                let source = format!("Config error source: {source_err}");
-                Err(err).context(source)
-            } else {
-                Err(err.into())
-            };
-
-            for cfg_path in base_config_sources.iter().chain(config_file_paths.iter()) {
-                err = err.with_context(|| format!("Config loaded: {cfg_path}"));
-            }
-            eprintln!("Loaded config for debugging: {formatted_config}");
-            err.context("Config deserialization error, please check the config reference (https://docs.hyperlane.xyz/docs/operators/agent-configuration/configuration-reference)")
-        })
-        .into_config_result(|| root_path.clone())?;
-
-    let res = raw_config.parse_config(&root_path);
-    if res.is_err() {
-        eprintln!("Loaded config for debugging: {formatted_config}");
-    }
-    res
+    Ok((
+        config_deserializer,
+        base_config_sources
+            .into_iter()
+            .chain(config_file_paths)
+            .collect(),
+    ))
 }
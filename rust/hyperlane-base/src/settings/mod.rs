@@ -80,6 +80,8 @@ mod envs {
 
 /// AWS Credentials provider.
 pub(crate) mod aws_credentials;
+/// Signers backed by AWS KMS keys
+mod aws_kms;
 mod base;
 /// Chain configuration
 mod chains;
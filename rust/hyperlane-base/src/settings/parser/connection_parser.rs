@@ -65,10 +65,18 @@ pub fn build_ethereum_connection_conf(
         })
         .unwrap_or_default();
 
+    let subscription_url = chain
+        .chain(err)
+        .get_opt_key("index")
+        .get_opt_key("subscriptionUrl")
+        .parse_from_str("Invalid subscription url")
+        .end();
+
     Some(ChainConnectionConf::Ethereum(h_eth::ConnectionConf {
         rpc_connection: rpc_connection_conf?,
         transaction_overrides,
         operation_batch,
+        subscription_url,
     }))
 }
 
@@ -151,6 +159,42 @@ pub fn build_cosmos_connection_conf(
     }
 }
 
+pub fn build_sealevel_connection_conf(
+    rpcs: &[Url],
+    chain: &ValueParser,
+    err: &mut ConfigParsingError,
+    default_rpc_consensus_type: &str,
+    operation_batch: OperationBatchConfig,
+) -> Option<ChainConnectionConf> {
+    let Some(first_url) = rpcs.first() else {
+        return None;
+    };
+    let rpc_consensus_type = chain
+        .chain(err)
+        .get_opt_key("rpcConsensusType")
+        .parse_string()
+        .unwrap_or(default_rpc_consensus_type);
+
+    let (urls, quorum) = match rpc_consensus_type {
+        "single" => (vec![first_url.clone()], false),
+        "fallback" => (rpcs.to_vec(), false),
+        "quorum" => (rpcs.to_vec(), true),
+        ty => {
+            err.push(
+                &chain.cwp + "rpc_consensus_type",
+                eyre!("unknown rpc consensus type `{ty}`"),
+            );
+            return None;
+        }
+    };
+
+    Some(ChainConnectionConf::Sealevel(h_sealevel::ConnectionConf {
+        urls,
+        quorum,
+        operation_batch,
+    }))
+}
+
 pub fn build_connection_conf(
     domain_protocol: HyperlaneDomainProtocol,
     rpcs: &[Url],
@@ -171,12 +215,13 @@ pub fn build_connection_conf(
             .iter()
             .next()
             .map(|url| ChainConnectionConf::Fuel(h_fuel::ConnectionConf { url: url.clone() })),
-        HyperlaneDomainProtocol::Sealevel => rpcs.iter().next().map(|url| {
-            ChainConnectionConf::Sealevel(h_sealevel::ConnectionConf {
-                url: url.clone(),
-                operation_batch,
-            })
-        }),
+        HyperlaneDomainProtocol::Sealevel => build_sealevel_connection_conf(
+            rpcs,
+            chain,
+            err,
+            default_rpc_consensus_type,
+            operation_batch,
+        ),
         HyperlaneDomainProtocol::Cosmos => {
             build_cosmos_connection_conf(rpcs, chain, err, operation_batch)
         }
@@ -7,16 +7,19 @@
 use std::{
     collections::{HashMap, HashSet},
     default::Default,
+    path::PathBuf,
+    time::Duration,
 };
 
 use convert_case::{Case, Casing};
 use eyre::{eyre, Context};
 use h_cosmos::RawCosmosAmount;
 use hyperlane_core::{
-    cfg_unwrap_all, config::*, HyperlaneDomain, HyperlaneDomainProtocol,
+    cfg_unwrap_all, config::*, BlockFinality, HyperlaneDomain, HyperlaneDomainProtocol,
     HyperlaneDomainTechnicalStack, IndexMode,
 };
 use itertools::Itertools;
+use rusoto_core::Region;
 use serde::Deserialize;
 use serde_json::Value;
 use url::Url;
@@ -25,7 +28,7 @@ pub use self::json_value_parser::ValueParser;
 pub use super::envs::*;
 use crate::settings::{
     chains::IndexSettings, parser::connection_parser::build_connection_conf, trace::TracingConfig,
-    ChainConf, CoreContractAddresses, Settings, SignerConf,
+    ChainConf, CoreContractAddresses, KeystorePassphrase, Settings, SignerConf,
 };
 
 mod connection_parser;
@@ -129,6 +132,17 @@ fn parse_chain(
         .and_then(parse_signer)
         .end();
 
+    let additional_signers = chain
+        .chain(&mut err)
+        .get_opt_key("additionalSigners")
+        .into_array_iter()
+        .map(|signers| {
+            signers
+                .filter_map(|signer| parse_signer(signer).take_config_err(&mut err))
+                .collect()
+        })
+        .unwrap_or_default();
+
     let reorg_period = chain
         .chain(&mut err)
         .get_opt_key("blocks")
@@ -136,6 +150,13 @@ fn parse_chain(
         .parse_u32()
         .unwrap_or(1);
 
+    let finality = chain
+        .chain(&mut err)
+        .get_opt_key("blocks")
+        .get_opt_key("finality")
+        .parse_value("Invalid block finality")
+        .unwrap_or_default();
+
     let rpcs = parse_base_and_override_urls(&chain, "rpcUrls", "customRpcUrls", "http", &mut err);
 
     let from = chain
@@ -200,6 +221,15 @@ fn parse_chain(
         .unwrap_or(1);
 
     cfg_unwrap_all!(&chain.cwp, err: [domain]);
+    if finality != BlockFinality::BlockLag
+        && domain.domain_protocol() != HyperlaneDomainProtocol::Ethereum
+    {
+        err.push(
+            &chain.cwp + "blocks" + "finality",
+            eyre!("Block tag finality is only supported on Ethereum chains"),
+        );
+    }
+
     let connection = build_connection_conf(
         domain.domain_protocol(),
         &rpcs,
@@ -216,7 +246,9 @@ fn parse_chain(
     err.into_result(ChainConf {
         domain,
         signer,
+        additional_signers,
         reorg_period,
+        finality,
         addresses: CoreContractAddresses {
             mailbox,
             interchain_gas_paymaster,
@@ -294,6 +326,7 @@ fn parse_signer(signer: ValueParser) -> ConfigResult<SignerConf> {
     let key_is_some = matches!(signer.get_opt_key("key"), Ok(Some(_)));
     let id_is_some = matches!(signer.get_opt_key("id"), Ok(Some(_)));
     let region_is_some = matches!(signer.get_opt_key("region"), Ok(Some(_)));
+    let path_is_some = matches!(signer.get_opt_key("path"), Ok(Some(_)));
 
     macro_rules! parse_signer {
         (hexKey) => {{
@@ -311,12 +344,31 @@ fn parse_signer(signer: ValueParser) -> ConfigResult<SignerConf> {
                 .parse_string()
                 .unwrap_or("")
                 .to_owned();
-            let region = signer
+            let region: Region = signer
                 .chain(&mut err)
                 .get_key("region")
                 .parse_from_str("Expected AWS region")
                 .unwrap_or_default();
-            err.into_result(SignerConf::Aws { id, region })
+            // Allows pointing the signer at a KMS compatible endpoint other than AWS
+            let region = match signer
+                .chain(&mut err)
+                .get_opt_key("endpoint")
+                .parse_string()
+                .end()
+            {
+                Some(endpoint) => Region::Custom {
+                    name: region.name().to_owned(),
+                    endpoint: endpoint.to_owned(),
+                },
+                None => region,
+            };
+            let prefix = signer
+                .chain(&mut err)
+                .get_opt_key("prefix")
+                .parse_string()
+                .end()
+                .map(str::to_owned);
+            err.into_result(SignerConf::Aws { id, region, prefix })
         }};
         (cosmosKey) => {{
             let key = signer
@@ -334,17 +386,124 @@ fn parse_signer(signer: ValueParser) -> ConfigResult<SignerConf> {
                 prefix: prefix.to_string(),
             })
         }};
+        (remote) => {{
+            let url = signer
+                .chain(&mut err)
+                .get_key("url")
+                .parse_from_str("Expected remote signer url")
+                .end();
+            let address = signer
+                .chain(&mut err)
+                .get_key("address")
+                .parse_from_str("Expected remote signer address")
+                .unwrap_or_default();
+            let timeout = signer
+                .chain(&mut err)
+                .get_opt_key("timeout")
+                .parse_u64()
+                .map(Duration::from_secs)
+                .unwrap_or(Duration::from_secs(10));
+            let ca_cert = signer
+                .chain(&mut err)
+                .get_opt_key("caCert")
+                .parse_string()
+                .end()
+                .map(PathBuf::from);
+            let client_cert_path = signer
+                .chain(&mut err)
+                .get_opt_key("clientCert")
+                .parse_string()
+                .end();
+            let client_key_path = signer
+                .chain(&mut err)
+                .get_opt_key("clientKey")
+                .parse_string()
+                .end();
+            let client_cert = match (client_cert_path, client_key_path) {
+                (Some(cert), Some(key)) => Some((cert.into(), key.into())),
+                (None, None) => None,
+                _ => {
+                    err.push(
+                        &signer.cwp + "client_cert",
+                        eyre!("Expected both `clientCert` and `clientKey` to be set"),
+                    );
+                    None
+                }
+            };
+            match url {
+                Some(url) => err.into_result(SignerConf::Remote {
+                    url,
+                    address,
+                    timeout,
+                    ca_cert,
+                    client_cert,
+                }),
+                None => Err(err),
+            }
+        }};
+        (keystore) => {{
+            let path = signer
+                .chain(&mut err)
+                .get_key("path")
+                .parse_string()
+                .unwrap_or_default();
+            let passphrase_file = signer
+                .chain(&mut err)
+                .get_opt_key("passphraseFile")
+                .parse_string()
+                .end();
+            let passphrase_env = signer
+                .chain(&mut err)
+                .get_opt_key("passphraseEnv")
+                .parse_string()
+                .end();
+            let passphrase = match (passphrase_file, passphrase_env) {
+                (Some(file), None) => Some(KeystorePassphrase::File(file.into())),
+                (None, Some(var)) => Some(KeystorePassphrase::Env(var.to_owned())),
+                _ => {
+                    err.push(
+                        &signer.cwp + "passphrase_file",
+                        eyre!("Expected exactly one of `passphraseFile` or `passphraseEnv`"),
+                    );
+                    None
+                }
+            };
+            let prefix = signer
+                .chain(&mut err)
+                .get_opt_key("prefix")
+                .parse_string()
+                .end()
+                .map(str::to_owned);
+            // Decrypted once here rather than each time a signer is built from
+            // the conf, as decrypting the keystore is deliberately slow.
+            match passphrase {
+                Some(passphrase) => err
+                    .into_result(SignerConf::Keystore {
+                        path: path.into(),
+                        passphrase,
+                        prefix,
+                    })
+                    .and_then(|conf| {
+                        conf.decrypt_keystore()
+                            .into_config_result(|| &signer.cwp + "path")
+                    }),
+                None => Err(err),
+            }
+        }};
     }
 
     match signer_type {
         Some("hexKey") => parse_signer!(hexKey),
         Some("aws") => parse_signer!(aws),
         Some("cosmosKey") => parse_signer!(cosmosKey),
+        Some("keystore") => parse_signer!(keystore),
+        Some("remote") => parse_signer!(remote),
         Some(t) => {
             Err(eyre!("Unknown signer type `{t}`")).into_config_result(|| &signer.cwp + "type")
         }
         None if key_is_some => parse_signer!(hexKey),
         None if id_is_some | region_is_some => parse_signer!(aws),
+        None if path_is_some => parse_signer!(keystore),
         None => Ok(SignerConf::Node),
     }
 }
@@ -1,15 +1,19 @@
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
 use async_trait::async_trait;
 use ed25519_dalek::SecretKey;
 use ethers::prelude::{AwsSigner, LocalWallet};
 use ethers::utils::hex::ToHex;
-use eyre::{bail, Context, Report};
-use hyperlane_core::H256;
-use hyperlane_sealevel::Keypair;
+use eyre::{bail, eyre, Context, Report};
+use hyperlane_core::{H160, H256};
+use hyperlane_sealevel::{Keypair, SealevelSigner};
 use rusoto_core::Region;
 use rusoto_kms::KmsClient;
 use tracing::instrument;
+use url::Url;
 
 use super::aws_credentials::AwsChainCredentialsProvider;
+use super::aws_kms::{AwsKmsEd25519Signer, AwsKmsSecp256k1Signer};
 use crate::types::utils;
 
 /// Signer types
@@ -27,6 +31,8 @@ pub enum SignerConf {
         id: String,
         /// The AWS region
         region: Region,
+        /// Prefix for the address, only used by Cosmos chains
+        prefix: Option<String>,
     },
     /// Cosmos Specific key
     CosmosKey {
@@ -35,16 +41,94 @@ pub enum SignerConf {
         /// Prefix for cosmos address
         prefix: String,
     },
+    /// A key in an encrypted keystore file. secp256k1 keys use the Ethereum V3
+    /// keystore format, ed25519 keys use the same format with the 32 byte
+    /// secret key as the encrypted payload. Keystores in the agent config are
+    /// decrypted into a `HexKey` or `CosmosKey` when the config is parsed.
+    Keystore {
+        /// Path to the keystore file
+        path: PathBuf,
+        /// Where to read the keystore passphrase from
+        passphrase: KeystorePassphrase,
+        /// Prefix for the address, only used by Cosmos chains
+        prefix: Option<String>,
+    },
+    /// A key held by a remote signing service, reached over HTTP through the
+    /// web3signer eth1 API.
+    Remote {
+        /// The base url of the signing service
+        url: Url,
+        /// The address of the key
+        address: H160,
+        /// How long to wait for a signature
+        timeout: Duration,
+        /// PEM encoded certificate of the CA the service's certificate must be
+        /// issued by, in addition to the system's root certificates
+        ca_cert: Option<PathBuf>,
+        /// PEM encoded client certificate and PKCS #8 key for mutual TLS
+        client_cert: Option<(PathBuf, PathBuf)>,
+    },
     /// Assume node will sign on RPC calls
     #[default]
     Node,
 }
 
+/// Source of the passphrase of a keystore file
+#[derive(Debug, Clone)]
+pub enum KeystorePassphrase {
+    /// Read from a file, ignoring trailing newlines
+    File(PathBuf),
+    /// Read from an env var
+    Env(String),
+}
+
+impl KeystorePassphrase {
+    fn read(&self) -> Result<String, Report> {
+        Ok(match self {
+            KeystorePassphrase::File(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Unable to read keystore passphrase file {path:?}"))?
+                .trim_end_matches(['\r', '\n'])
+                .to_owned(),
+            KeystorePassphrase::Env(var) => std::env::var(var)
+                .with_context(|| format!("Unable to read keystore passphrase env var {var}"))?,
+        })
+    }
+}
+
 impl SignerConf {
     /// Try to convert the ethereum signer to a local wallet
     #[instrument(err)]
     pub async fn build<S: BuildableWithSignerConf>(&self) -> Result<S, Report> {
-        S::build(self).await
+        if let SignerConf::Keystore { .. } = self {
+            S::build(&self.decrypt_keystore()?).await
+        } else {
+            S::build(self).await
+        }
+    }
+
+    /// Decrypt a `Keystore` signer into the equivalent local key signer.
+    pub(crate) fn decrypt_keystore(&self) -> Result<SignerConf, Report> {
+        let SignerConf::Keystore {
+            path,
+            passphrase,
+            prefix,
+        } = self
+        else {
+            bail!("Expected a keystore signer");
+        };
+        let secret = eth_keystore::decrypt_key(path, passphrase.read()?)
+            .with_context(|| format!("Unable to decrypt keystore {path:?}"))?;
+        if secret.len() != H256::len_bytes() {
+            bail!("Expected a 32 byte key in keystore {path:?}");
+        }
+        let key = H256::from_slice(&secret);
+        Ok(match prefix {
+            Some(prefix) => SignerConf::CosmosKey {
+                key,
+                prefix: prefix.clone(),
+            },
+            None => SignerConf::HexKey { key },
+        })
     }
 }
 
@@ -61,6 +145,17 @@ pub trait BuildableWithSignerConf: Sized + ChainSigner {
     async fn build(conf: &SignerConf) -> Result<Self, Report>;
 }
 
+/// Build a KMS client for `region`, using the AWS credentials in the env.
+fn kms_client(region: &Region) -> KmsClient {
+    KmsClient::new_with_client(
+        rusoto_core::Client::new_with(
+            AwsChainCredentialsProvider::new(),
+            utils::http_client_with_timeout().unwrap(),
+        ),
+        region.clone(),
+    )
+}
+
 #[async_trait]
 impl BuildableWithSignerConf for hyperlane_ethereum::Signers {
     async fn build(conf: &SignerConf) -> Result<Self, Report> {
@@ -71,21 +166,41 @@ impl BuildableWithSignerConf for hyperlane_ethereum::Signers {
                         .context("Invalid ethereum signer key")?,
                 ),
             )),
-            SignerConf::Aws { id, region } => {
-                let client = KmsClient::new_with_client(
-                    rusoto_core::Client::new_with(
-                        AwsChainCredentialsProvider::new(),
-                        utils::http_client_with_timeout().unwrap(),
-                    ),
-                    region.clone(),
-                );
-
-                let signer = AwsSigner::new(client, id, 0).await?;
+            SignerConf::Aws { id, region, .. } => {
+                let signer = AwsSigner::new(kms_client(region), id, 0).await?;
                 hyperlane_ethereum::Signers::Aws(signer)
             }
             SignerConf::CosmosKey { .. } => {
                 bail!("cosmosKey signer is not supported by Ethereum")
             }
+            SignerConf::Remote {
+                url,
+                address,
+                timeout,
+                ca_cert,
+                client_cert,
+            } => {
+                let mut client = reqwest::Client::builder().timeout(*timeout);
+                if let Some(path) = ca_cert {
+                    let pem = std::fs::read(path)
+                        .with_context(|| format!("Unable to read CA certificate {path:?}"))?;
+                    client = client.add_root_certificate(reqwest::Certificate::from_pem(&pem)?);
+                }
+                if let Some((cert_path, key_path)) = client_cert {
+                    let cert = std::fs::read(cert_path).with_context(|| {
+                        format!("Unable to read client certificate {cert_path:?}")
+                    })?;
+                    let key = std::fs::read(key_path)
+                        .with_context(|| format!("Unable to read client key {key_path:?}"))?;
+                    client = client.identity(reqwest::Identity::from_pkcs8_pem(&cert, &key)?);
+                }
+                hyperlane_ethereum::Signers::Remote(hyperlane_ethereum::RemoteSigner::new(
+                    client.build()?,
+                    url.clone(),
+                    (*address).into(),
+                ))
+            }
+            SignerConf::Keystore { .. } => bail!("Keystore signer must be decrypted first"),
             SignerConf::Node => bail!("Node signer"),
         })
     }
@@ -119,38 +234,48 @@ impl ChainSigner for fuels::prelude::WalletUnlocked {
 }
 
 #[async_trait]
-impl BuildableWithSignerConf for Keypair {
+impl BuildableWithSignerConf for SealevelSigner {
     async fn build(conf: &SignerConf) -> Result<Self, Report> {
-        if let SignerConf::HexKey { key } = conf {
-            let secret = SecretKey::from_bytes(key.as_bytes())
-                .context("Invalid sealevel ed25519 secret key")?;
-            Ok(
+        Ok(match conf {
+            SignerConf::HexKey { key } => {
+                let secret = SecretKey::from_bytes(key.as_bytes())
+                    .context("Invalid sealevel ed25519 secret key")?;
                 Keypair::from_bytes(&ed25519_dalek::Keypair::from(secret).to_bytes())
-                    .context("Unable to create Keypair")?,
-            )
-        } else {
-            bail!(format!("{conf:?} key is not supported by sealevel"));
-        }
+                    .context("Unable to create Keypair")?
+                    .into()
+            }
+            SignerConf::Aws { id, region, .. } => SealevelSigner::Remote(Arc::new(
+                AwsKmsEd25519Signer::new(kms_client(region), id.clone()).await?,
+            )),
+            _ => bail!(format!("{conf:?} key is not supported by sealevel")),
+        })
     }
 }
 
-impl ChainSigner for Keypair {
+impl ChainSigner for SealevelSigner {
     fn address_string(&self) -> String {
-        solana_sdk::signer::Signer::pubkey(self).to_string()
+        self.pubkey().to_string()
     }
 }
 
 #[async_trait]
 impl BuildableWithSignerConf for hyperlane_cosmos::Signer {
     async fn build(conf: &SignerConf) -> Result<Self, Report> {
-        if let SignerConf::CosmosKey { key, prefix } = conf {
-            Ok(hyperlane_cosmos::Signer::new(
-                key.as_bytes().to_vec(),
-                prefix.clone(),
-            )?)
-        } else {
-            bail!(format!("{conf:?} key is not supported by cosmos"));
-        }
+        Ok(match conf {
+            SignerConf::CosmosKey { key, prefix } => {
+                hyperlane_cosmos::Signer::new(key.as_bytes().to_vec(), prefix.clone())?
+            }
+            SignerConf::Aws { id, region, prefix } => {
+                let prefix = prefix
+                    .clone()
+                    .ok_or_else(|| eyre!("AWS signers for cosmos require a `prefix`"))?;
+                hyperlane_cosmos::Signer::new_remote(
+                    Arc::new(AwsKmsSecp256k1Signer::new(kms_client(region), id.clone()).await?),
+                    prefix,
+                )?
+            }
+            _ => bail!(format!("{conf:?} key is not supported by cosmos")),
+        })
     }
 }
 
@@ -159,3 +284,91 @@ impl ChainSigner for hyperlane_cosmos::Signer {
         self.address.clone()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hyperlane_core::config::FromRawConf;
+
+    use super::*;
+    use crate::settings::parser::RawAgentSignerConf;
+
+    #[test]
+    fn test_decrypt_keystore() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = H256::repeat_byte(7);
+        eth_keystore::encrypt_key(
+            dir.path(),
+            &mut ethers::core::rand::thread_rng(),
+            key.as_bytes(),
+            "hunter2",
+            Some("key.json"),
+        )
+        .unwrap();
+        let passphrase_path = dir.path().join("passphrase");
+        std::fs::write(&passphrase_path, "hunter2\n").unwrap();
+
+        let conf = SignerConf::Keystore {
+            path: dir.path().join("key.json"),
+            passphrase: KeystorePassphrase::File(passphrase_path.clone()),
+            prefix: None,
+        };
+        assert!(matches!(
+            conf.decrypt_keystore().unwrap(),
+            SignerConf::HexKey { key: decrypted } if decrypted == key
+        ));
+
+        let conf = SignerConf::Keystore {
+            path: dir.path().join("key.json"),
+            passphrase: KeystorePassphrase::File(passphrase_path),
+            prefix: Some("neutron".into()),
+        };
+        assert!(matches!(
+            conf.decrypt_keystore().unwrap(),
+            SignerConf::CosmosKey { key: decrypted, prefix } if decrypted == key && prefix == "neutron"
+        ));
+
+        std::fs::write(dir.path().join("passphrase"), "wrong").unwrap();
+        let conf = SignerConf::Keystore {
+            path: dir.path().join("key.json"),
+            passphrase: KeystorePassphrase::File(dir.path().join("passphrase")),
+            prefix: None,
+        };
+        assert!(conf.decrypt_keystore().is_err());
+    }
+
+    #[test]
+    fn test_keystore_decrypted_when_parsed() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = H256::repeat_byte(7);
+        eth_keystore::encrypt_key(
+            dir.path(),
+            &mut ethers::core::rand::thread_rng(),
+            key.as_bytes(),
+            "hunter2",
+            Some("key.json"),
+        )
+        .unwrap();
+        let passphrase_path = dir.path().join("passphrase");
+        std::fs::write(&passphrase_path, "hunter2").unwrap();
+
+        let raw: RawAgentSignerConf = serde_json::from_value(serde_json::json!({
+            "type": "keystore",
+            "path": dir.path().join("key.json"),
+            "passphraseFile": passphrase_path,
+        }))
+        .unwrap();
+        assert!(matches!(
+            SignerConf::from_config(raw, &Default::default()).unwrap(),
+            SignerConf::HexKey { key: decrypted } if decrypted == key
+        ));
+
+        std::fs::write(&passphrase_path, "wrong").unwrap();
+        let raw: RawAgentSignerConf = serde_json::from_value(serde_json::json!({
+            "type": "keystore",
+            "path": dir.path().join("key.json"),
+            "passphraseFile": passphrase_path,
+        }))
+        .unwrap();
+        assert!(SignerConf::from_config(raw, &Default::default()).is_err());
+    }
+}
@@ -3,7 +3,9 @@ use std::fmt::Debug;
 use async_trait::async_trait;
 use eyre::Result;
 
-use hyperlane_core::{SignedAnnouncement, SignedCheckpointWithMessageId};
+use hyperlane_core::{
+    SignedAnnouncement, SignedCheckpointWithMessageId, SignedStorageLocationRevocation,
+};
 
 /// A generic trait to read/write Checkpoints offchain
 #[async_trait]
@@ -29,6 +31,14 @@ pub trait CheckpointSyncer: Debug + Send + Sync {
     ) -> Result<()>;
     /// Write the signed announcement to this syncer
     async fn write_announcement(&self, signed_announcement: &SignedAnnouncement) -> Result<()>;
+    /// Write a signed revocation of this syncer's storage location, or
+    /// overwrite a previously written one with `None`
+    async fn write_revocation(
+        &self,
+        signed_revocation: Option<&SignedStorageLocationRevocation>,
+    ) -> Result<()>;
+    /// Attempt to fetch the signed revocation of this syncer's storage location
+    async fn fetch_revocation(&self) -> Result<Option<SignedStorageLocationRevocation>>;
     /// Return the announcement storage location for this syncer
     fn announcement_location(&self) -> String;
 }
@@ -2,12 +2,15 @@ use crate::CheckpointSyncer;
 use async_trait::async_trait;
 use derive_new::new;
 use eyre::{bail, Result};
-use hyperlane_core::{SignedAnnouncement, SignedCheckpointWithMessageId};
+use hyperlane_core::{
+    SignedAnnouncement, SignedCheckpointWithMessageId, SignedStorageLocationRevocation,
+};
 use std::fmt;
 use ya_gcp::{storage::StorageClient, AuthFlow, ClientBuilder, ClientBuilderConfig};
 
 const LATEST_INDEX_KEY: &str = "gcsLatestIndexKey";
 const ANNOUNCEMENT_KEY: &str = "gcsAnnouncementKey";
+const REVOCATION_KEY: &str = "gcsRevocationKey";
 /// Path to GCS users_secret file
 pub const GCS_USER_SECRET: &str = "GCS_USER_SECRET";
 /// Path to GCS Service account key
@@ -186,6 +189,35 @@ impl CheckpointSyncer for GcsStorageClient {
         Ok(())
     }
 
+    /// Write a signed revocation of this syncer's storage location, or
+    /// overwrite a previously written one with `None`
+    async fn write_revocation(
+        &self,
+        signed_revocation: Option<&SignedStorageLocationRevocation>,
+    ) -> Result<()> {
+        self.inner
+            .insert_object(
+                &self.bucket,
+                REVOCATION_KEY,
+                serde_json::to_string(&signed_revocation)?,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Attempt to fetch the signed revocation of this syncer's storage location
+    async fn fetch_revocation(&self) -> Result<Option<SignedStorageLocationRevocation>> {
+        match self.inner.get_object(&self.bucket, REVOCATION_KEY).await {
+            // `null` if a revocation was overwritten
+            Ok(data) => Ok(serde_json::from_slice(data.as_ref())?),
+            Err(e) => match e {
+                // never revoked
+                ya_gcp::storage::ObjectError::InvalidName(_) => Ok(None),
+                _ => bail!(e),
+            },
+        }
+    }
+
     /// Return the announcement storage location for this syncer
     fn announcement_location(&self) -> String {
         format!("gs://{}/{}", &self.bucket, ANNOUNCEMENT_KEY)
@@ -2,7 +2,9 @@ use std::path::PathBuf;
 
 use async_trait::async_trait;
 use eyre::{Context, Result};
-use hyperlane_core::{SignedAnnouncement, SignedCheckpointWithMessageId};
+use hyperlane_core::{
+    SignedAnnouncement, SignedCheckpointWithMessageId, SignedStorageLocationRevocation,
+};
 use prometheus::IntGauge;
 
 use crate::traits::CheckpointSyncer;
@@ -40,6 +42,10 @@ impl LocalStorage {
     fn announcement_file_path(&self) -> PathBuf {
         self.path.join("announcement.json")
     }
+
+    fn revocation_file_path(&self) -> PathBuf {
+        self.path.join("revocation.json")
+    }
 }
 
 #[async_trait]
@@ -100,7 +106,69 @@ impl CheckpointSyncer for LocalStorage {
         Ok(())
     }
 
+    async fn write_revocation(
+        &self,
+        signed_revocation: Option<&SignedStorageLocationRevocation>,
+    ) -> Result<()> {
+        let serialized_revocation = serde_json::to_string_pretty(&signed_revocation)?;
+        let path = self.revocation_file_path();
+        tokio::fs::write(&path, &serialized_revocation)
+            .await
+            .with_context(|| format!("Writing revocation to {path:?}"))?;
+        Ok(())
+    }
+
+    async fn fetch_revocation(&self) -> Result<Option<SignedStorageLocationRevocation>> {
+        let Ok(data) = tokio::fs::read(self.revocation_file_path()).await else {
+            return Ok(None);
+        };
+        // `null` if a revocation was overwritten
+        let revocation = serde_json::from_slice(&data)?;
+        Ok(revocation)
+    }
+
     fn announcement_location(&self) -> String {
         format!("file://{}", self.path.to_str().unwrap())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use hyperlane_core::{Signature, StorageLocationRevocation, H160, H256, U256};
+
+    use super::*;
+
+    fn signed_revocation() -> SignedStorageLocationRevocation {
+        SignedStorageLocationRevocation {
+            value: StorageLocationRevocation {
+                validator: H160::repeat_byte(1),
+                mailbox_address: H256::repeat_byte(2),
+                mailbox_domain: 3,
+                storage_location: "file:///revoked".to_owned(),
+                replacement_location: "file:///replacement".to_owned(),
+            },
+            signature: Signature {
+                r: U256::one(),
+                s: U256::one(),
+                v: 27,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revocation_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = LocalStorage::new(dir.path().to_path_buf(), None).unwrap();
+
+        // Never revoked
+        assert_eq!(storage.fetch_revocation().await.unwrap(), None);
+
+        let revocation = signed_revocation();
+        storage.write_revocation(Some(&revocation)).await.unwrap();
+        assert_eq!(storage.fetch_revocation().await.unwrap(), Some(revocation));
+
+        // Overwritten, e.g. once the location is announced again
+        storage.write_revocation(None).await.unwrap();
+        assert_eq!(storage.fetch_revocation().await.unwrap(), None);
+    }
+}
@@ -4,7 +4,9 @@ use async_trait::async_trait;
 use derive_new::new;
 use eyre::{bail, Result};
 use futures_util::TryStreamExt;
-use hyperlane_core::{SignedAnnouncement, SignedCheckpointWithMessageId};
+use hyperlane_core::{
+    SignedAnnouncement, SignedCheckpointWithMessageId, SignedStorageLocationRevocation,
+};
 use prometheus::IntGauge;
 use rusoto_core::{
     credential::{Anonymous, AwsCredentials, StaticProvider},
@@ -139,6 +141,10 @@ impl S3Storage {
     fn announcement_key() -> String {
         "announcement.json".to_owned()
     }
+
+    fn revocation_key() -> String {
+        "revocation.json".to_owned()
+    }
 }
 
 #[async_trait]
@@ -195,6 +201,26 @@ impl CheckpointSyncer for S3Storage {
         Ok(())
     }
 
+    async fn write_revocation(
+        &self,
+        signed_revocation: Option<&SignedStorageLocationRevocation>,
+    ) -> Result<()> {
+        let serialized_revocation = serde_json::to_string_pretty(&signed_revocation)?;
+        self.write_to_bucket(S3Storage::revocation_key(), &serialized_revocation)
+            .await?;
+        Ok(())
+    }
+
+    async fn fetch_revocation(&self) -> Result<Option<SignedStorageLocationRevocation>> {
+        self.anonymously_read_from_bucket(S3Storage::revocation_key())
+            .await?
+            // `null` if a revocation was overwritten
+            .map(|data| serde_json::from_slice::<Option<_>>(&data))
+            .transpose()
+            .map(Option::flatten)
+            .map_err(Into::into)
+    }
+
     fn announcement_location(&self) -> String {
         match self.folder.as_deref() {
             None | Some("") => format!("s3://{}/{}", self.bucket, self.region.name()),
@@ -204,3 +230,98 @@ impl CheckpointSyncer for S3Storage {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+
+    use axum::{
+        body::Bytes,
+        extract::{Path, State},
+        http::StatusCode,
+        response::{IntoResponse, Response},
+        routing::get,
+        Router,
+    };
+    use hyperlane_core::{Signature, StorageLocationRevocation, H160, H256, U256};
+
+    use super::*;
+
+    type Objects = Arc<Mutex<HashMap<String, Bytes>>>;
+
+    const NO_SUCH_KEY: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?><Error><Code>NoSuchKey</Code><Message>The specified key does not exist.</Message></Error>";
+
+    async fn get_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+    ) -> Response {
+        match objects.lock().unwrap().get(&format!("{bucket}/{key}")) {
+            Some(body) => body.clone().into_response(),
+            None => (StatusCode::NOT_FOUND, NO_SUCH_KEY).into_response(),
+        }
+    }
+
+    async fn put_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        body: Bytes,
+    ) -> StatusCode {
+        objects
+            .lock()
+            .unwrap()
+            .insert(format!("{bucket}/{key}"), body);
+        StatusCode::OK
+    }
+
+    /// Serves path-style S3 object reads and writes from memory.
+    async fn mock_s3() -> Region {
+        let server = axum::Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(
+            Router::new()
+                .route("/:bucket/:key", get(get_object).put(put_object))
+                .with_state(Objects::default())
+                .into_make_service(),
+        );
+        let endpoint = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+        Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revocation_round_trip() {
+        // Writes are signed with credentials from the environment
+        std::env::set_var("AWS_ACCESS_KEY_ID", "test");
+        std::env::set_var("AWS_SECRET_ACCESS_KEY", "test");
+        let storage = S3Storage::new("bucket".to_owned(), None, mock_s3().await, None);
+
+        // Never revoked
+        assert_eq!(storage.fetch_revocation().await.unwrap(), None);
+
+        let revocation = SignedStorageLocationRevocation {
+            value: StorageLocationRevocation {
+                validator: H160::repeat_byte(1),
+                mailbox_address: H256::repeat_byte(2),
+                mailbox_domain: 3,
+                storage_location: "s3://bucket/us-east-1".to_owned(),
+                replacement_location: "s3://replacement/us-east-1".to_owned(),
+            },
+            signature: Signature {
+                r: U256::one(),
+                s: U256::one(),
+                v: 27,
+            },
+        };
+        storage.write_revocation(Some(&revocation)).await.unwrap();
+        assert_eq!(storage.fetch_revocation().await.unwrap(), Some(revocation));
+
+        // Overwritten, e.g. once the location is announced again
+        storage.write_revocation(None).await.unwrap();
+        assert_eq!(storage.fetch_revocation().await.unwrap(), None);
+    }
+}
@@ -184,6 +184,47 @@ impl MerkleTree {
         }
     }
 
+    /// Create a Merkle tree of `count` leaves from the branch of an
+    /// incremental Merkle tree of the same size, see
+    /// `IncrementalMerkle::branch`.
+    ///
+    /// The full subtrees left of the next leaf are only known by their hashes,
+    /// so they are pruned: leaves can be pushed and proven from `count` on,
+    /// but proofs of earlier leaves are meaningless.
+    pub fn from_branch(branch: &[H256], count: usize, depth: usize) -> Self {
+        use MerkleTree::*;
+
+        if count == 0 {
+            return Zero(depth);
+        }
+        if count == 1 << depth {
+            return match depth {
+                0 => Leaf(branch[0]),
+                // The placeholder leaves make `push_leaf` treat the subtree as full
+                _ => Node(
+                    branch[depth],
+                    Box::new(Leaf(H256::zero())),
+                    Box::new(Leaf(H256::zero())),
+                ),
+            };
+        }
+
+        let subtree_capacity = 1 << (depth - 1);
+        let (left_subtree, right_subtree) = if count > subtree_capacity {
+            (
+                MerkleTree::from_branch(branch, subtree_capacity, depth - 1),
+                MerkleTree::from_branch(branch, count - subtree_capacity, depth - 1),
+            )
+        } else {
+            (
+                MerkleTree::from_branch(branch, count, depth - 1),
+                Zero(depth - 1),
+            )
+        };
+        let hash = hash_concat(left_subtree.hash(), right_subtree.hash());
+        Node(hash, Box::new(left_subtree), Box::new(right_subtree))
+    }
+
     /// Push an element in the MerkleTree.
     /// MerkleTree and depth must be correct, as the algorithm expects valid data.
     pub fn push_leaf(&mut self, elem: H256, depth: usize) -> Result<(), MerkleTreeError> {
@@ -525,6 +566,29 @@ mod tests {
         assert_eq!(full.hash(), incr.root());
     }
 
+    #[test]
+    fn it_restores_from_incremental_branch() {
+        let leaves: Vec<_> = (0..13u8).map(H256::repeat_byte).collect();
+
+        for count in 0..8 {
+            let mut incr = incremental::IncrementalMerkle::default();
+            leaves[..count].iter().for_each(|leaf| incr.ingest(*leaf));
+            let mut restored = MerkleTree::from_branch(&incr.branch, count, TREE_DEPTH);
+            assert_eq!(restored.hash(), incr.root());
+
+            let mut full = MerkleTree::create(&leaves[..count], TREE_DEPTH);
+            for (i, leaf) in leaves.iter().enumerate().skip(count) {
+                restored.push_leaf(*leaf, TREE_DEPTH).unwrap();
+                full.push_leaf(*leaf, TREE_DEPTH).unwrap();
+                assert_eq!(restored.hash(), full.hash());
+                assert_eq!(
+                    restored.generate_proof(i, TREE_DEPTH),
+                    full.generate_proof(i, TREE_DEPTH)
+                );
+            }
+        }
+    }
+
     #[test]
     fn it_sets_zero_nodes_correctly() {
         let expected_zero_nodes: Vec<_> = (0..=TREE_DEPTH).map(MerkleTree::Zero).collect();
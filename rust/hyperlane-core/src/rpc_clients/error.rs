@@ -8,4 +8,12 @@ pub enum RpcClientError {
     /// Fallback providers failed
     #[error("All fallback providers failed. (Errors: {0:?})")]
     FallbackProvidersFailed(Vec<ChainCommunicationError>),
+    /// Not enough providers agreed on a response
+    #[error("Fewer than {threshold} providers agreed on a response. (Errors: {errors:?})")]
+    QuorumNotReached {
+        /// The number of providers that had to agree
+        threshold: usize,
+        /// Errors returned by the providers
+        errors: Vec<ChainCommunicationError>,
+    },
 }
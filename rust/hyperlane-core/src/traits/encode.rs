@@ -236,27 +236,39 @@ impl Decode for InterchainGasPayment {
     }
 }
 
-// TODO: Could generalize this implementation to support encoding arbitrary `Option<T>`
-// where T: Encode + Decode
-impl<T: Encode> Encode for Indexed<T> {
+impl<T: Encode> Encode for Option<T> {
     fn write_to<W>(&self, writer: &mut W) -> std::io::Result<usize>
     where
         W: std::io::Write,
     {
-        let mut written = 0;
-        written += self.inner().write_to(writer)?;
-        match self.sequence {
-            Some(sequence) => {
-                let sequence_is_defined = true;
-                written += sequence_is_defined.write_to(writer)?;
-                written += sequence.write_to(writer)?;
-            }
-            None => {
-                let sequence_is_defined = false;
-                written += sequence_is_defined.write_to(writer)?;
-            }
+        match self {
+            Some(inner) => Ok(true.write_to(writer)? + inner.write_to(writer)?),
+            None => false.write_to(writer),
         }
-        Ok(written)
+    }
+}
+
+impl<T: Decode> Decode for Option<T> {
+    fn read_from<R>(reader: &mut R) -> Result<Self, HyperlaneProtocolError>
+    where
+        R: std::io::Read,
+        Self: Sized,
+    {
+        let is_defined = bool::read_from(reader)?;
+        if is_defined {
+            Ok(Some(T::read_from(reader)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<T: Encode> Encode for Indexed<T> {
+    fn write_to<W>(&self, writer: &mut W) -> std::io::Result<usize>
+    where
+        W: std::io::Write,
+    {
+        Ok(self.inner().write_to(writer)? + self.sequence.write_to(writer)?)
     }
 }
 
@@ -267,10 +279,8 @@ impl<T: Decode> Decode for Indexed<T> {
         Self: Sized,
     {
         let inner = T::read_from(reader)?;
-        let sequence_is_defined = bool::read_from(reader)?;
         let mut indexed = Self::new(inner);
-        if sequence_is_defined {
-            let sequence = u32::read_from(reader)?;
+        if let Some(sequence) = Option::<u32>::read_from(reader)? {
             indexed = indexed.with_sequence(sequence)
         }
         Ok(indexed)
@@ -289,6 +299,15 @@ mod test {
         assert_eq!(indexed, decoded);
     }
 
+    #[test]
+    fn test_encoding_option() {
+        for value in [Some(42u64), None] {
+            let encoded = value.to_vec();
+            let decoded = Option::<u64>::read_from(&mut &encoded[..]).unwrap();
+            assert_eq!(value, decoded);
+        }
+    }
+
     #[test]
     fn test_encoding_interchain_gas_payment() {
         let payment = super::InterchainGasPayment {
@@ -10,6 +10,8 @@ use std::ops::RangeInclusive;
 use async_trait::async_trait;
 use auto_impl::auto_impl;
 use serde::Deserialize;
+#[cfg(feature = "async")]
+use tokio::sync::mpsc::Sender;
 
 use crate::{ChainResult, Indexed, LogMeta, H512};
 
@@ -46,6 +48,30 @@ pub trait Indexer<T: Sized>: Send + Sync + Debug {
     }
 }
 
+/// A log pushed by a `LogSubscriber` once it is considered final.
+#[derive(Debug, Clone)]
+pub struct SubscribedLog<T> {
+    /// The log
+    pub log: Indexed<T>,
+    /// Metadata of the log
+    pub meta: LogMeta,
+    /// Unix timestamp of the block the log was emitted in, in seconds
+    pub block_timestamp: Option<u64>,
+}
+
+/// Interface for pushing logs as they are emitted. This is a lower latency
+/// complement to polling an `Indexer`, which is still required to fill gaps
+/// when a subscription drops.
+#[cfg(feature = "async")]
+#[async_trait]
+#[auto_impl(&, Box, Arc)]
+pub trait LogSubscriber<T>: Send + Sync + Debug {
+    /// Subscribe to new logs and forward them to `sender` once they are final.
+    /// Returns once the subscription ends, e.g. because the connection was
+    /// dropped.
+    async fn subscribe(&self, sender: Sender<SubscribedLog<T>>) -> ChainResult<()>;
+}
+
 /// Interface for indexing data in sequence.
 /// SequenceAwareIndexer is an umbrella trait for all indexers types (sequence-aware and rate-limited).
 /// The rate-limited indexer doesn't need `SequenceAwareIndexer`, so impls of `SequenceAwareIndexer` just return nullish values.
@@ -3,10 +3,72 @@ use std::fmt::Debug;
 use async_trait::async_trait;
 use auto_impl::auto_impl;
 
-use crate::HyperlaneContract;
+use crate::{ChainResult, HyperlaneContract, TxOutcome, U256};
 
 /// Interface for the InterchainGasPaymaster chain contract.
 /// Allows abstraction over different chains.
 #[async_trait]
 #[auto_impl(&, Box, Arc)]
-pub trait InterchainGasPaymaster: HyperlaneContract + Send + Sync + Debug {}
+pub trait InterchainGasPaymaster: HyperlaneContract + Send + Sync + Debug {
+    /// Gets the gas data the IGP's gas oracle holds for `remote_domain`, if any.
+    async fn remote_gas_data(&self, remote_domain: u32) -> ChainResult<Option<OracleGasData>>;
+
+    /// Sets the gas data held by the IGP's gas oracle for each remote domain.
+    /// Requires the signer to be the owner of the gas oracle, or on Sealevel
+    /// the updater of the IGP-owned price account the gas oracle reads from.
+    async fn set_remote_gas_data(
+        &self,
+        configs: Vec<RemoteGasDataConfig>,
+    ) -> ChainResult<TxOutcome>;
+}
+
+/// Gas data for a remote domain, as held by an IGP's gas oracle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteGasData {
+    /// The exchange rate of the remote native token to the local native token,
+    /// scaled as expected by the IGP's gas oracle.
+    pub token_exchange_rate: U256,
+    /// The gas price of the remote chain, in its lowest denomination.
+    pub gas_price: U256,
+    /// The number of decimals of the remote native token, for gas oracles that
+    /// account for decimals themselves, i.e. on Sealevel. `None` for gas oracles
+    /// whose exchange rate accounts for decimals, i.e. on EVM chains.
+    pub token_decimals: Option<u8>,
+}
+
+/// Gas data for a remote domain as read from an IGP's gas oracle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OracleGasData {
+    /// The gas data.
+    pub remote_gas_data: RemoteGasData,
+    /// The slot the gas data was last updated in, for gas oracles that reject
+    /// gas data once it's too many slots old, i.e. Sealevel price accounts.
+    pub last_updated_slot: Option<u64>,
+    /// The slot the gas data was read at, if `last_updated_slot` is known.
+    pub current_slot: Option<u64>,
+}
+
+impl OracleGasData {
+    /// Gas data of a gas oracle that doesn't record when it was updated.
+    pub fn new(remote_gas_data: RemoteGasData) -> Self {
+        Self {
+            remote_gas_data,
+            last_updated_slot: None,
+            current_slot: None,
+        }
+    }
+
+    /// The number of slots since the gas data was last updated, if known.
+    pub fn slots_since_update(&self) -> Option<u64> {
+        Some(self.current_slot?.saturating_sub(self.last_updated_slot?))
+    }
+}
+
+/// The gas data to set for a remote domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteGasDataConfig {
+    /// The remote domain.
+    pub remote_domain: u32,
+    /// The gas data for the remote domain.
+    pub remote_gas_data: RemoteGasData,
+}
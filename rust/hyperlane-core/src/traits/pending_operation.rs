@@ -67,8 +67,14 @@ pub trait PendingOperation: Send + Sync + Debug + TryBatchAs<HyperlaneMessage> {
     /// submit call.
     async fn prepare(&mut self) -> PendingOperationResult;
 
-    /// Submit this operation to the blockchain
-    async fn submit(&mut self);
+    /// Submit this operation to the blockchain. Returns `Reprepare` if the
+    /// submission failed and `Success` otherwise.
+    async fn submit(&mut self) -> PendingOperationResult;
+
+    /// Select which of the destination chain's signers the next `submit`
+    /// call uses, by its index in the signer pool. Index 0 is the chain's
+    /// primary signer, which is used by default.
+    fn set_signer_index(&mut self, signer_index: usize);
 
     /// Set the outcome of the `submit` call
     fn set_submission_outcome(&mut self, outcome: TxOutcome);
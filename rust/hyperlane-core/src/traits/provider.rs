@@ -28,6 +28,16 @@ pub trait HyperlaneProvider: HyperlaneChain + Send + Sync + Debug {
     /// Fetch the balance of the wallet address associated with the chain provider.
     async fn get_balance(&self, address: String) -> ChainResult<U256>;
 
+    /// Fetch the nonce (or account sequence) of the wallet address, i.e. the
+    /// number of transactions it has sent. Returns `None` on chains that have
+    /// no notion of account nonces.
+    async fn get_nonce(&self, address: String) -> ChainResult<Option<u64>>;
+
+    /// Fetch the price currently paid per unit of gas, in the smallest
+    /// denomination of the native token. Returns `None` on chains that don't
+    /// report one.
+    async fn get_gas_price(&self) -> ChainResult<Option<U256>>;
+
     /// Fetch metrics related to this chain
     async fn get_chain_metrics(&self) -> ChainResult<Option<ChainInfo>>;
 }
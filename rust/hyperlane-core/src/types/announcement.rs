@@ -4,7 +4,10 @@ use sha3::{digest::Update, Digest, Keccak256};
 use std::fmt::{Debug, Formatter};
 
 use crate::utils::{fmt_address_for_domain, fmt_domain};
-use crate::{utils::announcement_domain_hash, Signable, SignedType, H160, H256};
+use crate::{
+    utils::{announcement_domain_hash, revocation_domain_hash},
+    Signable, SignedType, H160, H256,
+};
 
 /// An Hyperlane checkpoint
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -50,3 +53,63 @@ impl Signable for Announcement {
 
 /// An announcement that has been signed.
 pub type SignedAnnouncement = SignedType<Announcement>;
+
+/// A storage location announced on the ValidatorAnnounce contract, as
+/// indexed from its announcement events.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ValidatorAnnouncement {
+    /// The validator address
+    pub validator: H160,
+    /// The announced location of signed checkpoints
+    pub storage_location: String,
+}
+
+/// A validator's revocation of a storage location it previously announced.
+/// Revocations are written to the revoked location so that relayers skip it.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StorageLocationRevocation {
+    /// The validator address
+    pub validator: H160,
+    /// The mailbox address
+    pub mailbox_address: H256,
+    /// The mailbox chain
+    pub mailbox_domain: u32,
+    /// The revoked location of signed checkpoints
+    pub storage_location: String,
+    /// The location of signed checkpoints that replaces the revoked one
+    pub replacement_location: String,
+}
+
+impl Debug for StorageLocationRevocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "StorageLocationRevocation {{ validator: {:?}, mailbox_address: {}, mailbox_domain: {}, storage_location: {}, replacement_location: {} }}",
+            self.validator,
+            fmt_address_for_domain(self.mailbox_domain, self.mailbox_address),
+            fmt_domain(self.mailbox_domain),
+            self.storage_location,
+            self.replacement_location
+        )
+    }
+}
+
+#[async_trait]
+impl Signable for StorageLocationRevocation {
+    fn signing_hash(&self) -> H256 {
+        H256::from_slice(
+            Keccak256::new()
+                .chain(revocation_domain_hash(
+                    self.mailbox_address,
+                    self.mailbox_domain,
+                ))
+                .chain(&self.storage_location)
+                .chain(&self.replacement_location)
+                .finalize()
+                .as_slice(),
+        )
+    }
+}
+
+/// A storage location revocation that has been signed.
+pub type SignedStorageLocationRevocation = SignedType<StorageLocationRevocation>;
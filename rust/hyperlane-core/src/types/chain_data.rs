@@ -1,4 +1,5 @@
 use derive_new::new;
+use serde::Deserialize;
 
 use crate::{H256, U256};
 
@@ -13,6 +14,19 @@ pub struct BlockInfo {
     pub number: u64,
 }
 
+/// How the latest final block of a chain is determined.
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockFinality {
+    /// Lag behind the latest block by the chain's reorg period.
+    #[default]
+    BlockLag,
+    /// Use the `safe` block tag of the RPC.
+    Safe,
+    /// Use the `finalized` block tag of the RPC.
+    Finalized,
+}
+
 /// Metrics about the chain.
 #[derive(Debug, Clone, Default, new)]
 pub struct ChainInfo {
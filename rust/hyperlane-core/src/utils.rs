@@ -50,6 +50,18 @@ pub fn announcement_domain_hash(address: H256, domain: impl Into<u32>) -> H256 {
     )
 }
 
+/// Computes hash of domain concatenated with "HYPERLANE_REVOCATION"
+pub fn revocation_domain_hash(address: H256, domain: impl Into<u32>) -> H256 {
+    H256::from_slice(
+        Keccak256::new()
+            .chain(domain.into().to_be_bytes())
+            .chain(address)
+            .chain("HYPERLANE_REVOCATION")
+            .finalize()
+            .as_slice(),
+    )
+}
+
 /// Pretty print an address based on the domain it is for.
 pub fn fmt_address_for_domain(domain: u32, addr: H256) -> String {
     KnownHyperlaneDomain::try_from(domain)
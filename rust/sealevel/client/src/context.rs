@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use solana_client::{
     rpc_client::RpcClient,
     rpc_config::{RpcSendTransactionConfig, RpcTransactionConfig},
@@ -13,7 +14,7 @@ use solana_sdk::{
     transaction::Transaction,
 };
 use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding};
-use std::{cell::RefCell, io::Read};
+use std::{cell::RefCell, fs::File, io::Read, path::PathBuf, rc::Rc};
 
 pub(crate) struct PayerKeypair {
     pub keypair: Keypair,
@@ -27,6 +28,29 @@ pub(crate) struct Context {
     pub commitment: CommitmentConfig,
     pub initial_instructions: RefCell<Vec<InstructionWithDescription>>,
     pub require_tx_approval: bool,
+    /// If the payer can't sign, transactions are collected into this bundle
+    /// rather than printed one at a time for manual submission.
+    transaction_bundle: Option<Rc<TransactionBundle>>,
+}
+
+/// A JSON file of unsigned transactions to be imported into a multisig.
+pub(crate) struct TransactionBundle {
+    path: PathBuf,
+    /// The fee payer of every transaction in the bundle.
+    payer: Pubkey,
+    transactions: RefCell<Vec<UnsignedTransaction>>,
+}
+
+/// An unsigned transaction intended to be submitted via a multisig.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnsignedTransaction {
+    /// The RPC URL of the chain the transaction is intended for.
+    rpc_url: String,
+    /// Descriptions of each instruction in the transaction.
+    instructions: Vec<String>,
+    /// The bincode-serialized transaction, base58 encoded.
+    transaction_base58: String,
 }
 
 pub(crate) struct InstructionWithDescription {
@@ -60,6 +84,7 @@ impl Context {
         commitment: CommitmentConfig,
         initial_instructions: RefCell<Vec<InstructionWithDescription>>,
         require_tx_approval: bool,
+        transaction_bundle: Option<(PathBuf, Pubkey)>,
     ) -> Self {
         Self {
             client,
@@ -68,9 +93,20 @@ impl Context {
             commitment,
             initial_instructions,
             require_tx_approval,
+            transaction_bundle: transaction_bundle.map(|(path, payer)| {
+                Rc::new(TransactionBundle {
+                    path,
+                    payer,
+                    transactions: RefCell::new(vec![]),
+                })
+            }),
         }
     }
 
+    pub(crate) fn transaction_bundle(&self) -> Option<Rc<TransactionBundle>> {
+        self.transaction_bundle.clone()
+    }
+
     pub(crate) fn new_txn(&self) -> TxnBuilder {
         TxnBuilder {
             ctx: self,
@@ -154,11 +190,43 @@ impl<'ctx, 'rpc> TxnBuilder<'ctx, 'rpc> {
             );
         }
 
-        let message = Message::new(&self.instructions(), None);
-        let txn = Transaction::new_unsigned(message);
         println!(
             "\t==== Transaction in base58: ====\n\t{}",
-            bs58::encode(bincode::serialize(&txn).unwrap()).into_string()
+            self.unsigned_transaction_base58()
+        );
+    }
+
+    fn unsigned_transaction_base58(&self) -> String {
+        let payer = self
+            .ctx
+            .transaction_bundle
+            .as_ref()
+            .map(|bundle| &bundle.payer);
+        let message = Message::new(&self.instructions(), payer);
+        let txn = Transaction::new_unsigned(message);
+        bs58::encode(bincode::serialize(&txn).unwrap()).into_string()
+    }
+
+    fn add_to_bundle(&self, bundle: &TransactionBundle) {
+        let client = self.client.unwrap_or(&self.ctx.client);
+        let mut transactions = bundle.transactions.borrow_mut();
+        transactions.push(UnsignedTransaction {
+            rpc_url: client.url(),
+            instructions: self
+                .instructions_with_descriptions
+                .iter()
+                .map(|i| {
+                    i.description
+                        .clone()
+                        .unwrap_or_else(|| "No description provided".to_owned())
+                })
+                .collect(),
+            transaction_base58: self.unsigned_transaction_base58(),
+        });
+        println!(
+            "Added transaction {} to bundle {}",
+            transactions.len() - 1,
+            bundle.path.display()
         );
     }
 
@@ -181,7 +249,11 @@ impl<'ctx, 'rpc> TxnBuilder<'ctx, 'rpc> {
 
             self.pretty_print_transaction();
 
-            wait_for_user_confirmation();
+            if let Some(bundle) = &self.ctx.transaction_bundle {
+                self.add_to_bundle(bundle);
+            } else {
+                wait_for_user_confirmation();
+            }
 
             return None;
         }
@@ -235,6 +307,33 @@ impl<'ctx, 'rpc> TxnBuilder<'ctx, 'rpc> {
     }
 }
 
+impl TransactionBundle {
+    /// Writes the bundled transactions to the bundle file. This is done once
+    /// all transactions are known, so a command that fails part way doesn't
+    /// leave an incomplete bundle behind.
+    pub(crate) fn write(&self) -> std::io::Result<()> {
+        let transactions = self.transactions.borrow();
+        File::create(&self.path)
+            .and_then(|file| serde_json::to_writer_pretty(file, &*transactions).map_err(Into::into))
+            .map_err(|err| {
+                std::io::Error::new(
+                    err.kind(),
+                    format!(
+                        "Failed to write transaction bundle {}: {}",
+                        self.path.display(),
+                        err
+                    ),
+                )
+            })?;
+        println!(
+            "Wrote {} transactions to bundle {}",
+            transactions.len(),
+            self.path.display()
+        );
+        Ok(())
+    }
+}
+
 // Poor man's strategy for waiting for user confirmation
 fn wait_for_user_confirmation() {
     println!("Continue? [y/n] then press Enter");
@@ -253,3 +352,86 @@ fn wait_for_user_confirmation() {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use solana_sdk::instruction::AccountMeta;
+
+    use super::*;
+
+    #[test]
+    fn test_transaction_bundle_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("transaction-bundle-{}.json", Pubkey::new_unique()));
+        let payer = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let ctx = Context::new(
+            RpcClient::new("http://localhost:8899".to_owned()),
+            owner,
+            None,
+            CommitmentConfig::confirmed(),
+            RefCell::new(vec![]),
+            false,
+            Some((path.clone(), payer)),
+        );
+
+        let program_id = Pubkey::new_unique();
+        let writable = Pubkey::new_unique();
+        let readonly = Pubkey::new_unique();
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &[1, 2, 3],
+            vec![
+                AccountMeta::new_readonly(owner, true),
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(readonly, false),
+            ],
+        );
+        assert!(ctx
+            .new_txn()
+            .add_with_description(instruction.clone(), "Test instruction")
+            .send_with_payer()
+            .is_none());
+        ctx.transaction_bundle().unwrap().write().unwrap();
+
+        let file = File::open(&path).unwrap();
+        let transactions: Vec<UnsignedTransaction> = serde_json::from_reader(file).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].rpc_url, "http://localhost:8899");
+        assert_eq!(transactions[0].instructions, vec!["Test instruction"]);
+
+        let txn: Transaction = bincode::deserialize(
+            &bs58::decode(&transactions[0].transaction_base58)
+                .into_vec()
+                .unwrap(),
+        )
+        .unwrap();
+        let message = &txn.message;
+        // The payer is the fee payer, and the owner is the only other signer.
+        assert_eq!(message.account_keys[0], payer);
+        assert_eq!(message.header.num_required_signatures, 2);
+        assert!(message.is_signer(0) && message.is_writable(0));
+
+        assert_eq!(message.instructions.len(), 1);
+        let compiled = &message.instructions[0];
+        assert_eq!(
+            message.account_keys[compiled.program_id_index as usize],
+            program_id
+        );
+        assert_eq!(compiled.data, instruction.data);
+        let metas = compiled
+            .accounts
+            .iter()
+            .map(|index| {
+                let index = *index as usize;
+                AccountMeta {
+                    pubkey: message.account_keys[index],
+                    is_signer: message.is_signer(index),
+                    is_writable: message.is_writable(index),
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(metas, instruction.accounts);
+    }
+}
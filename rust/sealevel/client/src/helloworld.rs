@@ -12,6 +12,7 @@ use hyperlane_sealevel_hello_world::{
 };
 use hyperlane_sealevel_igp::accounts::InterchainGasPaymasterType;
 use serde::{Deserialize, Serialize};
+use solana_client::client_error::ClientError;
 use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
 
 use crate::{
@@ -53,14 +54,18 @@ impl HelloWorldDeployer {
         Self {}
     }
 
-    fn get_storage(&self, client: &RpcClient, program_id: &Pubkey) -> HelloWorldStorage {
+    fn get_storage(
+        &self,
+        client: &RpcClient,
+        program_id: &Pubkey,
+    ) -> Result<HelloWorldStorage, ClientError> {
         let (program_storage_account, _program_storage_bump) =
             Pubkey::find_program_address(program_storage_pda_seeds!(), program_id);
 
-        let account = client.get_account(&program_storage_account).unwrap();
-        *HelloWorldStorageAccount::fetch(&mut &account.data[..])
+        let account = client.get_account(&program_storage_account)?;
+        Ok(*HelloWorldStorageAccount::fetch(&mut &account.data[..])
             .unwrap()
-            .into_inner()
+            .into_inner())
     }
 }
 
@@ -78,10 +83,14 @@ impl RouterDeployer<HelloWorldConfig> for HelloWorldDeployer {
         enroll_remote_routers_instruction(program_id, payer, router_configs).unwrap()
     }
 
-    fn get_routers(&self, client: &RpcClient, program_id: &Pubkey) -> HashMap<u32, H256> {
-        let storage = self.get_storage(client, program_id);
+    fn get_routers(
+        &self,
+        client: &RpcClient,
+        program_id: &Pubkey,
+    ) -> Result<HashMap<u32, H256>, ClientError> {
+        let storage = self.get_storage(client, program_id)?;
 
-        storage.routers
+        Ok(storage.routers)
     }
 
     fn init_program_idempotent(
@@ -142,10 +151,14 @@ impl RouterConfigGetter for HelloWorldConfig {
 
 impl Ownable for HelloWorldDeployer {
     /// Gets the owner configured on-chain.
-    fn get_owner(&self, client: &RpcClient, program_id: &Pubkey) -> Option<Pubkey> {
-        let storage = self.get_storage(client, program_id);
+    fn get_owner(
+        &self,
+        client: &RpcClient,
+        program_id: &Pubkey,
+    ) -> Result<Option<Pubkey>, ClientError> {
+        let storage = self.get_storage(client, program_id)?;
 
-        storage.owner
+        Ok(storage.owner)
     }
 
     /// Gets an instruction to set the owner.
@@ -164,10 +177,10 @@ impl ConnectionClient for HelloWorldDeployer {
         &self,
         client: &RpcClient,
         program_id: &Pubkey,
-    ) -> Option<Pubkey> {
-        let storage = self.get_storage(client, program_id);
+    ) -> Result<Option<Pubkey>, ClientError> {
+        let storage = self.get_storage(client, program_id)?;
 
-        storage.ism
+        Ok(storage.ism)
     }
 
     fn set_interchain_security_module_instruction(
@@ -176,7 +189,7 @@ impl ConnectionClient for HelloWorldDeployer {
         program_id: &Pubkey,
         ism: Option<Pubkey>,
     ) -> Instruction {
-        let storage = self.get_storage(client, program_id);
+        let storage = self.get_storage(client, program_id).unwrap();
 
         set_interchain_security_module_instruction(*program_id, storage.owner.unwrap(), ism)
             .unwrap()
@@ -186,10 +199,10 @@ impl ConnectionClient for HelloWorldDeployer {
         &self,
         client: &RpcClient,
         program_id: &Pubkey,
-    ) -> Option<(Pubkey, InterchainGasPaymasterType)> {
-        let storage = self.get_storage(client, program_id);
+    ) -> Result<Option<(Pubkey, InterchainGasPaymasterType)>, ClientError> {
+        let storage = self.get_storage(client, program_id)?;
 
-        storage.igp
+        Ok(storage.igp)
     }
 
     fn set_interchain_gas_paymaster_instruction(
@@ -24,8 +24,9 @@ use hyperlane_core::H256;
 
 use hyperlane_sealevel_igp::{
     accounts::{
-        GasOracle, GasPaymentAccount, IgpAccount, InterchainGasPaymasterType, OverheadIgpAccount,
-        ProgramDataAccount as IgpProgramDataAccount, RemoteGasData,
+        GasOracle, GasPaymentAccount, GasPriceAccount, IgpAccount, InterchainGasPaymasterType,
+        OverheadIgpAccount, PriceAccountGasOracle, ProgramDataAccount as IgpProgramDataAccount,
+        RemoteGasData,
     },
     igp_program_data_pda_seeds,
     instruction::{GasOracleConfig, GasOverheadConfig},
@@ -143,6 +144,28 @@ pub(crate) fn process_igp_cmd(mut ctx: Context, cmd: IgpCmd) {
 
             write_json(&artifacts_path, artifacts);
         }
+        IgpSubCmd::InitGasPriceAccount(init) => {
+            let (ixn, price_account) =
+                hyperlane_sealevel_igp::instruction::init_gas_price_account_instruction(
+                    init.program_id,
+                    ctx.payer_pubkey,
+                    get_context_salt(init.context.as_ref()),
+                    init.updater,
+                )
+                .unwrap();
+
+            ctx.new_txn()
+                .add_with_description(
+                    ixn,
+                    format!(
+                        "Initializing price account {} with updater {}",
+                        price_account, init.updater
+                    ),
+                )
+                .send_with_payer();
+
+            println!("Price account: {}", price_account);
+        }
         IgpSubCmd::Query(query) => {
             let (program_data_account_pda, _program_data_account_bump) =
                 Pubkey::find_program_address(igp_program_data_pda_seeds!(), &query.program_id);
@@ -194,12 +217,28 @@ pub(crate) fn process_igp_cmd(mut ctx: Context, cmd: IgpCmd) {
                 hyperlane_sealevel_igp::overhead_igp_pda_seeds!(salt),
                 &payment_details.program_id,
             );
+
+            // Price account gas oracles require the price account to be passed in.
+            let igp = ctx
+                .client
+                .get_account_with_commitment(&igp_account, ctx.commitment)
+                .unwrap()
+                .value
+                .expect("IGP account not found. Make sure you are connected to the right RPC.");
+            let igp = IgpAccount::fetch(&mut &igp.data[..]).unwrap().into_inner();
+            let price_account = igp
+                .gas_oracles
+                .get(&payment_details.destination_domain)
+                .and_then(|gas_oracle| gas_oracle.price_account())
+                .copied();
+
             let (ixn, gas_payment_data_account) =
                 hyperlane_sealevel_igp::instruction::pay_for_gas_instruction(
                     payment_details.program_id,
                     ctx.payer_pubkey,
                     igp_account,
                     Some(overhead_igp_account),
+                    price_account,
                     unique_gas_payment_keypair.pubkey(),
                     H256::from_str(&payment_details.message_id).unwrap(),
                     payment_details.destination_domain,
@@ -281,14 +320,23 @@ pub(crate) fn process_igp_cmd(mut ctx: Context, cmd: IgpCmd) {
             );
             match args.cmd {
                 GetSetCmd::Set(set_args) => {
-                    let remote_gas_data = RemoteGasData {
-                        token_exchange_rate: set_args.token_exchange_rate,
-                        gas_price: set_args.gas_price,
-                        token_decimals: set_args.token_decimals,
+                    let gas_oracle = if let Some(price_account) = set_args.price_account {
+                        GasOracle::PriceAccount(PriceAccountGasOracle {
+                            price_account,
+                            price_account_owner: set_args.price_account_owner.unwrap(),
+                            max_staleness_slots: set_args.max_staleness_slots.unwrap(),
+                            token_decimals: set_args.token_decimals,
+                        })
+                    } else {
+                        GasOracle::RemoteGasData(RemoteGasData {
+                            token_exchange_rate: set_args.token_exchange_rate.unwrap(),
+                            gas_price: set_args.gas_price.unwrap(),
+                            token_decimals: set_args.token_decimals,
+                        })
                     };
                     let gas_oracle_config = GasOracleConfig {
                         domain: args.remote_domain,
-                        gas_oracle: Some(GasOracle::RemoteGasData(remote_gas_data)),
+                        gas_oracle: Some(gas_oracle),
                     };
                     let instruction =
                         hyperlane_sealevel_igp::instruction::set_gas_oracle_configs_instruction(
@@ -315,10 +363,20 @@ pub(crate) fn process_igp_cmd(mut ctx: Context, cmd: IgpCmd) {
                         .unwrap()
                         .into_inner();
 
-                    println!(
-                        "IGP account gas oracle: {:#?}",
-                        igp_account.gas_oracles.get(&args.remote_domain)
-                    );
+                    let gas_oracle = igp_account.gas_oracles.get(&args.remote_domain);
+                    println!("IGP account gas oracle: {:#?}", gas_oracle);
+
+                    if let Some(price_account) = gas_oracle.and_then(|o| o.price_account()) {
+                        let price_account = ctx
+                            .client
+                            .get_account_with_commitment(price_account, ctx.commitment)
+                            .unwrap()
+                            .value
+                            .expect("Price account not found");
+                        let gas_price =
+                            GasPriceAccount::fetch_data(&mut &price_account.data[..]).unwrap();
+                        println!("Price account gas price: {:#?}", gas_price);
+                    }
                 }
             }
         }
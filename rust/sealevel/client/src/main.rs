@@ -6,9 +6,14 @@
 use std::{path::PathBuf, str::FromStr};
 
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_clap_utils::input_validators::{is_keypair, is_url, normalize_to_url_if_moniker};
 use solana_cli_config::{Config, CONFIG_FILE};
-use solana_client::rpc_client::RpcClient;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
 use solana_program::pubkey;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
@@ -23,11 +28,14 @@ use account_utils::DiscriminatorEncode;
 use hyperlane_core::{H160, H256};
 use hyperlane_sealevel_connection_client::router::RemoteRouterConfig;
 use hyperlane_sealevel_igp::{
-    accounts::{InterchainGasPaymasterType, OverheadIgpAccount},
+    accounts::{IgpAccount, InterchainGasPaymasterType, OverheadIgpAccount},
     igp_gas_payment_pda_seeds, igp_program_data_pda_seeds,
 };
 use hyperlane_sealevel_mailbox::{
-    accounts::{InboxAccount, OutboxAccount},
+    accounts::{
+        InboxAccount, MessagePdaCloseConfig, OutboxAccount, DISPATCHED_MESSAGE_DISCRIMINATOR,
+        PROCESSED_MESSAGE_DISCRIMINATOR,
+    },
     instruction::{Instruction as MailboxInstruction, OutboxDispatch},
     mailbox_dispatched_message_pda_seeds, mailbox_inbox_pda_seeds,
     mailbox_message_dispatch_authority_pda_seeds, mailbox_outbox_pda_seeds,
@@ -36,7 +44,10 @@ use hyperlane_sealevel_mailbox::{
 
 use hyperlane_sealevel_token::{
     hyperlane_token_ata_payer_pda_seeds, hyperlane_token_mint_pda_seeds,
-    spl_associated_token_account::get_associated_token_address_with_program_id, spl_token_2022,
+    metaplex::{Metadata, MPL_TOKEN_METADATA_PROGRAM_ID},
+    mpl_token_metadata_pda_seeds,
+    spl_associated_token_account::get_associated_token_address_with_program_id,
+    spl_token_2022,
 };
 use hyperlane_sealevel_token_collateral::{
     hyperlane_token_escrow_pda_seeds, plugin::CollateralPlugin,
@@ -99,6 +110,14 @@ struct Cli {
     config: Option<String>,
     #[arg(long, default_value_t = false)]
     require_tx_approval: bool,
+    /// When the keypair is a pubkey, e.g. a Squads multisig that owns the programs,
+    /// write the unsigned transactions to this JSON file instead of prompting for each one.
+    /// Nothing is sent.
+    #[arg(long, requires = "transaction_bundle_payer")]
+    transaction_bundle: Option<PathBuf>,
+    /// The fee payer of the transactions in the bundle, e.g. the multisig's vault.
+    #[arg(long, requires = "transaction_bundle")]
+    transaction_bundle_payer: Option<Pubkey>,
 }
 
 #[derive(Subcommand)]
@@ -131,6 +150,7 @@ pub(crate) struct WarpRouteCmd {
 pub(crate) enum WarpRouteSubCmd {
     Deploy(WarpRouteDeploy),
     DestinationGas(DestinationGasArgs),
+    Check(WarpRouteCheck),
 }
 
 #[derive(Args)]
@@ -149,6 +169,20 @@ pub(crate) struct WarpRouteDeploy {
     ata_payer_funding_amount: Option<u64>,
 }
 
+/// Compares deployed warp route programs with the token config without sending any
+/// transactions, exiting with a non-zero code if any drift is found.
+#[derive(Args)]
+pub(crate) struct WarpRouteCheck {
+    #[command(flatten)]
+    env_args: EnvironmentArgs,
+    #[arg(long)]
+    warp_route_name: String,
+    #[arg(long)]
+    token_config_file: PathBuf,
+    #[arg(long)]
+    chain_config_file: PathBuf,
+}
+
 #[derive(Args)]
 struct DestinationGasArgs {
     #[arg(long)]
@@ -202,6 +236,10 @@ enum MailboxSubCmd {
     Delivered(Delivered),
     TransferOwnership(TransferOwnership),
     SetDefaultIsm(SetDefaultIsm),
+    SetDefaultHook(SetHook),
+    SetRequiredHook(SetHook),
+    SetMessagePdaCloseConfig(SetMessagePdaCloseConfig),
+    CloseMessagePdas(CloseMessagePdas),
 }
 
 const MAILBOX_PROG_ID: Pubkey = pubkey!("692KZJaoe2KRcD6uhCQDLLXnLNA5ZLnfvdqjE4aX9iu1");
@@ -234,6 +272,48 @@ struct SetDefaultIsm {
     default_ism: Pubkey,
 }
 
+#[derive(Args)]
+struct SetHook {
+    #[arg(long, short, default_value_t = MAILBOX_PROG_ID)]
+    program_id: Pubkey,
+    /// The hook program. If not specified, the hook is unset.
+    #[arg(long)]
+    hook: Option<Pubkey>,
+}
+
+#[derive(Args)]
+struct SetMessagePdaCloseConfig {
+    #[arg(long, short, default_value_t = MAILBOX_PROG_ID)]
+    program_id: Pubkey,
+    /// The account that receives reclaimed rent. If not specified, message PDAs can't be closed.
+    #[arg(long)]
+    beneficiary: Option<Pubkey>,
+    /// The number of slots after which anyone may close a message PDA.
+    /// If not specified, only the owner can close message PDAs.
+    #[arg(long)]
+    permissionless_close_delay_slots: Option<u64>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum MessagePdaType {
+    Dispatched,
+    Processed,
+}
+
+#[derive(Args)]
+struct CloseMessagePdas {
+    #[arg(long, short, default_value_t = MAILBOX_PROG_ID)]
+    program_id: Pubkey,
+    #[arg(long, value_enum)]
+    pda_type: MessagePdaType,
+    /// Only close message PDAs that are at least this many slots old.
+    /// Non-owners are additionally subject to the permissionless close delay.
+    #[arg(long, default_value_t = 0)]
+    min_age_slots: u64,
+    #[arg(long, default_value_t = 10)]
+    batch_size: usize,
+}
+
 #[derive(Args)]
 struct Outbox {
     #[arg(long, short, default_value_t = ECLIPSE_DOMAIN)]
@@ -386,6 +466,7 @@ enum IgpSubCmd {
     DeployProgram(IgpDeployProgramArgs),
     InitIgpAccount(InitIgpAccountArgs),
     InitOverheadIgpAccount(InitOverheadIgpAccountArgs),
+    InitGasPriceAccount(InitGasPriceAccountArgs),
     Query(IgpQueryArgs),
     PayForGas(PayForGasArgs),
     Claim(ClaimArgs),
@@ -440,6 +521,17 @@ struct InitOverheadIgpAccountArgs {
     overhead_config_file: Option<PathBuf>,
 }
 
+#[derive(Args)]
+struct InitGasPriceAccountArgs {
+    #[arg(long)]
+    program_id: Pubkey,
+    /// The account allowed to set the gas price, e.g. the gas oracle updater's signer.
+    #[arg(long)]
+    updater: Pubkey,
+    #[arg(long)]
+    context: Option<String>,
+}
+
 #[derive(Args)]
 struct IgpQueryArgs {
     #[arg(long)]
@@ -504,12 +596,24 @@ struct GasOracleConfigArgs {
 
 #[derive(Args)]
 struct SetGasOracleArgs {
+    #[arg(long, required_unless_present = "price_account")]
+    token_exchange_rate: Option<u128>,
+    #[arg(long, required_unless_present = "price_account")]
+    gas_price: Option<u128>,
     #[arg(long)]
-    token_exchange_rate: u128,
+    token_decimals: u8,
+    /// Read the token exchange rate and gas price from this price account
+    /// instead of setting them directly.
+    #[arg(
+        long,
+        conflicts_with_all = ["token_exchange_rate", "gas_price"],
+        requires_all = ["price_account_owner", "max_staleness_slots"]
+    )]
+    price_account: Option<Pubkey>,
     #[arg(long)]
-    gas_price: u128,
+    price_account_owner: Option<Pubkey>,
     #[arg(long)]
-    token_decimals: u8,
+    max_staleness_slots: Option<u64>,
 }
 
 #[derive(Args)]
@@ -677,7 +781,7 @@ pub(crate) struct HelloWorldQuery {
     program_id: Pubkey,
 }
 
-fn main() {
+fn main() -> std::io::Result<()> {
     pretty_env_logger::init();
 
     let cli = Cli::parse();
@@ -709,6 +813,10 @@ fn main() {
         (Pubkey::from_str(&keypair_path).unwrap(), None)
     };
 
+    if cli.transaction_bundle.is_some() && payer_keypair.is_some() {
+        panic!("A transaction bundle can only be written when the keypair is a pubkey");
+    }
+
     let commitment = CommitmentConfig::confirmed();
 
     let mut instructions = vec![];
@@ -740,7 +848,9 @@ fn main() {
         commitment,
         instructions.into(),
         cli.require_tx_approval,
+        cli.transaction_bundle.zip(cli.transaction_bundle_payer),
     );
+    let transaction_bundle = ctx.transaction_bundle();
     match cli.cmd {
         HyperlaneSealevelCmd::Mailbox(cmd) => process_mailbox_cmd(ctx, cmd),
         HyperlaneSealevelCmd::Token(cmd) => process_token_cmd(ctx, cmd),
@@ -753,6 +863,141 @@ fn main() {
         HyperlaneSealevelCmd::HelloWorld(cmd) => process_helloworld_cmd(ctx, cmd),
         HyperlaneSealevelCmd::Igp(cmd) => process_igp_cmd(ctx, cmd),
     }
+
+    if let Some(transaction_bundle) = transaction_bundle {
+        transaction_bundle.write()?;
+    }
+    Ok(())
+}
+
+/// Closes all message PDAs of the given type that are old enough to be closed by the payer.
+fn close_message_pdas(ctx: &Context, close: CloseMessagePdas) {
+    let (outbox_account, _outbox_bump) =
+        Pubkey::find_program_address(mailbox_outbox_pda_seeds!(), &close.program_id);
+    let outbox_data = ctx
+        .client
+        .get_account_with_commitment(&outbox_account, ctx.commitment)
+        .unwrap()
+        .value
+        .expect("Outbox account not found")
+        .data;
+    let outbox = OutboxAccount::fetch(&mut &outbox_data[..])
+        .unwrap()
+        .into_inner();
+    let config = outbox
+        .message_pda_close_config
+        .expect("No message PDA close config set on the Outbox");
+
+    let min_age_slots = if outbox.owner == Some(ctx.payer_pubkey) {
+        close.min_age_slots
+    } else {
+        let delay = config
+            .permissionless_close_delay_slots
+            .expect("Only the owner can close message PDAs");
+        close.min_age_slots.max(delay)
+    };
+    let current_slot = ctx.client.get_slot_with_commitment(ctx.commitment).unwrap();
+
+    // Only request the fields needed to build the close instructions.
+    // Offsets skip the `initialized` bool flag and the discriminator.
+    let (discriminator, data_slice) = match close.pda_type {
+        // `slot` followed by `unique_message_pubkey` and the first byte of the encoded
+        // message, which closed PDAs no longer have.
+        MessagePdaType::Dispatched => (
+            DISPATCHED_MESSAGE_DISCRIMINATOR,
+            UiDataSliceConfig {
+                offset: 1 + 8 + 4,
+                length: 8 + 32 + 1,
+            },
+        ),
+        // `message_id` followed by `slot`.
+        MessagePdaType::Processed => (
+            PROCESSED_MESSAGE_DISCRIMINATOR,
+            UiDataSliceConfig {
+                offset: 1 + 8 + 8,
+                length: 32 + 8,
+            },
+        ),
+    };
+    let accounts = ctx
+        .client
+        .get_program_accounts_with_config(
+            &close.program_id,
+            RpcProgramAccountsConfig {
+                filters: Some(vec![RpcFilterType::Memcmp(Memcmp {
+                    offset: 1,
+                    bytes: MemcmpEncodedBytes::Base58(bs58::encode(discriminator).into_string()),
+                    encoding: None,
+                })]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    data_slice: Some(data_slice),
+                    commitment: Some(ctx.commitment),
+                    min_context_slot: None,
+                },
+                with_context: Some(false),
+            },
+        )
+        .unwrap();
+
+    let instructions = accounts
+        .into_iter()
+        .filter_map(|(pubkey, account)| {
+            let data = &account.data;
+            let instruction = match close.pda_type {
+                MessagePdaType::Dispatched => {
+                    if data.len() < 8 + 32 + 1 {
+                        return None;
+                    }
+                    let slot = u64::from_le_bytes(data[0..8].try_into().unwrap());
+                    if slot.saturating_add(min_age_slots) > current_slot {
+                        return None;
+                    }
+                    let unique_message_pubkey = Pubkey::new(&data[8..40]);
+                    hyperlane_sealevel_mailbox::instruction::close_dispatched_message_instruction(
+                        close.program_id,
+                        unique_message_pubkey,
+                        config.beneficiary,
+                        ctx.payer_pubkey,
+                    )
+                }
+                MessagePdaType::Processed => {
+                    let slot = u64::from_le_bytes(data[32..40].try_into().unwrap());
+                    if slot.saturating_add(min_age_slots) > current_slot {
+                        return None;
+                    }
+                    let message_id = H256::from_slice(&data[0..32]);
+                    hyperlane_sealevel_mailbox::instruction::close_processed_message_instruction(
+                        close.program_id,
+                        message_id,
+                        config.beneficiary,
+                        ctx.payer_pubkey,
+                    )
+                }
+            }
+            .unwrap();
+            // Skip any accounts that aren't the expected PDAs.
+            (instruction.accounts[1].pubkey == pubkey).then_some(instruction)
+        })
+        .collect::<Vec<_>>();
+
+    println!(
+        "Closing {} {:?} message PDAs older than {} slots",
+        instructions.len(),
+        close.pda_type,
+        min_age_slots
+    );
+    for batch in instructions.chunks(close.batch_size) {
+        batch
+            .iter()
+            .fold(ctx.new_txn(), |txn, instruction| {
+                txn.add_with_description(
+                    instruction.clone(),
+                    format!("Closing message PDA {}", instruction.accounts[1].pubkey),
+                )
+            })
+            .send_with_payer();
+    }
 }
 
 fn process_mailbox_cmd(ctx: Context, cmd: MailboxCmd) {
@@ -872,9 +1117,162 @@ fn process_mailbox_cmd(ctx: Context, cmd: MailboxCmd) {
                 )
                 .send_with_payer();
         }
+        MailboxSubCmd::SetDefaultHook(set_hook) => {
+            let instruction =
+                hyperlane_sealevel_mailbox::instruction::set_default_hook_instruction(
+                    set_hook.program_id,
+                    ctx.payer_pubkey,
+                    set_hook.hook,
+                )
+                .unwrap();
+            ctx.new_txn()
+                .add_with_description(
+                    instruction,
+                    format!("Setting default hook to {:?}", set_hook.hook),
+                )
+                .send_with_payer();
+        }
+        MailboxSubCmd::SetRequiredHook(set_hook) => {
+            let instruction =
+                hyperlane_sealevel_mailbox::instruction::set_required_hook_instruction(
+                    set_hook.program_id,
+                    ctx.payer_pubkey,
+                    set_hook.hook,
+                )
+                .unwrap();
+            ctx.new_txn()
+                .add_with_description(
+                    instruction,
+                    format!("Setting required hook to {:?}", set_hook.hook),
+                )
+                .send_with_payer();
+        }
+        MailboxSubCmd::SetMessagePdaCloseConfig(set_config) => {
+            let config = set_config
+                .beneficiary
+                .map(|beneficiary| MessagePdaCloseConfig {
+                    beneficiary,
+                    permissionless_close_delay_slots: set_config.permissionless_close_delay_slots,
+                });
+            let instruction =
+                hyperlane_sealevel_mailbox::instruction::set_message_pda_close_config_instruction(
+                    set_config.program_id,
+                    ctx.payer_pubkey,
+                    config.clone(),
+                )
+                .unwrap();
+            ctx.new_txn()
+                .add_with_description(
+                    instruction,
+                    format!("Setting message PDA close config to {:?}", config),
+                )
+                .send_with_payer();
+        }
+        MailboxSubCmd::CloseMessagePdas(close) => close_message_pdas(&ctx, close),
     };
 }
 
+/// Gets the account metas of the post-dispatch hooks called by the Mailbox when dispatching
+/// a message with the IGP (if any) as the custom hook, in the order expected by the Mailbox,
+/// along with the number of accounts of each hook.
+/// Hooks other than the IGP are expected to not require any accounts.
+fn post_dispatch_hook_account_metas(
+    ctx: &Context,
+    mailbox: Pubkey,
+    igp: Option<(Pubkey, InterchainGasPaymasterType)>,
+    payer: Pubkey,
+    unique_message_account: Pubkey,
+    destination_domain: u32,
+) -> (Vec<AccountMeta>, Vec<u8>) {
+    let (outbox_account, _outbox_bump) =
+        Pubkey::find_program_address(mailbox_outbox_pda_seeds!(), &mailbox);
+    let fetched_outbox_account = ctx
+        .client
+        .get_account_with_commitment(&outbox_account, ctx.commitment)
+        .unwrap()
+        .value
+        .unwrap();
+    let outbox = OutboxAccount::fetch(&mut &fetched_outbox_account.data[..])
+        .unwrap()
+        .into_inner();
+
+    let mut accounts = vec![];
+    let mut hook_account_counts = vec![];
+    for hook in outbox.post_dispatch_hooks(igp.as_ref().map(|(program_id, _)| *program_id)) {
+        accounts.push(AccountMeta::new_readonly(hook, false));
+
+        let Some((igp_program_id, igp_account_type)) = igp
+            .as_ref()
+            .filter(|(igp_program_id, _)| *igp_program_id == hook)
+        else {
+            hook_account_counts.push(0);
+            continue;
+        };
+        let hook_accounts_start = accounts.len();
+
+        // The accounts expected by the IGP's `PayForGas` instruction.
+        let (igp_program_data, _bump) =
+            Pubkey::find_program_address(igp_program_data_pda_seeds!(), igp_program_id);
+        let (gas_payment_pda, _bump) = Pubkey::find_program_address(
+            igp_gas_payment_pda_seeds!(&unique_message_account),
+            igp_program_id,
+        );
+        accounts.extend([
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new(igp_program_data, false),
+            AccountMeta::new_readonly(unique_message_account, true),
+            AccountMeta::new(gas_payment_pda, false),
+        ]);
+
+        let igp_account_id = match igp_account_type {
+            InterchainGasPaymasterType::OverheadIgp(overhead_igp_account_id) => {
+                let overhead_igp_account = ctx
+                    .client
+                    .get_account_with_commitment(overhead_igp_account_id, ctx.commitment)
+                    .unwrap()
+                    .value
+                    .unwrap();
+                let overhead_igp_account =
+                    OverheadIgpAccount::fetch(&mut &overhead_igp_account.data[..])
+                        .unwrap()
+                        .into_inner();
+                // The inner IGP is expected first, then the overhead IGP.
+                accounts.extend([
+                    AccountMeta::new(overhead_igp_account.inner, false),
+                    AccountMeta::new_readonly(*overhead_igp_account_id, false),
+                ]);
+                overhead_igp_account.inner
+            }
+            InterchainGasPaymasterType::Igp(igp_account_id) => {
+                accounts.push(AccountMeta::new(*igp_account_id, false));
+                *igp_account_id
+            }
+        };
+
+        // Price account gas oracles expect the price account last.
+        let igp_account = ctx
+            .client
+            .get_account_with_commitment(&igp_account_id, ctx.commitment)
+            .unwrap()
+            .value
+            .unwrap();
+        let igp_account = IgpAccount::fetch(&mut &igp_account.data[..])
+            .unwrap()
+            .into_inner();
+        if let Some(price_account) = igp_account
+            .gas_oracles
+            .get(&destination_domain)
+            .and_then(|gas_oracle| gas_oracle.price_account())
+        {
+            accounts.push(AccountMeta::new_readonly(*price_account, false));
+        }
+        hook_account_counts.push((accounts.len() - hook_accounts_start) as u8);
+    }
+
+    (accounts, hook_account_counts)
+}
+
 fn process_token_cmd(ctx: Context, cmd: TokenCmd) {
     match cmd.cmd {
         TokenSubCmd::Query(query) => {
@@ -901,8 +1299,13 @@ fn process_token_cmd(ctx: Context, cmd: TokenCmd) {
                         hyperlane_token_ata_payer_pda_seeds!(),
                         &query.program_id,
                     );
+                    let (metadata_account, _metadata_bump) = Pubkey::find_program_address(
+                        mpl_token_metadata_pda_seeds!(mint_account),
+                        &MPL_TOKEN_METADATA_PROGRAM_ID,
+                    );
                     accounts_to_query.push(mint_account);
                     accounts_to_query.push(ata_payer_account);
+                    accounts_to_query.push(metadata_account);
                 }
                 TokenType::Collateral => {
                     let (escrow_account, _escrow_bump) = Pubkey::find_program_address(
@@ -978,6 +1381,24 @@ fn process_token_cmd(ctx: Context, cmd: TokenCmd) {
                         "ATA payer account: {}, bump={}",
                         ata_payer_account, ata_payer_bump,
                     );
+                    println!("--------------------------------");
+
+                    let (metadata_account, metadata_bump) = Pubkey::find_program_address(
+                        mpl_token_metadata_pda_seeds!(mint_account),
+                        &MPL_TOKEN_METADATA_PROGRAM_ID,
+                    );
+                    println!(
+                        "Metaplex token metadata: {}, bump={}",
+                        metadata_account, metadata_bump
+                    );
+                    if let Some(info) = &accounts[3] {
+                        match Metadata::fetch(&mut info.data.as_ref()) {
+                            Ok(metadata) => println!("{:#?}", metadata.data()),
+                            Err(err) => println!("Failed to deserialize account data: {}", err),
+                        }
+                    } else {
+                        println!("Not yet created?");
+                    }
                 }
                 TokenType::Collateral => {
                     let (escrow_account, escrow_bump) = Pubkey::find_program_address(
@@ -1041,12 +1462,6 @@ fn process_token_cmd(ctx: Context, cmd: TokenCmd) {
             let (mailbox_outbox_account, _mailbox_outbox_bump) =
                 Pubkey::find_program_address(mailbox_outbox_pda_seeds!(), &token.mailbox);
 
-            let ixn = HtInstruction::TransferRemote(HtTransferRemote {
-                destination_domain: xfer.destination_domain,
-                recipient,
-                amount_or_id: xfer.amount.into(),
-            });
-
             // Transfers tokens to a remote.
             // Burns the tokens from the sender's associated token account and
             // then dispatches a message to the remote recipient.
@@ -1060,14 +1475,8 @@ fn process_token_cmd(ctx: Context, cmd: TokenCmd) {
             // 6.    [signer] The token sender and mailbox payer.
             // 7.    [signer] Unique message / gas payment account.
             // 8.    [writeable] Message storage PDA.
-            //       ---- If using an IGP ----
-            // 9.    [executable] The IGP program.
-            // 10.   [writeable] The IGP program data.
-            // 11.   [writeable] Gas payment PDA.
-            // 12.   [] OPTIONAL - The Overhead IGP program, if the configured IGP is an Overhead IGP.
-            // 13.   [writeable] The IGP account.
-            //       ---- End if ----
-            // 14..N [??..??] Plugin-specific accounts.
+            // 9..M  [??..??] Plugin-specific accounts.
+            // M..N  [??..??] The Mailbox's post-dispatch hook accounts.
             let mut accounts = vec![
                 AccountMeta::new_readonly(system_program::id(), false),
                 AccountMeta::new_readonly(spl_noop::id(), false),
@@ -1080,43 +1489,6 @@ fn process_token_cmd(ctx: Context, cmd: TokenCmd) {
                 AccountMeta::new(dispatched_message_account, false),
             ];
 
-            if let Some((igp_program_id, igp_account_type)) = token.interchain_gas_paymaster {
-                let (igp_program_data, _bump) =
-                    Pubkey::find_program_address(igp_program_data_pda_seeds!(), &igp_program_id);
-                let (gas_payment_pda, _bump) = Pubkey::find_program_address(
-                    igp_gas_payment_pda_seeds!(&unique_message_account_keypair.pubkey()),
-                    &igp_program_id,
-                );
-
-                accounts.extend([
-                    AccountMeta::new_readonly(igp_program_id, false),
-                    AccountMeta::new(igp_program_data, false),
-                    AccountMeta::new(gas_payment_pda, false),
-                ]);
-
-                match igp_account_type {
-                    InterchainGasPaymasterType::OverheadIgp(overhead_igp_account_id) => {
-                        let overhead_igp_account = ctx
-                            .client
-                            .get_account_with_commitment(&overhead_igp_account_id, ctx.commitment)
-                            .unwrap()
-                            .value
-                            .unwrap();
-                        let overhead_igp_account =
-                            OverheadIgpAccount::fetch(&mut &overhead_igp_account.data[..])
-                                .unwrap()
-                                .into_inner();
-                        accounts.extend([
-                            AccountMeta::new_readonly(overhead_igp_account_id, false),
-                            AccountMeta::new(overhead_igp_account.inner, false),
-                        ]);
-                    }
-                    InterchainGasPaymasterType::Igp(igp_account_id) => {
-                        accounts.push(AccountMeta::new(igp_account_id, false));
-                    }
-                }
-            }
-
             match xfer.token_type {
                 TokenType::Native => {
                     // 5. [executable] The system program.
@@ -1176,6 +1548,23 @@ fn process_token_cmd(ctx: Context, cmd: TokenCmd) {
                 }
             }
 
+            let (hook_account_metas, hook_account_counts) = post_dispatch_hook_account_metas(
+                &ctx,
+                token.mailbox,
+                token.interchain_gas_paymaster,
+                sender.pubkey(),
+                unique_message_account_keypair.pubkey(),
+                xfer.destination_domain,
+            );
+            accounts.extend(hook_account_metas);
+
+            let ixn = HtInstruction::TransferRemote(HtTransferRemote {
+                destination_domain: xfer.destination_domain,
+                recipient,
+                amount_or_id: xfer.amount.into(),
+                hook_account_counts,
+            });
+
             eprintln!("accounts={:#?}", accounts); // FIXME remove
             let xfer_instruction = Instruction {
                 program_id: xfer.program_id,
@@ -2,11 +2,12 @@ use hyperlane_core::{utils::hex_or_base58_to_h256, H256};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
+    fmt::{Debug, Display},
     fs::File,
     path::{Path, PathBuf},
 };
 
-use solana_client::rpc_client::RpcClient;
+use solana_client::{client_error::ClientError, rpc_client::RpcClient};
 use solana_program::instruction::Instruction;
 use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signer};
 
@@ -16,7 +17,9 @@ use hyperlane_sealevel_igp::accounts::{Igp, InterchainGasPaymasterType, Overhead
 
 use crate::{
     artifacts::{write_json, HexAndBase58ProgramIdArtifact},
-    cmd_utils::{create_and_write_keypair, create_new_directory, deploy_program_idempotent},
+    cmd_utils::{
+        account_exists, create_and_write_keypair, create_new_directory, deploy_program_idempotent,
+    },
     read_core_program_ids, Context, CoreProgramIds,
 };
 
@@ -49,26 +52,25 @@ impl OptionalConnectionClientConfig {
     pub fn interchain_gas_paymaster_config(
         &self,
         client: &RpcClient,
-    ) -> Option<(Pubkey, InterchainGasPaymasterType)> {
+    ) -> Result<Option<(Pubkey, InterchainGasPaymasterType)>, ClientError> {
         if let Some(igp_pubkey) = self.interchain_gas_paymaster {
-            let account = client
-                .get_account(&self.interchain_gas_paymaster.unwrap())
-                .unwrap();
+            let account = client.get_account(&igp_pubkey)?;
 
             match &account.data[1..9] {
-                Igp::DISCRIMINATOR_SLICE => {
-                    Some((account.owner, InterchainGasPaymasterType::Igp(igp_pubkey)))
-                }
-                OverheadIgp::DISCRIMINATOR_SLICE => Some((
+                Igp::DISCRIMINATOR_SLICE => Ok(Some((
+                    account.owner,
+                    InterchainGasPaymasterType::Igp(igp_pubkey),
+                ))),
+                OverheadIgp::DISCRIMINATOR_SLICE => Ok(Some((
                     account.owner,
                     InterchainGasPaymasterType::OverheadIgp(igp_pubkey),
-                )),
+                ))),
                 _ => {
                     panic!("Invalid IGP account configured {}", igp_pubkey);
                 }
             }
         } else {
-            None
+            Ok(None)
         }
     }
 }
@@ -128,6 +130,145 @@ impl ChainMetadata {
     }
 }
 
+/// A difference between a router's on-chain state and its expected configuration,
+/// or an error reading the on-chain state, in which case it's unknown whether
+/// the field has drifted.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RouterDrift {
+    chain: String,
+    program_id: Option<String>,
+    field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl RouterDrift {
+    pub(crate) fn new(
+        chain_name: &str,
+        program_id: Option<&Pubkey>,
+        field: &str,
+        expected: impl Debug,
+        actual: impl Debug,
+    ) -> Self {
+        Self {
+            chain: chain_name.to_owned(),
+            program_id: program_id.map(|program_id| program_id.to_string()),
+            field: field.to_owned(),
+            expected: Some(format!("{:?}", expected)),
+            actual: Some(format!("{:?}", actual)),
+            error: None,
+        }
+    }
+
+    /// The on-chain value of `field` couldn't be read.
+    pub(crate) fn read_error(
+        chain_name: &str,
+        program_id: Option<&Pubkey>,
+        field: &str,
+        error: impl Display,
+    ) -> Self {
+        Self {
+            chain: chain_name.to_owned(),
+            program_id: program_id.map(|program_id| program_id.to_string()),
+            field: field.to_owned(),
+            expected: None,
+            actual: None,
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+/// The state of a router that is compared with its expected configuration.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct RouterState {
+    owner: Option<Pubkey>,
+    interchain_security_module: Option<Pubkey>,
+    interchain_gas_paymaster: Option<(Pubkey, InterchainGasPaymasterType)>,
+    /// The remote routers by domain, excluding the router's own domain.
+    remote_routers: HashMap<u32, H256>,
+}
+
+impl RouterState {
+    /// Reads the on-chain state of the router at `program_id`.
+    fn fetch<Config: RouterConfigGetter + std::fmt::Debug>(
+        deployer: &impl RouterDeployer<Config>,
+        client: &RpcClient,
+        program_id: &Pubkey,
+    ) -> Result<Self, ClientError> {
+        Ok(Self {
+            owner: deployer.get_owner(client, program_id)?,
+            interchain_security_module: deployer
+                .get_interchain_security_module(client, program_id)?,
+            interchain_gas_paymaster: deployer.get_interchain_gas_paymaster(client, program_id)?,
+            remote_routers: deployer.get_routers(client, program_id)?,
+        })
+    }
+
+    /// The fields in which `actual` differs from `self`, the expected state.
+    fn drift(&self, actual: &Self, chain_name: &str, program_id: &Pubkey) -> Vec<RouterDrift> {
+        let mut drift = vec![];
+
+        if actual.owner != self.owner {
+            drift.push(RouterDrift::new(
+                chain_name,
+                Some(program_id),
+                "owner",
+                self.owner,
+                actual.owner,
+            ));
+        }
+
+        if actual.interchain_security_module != self.interchain_security_module {
+            drift.push(RouterDrift::new(
+                chain_name,
+                Some(program_id),
+                "interchainSecurityModule",
+                self.interchain_security_module,
+                actual.interchain_security_module,
+            ));
+        }
+
+        if actual.interchain_gas_paymaster != self.interchain_gas_paymaster {
+            drift.push(RouterDrift::new(
+                chain_name,
+                Some(program_id),
+                "interchainGasPaymaster",
+                &self.interchain_gas_paymaster,
+                &actual.interchain_gas_paymaster,
+            ));
+        }
+
+        let mut domains = self
+            .remote_routers
+            .keys()
+            .chain(actual.remote_routers.keys())
+            .copied()
+            .collect::<Vec<u32>>();
+        domains.sort_unstable();
+        domains.dedup();
+        for domain in domains {
+            let expected_router = self.remote_routers.get(&domain);
+            let actual_router = actual.remote_routers.get(&domain);
+            if actual_router != expected_router {
+                drift.push(RouterDrift::new(
+                    chain_name,
+                    Some(program_id),
+                    &format!("remoteRouters.{}", domain),
+                    expected_router,
+                    actual_router,
+                ));
+            }
+        }
+
+        drift
+    }
+}
+
 pub trait RouterConfigGetter {
     fn router_config(&self) -> &RouterConfig;
 }
@@ -226,6 +367,21 @@ pub(crate) trait RouterDeployer<Config: RouterConfigGetter + std::fmt::Debug>:
         // By default, do nothing.
     }
 
+    /// Compares app-specific on-chain state with the config, returning any drift
+    /// and any errors reading the on-chain state as `RouterDrift::read_error`s.
+    /// Must not send any transactions.
+    fn check(
+        &self,
+        _ctx: &Context,
+        _app_configs: &HashMap<String, Config>,
+        _chain_configs: &HashMap<String, ChainMetadata>,
+        _chain_name: &str,
+        _program_id: &Pubkey,
+    ) -> Vec<RouterDrift> {
+        // By default, there's nothing app-specific to check.
+        vec![]
+    }
+
     /// The program's name, i.e. the name of the program's .so file (without the .so suffix)
     /// and the name that will be used to create the keypair file
     fn program_name(&self, config: &Config) -> &str;
@@ -237,12 +393,20 @@ pub(crate) trait RouterDeployer<Config: RouterConfigGetter + std::fmt::Debug>:
         router_configs: Vec<RemoteRouterConfig>,
     ) -> Instruction;
 
-    fn get_routers(&self, rpc_client: &RpcClient, program_id: &Pubkey) -> HashMap<u32, H256>;
+    fn get_routers(
+        &self,
+        rpc_client: &RpcClient,
+        program_id: &Pubkey,
+    ) -> Result<HashMap<u32, H256>, ClientError>;
 }
 
 pub(crate) trait Ownable {
     /// Gets the owner configured on-chain.
-    fn get_owner(&self, client: &RpcClient, program_id: &Pubkey) -> Option<Pubkey>;
+    fn get_owner(
+        &self,
+        client: &RpcClient,
+        program_id: &Pubkey,
+    ) -> Result<Option<Pubkey>, ClientError>;
 
     /// Gets an instruction to set the owner.
     fn set_owner_instruction(
@@ -259,7 +423,7 @@ pub(crate) trait ConnectionClient: Ownable {
         &self,
         client: &RpcClient,
         program_id: &Pubkey,
-    ) -> Option<Pubkey>;
+    ) -> Result<Option<Pubkey>, ClientError>;
 
     /// Gets an instruction to set the interchain security module.
     fn set_interchain_security_module_instruction(
@@ -274,7 +438,7 @@ pub(crate) trait ConnectionClient: Ownable {
         &self,
         client: &RpcClient,
         program_id: &Pubkey,
-    ) -> Option<(Pubkey, InterchainGasPaymasterType)>;
+    ) -> Result<Option<(Pubkey, InterchainGasPaymasterType)>, ClientError>;
 
     /// Gets an instruction to set the IGP.
     fn set_interchain_gas_paymaster_instruction(
@@ -302,14 +466,8 @@ pub(crate) fn deploy_routers<
     environment: &str,
     built_so_dir_path: PathBuf,
 ) {
-    // Load the app configs from the app config file.
-    let app_config_file = File::open(app_config_file_path).unwrap();
-    let app_configs: HashMap<String, Config> = serde_json::from_reader(app_config_file).unwrap();
-
-    // Load the chain configs from the chain config file.
-    let chain_config_file = File::open(chain_config_file_path).unwrap();
-    let chain_configs: HashMap<String, ChainMetadata> =
-        serde_json::from_reader(chain_config_file).unwrap();
+    let (app_configs, chain_configs) =
+        read_app_and_chain_configs::<Config>(app_config_file_path, chain_config_file_path);
 
     let environments_dir = create_new_directory(&environments_dir_path, environment);
 
@@ -319,28 +477,10 @@ pub(crate) fn deploy_routers<
 
     let existing_program_ids = read_router_program_ids(&deploy_dir);
 
-    // Builds a HashMap of all the foreign deployments from the app config.
+    // A map of all the routers, including the foreign deployments.
     // These domains with foreign deployments will not have any txs / deployments
     // made directly to them, but the routers will be enrolled on the other chains.
-    let foreign_deployments = app_configs
-        .iter()
-        .filter_map(|(chain_name, app_config)| {
-            app_config
-                .router_config()
-                .foreign_deployment
-                .as_ref()
-                .map(|foreign_deployment| {
-                    let chain_config = chain_configs.get(chain_name).unwrap();
-                    (
-                        chain_config.domain_id(),
-                        hex_or_base58_to_h256(foreign_deployment).unwrap(),
-                    )
-                })
-        })
-        .collect::<HashMap<u32, H256>>();
-
-    // A map of all the routers, including the foreign deployments.
-    let mut routers: HashMap<u32, H256> = foreign_deployments;
+    let mut routers: HashMap<u32, H256> = foreign_deployments(&app_configs, &chain_configs);
 
     // Non-foreign app configs to deploy to.
     let app_configs_to_deploy = app_configs
@@ -431,6 +571,167 @@ pub(crate) fn deploy_routers<
     write_router_program_ids(&deploy_dir, &routers_by_name);
 }
 
+/// Compares the on-chain state of deployed routers with the app config and the
+/// program IDs in the deploy artifacts, returning any drift. Sends no transactions.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn check_routers<
+    Config: for<'a> Deserialize<'a> + RouterConfigGetter + std::fmt::Debug + Clone,
+    Deployer: RouterDeployer<Config>,
+>(
+    ctx: &Context,
+    deployer: Deployer,
+    app_name: &str,
+    deploy_name: &str,
+    app_config_file_path: PathBuf,
+    chain_config_file_path: PathBuf,
+    environments_dir_path: PathBuf,
+    environment: &str,
+) -> Vec<RouterDrift> {
+    let (app_configs, chain_configs) =
+        read_app_and_chain_configs::<Config>(app_config_file_path, chain_config_file_path);
+
+    let deploy_dir = environments_dir_path
+        .join(environment)
+        .join(app_name)
+        .join(deploy_name);
+    let program_ids = read_router_program_ids(&deploy_dir).unwrap_or_default();
+
+    let mut routers = foreign_deployments(&app_configs, &chain_configs);
+    for (chain_name, app_config) in app_configs.iter() {
+        if app_config.router_config().foreign_deployment.is_some() {
+            continue;
+        }
+        if let Some(program_id) = program_ids.get(chain_name) {
+            routers.insert(
+                chain_configs[chain_name].domain_id(),
+                H256::from_slice(&program_id.to_bytes()[..]),
+            );
+        }
+    }
+
+    let mut drift = vec![];
+
+    for (chain_name, app_config) in app_configs.iter() {
+        if app_config.router_config().foreign_deployment.is_some() {
+            continue;
+        }
+        let chain_config = chain_configs
+            .get(chain_name)
+            .unwrap_or_else(|| panic!("Chain config not found for chain: {}", chain_name));
+        let client = chain_config.client();
+
+        let program_id = program_ids.get(chain_name);
+        let program_id = match program_id.map(|program_id| account_exists(&client, program_id)) {
+            Some(Ok(true)) => program_id.unwrap(),
+            Some(Err(err)) => {
+                drift.push(RouterDrift::read_error(
+                    chain_name, program_id, "program", err,
+                ));
+                continue;
+            }
+            Some(Ok(false)) | None => {
+                drift.push(RouterDrift::new(
+                    chain_name,
+                    program_id,
+                    "program",
+                    "deployed",
+                    "not deployed",
+                ));
+                continue;
+            }
+        };
+        let router_config = app_config.router_config();
+
+        let expected_igp = match router_config
+            .connection_client
+            .interchain_gas_paymaster_config(&client)
+        {
+            Ok(expected_igp) => expected_igp,
+            Err(err) => {
+                drift.push(RouterDrift::read_error(
+                    chain_name,
+                    Some(program_id),
+                    "interchainGasPaymaster",
+                    err,
+                ));
+                continue;
+            }
+        };
+        let domain_id = chain_config.domain_id();
+        let expected = RouterState {
+            owner: Some(router_config.ownable.owner(ctx.payer_pubkey)),
+            interchain_security_module: router_config
+                .connection_client
+                .interchain_security_module(),
+            interchain_gas_paymaster: expected_igp,
+            remote_routers: routers
+                .iter()
+                .filter(|(domain, _)| **domain != domain_id)
+                .map(|(domain, router)| (*domain, *router))
+                .collect(),
+        };
+
+        match RouterState::fetch(&deployer, &client, program_id) {
+            Ok(mut actual) => {
+                actual.remote_routers.remove(&domain_id);
+                drift.extend(expected.drift(&actual, chain_name, program_id));
+            }
+            Err(err) => {
+                drift.push(RouterDrift::read_error(
+                    chain_name,
+                    Some(program_id),
+                    "router",
+                    err,
+                ));
+                continue;
+            }
+        }
+
+        drift.extend(deployer.check(ctx, &app_configs, &chain_configs, chain_name, program_id));
+    }
+
+    drift
+}
+
+fn read_app_and_chain_configs<Config: for<'a> Deserialize<'a>>(
+    app_config_file_path: PathBuf,
+    chain_config_file_path: PathBuf,
+) -> (HashMap<String, Config>, HashMap<String, ChainMetadata>) {
+    // Load the app configs from the app config file.
+    let app_config_file = File::open(app_config_file_path).unwrap();
+    let app_configs: HashMap<String, Config> = serde_json::from_reader(app_config_file).unwrap();
+
+    // Load the chain configs from the chain config file.
+    let chain_config_file = File::open(chain_config_file_path).unwrap();
+    let chain_configs: HashMap<String, ChainMetadata> =
+        serde_json::from_reader(chain_config_file).unwrap();
+
+    (app_configs, chain_configs)
+}
+
+/// Builds a HashMap of all the foreign deployments from the app config.
+fn foreign_deployments<Config: RouterConfigGetter>(
+    app_configs: &HashMap<String, Config>,
+    chain_configs: &HashMap<String, ChainMetadata>,
+) -> HashMap<u32, H256> {
+    app_configs
+        .iter()
+        .filter_map(|(chain_name, app_config)| {
+            app_config
+                .router_config()
+                .foreign_deployment
+                .as_ref()
+                .map(|foreign_deployment| {
+                    let chain_config = chain_configs.get(chain_name).unwrap();
+                    (
+                        chain_config.domain_id(),
+                        hex_or_base58_to_h256(foreign_deployment).unwrap(),
+                    )
+                })
+        })
+        .collect::<HashMap<u32, H256>>()
+}
+
 // Idempotent.
 // TODO: This should really be brought out into some nicer abstraction, and we should
 // also look for IGP inconsistency etc.
@@ -443,7 +744,9 @@ fn configure_connection_client(
 ) {
     let client = chain_config.client();
 
-    let actual_ism = deployer.get_interchain_security_module(&client, program_id);
+    let actual_ism = deployer
+        .get_interchain_security_module(&client, program_id)
+        .unwrap();
     let expected_ism = router_config.connection_client.interchain_security_module();
 
     if actual_ism != expected_ism {
@@ -465,10 +768,13 @@ fn configure_connection_client(
             .send_with_payer();
     }
 
-    let actual_igp = deployer.get_interchain_gas_paymaster(&client, program_id);
+    let actual_igp = deployer
+        .get_interchain_gas_paymaster(&client, program_id)
+        .unwrap();
     let expected_igp = router_config
         .connection_client
-        .interchain_gas_paymaster_config(&client);
+        .interchain_gas_paymaster_config(&client)
+        .unwrap();
 
     if actual_igp != expected_igp {
         let instruction = deployer.set_interchain_gas_paymaster_instruction(
@@ -506,7 +812,7 @@ fn configure_owner(
 ) {
     let client = chain_config.client();
 
-    let actual_owner = deployer.get_owner(&client, program_id);
+    let actual_owner = deployer.get_owner(&client, program_id).unwrap();
     let expected_owner = Some(router_config.ownable.owner(ctx.payer_pubkey));
 
     if actual_owner != expected_owner {
@@ -545,7 +851,9 @@ fn enroll_all_remote_routers<
         let program_id: Pubkey =
             Pubkey::new_from_array(*routers.get(&domain_id).unwrap().as_fixed_bytes());
 
-        let enrolled_routers = deployer.get_routers(&chain_config.client(), &program_id);
+        let enrolled_routers = deployer
+            .get_routers(&chain_config.client(), &program_id)
+            .unwrap();
         let expected_routers = routers
             .iter()
             .filter(|(router_domain_id, _)| *router_domain_id != &domain_id)
@@ -634,3 +942,91 @@ fn read_router_program_ids(deploy_dir: &Path) -> Option<HashMap<String, Pubkey>>
 
     Some(existing_program_ids)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_router_state_drift() {
+        let chain_name = "sealeveltest1";
+        let program_id = Pubkey::new_unique();
+        let igp_program_id = Pubkey::new_unique();
+        let igp_account = Pubkey::new_unique();
+        let ism = Pubkey::new_unique();
+
+        let expected = RouterState {
+            owner: Some(Pubkey::new_unique()),
+            interchain_security_module: Some(ism),
+            interchain_gas_paymaster: Some((
+                igp_program_id,
+                InterchainGasPaymasterType::OverheadIgp(igp_account),
+            )),
+            remote_routers: HashMap::from([
+                (1, H256::repeat_byte(1)),
+                (2, H256::repeat_byte(2)),
+                (3, H256::repeat_byte(3)),
+            ]),
+        };
+        assert_eq!(expected.drift(&expected, chain_name, &program_id), vec![]);
+
+        let actual = RouterState {
+            owner: None,
+            interchain_security_module: Some(ism),
+            interchain_gas_paymaster: Some((
+                igp_program_id,
+                InterchainGasPaymasterType::Igp(igp_account),
+            )),
+            remote_routers: HashMap::from([
+                (1, H256::repeat_byte(1)),
+                (2, H256::repeat_byte(9)),
+                (4, H256::repeat_byte(4)),
+            ]),
+        };
+        let drift = expected.drift(&actual, chain_name, &program_id);
+        let fields = drift
+            .iter()
+            .map(|drift| drift.field.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            vec![
+                "owner",
+                "interchainGasPaymaster",
+                "remoteRouters.2",
+                "remoteRouters.3",
+                "remoteRouters.4",
+            ]
+        );
+        assert_eq!(
+            drift[3],
+            RouterDrift::new(
+                chain_name,
+                Some(&program_id),
+                "remoteRouters.3",
+                Some(H256::repeat_byte(3)),
+                None::<H256>,
+            )
+        );
+    }
+
+    #[test]
+    fn test_read_error_drift_serialization() {
+        let program_id = Pubkey::new_unique();
+        let drift = RouterDrift::read_error(
+            "sealeveltest1",
+            Some(&program_id),
+            "router",
+            "connection refused",
+        );
+        assert_eq!(
+            serde_json::to_value(&drift).unwrap(),
+            serde_json::json!({
+                "chain": "sealeveltest1",
+                "programId": program_id.to_string(),
+                "field": "router",
+                "error": "connection refused",
+            })
+        );
+    }
+}
@@ -7,14 +7,22 @@ use std::{collections::HashMap, fmt::Debug};
 
 use solana_client::{client_error::ClientError, rpc_client::RpcClient};
 
-use solana_sdk::{instruction::Instruction, program_error::ProgramError, pubkey::Pubkey};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, instruction::Instruction, program_error::ProgramError,
+    pubkey::Pubkey,
+};
 
 use hyperlane_sealevel_connection_client::{
     gas_router::GasRouterConfig, router::RemoteRouterConfig,
 };
 use hyperlane_sealevel_igp::accounts::InterchainGasPaymasterType;
 use hyperlane_sealevel_token::{
-    hyperlane_token_mint_pda_seeds, plugin::SyntheticPlugin, spl_token, spl_token_2022,
+    hyperlane_token_mint_pda_seeds,
+    instruction::{set_token_metadata_instruction, SetTokenMetadata},
+    metaplex::{Metadata, MPL_TOKEN_METADATA_PROGRAM_ID},
+    mpl_token_metadata_pda_seeds,
+    plugin::SyntheticPlugin,
+    spl_token, spl_token_2022,
 };
 use hyperlane_sealevel_token_lib::{
     accounts::{HyperlaneToken, HyperlaneTokenAccount},
@@ -29,8 +37,8 @@ use crate::{
     cmd_utils::account_exists,
     core::CoreProgramIds,
     router::{
-        deploy_routers, ChainMetadata, ConnectionClient, Ownable, RouterConfig, RouterConfigGetter,
-        RouterDeployer,
+        check_routers, deploy_routers, ChainMetadata, ConnectionClient, Ownable, RouterConfig,
+        RouterConfigGetter, RouterDeployer, RouterDrift,
     },
     Context, TokenType as FlatTokenType, WarpRouteCmd, WarpRouteSubCmd,
 };
@@ -77,9 +85,21 @@ impl TokenType {
 struct TokenMetadata {
     name: String,
     symbol: String,
+    /// A URI pointing to off-chain JSON metadata, e.g. including an image.
+    uri: Option<String>,
     total_supply: Option<String>,
 }
 
+impl TokenMetadata {
+    fn set_token_metadata(&self) -> SetTokenMetadata {
+        SetTokenMetadata {
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone().unwrap_or_default(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 enum SplTokenProgramType {
@@ -130,6 +150,22 @@ pub(crate) fn process_warp_route_cmd(mut ctx: Context, cmd: WarpRouteCmd) {
                 deploy.built_so_dir,
             );
         }
+        WarpRouteSubCmd::Check(check) => {
+            let drift = check_routers(
+                &ctx,
+                WarpRouteDeployer::new(None),
+                "warp-routes",
+                &check.warp_route_name,
+                check.token_config_file,
+                check.chain_config_file,
+                check.env_args.environments_dir,
+                &check.env_args.environment,
+            );
+            println!("{}", serde_json::to_string_pretty(&drift).unwrap());
+            if !drift.is_empty() {
+                std::process::exit(1);
+            }
+        }
         WarpRouteSubCmd::DestinationGas(args) => {
             let destination_gas = get_destination_gas(&ctx.client, &args.program_id).unwrap();
             println!(
@@ -172,10 +208,14 @@ impl RouterDeployer<TokenConfig> for WarpRouteDeployer {
         enroll_remote_routers_instruction(program_id, payer, router_configs).unwrap()
     }
 
-    fn get_routers(&self, client: &RpcClient, program_id: &Pubkey) -> HashMap<u32, H256> {
-        let token_data = get_token_data::<()>(client, program_id);
+    fn get_routers(
+        &self,
+        client: &RpcClient,
+        program_id: &Pubkey,
+    ) -> Result<HashMap<u32, H256>, ClientError> {
+        let token_data = get_token_data::<()>(client, program_id)?;
 
-        token_data.remote_routers
+        Ok(token_data.remote_routers)
     }
 
     fn init_program_idempotent(
@@ -224,6 +264,7 @@ impl RouterDeployer<TokenConfig> for WarpRouteDeployer {
                 .router_config()
                 .connection_client
                 .interchain_gas_paymaster_config(client)
+                .unwrap()
                 .unwrap_or((
                     core_program_ids.igp_program_id,
                     InterchainGasPaymasterType::OverheadIgp(core_program_ids.overhead_igp_account),
@@ -297,7 +338,7 @@ impl RouterDeployer<TokenConfig> for WarpRouteDeployer {
         .send_with_payer();
     }
 
-    /// Sets gas router configs on all deployable chains.
+    /// Sets gas router configs and synthetic token metadata on all deployable chains.
     fn post_deploy(
         &self,
         ctx: &mut Context,
@@ -316,25 +357,24 @@ impl RouterDeployer<TokenConfig> for WarpRouteDeployer {
             let program_id: Pubkey =
                 Pubkey::new_from_array(*routers.get(&domain_id).unwrap().as_fixed_bytes());
 
+            if let TokenType::Synthetic(token_metadata) =
+                &app_configs_to_deploy[*chain_name].token_type
+            {
+                set_token_metadata_idempotent(
+                    ctx,
+                    &chain_config.client(),
+                    chain_name,
+                    program_id,
+                    token_metadata,
+                );
+            }
+
             // And set destination gas
             let configured_destination_gas =
                 get_destination_gas(&chain_config.client(), &program_id).unwrap();
 
-            let expected_destination_gas = app_configs
-                .iter()
-                // filter out local chain
-                .filter(|(dest_chain_name, _)| dest_chain_name != chain_name)
-                .map(|(dest_chain_name, app_config)| {
-                    let domain = chain_configs.get(dest_chain_name).unwrap().domain_id();
-                    (
-                        domain,
-                        GasRouterConfig {
-                            domain,
-                            gas: Some(app_config.token_type.gas_overhead_default()),
-                        },
-                    )
-                })
-                .collect::<HashMap<u32, GasRouterConfig>>();
+            let expected_destination_gas =
+                expected_destination_gas(app_configs, chain_configs, chain_name);
 
             // Destination gas to set or update to a Some value
             let destination_gas_to_set = expected_destination_gas
@@ -383,6 +423,101 @@ impl RouterDeployer<TokenConfig> for WarpRouteDeployer {
             }
         }
     }
+
+    /// Checks destination gas and synthetic token metadata.
+    fn check(
+        &self,
+        ctx: &Context,
+        app_configs: &HashMap<String, TokenConfig>,
+        chain_configs: &HashMap<String, ChainMetadata>,
+        chain_name: &str,
+        program_id: &Pubkey,
+    ) -> Vec<RouterDrift> {
+        let client = chain_configs[chain_name].client();
+        let mut drift = vec![];
+
+        match get_destination_gas(&client, program_id) {
+            Ok(configured_destination_gas) => {
+                let expected_destination_gas =
+                    expected_destination_gas(app_configs, chain_configs, chain_name);
+                let mut domains = configured_destination_gas
+                    .keys()
+                    .chain(expected_destination_gas.keys())
+                    .copied()
+                    .collect::<Vec<u32>>();
+                domains.sort_unstable();
+                domains.dedup();
+                for domain in domains {
+                    let expected_gas = expected_destination_gas
+                        .get(&domain)
+                        .and_then(|config| config.gas);
+                    let actual_gas = configured_destination_gas.get(&domain).copied();
+                    if actual_gas != expected_gas {
+                        drift.push(RouterDrift::new(
+                            chain_name,
+                            Some(program_id),
+                            &format!("destinationGas.{}", domain),
+                            expected_gas,
+                            actual_gas,
+                        ));
+                    }
+                }
+            }
+            Err(err) => drift.push(RouterDrift::read_error(
+                chain_name,
+                Some(program_id),
+                "destinationGas",
+                err,
+            )),
+        }
+
+        if let TokenType::Synthetic(token_metadata) = &app_configs[chain_name].token_type {
+            let expected_metadata = Some(token_metadata.set_token_metadata());
+            match get_token_metadata(&client, program_id, ctx.commitment) {
+                Ok(actual_metadata) if actual_metadata != expected_metadata => {
+                    drift.push(RouterDrift::new(
+                        chain_name,
+                        Some(program_id),
+                        "tokenMetadata",
+                        expected_metadata,
+                        actual_metadata,
+                    ))
+                }
+                Ok(_) => {}
+                Err(err) => drift.push(RouterDrift::read_error(
+                    chain_name,
+                    Some(program_id),
+                    "tokenMetadata",
+                    err,
+                )),
+            }
+        }
+
+        drift
+    }
+}
+
+/// The destination gas expected to be configured on `chain_name` for every other chain.
+fn expected_destination_gas(
+    app_configs: &HashMap<String, TokenConfig>,
+    chain_configs: &HashMap<String, ChainMetadata>,
+    chain_name: &str,
+) -> HashMap<u32, GasRouterConfig> {
+    app_configs
+        .iter()
+        // filter out local chain
+        .filter(|(dest_chain_name, _)| *dest_chain_name != chain_name)
+        .map(|(dest_chain_name, app_config)| {
+            let domain = chain_configs.get(dest_chain_name).unwrap().domain_id();
+            (
+                domain,
+                GasRouterConfig {
+                    domain,
+                    gas: Some(app_config.token_type.gas_overhead_default()),
+                },
+            )
+        })
+        .collect()
 }
 
 impl RouterConfigGetter for TokenConfig {
@@ -393,10 +528,14 @@ impl RouterConfigGetter for TokenConfig {
 
 impl Ownable for WarpRouteDeployer {
     /// Gets the owner configured on-chain.
-    fn get_owner(&self, client: &RpcClient, program_id: &Pubkey) -> Option<Pubkey> {
-        let token = get_token_data::<()>(client, program_id);
+    fn get_owner(
+        &self,
+        client: &RpcClient,
+        program_id: &Pubkey,
+    ) -> Result<Option<Pubkey>, ClientError> {
+        let token = get_token_data::<()>(client, program_id)?;
 
-        token.owner
+        Ok(token.owner)
     }
 
     /// Gets an instruction to set the owner.
@@ -406,7 +545,7 @@ impl Ownable for WarpRouteDeployer {
         program_id: &Pubkey,
         new_owner: Option<Pubkey>,
     ) -> Instruction {
-        let token = get_token_data::<()>(client, program_id);
+        let token = get_token_data::<()>(client, program_id).unwrap();
 
         transfer_ownership_instruction(*program_id, token.owner.unwrap(), new_owner).unwrap()
     }
@@ -417,10 +556,10 @@ impl ConnectionClient for WarpRouteDeployer {
         &self,
         client: &RpcClient,
         program_id: &Pubkey,
-    ) -> Option<Pubkey> {
-        let token_data = get_token_data::<()>(client, program_id);
+    ) -> Result<Option<Pubkey>, ClientError> {
+        let token_data = get_token_data::<()>(client, program_id)?;
 
-        token_data.interchain_security_module
+        Ok(token_data.interchain_security_module)
     }
 
     fn set_interchain_security_module_instruction(
@@ -429,7 +568,7 @@ impl ConnectionClient for WarpRouteDeployer {
         program_id: &Pubkey,
         ism: Option<Pubkey>,
     ) -> Instruction {
-        let token_data = get_token_data::<()>(client, program_id);
+        let token_data = get_token_data::<()>(client, program_id).unwrap();
 
         set_interchain_security_module_instruction(*program_id, token_data.owner.unwrap(), ism)
             .unwrap()
@@ -439,10 +578,10 @@ impl ConnectionClient for WarpRouteDeployer {
         &self,
         client: &RpcClient,
         program_id: &Pubkey,
-    ) -> Option<(Pubkey, InterchainGasPaymasterType)> {
-        let token_data = get_token_data::<()>(client, program_id);
+    ) -> Result<Option<(Pubkey, InterchainGasPaymasterType)>, ClientError> {
+        let token_data = get_token_data::<()>(client, program_id)?;
 
-        token_data.interchain_gas_paymaster
+        Ok(token_data.interchain_gas_paymaster)
     }
 
     fn set_interchain_gas_paymaster_instruction(
@@ -451,34 +590,99 @@ impl ConnectionClient for WarpRouteDeployer {
         program_id: &Pubkey,
         igp_config: Option<(Pubkey, InterchainGasPaymasterType)>,
     ) -> Option<Instruction> {
-        let token_data = get_token_data::<()>(client, program_id);
+        let token_data = get_token_data::<()>(client, program_id).unwrap();
 
         Some(set_igp_instruction(*program_id, token_data.owner.unwrap(), igp_config).unwrap())
     }
 }
 
-fn get_token_data<T>(client: &RpcClient, program_id: &Pubkey) -> HyperlaneToken<T>
+fn get_token_data<T>(
+    client: &RpcClient,
+    program_id: &Pubkey,
+) -> Result<HyperlaneToken<T>, ClientError>
 where
     T: BorshDeserialize + BorshSerialize + Default + account_utils::Data,
 {
     let (token_pda, _token_bump) =
         Pubkey::find_program_address(hyperlane_token_pda_seeds!(), program_id);
 
-    let account = client.get_account(&token_pda).unwrap();
-    *HyperlaneTokenAccount::<T>::fetch(&mut &account.data[..])
+    let account = client.get_account(&token_pda)?;
+    Ok(*HyperlaneTokenAccount::<T>::fetch(&mut &account.data[..])
         .unwrap()
-        .into_inner()
+        .into_inner())
 }
 
 fn get_destination_gas(
     client: &RpcClient,
     program_id: &Pubkey,
 ) -> Result<HashMap<u32, u64>, ClientError> {
-    let token_data = get_token_data::<()>(client, program_id);
+    let token_data = get_token_data::<()>(client, program_id)?;
 
     Ok(token_data.destination_gas)
 }
 
+/// Sets the synthetic token's on-chain metadata if it differs from the configured metadata.
+fn set_token_metadata_idempotent(
+    ctx: &mut Context,
+    client: &RpcClient,
+    chain_name: &str,
+    program_id: Pubkey,
+    token_metadata: &TokenMetadata,
+) {
+    let expected_metadata = token_metadata.set_token_metadata();
+
+    let actual_metadata = get_token_metadata(client, &program_id, ctx.commitment).unwrap();
+
+    if actual_metadata.as_ref() == Some(&expected_metadata) {
+        println!(
+            "No token metadata changes for chain: {}, program_id {}",
+            chain_name, program_id
+        );
+        return;
+    }
+
+    let owner = get_token_data::<()>(client, &program_id)
+        .unwrap()
+        .owner
+        .expect("Cannot set token metadata without an owner");
+    let description = format!(
+        "Setting token metadata for chain: {}, program_id {}, metadata: {:?}",
+        chain_name, program_id, expected_metadata,
+    );
+    ctx.new_txn()
+        .add_with_description(
+            set_token_metadata_instruction(program_id, owner, expected_metadata).unwrap(),
+            description,
+        )
+        .with_client(client)
+        .send_with_payer();
+}
+
+/// Gets the synthetic token's Metaplex metadata, if it has been set.
+fn get_token_metadata(
+    client: &RpcClient,
+    program_id: &Pubkey,
+    commitment: CommitmentConfig,
+) -> Result<Option<SetTokenMetadata>, ClientError> {
+    let (mint_account, _mint_bump) =
+        Pubkey::find_program_address(hyperlane_token_mint_pda_seeds!(), program_id);
+    let (metadata_account, _metadata_bump) = Pubkey::find_program_address(
+        mpl_token_metadata_pda_seeds!(mint_account),
+        &MPL_TOKEN_METADATA_PROGRAM_ID,
+    );
+    Ok(client
+        .get_account_with_commitment(&metadata_account, commitment)?
+        .value
+        .map(|account| {
+            let metadata = Metadata::fetch(&mut &account.data[..]).unwrap().data();
+            SetTokenMetadata {
+                name: metadata.name,
+                symbol: metadata.symbol,
+                uri: metadata.uri,
+            }
+        }))
+}
+
 // Funds the ATA payer up to the specified amount.
 fn fund_ata_payer_up_to(
     ctx: &mut Context,
@@ -66,7 +66,7 @@ pub trait HyperlaneGasRouterAccessControl: HyperlaneGasRouter + AccessControl {
 impl<T> HyperlaneGasRouterAccessControl for T where T: HyperlaneGasRouter + AccessControl {}
 
 /// The Hyperlane gas router pattern with a helper function to dispatch messages
-/// to a remote routers & pay for gas with the configured gas amount.
+/// to a remote routers & pay for the configured gas amount via the post-dispatch hooks.
 #[allow(clippy::too_many_arguments)]
 pub trait HyperlaneGasRouterDispatch: HyperlaneGasRouter + HyperlaneRouterDispatch {
     fn dispatch_with_gas(
@@ -75,10 +75,9 @@ pub trait HyperlaneGasRouterDispatch: HyperlaneGasRouter + HyperlaneRouterDispat
         dispatch_authority_seeds: &[&[u8]],
         destination_domain: u32,
         message_body: Vec<u8>,
-        dispatch_account_metas: Vec<AccountMeta>,
-        dispatch_account_infos: &[AccountInfo],
-        payment_account_metas: Vec<AccountMeta>,
-        payment_account_infos: &[AccountInfo],
+        hook_account_counts: Vec<u8>,
+        account_metas: Vec<AccountMeta>,
+        account_infos: &[AccountInfo],
     ) -> Result<H256, ProgramError> {
         HyperlaneRouterDispatch::dispatch_with_gas(
             self,
@@ -88,10 +87,9 @@ pub trait HyperlaneGasRouterDispatch: HyperlaneGasRouter + HyperlaneRouterDispat
             message_body,
             self.destination_gas(destination_domain)
                 .ok_or(ProgramError::InvalidArgument)?,
-            dispatch_account_metas,
-            dispatch_account_infos,
-            payment_account_metas,
-            payment_account_infos,
+            hook_account_counts,
+            account_metas,
+            account_infos,
         )
     }
 }
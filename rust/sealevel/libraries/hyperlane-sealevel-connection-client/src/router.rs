@@ -1,17 +1,16 @@
 use access_control::AccessControl;
 use borsh::{BorshDeserialize, BorshSerialize};
 use hyperlane_core::H256;
-use hyperlane_sealevel_igp::instruction::{
-    Instruction as IgpInstruction, PayForGas as IgpPayForGas,
-};
 use hyperlane_sealevel_mailbox::instruction::{
     Instruction as MailboxInstruction, OutboxDispatch as MailboxOutboxDispatch,
+    OutboxDispatchWithHook as MailboxOutboxDispatchWithHook,
 };
+use hyperlane_sealevel_post_dispatch_hook_interface::StandardHookMetadata;
 use solana_program::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction},
     msg,
-    program::{get_return_data, invoke, invoke_signed},
+    program::{get_return_data, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
 };
@@ -100,43 +99,62 @@ pub trait HyperlaneRouterDispatch: HyperlaneRouter + HyperlaneConnectionClient {
         account_metas: Vec<AccountMeta>,
         account_infos: &[AccountInfo],
     ) -> Result<H256, ProgramError> {
-        // The recipient is the remote router, which must be enrolled.
-        let recipient = *self
-            .router(destination_domain)
-            .ok_or(ProgramError::InvalidArgument)?;
-
-        let dispatch_instruction = MailboxInstruction::OutboxDispatch(MailboxOutboxDispatch {
+        let dispatch = MailboxOutboxDispatch {
             sender: *program_id,
             destination_domain,
-            recipient,
+            recipient: self.remote_router(destination_domain)?,
             message_body,
-        });
-        let mailbox = self.mailbox();
-        let mailbox_ixn = Instruction {
-            program_id: *mailbox,
-            data: dispatch_instruction.into_instruction_data()?,
-            accounts: account_metas,
         };
-        // Call the Mailbox program to dispatch the message.
-        invoke_signed(&mailbox_ixn, account_infos, &[dispatch_authority_seeds])?;
-
-        // Parse the message ID from the return data from the prior dispatch.
-        let (returning_program_id, returned_data) =
-            get_return_data().ok_or(ProgramError::InvalidArgument)?;
-        // The mailbox itself doesn't make any CPIs, but as a sanity check we confirm
-        // that the return data is from the mailbox.
-        if returning_program_id != *mailbox {
-            return Err(ProgramError::InvalidArgument);
-        }
-        let message_id: H256 =
-            H256::try_from_slice(&returned_data).map_err(|_| ProgramError::InvalidArgument)?;
+        self.invoke_mailbox_dispatch(
+            dispatch_authority_seeds,
+            MailboxInstruction::OutboxDispatch(dispatch),
+            account_metas,
+            account_infos,
+        )
+    }
 
-        Ok(message_id)
+    /// Dispatches a message to the remote router for the provided destination domain,
+    /// calling the Mailbox's post-dispatch hooks with `metadata`.
+    /// If `custom_hook` is specified, it is called instead of the Mailbox's default hook.
+    ///
+    /// `account_metas` and `account_infos` are expected to end with the accounts
+    /// of the hooks, as described by the Mailbox's `OutboxDispatchWithHook` instruction,
+    /// with `hook_account_counts` holding the number of accounts of each hook.
+    #[allow(clippy::too_many_arguments)]
+    fn dispatch_with_hook(
+        &self,
+        program_id: &Pubkey,
+        dispatch_authority_seeds: &[&[u8]],
+        destination_domain: u32,
+        message_body: Vec<u8>,
+        metadata: Vec<u8>,
+        custom_hook: Option<Pubkey>,
+        hook_account_counts: Vec<u8>,
+        account_metas: Vec<AccountMeta>,
+        account_infos: &[AccountInfo],
+    ) -> Result<H256, ProgramError> {
+        let dispatch = MailboxOutboxDispatch {
+            sender: *program_id,
+            destination_domain,
+            recipient: self.remote_router(destination_domain)?,
+            message_body,
+        };
+        self.invoke_mailbox_dispatch(
+            dispatch_authority_seeds,
+            MailboxInstruction::OutboxDispatchWithHook(MailboxOutboxDispatchWithHook {
+                dispatch,
+                metadata,
+                custom_hook,
+                hook_account_counts,
+            }),
+            account_metas,
+            account_infos,
+        )
     }
 
     /// Dispatches a message to the remote router for the provided destination domain,
-    /// paying for gas with the IGP.
-    /// Errors if there is no IGP configured.
+    /// paying for `gas_amount` of destination gas via the post-dispatch hooks.
+    /// The configured IGP program, if any, is used as the custom hook.
     #[allow(clippy::too_many_arguments)]
     fn dispatch_with_gas(
         &self,
@@ -145,36 +163,65 @@ pub trait HyperlaneRouterDispatch: HyperlaneRouter + HyperlaneConnectionClient {
         destination_domain: u32,
         message_body: Vec<u8>,
         gas_amount: u64,
-        dispatch_account_metas: Vec<AccountMeta>,
-        dispatch_account_infos: &[AccountInfo],
-        payment_account_metas: Vec<AccountMeta>,
-        payment_account_infos: &[AccountInfo],
+        hook_account_counts: Vec<u8>,
+        account_metas: Vec<AccountMeta>,
+        account_infos: &[AccountInfo],
     ) -> Result<H256, ProgramError> {
-        let message_id = self.dispatch(
+        let metadata = StandardHookMetadata {
+            gas_limit: Some(gas_amount),
+        }
+        .try_to_vec()?;
+        let custom_hook = self
+            .interchain_gas_paymaster()
+            .map(|(igp_program_id, _)| *igp_program_id);
+
+        self.dispatch_with_hook(
             program_id,
             dispatch_authority_seeds,
             destination_domain,
             message_body,
-            dispatch_account_metas,
-            dispatch_account_infos,
-        )?;
+            metadata,
+            custom_hook,
+            hook_account_counts,
+            account_metas,
+            account_infos,
+        )
+    }
 
-        // Call the IGP to pay for gas.
-        let (igp_program_id, _) = self
-            .interchain_gas_paymaster()
-            .ok_or(ProgramError::InvalidArgument)?;
-
-        let igp_ixn = Instruction::new_with_borsh(
-            *igp_program_id,
-            &IgpInstruction::PayForGas(IgpPayForGas {
-                message_id,
-                destination_domain,
-                gas_amount,
-            }),
-            payment_account_metas,
-        );
+    /// Gets the remote router for the destination domain, which must be enrolled.
+    fn remote_router(&self, destination_domain: u32) -> Result<H256, ProgramError> {
+        self.router(destination_domain)
+            .copied()
+            .ok_or(ProgramError::InvalidArgument)
+    }
+
+    /// Calls the Mailbox to dispatch a message, returning the message ID.
+    fn invoke_mailbox_dispatch(
+        &self,
+        dispatch_authority_seeds: &[&[u8]],
+        dispatch_instruction: MailboxInstruction,
+        account_metas: Vec<AccountMeta>,
+        account_infos: &[AccountInfo],
+    ) -> Result<H256, ProgramError> {
+        let mailbox = self.mailbox();
+        let mailbox_ixn = Instruction {
+            program_id: *mailbox,
+            data: dispatch_instruction.into_instruction_data()?,
+            accounts: account_metas,
+        };
+        // Call the Mailbox program to dispatch the message.
+        invoke_signed(&mailbox_ixn, account_infos, &[dispatch_authority_seeds])?;
 
-        invoke(&igp_ixn, payment_account_infos)?;
+        // Parse the message ID from the return data from the prior dispatch.
+        let (returning_program_id, returned_data) =
+            get_return_data().ok_or(ProgramError::InvalidArgument)?;
+        // The Mailbox sets the return data after calling any post-dispatch hooks,
+        // but as a sanity check we confirm that the return data is from the mailbox.
+        if returning_program_id != *mailbox {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let message_id: H256 =
+            H256::try_from_slice(&returned_data).map_err(|_| ProgramError::InvalidArgument)?;
 
         Ok(message_id)
     }
@@ -66,6 +66,14 @@ pub struct TransferRemote {
     pub recipient: H256,
     /// The amount or ID of the token to transfer.
     pub amount_or_id: U256,
+    /// The number of accounts of each post-dispatch hook called by the Mailbox,
+    /// in the order the hooks are called. See `transfer_remote` for the accounts.
+    ///
+    /// Breaking change: before post-dispatch hooks, the IGP accounts directly
+    /// followed the message storage PDA, ahead of the plugin accounts. Instruction
+    /// data without this field fails to decode, so that transfers built for that
+    /// account layout are rejected instead of passing accounts to the wrong programs.
+    pub hook_account_counts: Vec<u8>,
 }
 
 /// Gets an instruction to initialize the program. This provides only the
@@ -264,14 +264,11 @@ where
     /// 6.    `[signer]` The token sender and mailbox payer.
     /// 7.    `[signer]` Unique message / gas payment account.
     /// 8.    `[writeable]` Message storage PDA.
-    ///       ---- If using an IGP ----
-    /// 9.    `[executable]` The IGP program.
-    /// 10.   `[writeable]` The IGP program data.
-    /// 11.   `[writeable]` Gas payment PDA.
-    /// 12.   `[]` OPTIONAL - The Overhead IGP program, if the configured IGP is an Overhead IGP.
-    /// 13.   `[writeable]` The IGP account.
-    ///      ---- End if ----
-    /// 14..N `[??..??]` Plugin-specific accounts.
+    /// 9..M  `[??..??]` Plugin-specific accounts.
+    /// M..N  `[??..??]` The Mailbox's post-dispatch hook accounts: for each hook, the hook
+    ///       program followed by the number of accounts in `xfer.hook_account_counts`. If an
+    ///       IGP is configured, it is used as the custom hook and its configured IGP account
+    ///       must be included.
     pub fn transfer_remote(
         program_id: &Pubkey,
         accounts: &[AccountInfo],
@@ -338,78 +335,6 @@ where
         // Similarly defer to the checks in the Mailbox to ensure account validity.
         let dispatched_message_pda = next_account_info(accounts_iter)?;
 
-        let igp_payment_accounts =
-            if let Some((igp_program_id, igp_account_type)) = token.interchain_gas_paymaster() {
-                // Account 9: The IGP program
-                let igp_program_account = next_account_info(accounts_iter)?;
-                if igp_program_account.key != igp_program_id {
-                    return Err(ProgramError::InvalidArgument);
-                }
-
-                // Account 10: The IGP program data.
-                // No verification is performed here, the IGP will do that.
-                let igp_program_data_account = next_account_info(accounts_iter)?;
-
-                // Account 11: The gas payment PDA.
-                // No verification is performed here, the IGP will do that.
-                let igp_payment_pda_account = next_account_info(accounts_iter)?;
-
-                // Account 12: The configured IGP account.
-                let configured_igp_account = next_account_info(accounts_iter)?;
-                if configured_igp_account.key != igp_account_type.key() {
-                    return Err(ProgramError::InvalidArgument);
-                }
-
-                // Accounts expected by the IGP's `PayForGas` instruction:
-                //
-                // 0. `[executable]` The system program.
-                // 1. `[signer]` The payer.
-                // 2. `[writeable]` The IGP program data.
-                // 3. `[signer]` Unique gas payment account.
-                // 4. `[writeable]` Gas payment PDA.
-                // 5. `[writeable]` The IGP account.
-                // 6. `[]` Overhead IGP account (optional).
-
-                let mut igp_payment_account_metas = vec![
-                    AccountMeta::new_readonly(solana_program::system_program::id(), false),
-                    AccountMeta::new(*sender_wallet.key, true),
-                    AccountMeta::new(*igp_program_data_account.key, false),
-                    AccountMeta::new_readonly(*unique_message_account.key, true),
-                    AccountMeta::new(*igp_payment_pda_account.key, false),
-                ];
-                let mut igp_payment_account_infos = vec![
-                    system_program_account.clone(),
-                    sender_wallet.clone(),
-                    igp_program_data_account.clone(),
-                    unique_message_account.clone(),
-                    igp_payment_pda_account.clone(),
-                ];
-
-                match igp_account_type {
-                    InterchainGasPaymasterType::Igp(_) => {
-                        igp_payment_account_metas
-                            .push(AccountMeta::new(*configured_igp_account.key, false));
-                        igp_payment_account_infos.push(configured_igp_account.clone());
-                    }
-                    InterchainGasPaymasterType::OverheadIgp(_) => {
-                        // Account 13: The inner IGP account.
-                        let inner_igp_account = next_account_info(accounts_iter)?;
-
-                        // The inner IGP is expected first, then the overhead IGP.
-                        igp_payment_account_metas.extend([
-                            AccountMeta::new(*inner_igp_account.key, false),
-                            AccountMeta::new_readonly(*configured_igp_account.key, false),
-                        ]);
-                        igp_payment_account_infos
-                            .extend([inner_igp_account.clone(), configured_igp_account.clone()]);
-                    }
-                };
-
-                Some((igp_payment_account_metas, igp_payment_account_infos))
-            } else {
-                None
-            };
-
         // The amount denominated in the local decimals.
         let local_amount: u64 = xfer
             .amount_or_id
@@ -428,11 +353,20 @@ where
             local_amount,
         )?;
 
-        if accounts_iter.next().is_some() {
-            return Err(ProgramError::from(Error::ExtraneousAccount));
+        // Accounts M..N: The post-dispatch hook accounts.
+        // Defer to the checks in the Mailbox and hooks, other than ensuring the
+        // configured IGP account is provided to the IGP hook.
+        let hook_accounts = accounts_iter.as_slice();
+        if let Some((_, igp_account_type)) = token.interchain_gas_paymaster() {
+            if !hook_accounts
+                .iter()
+                .any(|account| account.key == igp_account_type.key())
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
         }
 
-        let dispatch_account_metas = vec![
+        let mut dispatch_account_metas = vec![
             AccountMeta::new(*mailbox_outbox_account.key, false),
             AccountMeta::new_readonly(*dispatch_authority_account.key, true),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
@@ -441,7 +375,7 @@ where
             AccountMeta::new_readonly(*unique_message_account.key, true),
             AccountMeta::new(*dispatched_message_pda.key, false),
         ];
-        let dispatch_account_infos = &[
+        let mut dispatch_account_infos = vec![
             mailbox_outbox_account.clone(),
             dispatch_authority_account.clone(),
             system_program_account.clone(),
@@ -450,33 +384,41 @@ where
             unique_message_account.clone(),
             dispatched_message_pda.clone(),
         ];
+        dispatch_account_metas.extend(hook_accounts.iter().map(|account| AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        }));
+        dispatch_account_infos.extend_from_slice(hook_accounts);
 
         // The token message body, which specifies the remote_amount.
         let token_transfer_message =
             TokenMessage::new(xfer.recipient, remote_amount, vec![]).to_vec();
 
-        if let Some((igp_payment_account_metas, igp_payment_account_infos)) = igp_payment_accounts {
-            // Dispatch the message and pay for gas.
+        if token.interchain_gas_paymaster().is_some() {
+            // Dispatch the message and pay for gas with the configured IGP hook.
             HyperlaneGasRouterDispatch::dispatch_with_gas(
                 &*token,
                 program_id,
                 dispatch_authority_seeds,
                 xfer.destination_domain,
                 token_transfer_message,
+                xfer.hook_account_counts,
                 dispatch_account_metas,
-                dispatch_account_infos,
-                igp_payment_account_metas,
-                &igp_payment_account_infos,
+                &dispatch_account_infos,
             )?;
         } else {
-            // Dispatch the message.
-            token.dispatch(
+            // Dispatch the message, calling only the Mailbox's configured hooks.
+            token.dispatch_with_hook(
                 program_id,
                 dispatch_authority_seeds,
                 xfer.destination_domain,
                 token_transfer_message,
+                vec![],
+                None,
+                xfer.hook_account_counts,
                 dispatch_account_metas,
-                dispatch_account_infos,
+                &dispatch_account_infos,
             )?;
         }
 
@@ -0,0 +1,186 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+use spl_type_length_value::discriminator::Discriminator;
+
+/// Instructions that a Hyperlane post-dispatch hook is expected to process.
+/// The first 8 bytes of the encoded instruction is a discriminator that
+/// allows programs to implement the required interface.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum PostDispatchHookInstruction {
+    /// Called by the Mailbox after a message has been dispatched.
+    /// The accounts passed to the Mailbox after the hook's program ID are
+    /// passed through to the hook.
+    PostDispatch(PostDispatchInstruction),
+    /// Quotes the payment in lamports required by `PostDispatch` for a message,
+    /// returned as a `SimulationReturnData<u64>`.
+    QuoteDispatch(QuoteDispatchInstruction),
+}
+
+#[derive(Eq, PartialEq, BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct PostDispatchInstruction {
+    pub metadata: Vec<u8>,
+    pub message: Vec<u8>,
+}
+
+impl PostDispatchInstruction {
+    pub fn new(metadata: Vec<u8>, message: Vec<u8>) -> Self {
+        Self { metadata, message }
+    }
+}
+
+#[derive(Eq, PartialEq, BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct QuoteDispatchInstruction {
+    pub metadata: Vec<u8>,
+    pub message: Vec<u8>,
+}
+
+impl QuoteDispatchInstruction {
+    pub fn new(metadata: Vec<u8>, message: Vec<u8>) -> Self {
+        Self { metadata, message }
+    }
+}
+
+/// Metadata understood by the hooks shipped with Hyperlane, analogous to the
+/// EVM `StandardHookMetadata`. Empty metadata is equivalent to the default.
+#[derive(Eq, PartialEq, BorshSerialize, BorshDeserialize, Debug, Clone, Default)]
+pub struct StandardHookMetadata {
+    /// The gas limit to use when handling the message on the destination,
+    /// or the hook's default if not specified.
+    pub gas_limit: Option<u64>,
+}
+
+impl StandardHookMetadata {
+    /// Decodes the metadata, treating empty metadata as the default.
+    pub fn decode(metadata: &[u8]) -> Result<Self, ProgramError> {
+        if metadata.is_empty() {
+            return Ok(Self::default());
+        }
+        Self::try_from_slice(metadata).map_err(|err| ProgramError::BorshIoError(err.to_string()))
+    }
+}
+
+/// First 8 bytes of `hash::hashv(&[b"hyperlane-post-dispatch-hook:post-dispatch"])`
+const POST_DISPATCH_DISCRIMINATOR: [u8; Discriminator::LENGTH] =
+    [121, 67, 135, 153, 114, 129, 2, 213];
+const POST_DISPATCH_DISCRIMINATOR_SLICE: &[u8] = &POST_DISPATCH_DISCRIMINATOR;
+
+/// First 8 bytes of `hash::hashv(&[b"hyperlane-post-dispatch-hook:quote-dispatch"])`
+const QUOTE_DISPATCH_DISCRIMINATOR: [u8; Discriminator::LENGTH] =
+    [129, 95, 61, 116, 126, 140, 2, 194];
+const QUOTE_DISPATCH_DISCRIMINATOR_SLICE: &[u8] = &QUOTE_DISPATCH_DISCRIMINATOR;
+
+impl PostDispatchHookInstruction {
+    pub fn encode(&self) -> Result<Vec<u8>, ProgramError> {
+        let mut buf = vec![];
+        match self {
+            PostDispatchHookInstruction::PostDispatch(instruction) => {
+                buf.extend_from_slice(POST_DISPATCH_DISCRIMINATOR_SLICE);
+                buf.extend_from_slice(
+                    &instruction
+                        .try_to_vec()
+                        .map_err(|err| ProgramError::BorshIoError(err.to_string()))?[..],
+                );
+            }
+            PostDispatchHookInstruction::QuoteDispatch(instruction) => {
+                buf.extend_from_slice(QUOTE_DISPATCH_DISCRIMINATOR_SLICE);
+                buf.extend_from_slice(
+                    &instruction
+                        .try_to_vec()
+                        .map_err(|err| ProgramError::BorshIoError(err.to_string()))?[..],
+                );
+            }
+        }
+
+        Ok(buf)
+    }
+
+    pub fn decode(buf: &[u8]) -> Result<Self, ProgramError> {
+        if buf.len() < Discriminator::LENGTH {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let (discriminator, rest) = buf.split_at(Discriminator::LENGTH);
+        match discriminator {
+            POST_DISPATCH_DISCRIMINATOR_SLICE => {
+                let instruction = PostDispatchInstruction::try_from_slice(rest)
+                    .map_err(|err| ProgramError::BorshIoError(err.to_string()))?;
+                Ok(Self::PostDispatch(instruction))
+            }
+            QUOTE_DISPATCH_DISCRIMINATOR_SLICE => {
+                let instruction = QuoteDispatchInstruction::try_from_slice(rest)
+                    .map_err(|err| ProgramError::BorshIoError(err.to_string()))?;
+                Ok(Self::QuoteDispatch(instruction))
+            }
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use solana_program::hash::hashv;
+
+    #[test]
+    fn test_discriminator_slices() {
+        assert_eq!(
+            &hashv(&[b"hyperlane-post-dispatch-hook:post-dispatch"]).to_bytes()
+                [..Discriminator::LENGTH],
+            POST_DISPATCH_DISCRIMINATOR_SLICE,
+        );
+
+        assert_eq!(
+            &hashv(&[b"hyperlane-post-dispatch-hook:quote-dispatch"]).to_bytes()
+                [..Discriminator::LENGTH],
+            QUOTE_DISPATCH_DISCRIMINATOR_SLICE,
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_post_dispatch_instruction() {
+        let instruction = PostDispatchHookInstruction::PostDispatch(PostDispatchInstruction::new(
+            vec![5, 4, 3, 2, 1],
+            vec![1, 2, 3, 4, 5],
+        ));
+
+        let encoded = instruction.encode().unwrap();
+        assert_eq!(
+            &encoded[..Discriminator::LENGTH],
+            POST_DISPATCH_DISCRIMINATOR_SLICE,
+        );
+
+        let decoded = PostDispatchHookInstruction::decode(&encoded).unwrap();
+        assert_eq!(instruction, decoded);
+    }
+
+    #[test]
+    fn test_encode_decode_quote_dispatch_instruction() {
+        let instruction = PostDispatchHookInstruction::QuoteDispatch(
+            QuoteDispatchInstruction::new(vec![5, 4, 3, 2, 1], vec![1, 2, 3, 4, 5]),
+        );
+
+        let encoded = instruction.encode().unwrap();
+        assert_eq!(
+            &encoded[..Discriminator::LENGTH],
+            QUOTE_DISPATCH_DISCRIMINATOR_SLICE,
+        );
+
+        let decoded = PostDispatchHookInstruction::decode(&encoded).unwrap();
+        assert_eq!(instruction, decoded);
+    }
+
+    #[test]
+    fn test_decode_standard_hook_metadata() {
+        assert_eq!(
+            StandardHookMetadata::decode(&[]).unwrap(),
+            StandardHookMetadata::default()
+        );
+
+        let metadata = StandardHookMetadata {
+            gas_limit: Some(100_000),
+        };
+        assert_eq!(
+            StandardHookMetadata::decode(&metadata.try_to_vec().unwrap()).unwrap(),
+            metadata
+        );
+    }
+}
@@ -33,6 +33,9 @@ pub struct HelloWorldMessage {
     pub destination: u32,
     /// The message.
     pub message: String,
+    /// The number of accounts of each post-dispatch hook called by the Mailbox,
+    /// in the order the hooks are called.
+    pub hook_account_counts: Vec<u8>,
 }
 
 /// Instructions for the program.
@@ -8,7 +8,6 @@ use hyperlane_sealevel_connection_client::{
     router::{HyperlaneRouterAccessControl, HyperlaneRouterDispatch, RemoteRouterConfig},
     HyperlaneConnectionClient,
 };
-use hyperlane_sealevel_igp::accounts::InterchainGasPaymasterType;
 use hyperlane_sealevel_mailbox::{
     mailbox_message_dispatch_authority_pda_seeds, mailbox_process_authority_pda_seeds,
 };
@@ -165,13 +164,10 @@ fn init(program_id: &Pubkey, accounts: &[AccountInfo], init: Init) -> ProgramRes
 /// 7.  `[signer]` Unique message account.
 /// 8.  `[writeable]` Dispatched message PDA. An empty message PDA relating to the seeds
 ///     `mailbox_dispatched_message_pda_seeds` where the message contents will be stored.
-///     ---- if an IGP is configured ----
-/// 9.  `[executable]` The IGP program.
-/// 10. `[writeable]` The IGP program data.
-/// 11. `[writeable]` The gas payment PDA.
-/// 12. `[]` OPTIONAL - The Overhead IGP program, if the configured IGP is an Overhead IGP.
-/// 13. `[writeable]` The IGP account.
-///     ---- end if an IGP is configured ----
+/// 9..N `[??..??]` The Mailbox's post-dispatch hook accounts: for each hook, the hook
+///     program followed by the number of accounts in `hook_account_counts`. If an IGP is
+///     configured, it is used as the custom hook and its configured IGP account must be
+///     included.
 fn send_hello_world(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -218,7 +214,20 @@ fn send_hello_world(
     // Account 8: Dispatched message PDA.
     let dispatched_message_info = next_account_info(accounts_iter)?;
 
-    let dispatch_account_metas = vec![
+    // Accounts 9..N: The post-dispatch hook accounts.
+    // Defer to the checks in the Mailbox and hooks, other than ensuring the
+    // configured IGP account is provided to the IGP hook.
+    let hook_infos = accounts_iter.as_slice();
+    if let Some((_, igp_account_type)) = storage.interchain_gas_paymaster() {
+        if !hook_infos
+            .iter()
+            .any(|info| info.key == igp_account_type.key())
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    let mut dispatch_account_metas = vec![
         AccountMeta::new(*mailbox_outbox_info.key, false),
         AccountMeta::new_readonly(*dispatch_authority_info.key, true),
         AccountMeta::new_readonly(*system_program_info.key, false),
@@ -227,7 +236,7 @@ fn send_hello_world(
         AccountMeta::new_readonly(*unique_message_account_info.key, true),
         AccountMeta::new(*dispatched_message_info.key, false),
     ];
-    let dispatch_account_infos = &[
+    let mut dispatch_account_infos = vec![
         mailbox_outbox_info.clone(),
         dispatch_authority_info.clone(),
         system_program_info.clone(),
@@ -236,106 +245,40 @@ fn send_hello_world(
         unique_message_account_info.clone(),
         dispatched_message_info.clone(),
     ];
-
-    let igp_payment_accounts =
-        if let Some((igp_program_id, igp_account_type)) = storage.interchain_gas_paymaster() {
-            // Account 9: The IGP program
-            let igp_program_account_info = next_account_info(accounts_iter)?;
-            if igp_program_account_info.key != igp_program_id {
-                return Err(ProgramError::InvalidArgument);
-            }
-
-            // Account 10: The IGP program data.
-            // No verification is performed here, the IGP will do that.
-            let igp_program_data_account_info = next_account_info(accounts_iter)?;
-
-            // Account 11: The gas payment PDA.
-            // No verification is performed here, the IGP will do that.
-            let igp_payment_pda_account_info = next_account_info(accounts_iter)?;
-
-            // Account 12: The configured IGP account.
-            let configured_igp_account_info = next_account_info(accounts_iter)?;
-            if configured_igp_account_info.key != igp_account_type.key() {
-                return Err(ProgramError::InvalidArgument);
-            }
-
-            // Accounts expected by the IGP's `PayForGas` instruction:
-            //
-            // 0. `[executable]` The system program.
-            // 1. `[signer]` The payer.
-            // 2. `[writeable]` The IGP program data.
-            // 3. `[signer]` Unique gas payment account.
-            // 4. `[writeable]` Gas payment PDA.
-            // 5. `[writeable]` The IGP account.
-            // 6. `[]` Overhead IGP account (optional).
-
-            let mut igp_payment_account_metas = vec![
-                AccountMeta::new_readonly(solana_program::system_program::id(), false),
-                AccountMeta::new(*payer_info.key, true),
-                AccountMeta::new(*igp_program_data_account_info.key, false),
-                AccountMeta::new_readonly(*unique_message_account_info.key, true),
-                AccountMeta::new(*igp_payment_pda_account_info.key, false),
-            ];
-            let mut igp_payment_account_infos = vec![
-                system_program_info.clone(),
-                payer_info.clone(),
-                igp_program_data_account_info.clone(),
-                unique_message_account_info.clone(),
-                igp_payment_pda_account_info.clone(),
-            ];
-
-            match igp_account_type {
-                InterchainGasPaymasterType::Igp(_) => {
-                    igp_payment_account_metas
-                        .push(AccountMeta::new(*configured_igp_account_info.key, false));
-                    igp_payment_account_infos.push(configured_igp_account_info.clone());
-                }
-                InterchainGasPaymasterType::OverheadIgp(_) => {
-                    // Account 13: The inner IGP account.
-                    let inner_igp_account_info = next_account_info(accounts_iter)?;
-
-                    // The inner IGP is expected first, then the overhead IGP.
-                    igp_payment_account_metas.extend([
-                        AccountMeta::new(*inner_igp_account_info.key, false),
-                        AccountMeta::new_readonly(*configured_igp_account_info.key, false),
-                    ]);
-                    igp_payment_account_infos.extend([
-                        inner_igp_account_info.clone(),
-                        configured_igp_account_info.clone(),
-                    ]);
-                }
-            };
-
-            Some((igp_payment_account_metas, igp_payment_account_infos))
-        } else {
-            None
-        };
+    dispatch_account_metas.extend(hook_infos.iter().map(|info| AccountMeta {
+        pubkey: *info.key,
+        is_signer: info.is_signer,
+        is_writable: info.is_writable,
+    }));
+    dispatch_account_infos.extend_from_slice(hook_infos);
 
     let dispatch_authority_seeds: &[&[u8]] =
         mailbox_message_dispatch_authority_pda_seeds!(expected_dispatch_authority_bump);
 
-    if let Some((igp_payment_account_metas, igp_payment_account_infos)) = igp_payment_accounts {
-        // Dispatch the message and pay for gas.
+    if storage.interchain_gas_paymaster().is_some() {
+        // Dispatch the message and pay for gas with the configured IGP hook.
         storage.dispatch_with_gas(
             program_id,
             dispatch_authority_seeds,
             hello_world.destination,
             hello_world.message.into(),
             HANDLE_GAS_AMOUNT,
+            hello_world.hook_account_counts,
             dispatch_account_metas,
-            dispatch_account_infos,
-            igp_payment_account_metas,
-            &igp_payment_account_infos,
+            &dispatch_account_infos,
         )?;
     } else {
-        // Dispatch the message.
-        storage.dispatch(
+        // Dispatch the message, calling only the Mailbox's configured hooks.
+        storage.dispatch_with_hook(
             program_id,
             dispatch_authority_seeds,
             hello_world.destination,
             hello_world.message.into(),
+            vec![],
+            None,
+            hello_world.hook_account_counts,
             dispatch_account_metas,
-            dispatch_account_infos,
+            &dispatch_account_infos,
         )?;
     }
 
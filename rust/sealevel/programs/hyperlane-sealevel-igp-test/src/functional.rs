@@ -1,4 +1,5 @@
-use hyperlane_core::H256;
+use borsh::BorshSerialize;
+use hyperlane_core::{Encode, HyperlaneMessage, H256};
 
 use std::collections::HashMap;
 
@@ -10,7 +11,7 @@ use solana_program::{
 };
 use solana_program_test::*;
 use solana_sdk::{
-    instruction::InstructionError, signature::Signature, signature::Signer,
+    account::Account, instruction::InstructionError, signature::Signature, signature::Signer,
     signer::keypair::Keypair, transaction::TransactionError,
 };
 
@@ -21,21 +22,26 @@ use hyperlane_test_utils::{
 use serializable_account_meta::SimulationReturnData;
 
 use access_control::AccessControl;
-use account_utils::{AccountData, DiscriminatorPrefixed, DiscriminatorPrefixedData};
+use account_utils::{AccountData, DiscriminatorPrefixed, DiscriminatorPrefixedData, SizedData};
 use hyperlane_sealevel_igp::{
     accounts::{
-        GasOracle, GasPaymentAccount, GasPaymentData, Igp, IgpAccount, OverheadIgp,
-        OverheadIgpAccount, ProgramData, ProgramDataAccount, RemoteGasData, SOL_DECIMALS,
-        TOKEN_EXCHANGE_RATE_SCALE,
+        GasOracle, GasPaymentAccount, GasPaymentData, GasPrice, GasPriceAccount, Igp, IgpAccount,
+        OverheadIgp, OverheadIgpAccount, PriceAccountGasOracle, ProgramData, ProgramDataAccount,
+        RemoteGasData, SOL_DECIMALS, TOKEN_EXCHANGE_RATE_SCALE,
     },
     error::Error as IgpError,
     igp_gas_payment_pda_seeds, igp_pda_seeds, igp_program_data_pda_seeds,
     instruction::{
-        GasOracleConfig, GasOverheadConfig, InitIgp, InitOverheadIgp,
-        Instruction as IgpInstruction, PayForGas, QuoteGasPayment,
+        init_gas_price_account_instruction, set_gas_price_instruction, GasOracleConfig,
+        GasOverheadConfig, InitIgp, InitOverheadIgp, Instruction as IgpInstruction, PayForGas,
+        QuoteGasPayment,
     },
     overhead_igp_pda_seeds,
-    processor::process_instruction as igp_process_instruction,
+    processor::{process_instruction as igp_process_instruction, DEFAULT_HOOK_GAS_AMOUNT},
+};
+use hyperlane_sealevel_post_dispatch_hook_interface::{
+    PostDispatchHookInstruction, PostDispatchInstruction, QuoteDispatchInstruction,
+    StandardHookMetadata,
 };
 
 const TEST_DESTINATION_DOMAIN: u32 = 11111;
@@ -56,6 +62,41 @@ async fn setup_client() -> (BanksClient, Keypair) {
     (banks_client, payer)
 }
 
+/// Sets up a client with a mock price account owned by `price_account_owner`,
+/// warping to `current_slot`.
+async fn setup_client_with_price_account(
+    price_account: Pubkey,
+    price_account_owner: Pubkey,
+    gas_price: GasPrice,
+    current_slot: u64,
+) -> (BanksClient, Keypair) {
+    let program_id = igp_program_id();
+    let mut program_test = ProgramTest::new(
+        "hyperlane_sealevel_igp",
+        program_id,
+        processor!(igp_process_instruction),
+    );
+
+    let price_account_data = GasPriceAccount::from(DiscriminatorPrefixed::new(gas_price));
+    let mut data = vec![0; price_account_data.size()];
+    price_account_data.store_in_slice(&mut data).unwrap();
+    program_test.add_account(
+        price_account,
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: price_account_owner,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    let mut context = program_test.start_with_context().await;
+    context.warp_to_slot(current_slot).unwrap();
+
+    (context.banks_client, context.payer)
+}
+
 async fn initialize(
     banks_client: &mut BanksClient,
     payer: &Keypair,
@@ -742,6 +783,27 @@ async fn quote_gas_payment(
     gas_amount: u64,
     igp_key: Pubkey,
     overhead_igp_key: Option<Pubkey>,
+) -> Result<u64, BanksClientError> {
+    quote_gas_payment_with_price_account(
+        banks_client,
+        payer,
+        destination_domain,
+        gas_amount,
+        igp_key,
+        overhead_igp_key,
+        None,
+    )
+    .await
+}
+
+async fn quote_gas_payment_with_price_account(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    destination_domain: u32,
+    gas_amount: u64,
+    igp_key: Pubkey,
+    overhead_igp_key: Option<Pubkey>,
+    price_account: Option<Pubkey>,
 ) -> Result<u64, BanksClientError> {
     let mut accounts = vec![
         AccountMeta::new_readonly(system_program::id(), false),
@@ -750,6 +812,9 @@ async fn quote_gas_payment(
     if let Some(overhead_igp_key) = overhead_igp_key {
         accounts.push(AccountMeta::new_readonly(overhead_igp_key, false));
     }
+    if let Some(price_account) = price_account {
+        accounts.push(AccountMeta::new_readonly(price_account, false));
+    }
 
     let instruction = Instruction::new_with_borsh(
         igp_program_id(),
@@ -976,6 +1041,30 @@ async fn pay_for_gas(
     destination_domain: u32,
     gas_amount: u64,
     message_id: H256,
+) -> Result<(Pubkey, Keypair, Signature), BanksClientError> {
+    pay_for_gas_with_price_account(
+        banks_client,
+        payer,
+        igp,
+        overhead_igp,
+        None,
+        destination_domain,
+        gas_amount,
+        message_id,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn pay_for_gas_with_price_account(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    igp: Pubkey,
+    overhead_igp: Option<Pubkey>,
+    price_account: Option<Pubkey>,
+    destination_domain: u32,
+    gas_amount: u64,
+    message_id: H256,
 ) -> Result<(Pubkey, Keypair, Signature), BanksClientError> {
     let program_id = igp_program_id();
     let unique_payment_account = Keypair::new();
@@ -993,6 +1082,7 @@ async fn pay_for_gas(
     // 4. `[writeable]` Gas payment PDA.
     // 5. `[writeable]` The IGP account.
     // 6. `[]` Overhead IGP account (optional).
+    // 7. `[]` The price account (optional).
     let mut accounts = vec![
         AccountMeta::new_readonly(system_program::id(), false),
         AccountMeta::new(payer.pubkey(), true),
@@ -1004,6 +1094,9 @@ async fn pay_for_gas(
     if let Some(overhead_igp) = overhead_igp {
         accounts.push(AccountMeta::new_readonly(overhead_igp, false));
     }
+    if let Some(price_account) = price_account {
+        accounts.push(AccountMeta::new_readonly(price_account, false));
+    }
 
     let instruction = Instruction::new_with_borsh(
         program_id,
@@ -1271,6 +1364,603 @@ async fn test_pay_for_gas_errors_if_no_gas_oracle() {
     );
 }
 
+// ============ Price account gas oracle ============
+
+const TEST_PRICE_ACCOUNT_SLOT: u64 = 100;
+const TEST_MAX_STALENESS_SLOTS: u64 = 10;
+
+/// Sets up a client with a mock price account updated at `last_updated_slot`,
+/// and IGPs whose gas oracle for the test destination reads from it.
+async fn setup_price_account_gas_oracle(
+    last_updated_slot: u64,
+    gas_overhead: Option<u64>,
+) -> (BanksClient, Keypair, Pubkey, Pubkey, Pubkey) {
+    let price_account = Pubkey::new_unique();
+    let price_account_owner = Pubkey::new_unique();
+
+    let (mut banks_client, payer) = setup_client_with_price_account(
+        price_account,
+        price_account_owner,
+        GasPrice {
+            updater: Pubkey::new_unique(),
+            // 0.2 exchange rate (remote token less valuable)
+            token_exchange_rate: (TOKEN_EXCHANGE_RATE_SCALE / 5),
+            gas_price: 150u128,
+            last_updated_slot,
+        },
+        TEST_PRICE_ACCOUNT_SLOT,
+    )
+    .await;
+
+    initialize(&mut banks_client, &payer).await.unwrap();
+
+    let (igp_key, overhead_igp_key) = setup_test_igps(
+        &mut banks_client,
+        &payer,
+        TEST_DESTINATION_DOMAIN,
+        GasOracle::PriceAccount(PriceAccountGasOracle {
+            price_account,
+            price_account_owner,
+            max_staleness_slots: TEST_MAX_STALENESS_SLOTS,
+            token_decimals: LOCAL_DECIMALS,
+        }),
+        gas_overhead,
+    )
+    .await;
+
+    (
+        banks_client,
+        payer,
+        igp_key,
+        overhead_igp_key,
+        price_account,
+    )
+}
+
+#[tokio::test]
+async fn test_quote_gas_payment_with_price_account() {
+    let (mut banks_client, payer, igp_key, overhead_igp_key, price_account) =
+        setup_price_account_gas_oracle(
+            TEST_PRICE_ACCOUNT_SLOT - TEST_MAX_STALENESS_SLOTS,
+            Some(TEST_GAS_OVERHEAD_AMOUNT),
+        )
+        .await;
+
+    // 300,000 destination gas
+    // 150 gas price
+    // 300,000 * 150 = 45000000 (0.045 remote tokens w/ 9 decimals)
+    // Using the 0.2 token exchange rate from the price account:
+    // 45000000 * 0.2 = 9000000 (0.009 local tokens w/ 9 decimals)
+    let expected_quote = 9000000u64;
+
+    assert_eq!(
+        quote_gas_payment_with_price_account(
+            &mut banks_client,
+            &payer,
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+            igp_key,
+            None,
+            Some(price_account),
+        )
+        .await
+        .unwrap(),
+        expected_quote,
+    );
+
+    // The price account is expected after the overhead IGP.
+    assert_eq!(
+        quote_gas_payment_with_price_account(
+            &mut banks_client,
+            &payer,
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT - TEST_GAS_OVERHEAD_AMOUNT,
+            igp_key,
+            Some(overhead_igp_key),
+            Some(price_account),
+        )
+        .await
+        .unwrap(),
+        expected_quote,
+    );
+}
+
+#[tokio::test]
+async fn test_pay_for_gas_with_price_account() {
+    let (mut banks_client, payer, igp_key, overhead_igp_key, price_account) =
+        setup_price_account_gas_oracle(TEST_PRICE_ACCOUNT_SLOT, Some(TEST_GAS_OVERHEAD_AMOUNT))
+            .await;
+    let message_id = H256::random();
+    let gas_amount = TEST_GAS_AMOUNT - TEST_GAS_OVERHEAD_AMOUNT;
+
+    let igp_balance_before = banks_client.get_balance(igp_key).await.unwrap();
+
+    let (gas_payment_pda_key, unique_payment_account, payment_tx_signature) =
+        pay_for_gas_with_price_account(
+            &mut banks_client,
+            &payer,
+            igp_key,
+            Some(overhead_igp_key),
+            Some(price_account),
+            TEST_DESTINATION_DOMAIN,
+            gas_amount,
+            message_id,
+        )
+        .await
+        .unwrap();
+
+    let igp_balance_after = banks_client.get_balance(igp_key).await.unwrap();
+    assert_eq!(igp_balance_after - igp_balance_before, 9000000u64);
+
+    assert_gas_payment(
+        &mut banks_client,
+        igp_key,
+        payment_tx_signature,
+        unique_payment_account.pubkey(),
+        gas_payment_pda_key,
+        TEST_DESTINATION_DOMAIN,
+        TEST_GAS_AMOUNT,
+        9000000u64,
+        message_id,
+        0,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_pay_for_gas_with_price_account_errors_if_stale() {
+    let (mut banks_client, payer, igp_key, _overhead_igp_key, price_account) =
+        setup_price_account_gas_oracle(
+            TEST_PRICE_ACCOUNT_SLOT - TEST_MAX_STALENESS_SLOTS - 1,
+            None,
+        )
+        .await;
+
+    assert_transaction_error(
+        quote_gas_payment_with_price_account(
+            &mut banks_client,
+            &payer,
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+            igp_key,
+            None,
+            Some(price_account),
+        )
+        .await,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(IgpError::StaleGasPrice as u32),
+        ),
+    );
+
+    assert_transaction_error(
+        pay_for_gas_with_price_account(
+            &mut banks_client,
+            &payer,
+            igp_key,
+            None,
+            Some(price_account),
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+            H256::random(),
+        )
+        .await,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(IgpError::StaleGasPrice as u32),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_pay_for_gas_with_price_account_errors_if_price_account_invalid() {
+    let (mut banks_client, payer, igp_key, overhead_igp_key, _price_account) =
+        setup_price_account_gas_oracle(TEST_PRICE_ACCOUNT_SLOT, None).await;
+
+    // No price account
+    assert_transaction_error(
+        pay_for_gas(
+            &mut banks_client,
+            &payer,
+            igp_key,
+            None,
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+            H256::random(),
+        )
+        .await,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+    );
+
+    // The overhead IGP in place of the price account
+    assert_transaction_error(
+        pay_for_gas(
+            &mut banks_client,
+            &payer,
+            igp_key,
+            Some(overhead_igp_key),
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+            H256::random(),
+        )
+        .await,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
+
+#[tokio::test]
+async fn test_pay_for_gas_with_price_account_errors_if_wrong_owner() {
+    let price_account = Pubkey::new_unique();
+
+    // The price account is owned by a different program than the gas oracle expects.
+    let (mut banks_client, payer) = setup_client_with_price_account(
+        price_account,
+        Pubkey::new_unique(),
+        GasPrice {
+            updater: Pubkey::new_unique(),
+            token_exchange_rate: TOKEN_EXCHANGE_RATE_SCALE,
+            gas_price: 1u128,
+            last_updated_slot: TEST_PRICE_ACCOUNT_SLOT,
+        },
+        TEST_PRICE_ACCOUNT_SLOT,
+    )
+    .await;
+
+    initialize(&mut banks_client, &payer).await.unwrap();
+
+    let (igp_key, _overhead_igp_key) = setup_test_igps(
+        &mut banks_client,
+        &payer,
+        TEST_DESTINATION_DOMAIN,
+        GasOracle::PriceAccount(PriceAccountGasOracle {
+            price_account,
+            price_account_owner: Pubkey::new_unique(),
+            max_staleness_slots: TEST_MAX_STALENESS_SLOTS,
+            token_decimals: LOCAL_DECIMALS,
+        }),
+        None,
+    )
+    .await;
+
+    assert_transaction_error(
+        pay_for_gas_with_price_account(
+            &mut banks_client,
+            &payer,
+            igp_key,
+            None,
+            Some(price_account),
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+            H256::random(),
+        )
+        .await,
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId),
+    );
+}
+
+// ============ InitGasPriceAccount & SetGasPrice ============
+
+async fn initialize_gas_price_account(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    updater: Pubkey,
+) -> Result<Pubkey, BanksClientError> {
+    let (instruction, price_account) = init_gas_price_account_instruction(
+        igp_program_id(),
+        payer.pubkey(),
+        H256::random(),
+        updater,
+    )
+    .unwrap();
+
+    process_instruction(banks_client, instruction, payer, &[payer]).await?;
+
+    Ok(price_account)
+}
+
+async fn set_gas_price(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    price_account: Pubkey,
+    updater: &Keypair,
+    token_exchange_rate: u128,
+    gas_price: u128,
+) -> Result<Signature, BanksClientError> {
+    let instruction = set_gas_price_instruction(
+        igp_program_id(),
+        price_account,
+        updater.pubkey(),
+        token_exchange_rate,
+        gas_price,
+    )
+    .unwrap();
+
+    process_instruction(banks_client, instruction, payer, &[payer, updater]).await
+}
+
+async fn fetch_gas_price(banks_client: &mut BanksClient, price_account: Pubkey) -> GasPrice {
+    let account = banks_client
+        .get_account(price_account)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, igp_program_id());
+
+    GasPriceAccount::fetch_data(&mut &account.data[..])
+        .unwrap()
+        .unwrap()
+        .data
+}
+
+#[tokio::test]
+async fn test_initialize_gas_price_account() {
+    let (mut banks_client, payer) = setup_client().await;
+
+    let updater = Pubkey::new_unique();
+    let price_account = initialize_gas_price_account(&mut banks_client, &payer, updater)
+        .await
+        .unwrap();
+
+    // The price account is stale until the updater first sets a gas price.
+    assert_eq!(
+        fetch_gas_price(&mut banks_client, price_account).await,
+        GasPrice {
+            updater,
+            ..GasPrice::default()
+        },
+    );
+}
+
+#[tokio::test]
+async fn test_set_gas_price() {
+    let (mut banks_client, payer) = setup_client().await;
+
+    initialize(&mut banks_client, &payer).await.unwrap();
+
+    let updater = Keypair::new();
+    let price_account = initialize_gas_price_account(&mut banks_client, &payer, updater.pubkey())
+        .await
+        .unwrap();
+
+    let (igp_key, _overhead_igp_key) = setup_test_igps(
+        &mut banks_client,
+        &payer,
+        TEST_DESTINATION_DOMAIN,
+        GasOracle::PriceAccount(PriceAccountGasOracle {
+            price_account,
+            price_account_owner: igp_program_id(),
+            max_staleness_slots: TEST_MAX_STALENESS_SLOTS,
+            token_decimals: LOCAL_DECIMALS,
+        }),
+        None,
+    )
+    .await;
+
+    set_gas_price(
+        &mut banks_client,
+        &payer,
+        price_account,
+        &updater,
+        TOKEN_EXCHANGE_RATE_SCALE / 5,
+        150u128,
+    )
+    .await
+    .unwrap();
+
+    let gas_price = fetch_gas_price(&mut banks_client, price_account).await;
+    assert_eq!(gas_price.updater, updater.pubkey());
+    assert_eq!(gas_price.token_exchange_rate, TOKEN_EXCHANGE_RATE_SCALE / 5);
+    assert_eq!(gas_price.gas_price, 150u128);
+    assert!(gas_price.last_updated_slot > 0);
+
+    // Same expected quote as `test_quote_gas_payment_with_price_account`.
+    assert_eq!(
+        quote_gas_payment_with_price_account(
+            &mut banks_client,
+            &payer,
+            TEST_DESTINATION_DOMAIN,
+            TEST_GAS_AMOUNT,
+            igp_key,
+            None,
+            Some(price_account),
+        )
+        .await
+        .unwrap(),
+        9000000u64,
+    );
+}
+
+#[tokio::test]
+async fn test_set_gas_price_errors_if_not_updater() {
+    let (mut banks_client, payer) = setup_client().await;
+
+    let updater = Pubkey::new_unique();
+    let price_account = initialize_gas_price_account(&mut banks_client, &payer, updater)
+        .await
+        .unwrap();
+
+    let non_updater = Keypair::new();
+    assert_transaction_error(
+        set_gas_price(
+            &mut banks_client,
+            &payer,
+            price_account,
+            &non_updater,
+            TOKEN_EXCHANGE_RATE_SCALE,
+            1u128,
+        )
+        .await,
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
+    );
+}
+
+#[tokio::test]
+async fn test_set_gas_price_errors_if_price_account_not_owned_by_program() {
+    let price_account = Pubkey::new_unique();
+    let updater = Keypair::new();
+
+    let (mut banks_client, payer) = setup_client_with_price_account(
+        price_account,
+        Pubkey::new_unique(),
+        GasPrice {
+            updater: updater.pubkey(),
+            ..GasPrice::default()
+        },
+        TEST_PRICE_ACCOUNT_SLOT,
+    )
+    .await;
+
+    assert_transaction_error(
+        set_gas_price(
+            &mut banks_client,
+            &payer,
+            price_account,
+            &updater,
+            TOKEN_EXCHANGE_RATE_SCALE,
+            1u128,
+        )
+        .await,
+        TransactionError::InstructionError(0, InstructionError::IncorrectProgramId),
+    );
+}
+
+// ============ PostDispatch & QuoteDispatch hooks ============
+
+fn test_hook_message() -> HyperlaneMessage {
+    HyperlaneMessage {
+        destination: TEST_DESTINATION_DOMAIN,
+        body: vec![1, 2, 3, 4],
+        ..HyperlaneMessage::default()
+    }
+}
+
+async fn run_post_dispatch_hook_tests(metadata: Vec<u8>, expected_gas_amount: u64) {
+    let program_id = igp_program_id();
+    let (mut banks_client, payer) = setup_client().await;
+    let message = test_hook_message();
+
+    initialize(&mut banks_client, &payer).await.unwrap();
+
+    let (igp_key, _) = setup_test_igps(
+        &mut banks_client,
+        &payer,
+        TEST_DESTINATION_DOMAIN,
+        GasOracle::RemoteGasData(RemoteGasData {
+            token_exchange_rate: TOKEN_EXCHANGE_RATE_SCALE,
+            gas_price: 1u128,
+            token_decimals: LOCAL_DECIMALS,
+        }),
+        None,
+    )
+    .await;
+
+    // The hook quote is the same as quoting the expected gas amount.
+    let quote_instruction = Instruction::new_with_bytes(
+        program_id,
+        &PostDispatchHookInstruction::QuoteDispatch(QuoteDispatchInstruction::new(
+            metadata.clone(),
+            message.to_vec(),
+        ))
+        .encode()
+        .unwrap(),
+        vec![
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(igp_key, false),
+        ],
+    );
+    let quote = simulate_instruction::<SimulationReturnData<u64>>(
+        &mut banks_client,
+        &payer,
+        quote_instruction,
+    )
+    .await
+    .unwrap()
+    .unwrap()
+    .return_data;
+    assert_eq!(
+        quote,
+        quote_gas_payment(
+            &mut banks_client,
+            &payer,
+            TEST_DESTINATION_DOMAIN,
+            expected_gas_amount,
+            igp_key,
+            None,
+        )
+        .await
+        .unwrap(),
+    );
+
+    let unique_payment_account = Keypair::new();
+    let (igp_program_data_key, _) =
+        Pubkey::find_program_address(igp_program_data_pda_seeds!(), &program_id);
+    let (gas_payment_pda_key, _) = Pubkey::find_program_address(
+        igp_gas_payment_pda_seeds!(unique_payment_account.pubkey()),
+        &program_id,
+    );
+
+    let igp_balance_before = banks_client.get_balance(igp_key).await.unwrap();
+
+    // The accounts are the same as `PayForGas`.
+    let post_dispatch_instruction = Instruction::new_with_bytes(
+        program_id,
+        &PostDispatchHookInstruction::PostDispatch(PostDispatchInstruction::new(
+            metadata,
+            message.to_vec(),
+        ))
+        .encode()
+        .unwrap(),
+        vec![
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(igp_program_data_key, false),
+            AccountMeta::new_readonly(unique_payment_account.pubkey(), true),
+            AccountMeta::new(gas_payment_pda_key, false),
+            AccountMeta::new(igp_key, false),
+        ],
+    );
+    let payment_tx_signature = process_instruction(
+        &mut banks_client,
+        post_dispatch_instruction,
+        &payer,
+        &[&payer, &unique_payment_account],
+    )
+    .await
+    .unwrap();
+
+    let igp_balance_after = banks_client.get_balance(igp_key).await.unwrap();
+    assert_eq!(igp_balance_after - igp_balance_before, quote);
+
+    assert_gas_payment(
+        &mut banks_client,
+        igp_key,
+        payment_tx_signature,
+        unique_payment_account.pubkey(),
+        gas_payment_pda_key,
+        TEST_DESTINATION_DOMAIN,
+        expected_gas_amount,
+        quote,
+        message.id(),
+        0,
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_post_dispatch_hook_with_gas_limit() {
+    let metadata = StandardHookMetadata {
+        gas_limit: Some(TEST_GAS_AMOUNT),
+    }
+    .try_to_vec()
+    .unwrap();
+    run_post_dispatch_hook_tests(metadata, TEST_GAS_AMOUNT).await;
+}
+
+#[tokio::test]
+async fn test_post_dispatch_hook_with_empty_metadata() {
+    run_post_dispatch_hook_tests(vec![], DEFAULT_HOOK_GAS_AMOUNT).await;
+}
+
 // ============ Claim ============
 
 #[tokio::test]
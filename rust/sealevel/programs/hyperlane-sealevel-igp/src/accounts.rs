@@ -5,7 +5,9 @@ use std::{cmp::Ordering, collections::HashMap};
 use access_control::AccessControl;
 use account_utils::{AccountData, DiscriminatorData, DiscriminatorPrefixed, SizedData};
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::{clock::Slot, program_error::ProgramError, pubkey::Pubkey};
+use solana_program::{
+    account_info::AccountInfo, clock::Slot, program_error::ProgramError, pubkey::Pubkey,
+};
 
 use hyperlane_core::{H256, U256};
 
@@ -45,6 +47,8 @@ impl InterchainGasPaymasterType {
 pub enum GasOracle {
     /// Remote gas data stored directly in the variant data.
     RemoteGasData(RemoteGasData),
+    /// Remote gas data read from a separate price account.
+    PriceAccount(PriceAccountGasOracle),
     // Future gas oracle variants could include a Pyth type, generalized CPI type, etc.
 }
 
@@ -54,6 +58,116 @@ impl Default for GasOracle {
     }
 }
 
+impl GasOracle {
+    /// Returns the price account the gas oracle reads from, if any.
+    pub fn price_account(&self) -> Option<&Pubkey> {
+        match self {
+            GasOracle::RemoteGasData(_) => None,
+            GasOracle::PriceAccount(oracle) => Some(&oracle.price_account),
+        }
+    }
+
+    /// Gets the remote gas data provided by the gas oracle.
+    /// `price_account_info` must be provided for `PriceAccount` gas oracles,
+    /// whose data must have been updated within the staleness bound as of `current_slot`.
+    pub fn remote_gas_data(
+        &self,
+        price_account_info: Option<&AccountInfo>,
+        current_slot: Slot,
+    ) -> Result<RemoteGasData, ProgramError> {
+        match self {
+            GasOracle::RemoteGasData(remote_gas_data) => Ok(remote_gas_data.clone()),
+            GasOracle::PriceAccount(oracle) => {
+                let price_account_info =
+                    price_account_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+                oracle.remote_gas_data(price_account_info, current_slot)
+            }
+        }
+    }
+}
+
+/// A gas oracle that reads the token exchange rate and gas price from a price
+/// account, which is expected to be kept up to date by an off-chain updater.
+/// Price accounts owned by this program are initialized with `InitGasPriceAccount`
+/// and written by their updater with `SetGasPrice`, e.g. by the gas oracle updater
+/// agent. Price accounts owned by other programs must share the `GasPriceAccount`
+/// layout.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PriceAccountGasOracle {
+    /// The price account, whose data is a `GasPriceAccount`.
+    pub price_account: Pubkey,
+    /// The program expected to own the price account.
+    pub price_account_owner: Pubkey,
+    /// The maximum number of slots since the price account was last updated
+    /// for its data to be used.
+    pub max_staleness_slots: u64,
+    /// The number of decimals for the remote token.
+    pub token_decimals: u8,
+}
+
+impl PriceAccountGasOracle {
+    /// Reads the remote gas data from the price account.
+    /// Errors if the price account is not the configured one or its data is stale.
+    pub fn remote_gas_data(
+        &self,
+        price_account_info: &AccountInfo,
+        current_slot: Slot,
+    ) -> Result<RemoteGasData, ProgramError> {
+        if price_account_info.key != &self.price_account {
+            return Err(ProgramError::InvalidArgument);
+        }
+        if price_account_info.owner != &self.price_account_owner {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        let gas_price = GasPriceAccount::fetch_data(&mut &price_account_info.data.borrow()[..])?
+            .ok_or(ProgramError::UninitializedAccount)?;
+        if current_slot.saturating_sub(gas_price.last_updated_slot) > self.max_staleness_slots {
+            return Err(Error::StaleGasPrice.into());
+        }
+
+        Ok(RemoteGasData {
+            token_exchange_rate: gas_price.token_exchange_rate,
+            gas_price: gas_price.gas_price,
+            token_decimals: self.token_decimals,
+        })
+    }
+}
+
+/// A price account read by `PriceAccount` gas oracles.
+pub type GasPriceAccount = AccountData<DiscriminatorPrefixed<GasPrice>>;
+
+impl DiscriminatorData for GasPrice {
+    const DISCRIMINATOR: [u8; 8] = *b"GASPRICE";
+}
+
+/// Price account data.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Default, Clone)]
+pub struct GasPrice {
+    /// The account allowed to set the gas price of a price account owned by
+    /// this program.
+    pub updater: Pubkey,
+    /// The token exchange rate for the remote token, adjusted by the
+    /// TOKEN_EXCHANGE_RATE_SCALE.
+    pub token_exchange_rate: u128,
+    /// The gas price for the remote chain.
+    pub gas_price: u128,
+    /// The slot the price account was last updated in.
+    pub last_updated_slot: Slot,
+}
+
+impl SizedData for GasPrice {
+    fn size(&self) -> usize {
+        // 32 for updater
+        // 16 for token_exchange_rate
+        // 16 for gas_price
+        // 8 for last_updated_slot
+        32 + 16 + 16 + 8
+    }
+}
+
 /// The account for the program's global data.
 pub type ProgramDataAccount = AccountData<DiscriminatorPrefixed<ProgramData>>;
 
@@ -118,9 +232,16 @@ impl OverheadIgp {
         destination_domain: u32,
         gas_amount: u64,
         inner_igp: &Igp,
-    ) -> Result<u64, Error> {
+        price_account_info: Option<&AccountInfo>,
+        current_slot: Slot,
+    ) -> Result<u64, ProgramError> {
         let total_gas_amount = self.gas_overhead(destination_domain) + gas_amount;
-        inner_igp.quote_gas_payment(destination_domain, total_gas_amount)
+        inner_igp.quote_gas_payment(
+            destination_domain,
+            total_gas_amount,
+            price_account_info,
+            current_slot,
+        )
     }
 }
 
@@ -182,37 +303,28 @@ impl SizedData for Igp {
 }
 
 impl Igp {
-    /// Quotes a gas payment.
+    /// Gets the gas oracle for the destination domain.
     /// Returns an error if a gas oracle is not set for the destination domain.
+    pub fn gas_oracle(&self, destination_domain: u32) -> Result<&GasOracle, Error> {
+        self.gas_oracles
+            .get(&destination_domain)
+            .ok_or(Error::NoGasOracleSetForDestinationDomain)
+    }
+
+    /// Quotes a gas payment.
+    /// Returns an error if a gas oracle is not set for the destination domain,
+    /// or if the gas oracle's price account is required but missing or stale.
     pub fn quote_gas_payment(
         &self,
         destination_domain: u32,
         gas_amount: u64,
-    ) -> Result<u64, Error> {
-        let oracle = self
-            .gas_oracles
-            .get(&destination_domain)
-            .ok_or(Error::NoGasOracleSetForDestinationDomain)?;
-        let GasOracle::RemoteGasData(RemoteGasData {
-            token_exchange_rate,
-            gas_price,
-            token_decimals,
-        }) = oracle;
-
-        // Arithmetic is done using U256 to avoid overflows.
-
-        // The total cost quoted in the destination chain's native token.
-        let destination_gas_cost = U256::from(gas_amount) * U256::from(*gas_price);
-
-        // Convert to the local native token (decimals not yet accounted for).
-        let origin_cost = (destination_gas_cost * U256::from(*token_exchange_rate))
-            / U256::from(TOKEN_EXCHANGE_RATE_SCALE);
-
-        // Convert from the remote token's decimals to the local token's decimals.
-        let origin_cost = convert_decimals(origin_cost, *token_decimals, SOL_DECIMALS);
-
-        // Panics if an overflow occurs.
-        Ok(origin_cost.as_u64())
+        price_account_info: Option<&AccountInfo>,
+        current_slot: Slot,
+    ) -> Result<u64, ProgramError> {
+        let remote_gas_data = self
+            .gas_oracle(destination_domain)?
+            .remote_gas_data(price_account_info, current_slot)?;
+        Ok(remote_gas_data.quote_gas_payment(gas_amount))
     }
 }
 
@@ -244,6 +356,26 @@ pub struct RemoteGasData {
     pub token_decimals: u8,
 }
 
+impl RemoteGasData {
+    /// Quotes a gas payment in lamports for the given amount of destination gas.
+    pub fn quote_gas_payment(&self, gas_amount: u64) -> u64 {
+        // Arithmetic is done using U256 to avoid overflows.
+
+        // The total cost quoted in the destination chain's native token.
+        let destination_gas_cost = U256::from(gas_amount) * U256::from(self.gas_price);
+
+        // Convert to the local native token (decimals not yet accounted for).
+        let origin_cost = (destination_gas_cost * U256::from(self.token_exchange_rate))
+            / U256::from(TOKEN_EXCHANGE_RATE_SCALE);
+
+        // Convert from the remote token's decimals to the local token's decimals.
+        let origin_cost = convert_decimals(origin_cost, self.token_decimals, SOL_DECIMALS);
+
+        // Panics if an overflow occurs.
+        origin_cost.as_u64()
+    }
+}
+
 /// A discriminator used to easily identify gas payment accounts.
 /// This is the first 8 bytes of the account data.
 pub const GAS_PAYMENT_DISCRIMINATOR: &[u8; 8] = b"GASPAYMT";
@@ -9,6 +9,12 @@ pub enum Error {
     /// No gas oracle set for destination domain.
     #[error("No gas oracle set for destination domain")]
     NoGasOracleSetForDestinationDomain = 1,
+    /// Message decoding error.
+    #[error("Message decoding error")]
+    DecodeError = 2,
+    /// The gas oracle's price account has not been updated recently enough.
+    #[error("Stale gas price")]
+    StaleGasPrice = 3,
 }
 
 impl From<Error> for ProgramError {
@@ -11,7 +11,8 @@ use solana_program::{
 
 use crate::{
     accounts::{GasOracle, InterchainGasPaymasterType},
-    igp_gas_payment_pda_seeds, igp_pda_seeds, igp_program_data_pda_seeds, overhead_igp_pda_seeds,
+    igp_gas_payment_pda_seeds, igp_gas_price_pda_seeds, igp_pda_seeds, igp_program_data_pda_seeds,
+    overhead_igp_pda_seeds,
 };
 
 /// The program instructions.
@@ -39,6 +40,10 @@ pub enum Instruction {
     SetGasOracleConfigs(Vec<GasOracleConfig>),
     /// Claims lamports from an IGP, sending them to the IGP's beneficiary.
     Claim,
+    /// Initializes a price account for `PriceAccount` gas oracles.
+    InitGasPriceAccount(InitGasPriceAccount),
+    /// Sets the gas price of a price account. Only the price account's updater.
+    SetGasPrice(SetGasPrice),
 }
 
 /// Initializes an IGP.
@@ -63,6 +68,25 @@ pub struct InitOverheadIgp {
     pub inner: Pubkey,
 }
 
+/// Initializes a price account.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct InitGasPriceAccount {
+    /// A salt used for deriving the price account PDA.
+    pub salt: H256,
+    /// The account allowed to set the gas price.
+    pub updater: Pubkey,
+}
+
+/// Sets the gas price of a price account.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct SetGasPrice {
+    /// The token exchange rate for the remote token, adjusted by the
+    /// TOKEN_EXCHANGE_RATE_SCALE.
+    pub token_exchange_rate: u128,
+    /// The gas price for the remote chain.
+    pub gas_price: u128,
+}
+
 /// Pays for gas.
 #[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
 pub struct PayForGas {
@@ -268,6 +292,7 @@ pub fn pay_for_gas_instruction(
     payer: Pubkey,
     igp: Pubkey,
     overhead_igp: Option<Pubkey>,
+    price_account: Option<Pubkey>,
     unique_gas_payment_account_pubkey: Pubkey,
     message_id: H256,
     destination_domain: u32,
@@ -296,6 +321,7 @@ pub fn pay_for_gas_instruction(
     // 4. `[writeable]` Gas payment PDA.
     // 5. `[writeable]` The IGP account.
     // 6. `[]` Overhead IGP account (optional).
+    // 7. `[]` The price account (optional).
     let mut accounts = vec![
         AccountMeta::new_readonly(solana_program::system_program::id(), false),
         AccountMeta::new(payer, true),
@@ -307,6 +333,9 @@ pub fn pay_for_gas_instruction(
     if let Some(overhead_igp) = overhead_igp {
         accounts.push(AccountMeta::new_readonly(overhead_igp, false));
     }
+    if let Some(price_account) = price_account {
+        accounts.push(AccountMeta::new_readonly(price_account, false));
+    }
 
     let instruction = SolanaInstruction {
         program_id,
@@ -400,3 +429,65 @@ pub fn set_beneficiary_instruction(
 
     Ok(instruction)
 }
+
+/// Gets an instruction to initialize a price account.
+pub fn init_gas_price_account_instruction(
+    program_id: Pubkey,
+    payer: Pubkey,
+    salt: H256,
+    updater: Pubkey,
+) -> Result<(SolanaInstruction, Pubkey), ProgramError> {
+    let (price_account, _price_account_bump) =
+        Pubkey::try_find_program_address(igp_gas_price_pda_seeds!(salt), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let ixn = Instruction::InitGasPriceAccount(InitGasPriceAccount { salt, updater });
+
+    // Accounts:
+    // 0. `[executable]` The system program.
+    // 1. `[signer]` The payer account.
+    // 2. `[writeable]` The price account to initialize.
+    let accounts = vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new_readonly(payer, true),
+        AccountMeta::new(price_account, false),
+    ];
+
+    let instruction = SolanaInstruction {
+        program_id,
+        data: ixn.try_to_vec()?,
+        accounts,
+    };
+
+    Ok((instruction, price_account))
+}
+
+/// Gets an instruction to set the gas price of a price account.
+pub fn set_gas_price_instruction(
+    program_id: Pubkey,
+    price_account: Pubkey,
+    updater: Pubkey,
+    token_exchange_rate: u128,
+    gas_price: u128,
+) -> Result<SolanaInstruction, ProgramError> {
+    let ixn = Instruction::SetGasPrice(SetGasPrice {
+        token_exchange_rate,
+        gas_price,
+    });
+
+    // Accounts:
+    // 0. `[writeable]` The price account.
+    // 1. `[signer]` The price account's updater.
+    let accounts = vec![
+        AccountMeta::new(price_account, false),
+        AccountMeta::new_readonly(updater, true),
+    ];
+
+    let instruction = SolanaInstruction {
+        program_id,
+        data: ixn.try_to_vec()?,
+        accounts,
+    };
+
+    Ok(instruction)
+}
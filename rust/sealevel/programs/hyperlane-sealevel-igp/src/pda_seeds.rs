@@ -81,3 +81,23 @@ macro_rules! igp_gas_payment_pda_seeds {
         ]
     }};
 }
+
+/// Gets the PDA seeds for a price account owned by the IGP program,
+/// based upon a salt.
+#[macro_export]
+macro_rules! igp_gas_price_pda_seeds {
+    ($salt:expr) => {{
+        &[b"hyperlane_igp", b"-", b"gas_price", b"-", $salt.as_ref()]
+    }};
+
+    ($salt:expr, $bump_seed:expr) => {{
+        &[
+            b"hyperlane_igp",
+            b"-",
+            b"gas_price",
+            b"-",
+            $salt.as_ref(),
+            &[$bump_seed],
+        ]
+    }};
+}
@@ -1,6 +1,7 @@
 //! Program state processor.
 
 use borsh::{BorshDeserialize, BorshSerialize};
+use hyperlane_core::{Decode, HyperlaneMessage};
 use std::collections::HashMap;
 
 #[cfg(not(feature = "no-entrypoint"))]
@@ -23,21 +24,30 @@ use account_utils::{
     create_pda_account, verify_account_uninitialized, verify_rent_exempt, AccountData,
     DiscriminatorPrefixed, SizedData,
 };
+use hyperlane_sealevel_post_dispatch_hook_interface::{
+    PostDispatchHookInstruction, PostDispatchInstruction, QuoteDispatchInstruction,
+    StandardHookMetadata,
+};
 use serializable_account_meta::SimulationReturnData;
 
 use crate::{
     accounts::{
-        GasPaymentAccount, GasPaymentData, Igp, IgpAccount, OverheadIgp, OverheadIgpAccount,
-        ProgramData, ProgramDataAccount,
+        GasPaymentAccount, GasPaymentData, GasPrice, GasPriceAccount, Igp, IgpAccount, OverheadIgp,
+        OverheadIgpAccount, ProgramData, ProgramDataAccount,
     },
-    igp_gas_payment_pda_seeds, igp_pda_seeds, igp_program_data_pda_seeds,
+    error::Error,
+    igp_gas_payment_pda_seeds, igp_gas_price_pda_seeds, igp_pda_seeds, igp_program_data_pda_seeds,
     instruction::{
-        GasOracleConfig, GasOverheadConfig, InitIgp, InitOverheadIgp,
-        Instruction as IgpInstruction, PayForGas, QuoteGasPayment,
+        GasOracleConfig, GasOverheadConfig, InitGasPriceAccount, InitIgp, InitOverheadIgp,
+        Instruction as IgpInstruction, PayForGas, QuoteGasPayment, SetGasPrice,
     },
     overhead_igp_pda_seeds,
 };
 
+/// The gas amount paid for when the post-dispatch hook metadata doesn't specify
+/// a gas limit, matching the default of the EVM IGP hook.
+pub const DEFAULT_HOOK_GAS_AMOUNT: u64 = 50_000;
+
 #[cfg(not(feature = "no-entrypoint"))]
 entrypoint!(process_instruction);
 
@@ -47,6 +57,18 @@ pub fn process_instruction(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    // First, check if the instruction is one of the post-dispatch hook interface instructions.
+    if let Ok(hook_instruction) = PostDispatchHookInstruction::decode(instruction_data) {
+        return match hook_instruction {
+            PostDispatchHookInstruction::PostDispatch(post_dispatch) => {
+                hook_post_dispatch(program_id, accounts, post_dispatch)
+            }
+            PostDispatchHookInstruction::QuoteDispatch(quote_dispatch) => {
+                hook_quote_dispatch(program_id, accounts, quote_dispatch)
+            }
+        };
+    }
+
     match IgpInstruction::try_from_slice(instruction_data)? {
         IgpInstruction::Init => {
             init(program_id, accounts)?;
@@ -81,6 +103,12 @@ pub fn process_instruction(
         IgpInstruction::SetGasOracleConfigs(configs) => {
             set_gas_oracle_configs(program_id, accounts, configs)?;
         }
+        IgpInstruction::InitGasPriceAccount(data) => {
+            init_gas_price_account(program_id, accounts, data)?;
+        }
+        IgpInstruction::SetGasPrice(data) => {
+            set_gas_price(program_id, accounts, data)?;
+        }
     }
 
     Ok(())
@@ -265,6 +293,8 @@ fn init_igp_variant<T: account_utils::DiscriminatorPrefixedData + SizedData>(
 /// 4. `[writeable]` Gas payment PDA.
 /// 5. `[writeable]` The IGP account.
 /// 6. `[]` Overhead IGP account (optional).
+/// 7. `[]` The price account, required if the destination domain's gas oracle
+///    is a `PriceAccount` oracle. Always the final account.
 fn pay_for_gas(program_id: &Pubkey, accounts: &[AccountInfo], payment: PayForGas) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
@@ -329,10 +359,13 @@ fn pay_for_gas(program_id: &Pubkey, accounts: &[AccountInfo], payment: PayForGas
         return Err(ProgramError::InvalidSeeds);
     }
 
+    let (overhead_igp_info, price_account_info) =
+        split_optional_accounts(&igp, payment.destination_domain, accounts_iter.as_slice())?;
+
     // Account 6: Overhead IGP account (optional).
     // The caller is expected to only provide an overhead IGP they are comfortable
     // with / have configured themselves.
-    let gas_amount = if let Some(overhead_igp_info) = accounts_iter.next() {
+    let gas_amount = if let Some(overhead_igp_info) = overhead_igp_info {
         if overhead_igp_info.owner != program_id {
             return Err(ProgramError::IncorrectProgramId);
         }
@@ -352,7 +385,14 @@ fn pay_for_gas(program_id: &Pubkey, accounts: &[AccountInfo], payment: PayForGas
         payment.gas_amount
     };
 
-    let required_payment = igp.quote_gas_payment(payment.destination_domain, gas_amount)?;
+    // Account 7: The price account (optional).
+    let slot = Clock::get()?.slot;
+    let required_payment = igp.quote_gas_payment(
+        payment.destination_domain,
+        gas_amount,
+        price_account_info,
+        slot,
+    )?;
 
     // Transfer the required payment to the IGP.
     invoke(
@@ -369,7 +409,7 @@ fn pay_for_gas(program_id: &Pubkey, accounts: &[AccountInfo], payment: PayForGas
             gas_amount,
             payment: required_payment,
             unique_gas_payment_pubkey: *unique_gas_payment_account_info.key,
-            slot: Clock::get()?.slot,
+            slot,
         }
         .into(),
     );
@@ -410,6 +450,8 @@ fn pay_for_gas(program_id: &Pubkey, accounts: &[AccountInfo], payment: PayForGas
 /// 0. `[executable]` The system program.
 /// 1. `[]` The IGP account.
 /// 2. `[]` The overhead IGP account (optional).
+/// 3. `[]` The price account, required if the destination domain's gas oracle
+///    is a `PriceAccount` oracle. Always the final account.
 fn quote_gas_payment(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -430,11 +472,15 @@ fn quote_gas_payment(
     if igp_info.owner != program_id {
         return Err(ProgramError::IncorrectProgramId);
     }
+    let igp = IgpAccount::fetch(&mut &igp_info.data.borrow()[..])?.into_inner();
+
+    let (overhead_igp_info, price_account_info) =
+        split_optional_accounts(&igp, payment.destination_domain, accounts_iter.as_slice())?;
 
     // Account 2: Overhead IGP account (optional).
     // The caller is expected to only provide an overhead IGP they are comfortable
     // with / have configured themselves.
-    let gas_amount = if let Some(overhead_igp_info) = accounts_iter.next() {
+    let gas_amount = if let Some(overhead_igp_info) = overhead_igp_info {
         if overhead_igp_info.owner != program_id {
             return Err(ProgramError::IncorrectProgramId);
         }
@@ -451,15 +497,88 @@ fn quote_gas_payment(
         payment.gas_amount
     };
 
-    let igp = IgpAccount::fetch(&mut &igp_info.data.borrow()[..])?.into_inner();
-
-    let required_payment = igp.quote_gas_payment(payment.destination_domain, gas_amount)?;
+    // Account 3: The price account (optional).
+    let required_payment = igp.quote_gas_payment(
+        payment.destination_domain,
+        gas_amount,
+        price_account_info,
+        Clock::get()?.slot,
+    )?;
 
     set_return_data(&SimulationReturnData::new(required_payment).try_to_vec()?);
 
     Ok(())
 }
 
+/// Splits the accounts following the IGP account into the optional overhead IGP
+/// account and the price account. The price account is expected to be the final
+/// account if the destination domain's gas oracle reads from one.
+fn split_optional_accounts<'a, 'b>(
+    igp: &Igp,
+    destination_domain: u32,
+    accounts: &'a [AccountInfo<'b>],
+) -> Result<(Option<&'a AccountInfo<'b>>, Option<&'a AccountInfo<'b>>), ProgramError> {
+    let (accounts, price_account_info) = match igp
+        .gas_oracles
+        .get(&destination_domain)
+        .and_then(|gas_oracle| gas_oracle.price_account())
+    {
+        Some(_) => {
+            let (price_account_info, accounts) = accounts
+                .split_last()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            (accounts, Some(price_account_info))
+        }
+        None => (accounts, None),
+    };
+    Ok((accounts.first(), price_account_info))
+}
+
+/// Gets the message ID, destination domain and gas amount to pay for from
+/// post-dispatch hook metadata and a message.
+fn hook_payment_params(metadata: &[u8], message: &[u8]) -> Result<PayForGas, ProgramError> {
+    let metadata = StandardHookMetadata::decode(metadata)?;
+    let message = HyperlaneMessage::read_from(&mut std::io::Cursor::new(message))
+        .map_err(|_| ProgramError::from(Error::DecodeError))?;
+
+    Ok(PayForGas {
+        message_id: message.id(),
+        destination_domain: message.destination,
+        gas_amount: metadata.gas_limit.unwrap_or(DEFAULT_HOOK_GAS_AMOUNT),
+    })
+}
+
+/// Pays for gas for a dispatched message as a post-dispatch hook.
+///
+/// Accounts are the same as `PayForGas`.
+fn hook_post_dispatch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    post_dispatch: PostDispatchInstruction,
+) -> ProgramResult {
+    let payment = hook_payment_params(&post_dispatch.metadata, &post_dispatch.message)?;
+    pay_for_gas(program_id, accounts, payment)
+}
+
+/// Quotes the payment required by `hook_post_dispatch`.
+///
+/// Accounts are the same as `QuoteGasPayment`.
+fn hook_quote_dispatch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    quote_dispatch: QuoteDispatchInstruction,
+) -> ProgramResult {
+    let payment = hook_payment_params(&quote_dispatch.metadata, &quote_dispatch.message)?;
+    quote_gas_payment(
+        program_id,
+        accounts,
+        QuoteGasPayment {
+            destination_domain: payment.destination_domain,
+            gas_amount: payment.gas_amount,
+        },
+    )
+}
+
 /// Sets the beneficiary of an IGP.
 ///
 /// Accounts:
@@ -668,3 +787,66 @@ fn set_gas_oracle_configs(
 
     Ok(())
 }
+
+/// Initializes a price account owned by this program. Its gas price is stale
+/// until first set by the updater.
+///
+/// Accounts:
+/// 0. `[executable]` The system program.
+/// 1. `[signer]` The payer account.
+/// 2. `[writeable]` The price account to initialize.
+fn init_gas_price_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: InitGasPriceAccount,
+) -> ProgramResult {
+    let price_account_key = init_igp_variant(
+        program_id,
+        accounts,
+        |_bump_seed| {
+            DiscriminatorPrefixed::new(GasPrice {
+                updater: data.updater,
+                ..GasPrice::default()
+            })
+        },
+        igp_gas_price_pda_seeds!(data.salt),
+    )?;
+
+    msg!("Initialized price account: {}", price_account_key);
+
+    Ok(())
+}
+
+/// Sets the gas price of a price account owned by this program.
+///
+/// Accounts:
+/// 0. `[writeable]` The price account.
+/// 1. `[signer]` The price account's updater.
+fn set_gas_price(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: SetGasPrice,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account 0: The price account.
+    let price_account_info = next_account_info(accounts_iter)?;
+    if price_account_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let mut gas_price = GasPriceAccount::fetch_data(&mut &price_account_info.data.borrow()[..])?
+        .ok_or(ProgramError::UninitializedAccount)?;
+
+    // Account 1: The price account's updater.
+    let updater_info = next_account_info(accounts_iter)?;
+    if !updater_info.is_signer || updater_info.key != &gas_price.updater {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    gas_price.token_exchange_rate = data.token_exchange_rate;
+    gas_price.gas_price = data.gas_price;
+    gas_price.last_updated_slot = Clock::get()?.slot;
+    GasPriceAccount::from(gas_price).store(price_account_info, false)?;
+
+    Ok(())
+}
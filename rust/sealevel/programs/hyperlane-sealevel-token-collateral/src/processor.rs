@@ -116,17 +116,11 @@ fn initialize(program_id: &Pubkey, accounts: &[AccountInfo], init: Init) -> Prog
 /// 6.   `[signer]` The token sender and mailbox payer.
 /// 7.   `[signer]` Unique message / gas payment account.
 /// 8.   `[writeable]` Message storage PDA.
-///      ---- If using an IGP ----
-/// 9.   `[executable]` The IGP program.
-/// 10.  `[writeable]` The IGP program data.
-/// 11.  `[writeable]` Gas payment PDA.
-/// 12.  `[]` OPTIONAL - The Overhead IGP program, if the configured IGP is an Overhead IGP.
-/// 13.  `[writeable]` The IGP account.
-///      ---- End if ----
-/// 14.  `[executable]` The SPL token program for the mint.
-/// 15.  `[writeable]` The mint.
-/// 16.  `[writeable]` The token sender's associated token account, from which tokens will be sent.
-/// 17.  `[writeable]` The escrow PDA account.
+/// 9.   `[executable]` The SPL token program for the mint.
+/// 10.  `[writeable]` The mint.
+/// 11.  `[writeable]` The token sender's associated token account, from which tokens will be sent.
+/// 12.  `[writeable]` The escrow PDA account.
+/// 13..N `[??..??]` The Mailbox's post-dispatch hook accounts.
 fn transfer_remote(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -413,6 +413,8 @@ async fn test_transfer_remote() {
                 destination_domain: REMOTE_DOMAIN,
                 recipient: remote_token_recipient,
                 amount_or_id: transfer_amount.into(),
+                // The IGP hook's `PayForGas` accounts
+                hook_account_counts: vec![7],
             })
             .encode()
             .unwrap(),
@@ -425,15 +427,10 @@ async fn test_transfer_remote() {
             // 6.   `[signer]` The token sender and mailbox payer.
             // 7.   `[signer]` Unique message / gas payment account.
             // 8.   `[writeable]` Message storage PDA.
-            //      ---- If using an IGP ----
-            // 9.   `[executable]` The IGP program.
-            // 10.  `[writeable]` The IGP program data.
-            // 11.  `[writeable]` Gas payment PDA.
-            // 12.  `[]` OPTIONAL - The Overhead IGP program, if the configured IGP is an Overhead IGP.
-            // 13.  `[writeable]` The IGP account.
-            //      ---- End if ----
-            // 14.  `[executable]` The system program.
-            // 15.  `[writeable]` The native token collateral PDA account.
+            // 9.   `[executable]` The system program.
+            // 10.  `[writeable]` The native token collateral PDA account.
+            // 11..N `[??..??]` The Mailbox's post-dispatch hook accounts, here the IGP hook:
+            //     `[executable]` The IGP program, followed by its `PayForGas` accounts.
             vec![
                 AccountMeta::new_readonly(solana_program::system_program::id(), false),
                 AccountMeta::new_readonly(spl_noop::id(), false),
@@ -444,13 +441,16 @@ async fn test_transfer_remote() {
                 AccountMeta::new_readonly(token_sender_pubkey, true),
                 AccountMeta::new_readonly(unique_message_account_keypair.pubkey(), true),
                 AccountMeta::new(dispatched_message_key, false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+                AccountMeta::new(hyperlane_token_accounts.native_collateral, false),
                 AccountMeta::new_readonly(igp_accounts.program, false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+                AccountMeta::new(token_sender_pubkey, true),
                 AccountMeta::new(igp_accounts.program_data, false),
+                AccountMeta::new_readonly(unique_message_account_keypair.pubkey(), true),
                 AccountMeta::new(gas_payment_pda_key, false),
-                AccountMeta::new_readonly(igp_accounts.overhead_igp, false),
                 AccountMeta::new(igp_accounts.igp, false),
-                AccountMeta::new_readonly(solana_program::system_program::id(), false),
-                AccountMeta::new(hyperlane_token_accounts.native_collateral, false),
+                AccountMeta::new_readonly(igp_accounts.overhead_igp, false),
             ],
         )],
         Some(&token_sender_pubkey),
@@ -1,8 +1,17 @@
 //! Instructions for the program.
 
-use hyperlane_sealevel_token_lib::instruction::{init_instruction as lib_init_instruction, Init};
+use account_utils::{DiscriminatorData, DiscriminatorEncode};
+use borsh::{BorshDeserialize, BorshSerialize};
+use hyperlane_sealevel_token_lib::{
+    hyperlane_token_pda_seeds,
+    instruction::{init_instruction as lib_init_instruction, Init},
+};
 
-use crate::{hyperlane_token_ata_payer_pda_seeds, hyperlane_token_mint_pda_seeds};
+use crate::{
+    hyperlane_token_ata_payer_pda_seeds, hyperlane_token_mint_pda_seeds,
+    metaplex::{DataV2, MPL_TOKEN_METADATA_PROGRAM_ID},
+    mpl_token_metadata_pda_seeds,
+};
 
 use solana_program::{
     instruction::{AccountMeta, Instruction as SolanaInstruction},
@@ -10,6 +19,40 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
+/// Instructions specific to the synthetic token program.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub enum SyntheticInstruction {
+    /// Sets the Metaplex metadata of the mint, creating the metadata account if it doesn't
+    /// exist. Only owner.
+    SetTokenMetadata(SetTokenMetadata),
+}
+
+impl DiscriminatorData for SyntheticInstruction {
+    /// First 8 bytes of `hash::hashv(&[b"hyperlane-sealevel-token:synthetic-instruction"])`
+    const DISCRIMINATOR: [u8; Self::DISCRIMINATOR_LENGTH] = [13, 184, 172, 240, 71, 207, 67, 252];
+}
+
+/// Instruction data for setting the token metadata.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq, Clone)]
+pub struct SetTokenMetadata {
+    /// The name of the token.
+    pub name: String,
+    /// The symbol of the token.
+    pub symbol: String,
+    /// A URI pointing to off-chain JSON metadata.
+    pub uri: String,
+}
+
+impl From<SetTokenMetadata> for DataV2 {
+    fn from(metadata: SetTokenMetadata) -> Self {
+        Self {
+            name: metadata.name,
+            symbol: metadata.symbol,
+            uri: metadata.uri,
+        }
+    }
+}
+
 /// Gets an instruction to initialize the program.
 pub fn init_instruction(
     program_id: Pubkey,
@@ -35,3 +78,45 @@ pub fn init_instruction(
 
     Ok(instruction)
 }
+
+/// Gets an instruction to set the token metadata.
+pub fn set_token_metadata_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    metadata: SetTokenMetadata,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (token_key, _token_bump) =
+        Pubkey::try_find_program_address(hyperlane_token_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let (mint_key, _mint_bump) =
+        Pubkey::try_find_program_address(hyperlane_token_mint_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    let (metadata_key, _metadata_bump) = Pubkey::try_find_program_address(
+        mpl_token_metadata_pda_seeds!(mint_key),
+        &MPL_TOKEN_METADATA_PROGRAM_ID,
+    )
+    .ok_or(ProgramError::InvalidSeeds)?;
+
+    // 0. `[executable]` The system program.
+    // 1. `[executable]` The Metaplex token metadata program.
+    // 2. `[]` The token PDA account.
+    // 3. `[]` The mint / mint authority PDA account.
+    // 4. `[writeable]` The Metaplex metadata account of the mint.
+    // 5. `[signer, writeable]` The owner, who pays for any rent.
+    let instruction = SolanaInstruction {
+        program_id,
+        data: SyntheticInstruction::SetTokenMetadata(metadata).encode()?,
+        accounts: vec![
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(MPL_TOKEN_METADATA_PROGRAM_ID, false),
+            AccountMeta::new_readonly(token_key, false),
+            AccountMeta::new_readonly(mint_key, false),
+            AccountMeta::new(metadata_key, false),
+            AccountMeta::new(owner_payer, true),
+        ],
+    };
+
+    Ok(instruction)
+}
@@ -0,0 +1,235 @@
+//! Minimal bindings to the Metaplex Token Metadata program, which wallets and
+//! explorers read the synthetic token's name, symbol and URI from.
+//!
+//! The instructions are encoded here rather than depending on the
+//! `mpl-token-metadata` crate, which pins Solana versions of its own. Only the
+//! instructions and account fields used by the synthetic token are included.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey,
+    pubkey::Pubkey,
+};
+
+/// The Metaplex Token Metadata program ID.
+pub const MPL_TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Seeds relating to the Metaplex metadata PDA of a mint, derived from the
+/// Metaplex Token Metadata program ID.
+#[macro_export]
+macro_rules! mpl_token_metadata_pda_seeds {
+    ($mint:expr) => {{
+        &[
+            b"metadata",
+            $crate::metaplex::MPL_TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            $mint.as_ref(),
+        ]
+    }};
+}
+
+/// The maximum length of the token name, in bytes.
+pub const MAX_NAME_LENGTH: usize = 32;
+/// The maximum length of the token symbol, in bytes.
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+/// The maximum length of the token metadata URI, in bytes.
+pub const MAX_URI_LENGTH: usize = 200;
+
+/// The Metaplex account key of a metadata account, `Key::MetadataV1`.
+pub const METADATA_V1_KEY: u8 = 4;
+
+/// The `MetadataInstruction::UpdateMetadataAccountV2` variant index.
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+/// The `MetadataInstruction::CreateMetadataAccountV3` variant index.
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+/// Metaplex's `DataV2`, without creators, collection or uses, which are always
+/// encoded as `None`.
+#[derive(Debug, PartialEq, Eq, Default, Clone)]
+pub struct DataV2 {
+    /// The name of the token.
+    pub name: String,
+    /// The symbol of the token.
+    pub symbol: String,
+    /// A URI pointing to off-chain JSON metadata, e.g. including an image.
+    pub uri: String,
+}
+
+impl DataV2 {
+    /// Errors if any of the name, symbol, or URI are too long.
+    pub fn verify_lengths(&self) -> Result<(), ProgramError> {
+        if self.name.len() > MAX_NAME_LENGTH
+            || self.symbol.len() > MAX_SYMBOL_LENGTH
+            || self.uri.len() > MAX_URI_LENGTH
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+impl BorshSerialize for DataV2 {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.name.serialize(writer)?;
+        self.symbol.serialize(writer)?;
+        self.uri.serialize(writer)?;
+        // seller_fee_basis_points
+        0u16.serialize(writer)?;
+        // creators, collection, uses
+        [0u8; 3].serialize(writer)
+    }
+}
+
+/// The leading fields of a Metaplex `Metadata` account.
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq, Default, Clone)]
+pub struct Metadata {
+    /// The account key, `METADATA_V1_KEY`.
+    pub key: u8,
+    /// The authority allowed to update the metadata.
+    pub update_authority: Pubkey,
+    /// The mint that the metadata describes.
+    pub mint: Pubkey,
+    /// The name of the token, padded with null bytes.
+    pub name: String,
+    /// The symbol of the token, padded with null bytes.
+    pub symbol: String,
+    /// The URI of off-chain JSON metadata, padded with null bytes.
+    pub uri: String,
+}
+
+impl Metadata {
+    /// Decodes the leading fields of a Metaplex metadata account, ignoring
+    /// the rest of the account data.
+    pub fn fetch(data: &mut &[u8]) -> Result<Self, ProgramError> {
+        let metadata = Self::deserialize(data).map_err(|_| ProgramError::InvalidAccountData)?;
+        if metadata.key != METADATA_V1_KEY {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(metadata)
+    }
+
+    /// The name, symbol and URI without the null byte padding Metaplex
+    /// stores them with.
+    pub fn data(&self) -> DataV2 {
+        let trim = |s: &str| s.trim_end_matches('\0').to_string();
+        DataV2 {
+            name: trim(&self.name),
+            symbol: trim(&self.symbol),
+            uri: trim(&self.uri),
+        }
+    }
+}
+
+/// Gets a Metaplex `CreateMetadataAccountV3` instruction creating the mutable
+/// metadata account of `mint`.
+pub fn create_metadata_account_v3_instruction(
+    metadata: Pubkey,
+    mint: Pubkey,
+    mint_authority: Pubkey,
+    payer: Pubkey,
+    update_authority: Pubkey,
+    data: DataV2,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction_data = vec![CREATE_METADATA_ACCOUNT_V3];
+    // is_mutable, collection_details
+    (data, true, 0u8).serialize(&mut instruction_data)?;
+
+    Ok(Instruction {
+        program_id: MPL_TOKEN_METADATA_PROGRAM_ID,
+        data: instruction_data,
+        accounts: vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(mint_authority, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(update_authority, true),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ],
+    })
+}
+
+/// Gets a Metaplex `UpdateMetadataAccountV2` instruction setting the name,
+/// symbol and URI of the metadata account, leaving its other fields as is.
+pub fn update_metadata_account_v2_instruction(
+    metadata: Pubkey,
+    update_authority: Pubkey,
+    data: DataV2,
+) -> Result<Instruction, ProgramError> {
+    let mut instruction_data = vec![UPDATE_METADATA_ACCOUNT_V2];
+    // update_authority, primary_sale_happened, is_mutable
+    (Some(data), 0u8, 0u8, 0u8).serialize(&mut instruction_data)?;
+
+    Ok(Instruction {
+        program_id: MPL_TOKEN_METADATA_PROGRAM_ID,
+        data: instruction_data,
+        accounts: vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(update_authority, true),
+        ],
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn data() -> DataV2 {
+        DataV2 {
+            name: "Wrapped Ether".to_string(),
+            symbol: "WETH".to_string(),
+            uri: "".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_create_metadata_account_v3_data() {
+        let key = Pubkey::new_unique();
+        let instruction =
+            create_metadata_account_v3_instruction(key, key, key, key, key, data()).unwrap();
+
+        let mut expected = vec![33];
+        expected.extend_from_slice(&[13, 0, 0, 0]);
+        expected.extend_from_slice(b"Wrapped Ether");
+        expected.extend_from_slice(&[4, 0, 0, 0]);
+        expected.extend_from_slice(b"WETH");
+        expected.extend_from_slice(&[0, 0, 0, 0]);
+        // seller_fee_basis_points, creators, collection, uses
+        expected.extend_from_slice(&[0, 0, 0, 0, 0]);
+        // is_mutable, collection_details
+        expected.extend_from_slice(&[1, 0]);
+        assert_eq!(instruction.data, expected);
+    }
+
+    #[test]
+    fn test_update_metadata_account_v2_data() {
+        let key = Pubkey::new_unique();
+        let instruction = update_metadata_account_v2_instruction(key, key, data()).unwrap();
+
+        let mut expected = vec![15, 1];
+        expected.extend_from_slice(&data().try_to_vec().unwrap());
+        // update_authority, primary_sale_happened, is_mutable
+        expected.extend_from_slice(&[0, 0, 0]);
+        assert_eq!(instruction.data, expected);
+    }
+
+    #[test]
+    fn test_metadata_data_is_trimmed() {
+        let metadata = Metadata {
+            key: METADATA_V1_KEY,
+            update_authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            name: format!("{:\0<32}", "Wrapped Ether"),
+            symbol: format!("{:\0<10}", "WETH"),
+            uri: "\0".repeat(200),
+        };
+        let mut account_data = metadata.try_to_vec().unwrap();
+        // The fields following the uri
+        account_data.extend_from_slice(&[0; 16]);
+
+        let fetched = Metadata::fetch(&mut &account_data[..]).unwrap();
+        assert_eq!(fetched, metadata);
+        assert_eq!(fetched.data(), data());
+    }
+}
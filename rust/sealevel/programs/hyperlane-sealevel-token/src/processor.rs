@@ -1,5 +1,6 @@
 //! Program processor.
 
+use access_control::AccessControl;
 use account_utils::DiscriminatorDecode;
 use hyperlane_sealevel_connection_client::{
     gas_router::GasRouterConfig, router::RemoteRouterConfig,
@@ -9,12 +10,30 @@ use hyperlane_sealevel_message_recipient_interface::{
     HandleInstruction, MessageRecipientInstruction,
 };
 use hyperlane_sealevel_token_lib::{
+    accounts::HyperlaneToken,
+    error::Error,
     instruction::{Init, Instruction as TokenIxn, TransferRemote},
     processor::HyperlaneSealevelToken,
 };
-use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
 
-use crate::plugin::SyntheticPlugin;
+use crate::{
+    hyperlane_token_mint_pda_seeds,
+    instruction::{SetTokenMetadata, SyntheticInstruction},
+    metaplex::{
+        create_metadata_account_v3_instruction, update_metadata_account_v2_instruction, DataV2,
+        MPL_TOKEN_METADATA_PROGRAM_ID,
+    },
+    mpl_token_metadata_pda_seeds,
+    plugin::SyntheticPlugin,
+};
 
 #[cfg(not(feature = "no-entrypoint"))]
 solana_program::entrypoint!(process_instruction);
@@ -59,6 +78,19 @@ pub fn process_instruction(
         };
     }
 
+    // Then, check if the instruction is specific to the synthetic token program.
+    if let Ok(synthetic_instruction) = SyntheticInstruction::decode(instruction_data) {
+        return match synthetic_instruction {
+            SyntheticInstruction::SetTokenMetadata(metadata) => {
+                set_token_metadata(program_id, accounts, metadata)
+            }
+        }
+        .map_err(|err| {
+            msg!("{}", err);
+            err
+        });
+    }
+
     // Otherwise, try decoding a "normal" token instruction
     match TokenIxn::decode(instruction_data)? {
         TokenIxn::Init(init) => initialize(program_id, accounts, init),
@@ -113,17 +145,11 @@ fn initialize(program_id: &Pubkey, accounts: &[AccountInfo], init: Init) -> Prog
 /// 6.  `[signer]` The token sender and mailbox payer.
 /// 7.  `[signer]` Unique message / gas payment account.
 /// 8.  `[writeable]` Message storage PDA.
-///     ---- If using an IGP ----
-/// 9.  `[executable]` The IGP program.
-/// 10. `[writeable]` The IGP program data.
-/// 11. `[writeable]` Gas payment PDA.
-/// 12. `[]` OPTIONAL - The Overhead IGP program, if the configured IGP is an Overhead IGP.
-/// 13. `[writeable]` The IGP account.
-///      ---- End if ----
-/// 14. `[signer]` The token sender.
-/// 15. `[executable]` The spl_token_2022 program.
-/// 16. `[writeable]` The mint / mint authority PDA account.
-/// 17. `[writeable]` The token sender's associated token account, from which tokens will be burned.
+/// 9.  `[signer]` The token sender.
+/// 10. `[executable]` The spl_token_2022 program.
+/// 11. `[writeable]` The mint / mint authority PDA account.
+/// 12. `[writeable]` The token sender's associated token account, from which tokens will be burned.
+/// 13..N `[??..??]` The Mailbox's post-dispatch hook accounts.
 fn transfer_remote(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
@@ -263,3 +289,103 @@ fn set_interchain_gas_paymaster(
         program_id, accounts, new_igp,
     )
 }
+
+/// Lets the owner set the Metaplex metadata of the mint, creating the metadata
+/// account if it doesn't exist. The mint / mint authority PDA is the update
+/// authority of the metadata, so it can only be changed through this program.
+///
+/// Accounts:
+/// 0. `[executable]` The system program.
+/// 1. `[executable]` The Metaplex token metadata program.
+/// 2. `[]` The token PDA account.
+/// 3. `[]` The mint / mint authority PDA account.
+/// 4. `[writeable]` The Metaplex metadata account of the mint.
+/// 5. `[signer, writeable]` The access control owner, who pays for any rent.
+fn set_token_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    metadata: SetTokenMetadata,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account 0: System program.
+    let system_program = next_account_info(accounts_iter)?;
+    if system_program.key != &solana_program::system_program::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Account 1: Metaplex token metadata program.
+    let mpl_token_metadata_program = next_account_info(accounts_iter)?;
+    if mpl_token_metadata_program.key != &MPL_TOKEN_METADATA_PROGRAM_ID
+        || !mpl_token_metadata_program.executable
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Account 2: Token account.
+    let token_account = next_account_info(accounts_iter)?;
+    let token = HyperlaneToken::<SyntheticPlugin>::verify_account_and_fetch_inner(
+        program_id,
+        token_account,
+    )?;
+
+    // Account 3: Mint / mint authority account.
+    let mint_account = next_account_info(accounts_iter)?;
+    if mint_account.key != &token.plugin_data.mint {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Account 4: Metaplex metadata account.
+    let metadata_account = next_account_info(accounts_iter)?;
+    let (metadata_key, _metadata_bump) = Pubkey::find_program_address(
+        mpl_token_metadata_pda_seeds!(mint_account.key),
+        &MPL_TOKEN_METADATA_PROGRAM_ID,
+    );
+    if metadata_account.key != &metadata_key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Account 5: Owner.
+    let owner_account = next_account_info(accounts_iter)?;
+    token.ensure_owner_signer(owner_account)?;
+
+    if accounts_iter.next().is_some() {
+        return Err(ProgramError::from(Error::ExtraneousAccount));
+    }
+
+    let data = DataV2::from(metadata);
+    data.verify_lengths()?;
+
+    let mint_seeds: &[&[u8]] = hyperlane_token_mint_pda_seeds!(token.plugin_data.mint_bump);
+
+    if metadata_account.data_is_empty() {
+        let create_ixn = create_metadata_account_v3_instruction(
+            *metadata_account.key,
+            *mint_account.key,
+            *mint_account.key,
+            *owner_account.key,
+            *mint_account.key,
+            data,
+        )?;
+        invoke_signed(
+            &create_ixn,
+            &[
+                metadata_account.clone(),
+                mint_account.clone(),
+                owner_account.clone(),
+                system_program.clone(),
+            ],
+            &[mint_seeds],
+        )?;
+    } else {
+        let update_ixn =
+            update_metadata_account_v2_instruction(*metadata_account.key, *mint_account.key, data)?;
+        invoke_signed(
+            &update_ixn,
+            &[metadata_account.clone(), mint_account.clone()],
+            &[mint_seeds],
+        )?;
+    }
+
+    Ok(())
+}
@@ -3,6 +3,7 @@
 //! new PDA accounts.
 
 use account_utils::DiscriminatorEncode;
+use borsh::{BorshDeserialize, BorshSerialize};
 use hyperlane_core::{Encode, HyperlaneMessage, H256, U256};
 use hyperlane_sealevel_connection_client::{
     gas_router::GasRouterConfig, router::RemoteRouterConfig,
@@ -20,7 +21,14 @@ use hyperlane_sealevel_message_recipient_interface::{
     HandleInstruction, MessageRecipientInstruction,
 };
 use hyperlane_sealevel_token::{
-    hyperlane_token_ata_payer_pda_seeds, hyperlane_token_mint_pda_seeds, plugin::SyntheticPlugin,
+    hyperlane_token_ata_payer_pda_seeds, hyperlane_token_mint_pda_seeds,
+    instruction::{set_token_metadata_instruction, SetTokenMetadata},
+    metaplex::{
+        DataV2, Metadata, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH, METADATA_V1_KEY,
+        MPL_TOKEN_METADATA_PROGRAM_ID,
+    },
+    mpl_token_metadata_pda_seeds,
+    plugin::SyntheticPlugin,
     processor::process_instruction,
 };
 use hyperlane_sealevel_token_lib::{
@@ -31,13 +39,21 @@ use hyperlane_sealevel_token_lib::{
 };
 use hyperlane_test_utils::{
     assert_token_balance, assert_transaction_error, igp_program_id, initialize_igp_accounts,
-    initialize_mailbox, mailbox_id, new_funded_keypair, process, transfer_lamports, IgpAccounts,
+    initialize_mailbox, mailbox_id, new_funded_keypair, process,
+    process_instruction as process_test_instruction, transfer_lamports, IgpAccounts,
     MailboxAccounts,
 };
 use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
     instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    program_error::ProgramError,
     pubkey,
     pubkey::Pubkey,
+    rent::Rent,
+    system_instruction,
+    sysvar::Sysvar,
 };
 use solana_program_test::*;
 use solana_sdk::{
@@ -97,6 +113,12 @@ async fn setup_client() -> (BanksClient, Keypair) {
         processor!(hyperlane_sealevel_igp::processor::process_instruction),
     );
 
+    program_test.add_program(
+        "mpl_token_metadata",
+        MPL_TOKEN_METADATA_PROGRAM_ID,
+        processor!(mock_mpl_token_metadata_process_instruction),
+    );
+
     // This serves as the default ISM on the Mailbox
     program_test.add_program(
         "hyperlane_sealevel_test_ism",
@@ -707,6 +729,8 @@ async fn test_transfer_remote() {
                 destination_domain: REMOTE_DOMAIN,
                 recipient: remote_token_recipient,
                 amount_or_id: transfer_amount.into(),
+                // The IGP hook's `PayForGas` accounts
+                hook_account_counts: vec![7],
             })
             .encode()
             .unwrap(),
@@ -719,16 +743,11 @@ async fn test_transfer_remote() {
             // 6.  `[signer]` The token sender and mailbox payer.
             // 7.  `[signer]` Unique message account.
             // 8.  `[writeable]` Message storage PDA.
-            //     ---- If using an IGP ----
-            // 9.  `[executable]` The IGP program.
-            // 10. `[writeable]` The IGP program data.
-            // 11. `[writeable]` Gas payment PDA.
-            // 12. `[]` OPTIONAL - The Overhead IGP program, if the configured IGP is an Overhead IGP.
-            // 13. `[writeable]` The IGP account.
-            //      ---- End if ----
-            // 14. `[executable]` The spl_token_2022 program.
-            // 15. `[writeable]` The mint / mint authority PDA account.
-            // 16. `[writeable]` The token sender's associated token account, from which tokens will be burned.
+            // 9.  `[executable]` The spl_token_2022 program.
+            // 10. `[writeable]` The mint / mint authority PDA account.
+            // 11. `[writeable]` The token sender's associated token account, from which tokens will be burned.
+            // 12..N `[??..??]` The Mailbox's post-dispatch hook accounts, here the IGP hook:
+            //     `[executable]` The IGP program, followed by its `PayForGas` accounts.
             vec![
                 AccountMeta::new_readonly(solana_program::system_program::id(), false),
                 AccountMeta::new_readonly(spl_noop::id(), false),
@@ -739,14 +758,17 @@ async fn test_transfer_remote() {
                 AccountMeta::new_readonly(token_sender_pubkey, true),
                 AccountMeta::new_readonly(unique_message_account_keypair.pubkey(), true),
                 AccountMeta::new(dispatched_message_key, false),
+                AccountMeta::new_readonly(spl_token_2022::id(), false),
+                AccountMeta::new(hyperlane_token_accounts.mint, false),
+                AccountMeta::new(token_sender_ata, false),
                 AccountMeta::new_readonly(igp_accounts.program, false),
+                AccountMeta::new_readonly(solana_program::system_program::id(), false),
+                AccountMeta::new(token_sender_pubkey, true),
                 AccountMeta::new(igp_accounts.program_data, false),
+                AccountMeta::new_readonly(unique_message_account_keypair.pubkey(), true),
                 AccountMeta::new(gas_payment_pda_key, false),
-                AccountMeta::new_readonly(igp_accounts.overhead_igp, false),
                 AccountMeta::new(igp_accounts.igp, false),
-                AccountMeta::new_readonly(spl_token_2022::id(), false),
-                AccountMeta::new(hyperlane_token_accounts.mint, false),
-                AccountMeta::new(token_sender_ata, false),
+                AccountMeta::new_readonly(igp_accounts.overhead_igp, false),
             ],
         )],
         Some(&token_sender_pubkey),
@@ -1336,3 +1358,234 @@ async fn test_set_interchain_gas_paymaster_errors_if_owner_not_signer() {
         TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
     );
 }
+
+/// Stands in for the Metaplex token metadata program, which can't be loaded in
+/// these tests. Checks the accounts and signers of the `CreateMetadataAccountV3`
+/// and `UpdateMetadataAccountV2` instructions the token program invokes and
+/// stores the metadata padded the way Metaplex does.
+fn mock_mpl_token_metadata_process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let metadata_account = next_account_info(accounts_iter)?;
+    let (discriminator, mut data) = instruction_data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let padded = |s: String, length: usize| format!("{:\0<length$}", s);
+
+    match discriminator {
+        // CreateMetadataAccountV3
+        33 => {
+            let mint = next_account_info(accounts_iter)?;
+            let mint_authority = next_account_info(accounts_iter)?;
+            let payer = next_account_info(accounts_iter)?;
+            let update_authority = next_account_info(accounts_iter)?;
+            if !mint_authority.is_signer || !payer.is_signer || !update_authority.is_signer {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            let (metadata_key, metadata_bump) =
+                Pubkey::find_program_address(mpl_token_metadata_pda_seeds!(mint.key), program_id);
+            if metadata_account.key != &metadata_key || !metadata_account.data_is_empty() {
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            let (name, symbol, uri) = <(String, String, String)>::deserialize(&mut data)?;
+            let metadata = Metadata {
+                key: METADATA_V1_KEY,
+                update_authority: *update_authority.key,
+                mint: *mint.key,
+                name: padded(name, MAX_NAME_LENGTH),
+                symbol: padded(symbol, MAX_SYMBOL_LENGTH),
+                uri: padded(uri, MAX_URI_LENGTH),
+            }
+            .try_to_vec()?;
+
+            let seeds: &[&[u8]] = &[
+                b"metadata",
+                program_id.as_ref(),
+                mint.key.as_ref(),
+                &[metadata_bump],
+            ];
+            invoke_signed(
+                &system_instruction::create_account(
+                    payer.key,
+                    metadata_account.key,
+                    Rent::get()?.minimum_balance(metadata.len()),
+                    metadata.len() as u64,
+                    program_id,
+                ),
+                &[payer.clone(), metadata_account.clone()],
+                &[seeds],
+            )?;
+            metadata_account
+                .data
+                .borrow_mut()
+                .copy_from_slice(&metadata);
+        }
+        // UpdateMetadataAccountV2
+        15 => {
+            let update_authority = next_account_info(accounts_iter)?;
+            let mut metadata = Metadata::fetch(&mut &metadata_account.data.borrow()[..])?;
+            if !update_authority.is_signer || update_authority.key != &metadata.update_authority {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+
+            let (name, symbol, uri) = <Option<(String, String, String)>>::deserialize(&mut data)?
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            metadata.name = padded(name, MAX_NAME_LENGTH);
+            metadata.symbol = padded(symbol, MAX_SYMBOL_LENGTH);
+            metadata.uri = padded(uri, MAX_URI_LENGTH);
+            metadata.serialize(&mut &mut metadata_account.data.borrow_mut()[..])?;
+        }
+        _ => return Err(ProgramError::InvalidInstructionData),
+    }
+    Ok(())
+}
+
+async fn assert_token_metadata(
+    banks_client: &mut BanksClient,
+    mint: &Pubkey,
+    expected_data: DataV2,
+) {
+    let (metadata_key, _metadata_bump) = Pubkey::find_program_address(
+        mpl_token_metadata_pda_seeds!(mint),
+        &MPL_TOKEN_METADATA_PROGRAM_ID,
+    );
+    let metadata_account = banks_client
+        .get_account(metadata_key)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(metadata_account.owner, MPL_TOKEN_METADATA_PROGRAM_ID);
+    let metadata = Metadata::fetch(&mut &metadata_account.data[..]).unwrap();
+    assert_eq!(metadata.mint, *mint);
+    // The mint / mint authority PDA is the update authority
+    assert_eq!(metadata.update_authority, *mint);
+    assert_eq!(metadata.data(), expected_data);
+}
+
+#[tokio::test]
+async fn test_set_token_metadata() {
+    let program_id = hyperlane_sealevel_token_id();
+
+    let (mut banks_client, payer) = setup_client().await;
+
+    let hyperlane_token_accounts =
+        initialize_hyperlane_token(&program_id, &mut banks_client, &payer, None)
+            .await
+            .unwrap();
+
+    // Creates the Metaplex metadata account
+    let metadata = SetTokenMetadata {
+        name: "Wrapped Ether".to_string(),
+        symbol: "WETH".to_string(),
+        uri: "".to_string(),
+    };
+    process_test_instruction(
+        &mut banks_client,
+        set_token_metadata_instruction(program_id, payer.pubkey(), metadata.clone()).unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    assert_token_metadata(
+        &mut banks_client,
+        &hyperlane_token_accounts.mint,
+        metadata.into(),
+    )
+    .await;
+
+    // Updates the existing metadata account
+    let metadata = SetTokenMetadata {
+        name: "Ether".to_string(),
+        symbol: "ETH".to_string(),
+        uri: "https://example.com/eth.json".to_string(),
+    };
+    process_test_instruction(
+        &mut banks_client,
+        set_token_metadata_instruction(program_id, payer.pubkey(), metadata.clone()).unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    assert_token_metadata(
+        &mut banks_client,
+        &hyperlane_token_accounts.mint,
+        metadata.into(),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_set_token_metadata_errors_if_owner_not_signer() {
+    let program_id = hyperlane_sealevel_token_id();
+
+    let (mut banks_client, payer) = setup_client().await;
+
+    initialize_hyperlane_token(&program_id, &mut banks_client, &payer, None)
+        .await
+        .unwrap();
+
+    let non_owner = new_funded_keypair(&mut banks_client, &payer, ONE_SOL_IN_LAMPORTS).await;
+
+    let result = process_test_instruction(
+        &mut banks_client,
+        set_token_metadata_instruction(
+            program_id,
+            non_owner.pubkey(),
+            SetTokenMetadata {
+                name: "Wrapped Ether".to_string(),
+                symbol: "WETH".to_string(),
+                uri: "".to_string(),
+            },
+        )
+        .unwrap(),
+        &non_owner,
+        &[&non_owner],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
+
+#[tokio::test]
+async fn test_set_token_metadata_errors_if_symbol_too_long() {
+    let program_id = hyperlane_sealevel_token_id();
+
+    let (mut banks_client, payer) = setup_client().await;
+
+    initialize_hyperlane_token(&program_id, &mut banks_client, &payer, None)
+        .await
+        .unwrap();
+
+    let result = process_test_instruction(
+        &mut banks_client,
+        set_token_metadata_instruction(
+            program_id,
+            payer.pubkey(),
+            SetTokenMetadata {
+                name: "Wrapped Ether".to_string(),
+                symbol: "W".repeat(MAX_SYMBOL_LENGTH + 1),
+                uri: "".to_string(),
+            },
+        )
+        .unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await;
+
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
@@ -3,9 +3,16 @@ use hyperlane_core::{
     accumulator::incremental::IncrementalMerkle as MerkleTree, HyperlaneMessage, H256,
 };
 use hyperlane_sealevel_mailbox::{
-    accounts::{Inbox, InboxAccount, Outbox},
+    accounts::{
+        DispatchedMessage, DispatchedMessageAccount, Inbox, InboxAccount, MessagePdaCloseConfig,
+        Outbox,
+    },
     error::Error as MailboxError,
-    instruction::{Instruction as MailboxInstruction, OutboxDispatch},
+    instruction::{
+        close_dispatched_message_instruction, close_processed_message_instruction,
+        set_default_hook_instruction, set_message_pda_close_config_instruction,
+        set_required_hook_instruction, Instruction as MailboxInstruction, OutboxDispatch,
+    },
     mailbox_dispatched_message_pda_seeds,
 };
 use hyperlane_sealevel_test_ism::{program::TestIsmError, test_client::TestIsmTestClient};
@@ -110,6 +117,9 @@ async fn test_initialize() {
             outbox_bump_seed: mailbox_accounts.outbox_bump_seed,
             owner: Some(payer.pubkey()),
             tree: MerkleTree::default(),
+            default_hook: None,
+            required_hook: None,
+            message_pda_close_config: None,
         },
     )
     .await;
@@ -213,6 +223,9 @@ async fn test_dispatch_from_eoa() {
             outbox_bump_seed: mailbox_accounts.outbox_bump_seed,
             owner: Some(payer.pubkey()),
             tree: expected_tree.clone(),
+            default_hook: None,
+            required_hook: None,
+            message_pda_close_config: None,
         },
     )
     .await;
@@ -267,6 +280,9 @@ async fn test_dispatch_from_eoa() {
             outbox_bump_seed: mailbox_accounts.outbox_bump_seed,
             owner: Some(payer.pubkey()),
             tree: expected_tree,
+            default_hook: None,
+            required_hook: None,
+            message_pda_close_config: None,
         },
     )
     .await;
@@ -331,6 +347,9 @@ async fn test_dispatch_from_program() {
             outbox_bump_seed: mailbox_accounts.outbox_bump_seed,
             owner: Some(payer.pubkey()),
             tree: expected_tree,
+            default_hook: None,
+            required_hook: None,
+            message_pda_close_config: None,
         },
     )
     .await;
@@ -1048,3 +1067,436 @@ async fn test_inbox_set_default_ism_errors_if_owner_not_signer() {
         TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature),
     );
 }
+
+#[tokio::test]
+async fn test_outbox_set_hooks() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(&mut banks_client, &program_id, &payer, LOCAL_DOMAIN)
+        .await
+        .unwrap();
+
+    let default_hook = Pubkey::new_unique();
+    let required_hook = Pubkey::new_unique();
+
+    process_instruction(
+        &mut banks_client,
+        set_default_hook_instruction(program_id, payer.pubkey(), Some(default_hook)).unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+    process_instruction(
+        &mut banks_client,
+        set_required_hook_instruction(program_id, payer.pubkey(), Some(required_hook)).unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    assert_outbox(
+        &mut banks_client,
+        mailbox_accounts.outbox,
+        Outbox {
+            local_domain: LOCAL_DOMAIN,
+            outbox_bump_seed: mailbox_accounts.outbox_bump_seed,
+            owner: Some(payer.pubkey()),
+            tree: MerkleTree::default(),
+            default_hook: Some(default_hook),
+            required_hook: Some(required_hook),
+            message_pda_close_config: None,
+        },
+    )
+    .await;
+
+    // Unset the default hook
+    process_instruction(
+        &mut banks_client,
+        set_default_hook_instruction(program_id, payer.pubkey(), None).unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    assert_outbox(
+        &mut banks_client,
+        mailbox_accounts.outbox,
+        Outbox {
+            local_domain: LOCAL_DOMAIN,
+            outbox_bump_seed: mailbox_accounts.outbox_bump_seed,
+            owner: Some(payer.pubkey()),
+            tree: MerkleTree::default(),
+            default_hook: None,
+            required_hook: Some(required_hook),
+            message_pda_close_config: None,
+        },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_outbox_set_default_hook_errors_if_owner_not_signer() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    initialize_mailbox(&mut banks_client, &program_id, &payer, LOCAL_DOMAIN)
+        .await
+        .unwrap();
+
+    let non_owner = new_funded_keypair(&mut banks_client, &payer, 1000000000).await;
+
+    // Where the signer is not the owner
+    let result = process_instruction(
+        &mut banks_client,
+        set_default_hook_instruction(program_id, non_owner.pubkey(), Some(Pubkey::new_unique()))
+            .unwrap(),
+        &non_owner,
+        &[&non_owner],
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
+
+#[tokio::test]
+async fn test_dispatch_errors_if_hook_accounts_missing() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(&mut banks_client, &program_id, &payer, LOCAL_DOMAIN)
+        .await
+        .unwrap();
+
+    process_instruction(
+        &mut banks_client,
+        set_required_hook_instruction(program_id, payer.pubkey(), Some(Pubkey::new_unique()))
+            .unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    // The required hook's program account isn't provided
+    let result = dispatch_from_payer(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        OutboxDispatch {
+            sender: payer.pubkey(),
+            destination_domain: REMOTE_DOMAIN,
+            recipient: H256::random(),
+            message_body: vec![0x01],
+        },
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys),
+    );
+}
+
+async fn set_message_pda_close_config(
+    banks_client: &mut BanksClient,
+    payer: &Keypair,
+    config: MessagePdaCloseConfig,
+) {
+    process_instruction(
+        banks_client,
+        set_message_pda_close_config_instruction(mailbox_id(), payer.pubkey(), Some(config))
+            .unwrap(),
+        payer,
+        &[payer],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_close_dispatched_message() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(&mut banks_client, &program_id, &payer, LOCAL_DOMAIN)
+        .await
+        .unwrap();
+
+    let beneficiary = new_funded_keypair(&mut banks_client, &payer, 1000000000).await;
+    let config = MessagePdaCloseConfig {
+        beneficiary: beneficiary.pubkey(),
+        permissionless_close_delay_slots: Some(1000),
+    };
+    set_message_pda_close_config(&mut banks_client, &payer, config.clone()).await;
+
+    assert_outbox(
+        &mut banks_client,
+        mailbox_accounts.outbox,
+        Outbox {
+            local_domain: LOCAL_DOMAIN,
+            outbox_bump_seed: mailbox_accounts.outbox_bump_seed,
+            owner: Some(payer.pubkey()),
+            tree: MerkleTree::default(),
+            default_hook: None,
+            required_hook: None,
+            message_pda_close_config: Some(config),
+        },
+    )
+    .await;
+
+    let (_, dispatch_unique_keypair, dispatched_message_account_key) = dispatch_from_payer(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        OutboxDispatch {
+            sender: payer.pubkey(),
+            destination_domain: REMOTE_DOMAIN,
+            recipient: H256::random(),
+            message_body: vec![0x01],
+        },
+    )
+    .await
+    .unwrap();
+
+    let dispatched_message_account = banks_client
+        .get_account(dispatched_message_account_key)
+        .await
+        .unwrap()
+        .unwrap();
+    let dispatched_message_lamports = dispatched_message_account.lamports;
+    let dispatched_message =
+        DispatchedMessageAccount::fetch(&mut &dispatched_message_account.data[..])
+            .unwrap()
+            .into_inner();
+    let beneficiary_lamports = banks_client
+        .get_balance(beneficiary.pubkey())
+        .await
+        .unwrap();
+
+    // The owner can close the PDA before the permissionless close delay has passed
+    process_instruction(
+        &mut banks_client,
+        close_dispatched_message_instruction(
+            program_id,
+            dispatch_unique_keypair.pubkey(),
+            beneficiary.pubkey(),
+            payer.pubkey(),
+        )
+        .unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    // The header of the PDA is kept so that indexers can still find the message by its
+    // nonce, and the rest of its lamports are sent to the beneficiary
+    let dispatched_message_account = banks_client
+        .get_account(dispatched_message_account_key)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(dispatched_message_account.owner, program_id);
+    let closed_dispatched_message =
+        DispatchedMessageAccount::fetch(&mut &dispatched_message_account.data[..])
+            .unwrap()
+            .into_inner();
+    assert_eq!(
+        *closed_dispatched_message,
+        DispatchedMessage::new(
+            dispatched_message.nonce,
+            dispatched_message.slot,
+            dispatch_unique_keypair.pubkey(),
+            vec![],
+        )
+    );
+    let rent = banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        dispatched_message_account.lamports,
+        rent.minimum_balance(dispatched_message_account.data.len())
+    );
+    assert_eq!(
+        banks_client
+            .get_balance(beneficiary.pubkey())
+            .await
+            .unwrap(),
+        beneficiary_lamports + dispatched_message_lamports - dispatched_message_account.lamports,
+    );
+
+    // Closed PDAs can't be closed again
+    let result = process_instruction(
+        &mut banks_client,
+        close_dispatched_message_instruction(
+            program_id,
+            dispatch_unique_keypair.pubkey(),
+            beneficiary.pubkey(),
+            payer.pubkey(),
+        )
+        .unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData),
+    );
+}
+
+#[tokio::test]
+async fn test_close_dispatched_message_errors_if_permissionless_close_delay_not_passed() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(&mut banks_client, &program_id, &payer, LOCAL_DOMAIN)
+        .await
+        .unwrap();
+
+    let beneficiary = Pubkey::new_unique();
+    set_message_pda_close_config(
+        &mut banks_client,
+        &payer,
+        MessagePdaCloseConfig {
+            beneficiary,
+            permissionless_close_delay_slots: Some(1000),
+        },
+    )
+    .await;
+
+    let (_, dispatch_unique_keypair, _) = dispatch_from_payer(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        OutboxDispatch {
+            sender: payer.pubkey(),
+            destination_domain: REMOTE_DOMAIN,
+            recipient: H256::random(),
+            message_body: vec![0x01],
+        },
+    )
+    .await
+    .unwrap();
+
+    let non_owner = new_funded_keypair(&mut banks_client, &payer, 1000000000).await;
+
+    let result = process_instruction(
+        &mut banks_client,
+        close_dispatched_message_instruction(
+            program_id,
+            dispatch_unique_keypair.pubkey(),
+            beneficiary,
+            non_owner.pubkey(),
+        )
+        .unwrap(),
+        &non_owner,
+        &[&non_owner],
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(0, InstructionError::InvalidArgument),
+    );
+}
+
+#[tokio::test]
+async fn test_close_processed_message() {
+    let program_id = mailbox_id();
+    let (mut banks_client, payer, _, _) = setup_client().await;
+
+    let mailbox_accounts = initialize_mailbox(&mut banks_client, &program_id, &payer, LOCAL_DOMAIN)
+        .await
+        .unwrap();
+
+    let beneficiary = new_funded_keypair(&mut banks_client, &payer, 1000000000).await;
+    set_message_pda_close_config(
+        &mut banks_client,
+        &payer,
+        MessagePdaCloseConfig {
+            beneficiary: beneficiary.pubkey(),
+            permissionless_close_delay_slots: None,
+        },
+    )
+    .await;
+
+    let message = HyperlaneMessage {
+        version: 3,
+        nonce: 0,
+        origin: REMOTE_DOMAIN,
+        sender: payer.pubkey().to_bytes().into(),
+        destination: LOCAL_DOMAIN,
+        recipient: hyperlane_sealevel_test_send_receiver::id()
+            .to_bytes()
+            .into(),
+        body: vec![0, 1, 2, 3, 4, 5, 6, 7, 8],
+    };
+
+    let (_, processed_message_account_key) = process(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        vec![],
+        &message,
+    )
+    .await
+    .unwrap();
+
+    let beneficiary_lamports = banks_client
+        .get_balance(beneficiary.pubkey())
+        .await
+        .unwrap();
+
+    process_instruction(
+        &mut banks_client,
+        close_processed_message_instruction(
+            program_id,
+            message.id(),
+            beneficiary.pubkey(),
+            payer.pubkey(),
+        )
+        .unwrap(),
+        &payer,
+        &[&payer],
+    )
+    .await
+    .unwrap();
+
+    // The PDA still exists so that the message can't be processed again,
+    // but its data is removed and its excess rent sent to the beneficiary
+    let processed_message_account = banks_client
+        .get_account(processed_message_account_key)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(processed_message_account.owner, program_id);
+    assert!(processed_message_account.data.is_empty());
+    let rent = banks_client.get_rent().await.unwrap();
+    assert_eq!(processed_message_account.lamports, rent.minimum_balance(0));
+    assert!(
+        banks_client
+            .get_balance(beneficiary.pubkey())
+            .await
+            .unwrap()
+            > beneficiary_lamports
+    );
+
+    let result = process(
+        &mut banks_client,
+        &payer,
+        &mailbox_accounts,
+        vec![],
+        &message,
+    )
+    .await;
+    assert_transaction_error(
+        result,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(MailboxError::MessageAlreadyProcessed as u32),
+        ),
+    );
+}
@@ -90,7 +90,7 @@ impl Inbox {
 pub type OutboxAccount = AccountData<Outbox>;
 
 /// The Outbox account data, which is used when dispatching messages.
-#[derive(BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq)]
+#[derive(BorshSerialize, Debug, Default, PartialEq, Eq)]
 pub struct Outbox {
     /// The local domain.
     pub local_domain: u32,
@@ -100,6 +100,22 @@ pub struct Outbox {
     pub owner: Option<Pubkey>,
     /// The merkle tree of dispatched messages.
     pub tree: MerkleTree,
+    /// The post-dispatch hook called for messages that don't specify a custom hook.
+    pub default_hook: Option<Pubkey>,
+    /// The post-dispatch hook called for every message, before the default or custom hook.
+    pub required_hook: Option<Pubkey>,
+    /// If set, old dispatched and processed message PDAs can be closed to reclaim their rent.
+    pub message_pda_close_config: Option<MessagePdaCloseConfig>,
+}
+
+/// Configures the closing of old message PDAs.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default, PartialEq, Eq, Clone)]
+pub struct MessagePdaCloseConfig {
+    /// The account that receives the lamports of closed message PDAs.
+    pub beneficiary: Pubkey,
+    /// The number of slots after a message was dispatched or processed after which
+    /// anyone can close its PDA. If `None`, only the owner can close message PDAs.
+    pub permissionless_close_delay_slots: Option<u64>,
 }
 
 impl SizedData for Outbox {
@@ -108,7 +124,46 @@ impl SizedData for Outbox {
         // 1 byte outbox_bump_seed
         // 33 byte owner (1 byte enum variant, 32 byte pubkey)
         // 1032 byte tree (32 * 32 = 1024 byte branch, 8 byte count)
-        4 + 1 + 33 + 1032
+        // 33 byte default_hook (1 byte enum variant, 32 byte pubkey)
+        // 33 byte required_hook (1 byte enum variant, 32 byte pubkey)
+        // 42 byte message_pda_close_config (1 byte enum variant, 32 byte beneficiary,
+        //   9 byte permissionless_close_delay_slots)
+        4 + 1 + 33 + 1032 + 33 + 33 + 42
+    }
+}
+
+/// Outbox accounts created before hooks or the message PDA close config were
+/// introduced end early, so the missing fields are deserialized as `None`.
+/// Such accounts are reallocated the next time they're stored.
+impl BorshDeserialize for Outbox {
+    fn deserialize(reader: &mut &[u8]) -> std::io::Result<Self> {
+        let local_domain = u32::deserialize(reader)?;
+        let outbox_bump_seed = u8::deserialize(reader)?;
+        let owner = Option::<Pubkey>::deserialize(reader)?;
+        let tree = MerkleTree::deserialize(reader)?;
+        let (default_hook, required_hook) = if reader.is_empty() {
+            (None, None)
+        } else {
+            (
+                Option::<Pubkey>::deserialize(reader)?,
+                Option::<Pubkey>::deserialize(reader)?,
+            )
+        };
+        let message_pda_close_config = if reader.is_empty() {
+            None
+        } else {
+            Option::<MessagePdaCloseConfig>::deserialize(reader)?
+        };
+
+        Ok(Self {
+            local_domain,
+            outbox_bump_seed,
+            owner,
+            tree,
+            default_hook,
+            required_hook,
+            message_pda_close_config,
+        })
     }
 }
 
@@ -144,6 +199,42 @@ impl Outbox {
 
         Ok(*outbox)
     }
+
+    /// The hooks to call for a dispatched message, in order: the required hook
+    /// if configured, then the custom hook if specified or otherwise the default hook.
+    pub fn post_dispatch_hooks(&self, custom_hook: Option<Pubkey>) -> Vec<Pubkey> {
+        self.required_hook
+            .into_iter()
+            .chain(custom_hook.or(self.default_hook))
+            .collect()
+    }
+
+    /// Errors if the message PDA, created at `created_slot`, can't be closed by
+    /// `closer_info` at `current_slot`. The owner can close message PDAs at any time,
+    /// and anyone can once the configured permissionless close delay has passed.
+    /// Returns the beneficiary of the closed PDA's lamports.
+    pub fn ensure_message_pda_closable(
+        &self,
+        closer_info: &AccountInfo,
+        created_slot: Slot,
+        current_slot: Slot,
+    ) -> Result<Pubkey, ProgramError> {
+        let config = self
+            .message_pda_close_config
+            .as_ref()
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        let closer_is_owner = closer_info.is_signer && self.owner() == Some(closer_info.key);
+        let delay_passed = config
+            .permissionless_close_delay_slots
+            .map(|delay| current_slot >= created_slot.saturating_add(delay))
+            .unwrap_or(false);
+        if !closer_is_owner && !delay_passed {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(config.beneficiary)
+    }
 }
 
 /// An account corresponding to a dispatched message.
@@ -328,6 +419,12 @@ mod test {
             outbox_bump_seed: 69,
             owner: Some(Pubkey::new_unique()),
             tree: MerkleTree::default(),
+            default_hook: Some(Pubkey::new_unique()),
+            required_hook: Some(Pubkey::new_unique()),
+            message_pda_close_config: Some(MessagePdaCloseConfig {
+                beneficiary: Pubkey::new_unique(),
+                permissionless_close_delay_slots: Some(1000),
+            }),
         };
 
         let mut serialized = vec![];
@@ -339,6 +436,30 @@ mod test {
         assert_eq!(serialized.len(), outbox.size());
     }
 
+    #[test]
+    fn test_outbox_deser_without_hooks() {
+        let outbox = Outbox {
+            local_domain: 420,
+            outbox_bump_seed: 69,
+            owner: Some(Pubkey::new_unique()),
+            tree: MerkleTree::default(),
+            default_hook: None,
+            required_hook: None,
+            message_pda_close_config: None,
+        };
+
+        // The layout of an Outbox created before hooks were introduced
+        let mut serialized = vec![];
+        outbox.local_domain.serialize(&mut serialized).unwrap();
+        outbox.outbox_bump_seed.serialize(&mut serialized).unwrap();
+        outbox.owner.serialize(&mut serialized).unwrap();
+        outbox.tree.serialize(&mut serialized).unwrap();
+
+        let deserialized = Outbox::deserialize(&mut serialized.as_slice()).unwrap();
+
+        assert_eq!(outbox, deserialized);
+    }
+
     #[test]
     fn test_inbox_ser_deser() {
         let inbox = Inbox {
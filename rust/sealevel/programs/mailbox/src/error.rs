@@ -27,6 +27,13 @@ pub enum Error {
     /// The message is too large.
     #[error("Message is larger than the maximum allowed")]
     MaxMessageSizeExceeded = 7,
+    /// The accounts for a post-dispatch hook don't start with the hook program.
+    #[error("Unexpected post-dispatch hook program")]
+    UnexpectedHookProgram = 8,
+    /// The number of post-dispatch hook account counts doesn't match the
+    /// number of hooks called.
+    #[error("Post-dispatch hook account counts don't match the hooks")]
+    HookAccountCountMismatch = 9,
 }
 
 impl From<Error> for ProgramError {
@@ -8,7 +8,10 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::{mailbox_inbox_pda_seeds, mailbox_outbox_pda_seeds};
+use crate::{
+    accounts::MessagePdaCloseConfig, mailbox_dispatched_message_pda_seeds, mailbox_inbox_pda_seeds,
+    mailbox_outbox_pda_seeds, mailbox_processed_message_pda_seeds,
+};
 
 /// The current message version.
 pub const VERSION: u8 = 3;
@@ -39,6 +42,20 @@ pub enum Instruction {
     GetOwner,
     /// Transfers ownership of the Mailbox.
     TransferOwnership(Option<Pubkey>),
+    /// Dispatches a message, passing metadata to the post-dispatch hooks and
+    /// optionally overriding the default hook. Unlike `OutboxDispatch`, the
+    /// accounts of each hook are specified.
+    OutboxDispatchWithHook(OutboxDispatchWithHook),
+    /// Sets the default post-dispatch hook.
+    OutboxSetDefaultHook(Option<Pubkey>),
+    /// Sets the required post-dispatch hook.
+    OutboxSetRequiredHook(Option<Pubkey>),
+    /// Sets the config for closing old message PDAs.
+    OutboxSetMessagePdaCloseConfig(Option<MessagePdaCloseConfig>),
+    /// Removes the encoded message of a dispatched message PDA, reclaiming its rent.
+    CloseDispatchedMessage,
+    /// Removes the data of a processed message PDA, reclaiming its rent.
+    CloseProcessedMessage,
 }
 
 impl Instruction {
@@ -80,6 +97,20 @@ pub struct OutboxDispatch {
     pub message_body: Vec<u8>,
 }
 
+/// Instruction data for the OutboxDispatchWithHook instruction.
+#[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
+pub struct OutboxDispatchWithHook {
+    /// The message to dispatch.
+    pub dispatch: OutboxDispatch,
+    /// The metadata passed to the post-dispatch hooks.
+    pub metadata: Vec<u8>,
+    /// The hook to call instead of the default hook, if any.
+    pub custom_hook: Option<Pubkey>,
+    /// The number of accounts passed to each post-dispatch hook, in the order
+    /// the hooks are called, not counting the hook program itself.
+    pub hook_account_counts: Vec<u8>,
+}
+
 /// Instruction data for the InboxProcess instruction.
 #[derive(BorshDeserialize, BorshSerialize, Debug, PartialEq)]
 pub struct InboxProcess {
@@ -170,3 +201,140 @@ pub fn set_default_ism_instruction(
     };
     Ok(instruction)
 }
+
+/// Creates an OutboxSetDefaultHook instruction.
+pub fn set_default_hook_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    default_hook: Option<Pubkey>,
+) -> Result<SolanaInstruction, ProgramError> {
+    set_outbox_config_instruction(
+        program_id,
+        owner_payer,
+        Instruction::OutboxSetDefaultHook(default_hook),
+    )
+}
+
+/// Creates an OutboxSetRequiredHook instruction.
+pub fn set_required_hook_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    required_hook: Option<Pubkey>,
+) -> Result<SolanaInstruction, ProgramError> {
+    set_outbox_config_instruction(
+        program_id,
+        owner_payer,
+        Instruction::OutboxSetRequiredHook(required_hook),
+    )
+}
+
+/// Creates an OutboxSetMessagePdaCloseConfig instruction.
+pub fn set_message_pda_close_config_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    config: Option<MessagePdaCloseConfig>,
+) -> Result<SolanaInstruction, ProgramError> {
+    set_outbox_config_instruction(
+        program_id,
+        owner_payer,
+        Instruction::OutboxSetMessagePdaCloseConfig(config),
+    )
+}
+
+fn set_outbox_config_instruction(
+    program_id: Pubkey,
+    owner_payer: Pubkey,
+    instruction: Instruction,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (outbox_account, _outbox_bump) =
+        Pubkey::try_find_program_address(mailbox_outbox_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    // 0. `[executable]` The system program.
+    // 1. `[writeable]` The Outbox PDA account.
+    // 2. `[signer, writeable]` The owner of the Mailbox.
+    let instruction = SolanaInstruction {
+        program_id,
+        data: instruction.into_instruction_data()?,
+        accounts: vec![
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new(outbox_account, false),
+            AccountMeta::new(owner_payer, true),
+        ],
+    };
+    Ok(instruction)
+}
+
+/// Creates a CloseDispatchedMessage instruction for the dispatched message PDA
+/// relating to `unique_message_pubkey`.
+pub fn close_dispatched_message_instruction(
+    program_id: Pubkey,
+    unique_message_pubkey: Pubkey,
+    beneficiary: Pubkey,
+    closer: Pubkey,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (dispatched_message_account, _dispatched_message_bump) = Pubkey::try_find_program_address(
+        mailbox_dispatched_message_pda_seeds!(&unique_message_pubkey),
+        &program_id,
+    )
+    .ok_or(ProgramError::InvalidSeeds)?;
+
+    close_message_instruction(
+        program_id,
+        Instruction::CloseDispatchedMessage,
+        dispatched_message_account,
+        beneficiary,
+        closer,
+    )
+}
+
+/// Creates a CloseProcessedMessage instruction for the processed message PDA
+/// relating to `message_id`.
+pub fn close_processed_message_instruction(
+    program_id: Pubkey,
+    message_id: H256,
+    beneficiary: Pubkey,
+    closer: Pubkey,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (processed_message_account, _processed_message_bump) = Pubkey::try_find_program_address(
+        mailbox_processed_message_pda_seeds!(message_id),
+        &program_id,
+    )
+    .ok_or(ProgramError::InvalidSeeds)?;
+
+    close_message_instruction(
+        program_id,
+        Instruction::CloseProcessedMessage,
+        processed_message_account,
+        beneficiary,
+        closer,
+    )
+}
+
+fn close_message_instruction(
+    program_id: Pubkey,
+    instruction: Instruction,
+    message_account: Pubkey,
+    beneficiary: Pubkey,
+    closer: Pubkey,
+) -> Result<SolanaInstruction, ProgramError> {
+    let (outbox_account, _outbox_bump) =
+        Pubkey::try_find_program_address(mailbox_outbox_pda_seeds!(), &program_id)
+            .ok_or(ProgramError::InvalidSeeds)?;
+
+    // 0. `[]` The Outbox PDA account.
+    // 1. `[writeable]` The message PDA.
+    // 2. `[writeable]` The beneficiary.
+    // 3. `[signer]` The closer.
+    let instruction = SolanaInstruction {
+        program_id,
+        data: instruction.into_instruction_data()?,
+        accounts: vec![
+            AccountMeta::new_readonly(outbox_account, false),
+            AccountMeta::new(message_account, false),
+            AccountMeta::new(beneficiary, false),
+            AccountMeta::new_readonly(closer, true),
+        ],
+    };
+    Ok(instruction)
+}
@@ -28,6 +28,9 @@ use hyperlane_sealevel_interchain_security_module_interface::{
 use hyperlane_sealevel_message_recipient_interface::{
     HandleInstruction, MessageRecipientInstruction,
 };
+use hyperlane_sealevel_post_dispatch_hook_interface::{
+    PostDispatchHookInstruction, PostDispatchInstruction,
+};
 use serializable_account_meta::SimulationReturnData;
 
 use crate::{
@@ -37,8 +40,8 @@ use crate::{
     },
     error::Error,
     instruction::{
-        InboxProcess, Init, Instruction as MailboxIxn, OutboxDispatch, MAX_MESSAGE_BODY_BYTES,
-        VERSION,
+        InboxProcess, Init, Instruction as MailboxIxn, OutboxDispatch, OutboxDispatchWithHook,
+        MAX_MESSAGE_BODY_BYTES, VERSION,
     },
     mailbox_dispatched_message_pda_seeds, mailbox_inbox_pda_seeds,
     mailbox_message_dispatch_authority_pda_seeds, mailbox_outbox_pda_seeds,
@@ -61,7 +64,9 @@ pub fn process_instruction(
         MailboxIxn::InboxGetRecipientIsm(recipient) => {
             inbox_get_recipient_ism(program_id, accounts, recipient)
         }
-        MailboxIxn::OutboxDispatch(dispatch) => outbox_dispatch(program_id, accounts, dispatch),
+        MailboxIxn::OutboxDispatch(dispatch) => {
+            outbox_dispatch(program_id, accounts, dispatch, vec![], None, None)
+        }
         MailboxIxn::OutboxGetCount => outbox_get_count(program_id, accounts),
         MailboxIxn::OutboxGetLatestCheckpoint => outbox_get_latest_checkpoint(program_id, accounts),
         MailboxIxn::OutboxGetRoot => outbox_get_root(program_id, accounts),
@@ -69,6 +74,32 @@ pub fn process_instruction(
         MailboxIxn::TransferOwnership(new_owner) => {
             transfer_ownership(program_id, accounts, new_owner)
         }
+        MailboxIxn::OutboxDispatchWithHook(OutboxDispatchWithHook {
+            dispatch,
+            metadata,
+            custom_hook,
+            hook_account_counts,
+        }) => outbox_dispatch(
+            program_id,
+            accounts,
+            dispatch,
+            metadata,
+            custom_hook,
+            Some(hook_account_counts),
+        ),
+        MailboxIxn::OutboxSetDefaultHook(hook) => {
+            outbox_set_config(program_id, accounts, |outbox| outbox.default_hook = hook)
+        }
+        MailboxIxn::OutboxSetRequiredHook(hook) => {
+            outbox_set_config(program_id, accounts, |outbox| outbox.required_hook = hook)
+        }
+        MailboxIxn::OutboxSetMessagePdaCloseConfig(config) => {
+            outbox_set_config(program_id, accounts, |outbox| {
+                outbox.message_pda_close_config = config
+            })
+        }
+        MailboxIxn::CloseDispatchedMessage => close_dispatched_message(program_id, accounts),
+        MailboxIxn::CloseProcessedMessage => close_processed_message(program_id, accounts),
     }
     .map_err(|err| {
         msg!("{}", err);
@@ -143,6 +174,9 @@ fn initialize(program_id: &Pubkey, accounts: &[AccountInfo], init: Init) -> Prog
         outbox_bump_seed: outbox_bump,
         owner: Some(*payer_info.key),
         tree: MerkleTree::default(),
+        default_hook: None,
+        required_hook: None,
+        message_pda_close_config: None,
     });
 
     // Create the outbox PDA account.
@@ -548,19 +582,31 @@ fn inbox_set_default_ism(
 ///
 /// Sets the ID of the message as return data.
 ///
+/// After the message is stored, the post-dispatch hooks are called with `metadata`:
+/// the required hook if one is configured, followed by `custom_hook` if specified
+/// or otherwise the default hook if one is configured. `hook_account_counts` holds
+/// the number of accounts passed to each of these hooks. If it's not specified, as
+/// with the `OutboxDispatch` instruction, no accounts are passed to the hooks.
+///
 /// Accounts:
-/// 0. `[writeable]` Outbox PDA.
-/// 1. `[signer]` Message sender signer.
-/// 2. `[executable]` System program.
-/// 3. `[executable]` SPL Noop program.
-/// 4. `[signer]` Payer.
-/// 5. `[signer]` Unique message account.
-/// 6. `[writeable]` Dispatched message PDA. An empty message PDA relating to the seeds
-///    `mailbox_dispatched_message_pda_seeds` where the message contents will be stored.
+/// 0.     `[writeable]` Outbox PDA.
+/// 1.     `[signer]` Message sender signer.
+/// 2.     `[executable]` System program.
+/// 3.     `[executable]` SPL Noop program.
+/// 4.     `[signer, writeable]` Payer.
+/// 5.     `[signer]` Unique message account.
+/// 6.     `[writeable]` Dispatched message PDA. An empty message PDA relating to the seeds
+///        `mailbox_dispatched_message_pda_seeds` where the message contents will be stored.
+/// 7..N   For each post-dispatch hook, in the order they're called:
+///        `[executable]` The hook program, followed by the number of accounts given
+///        by `hook_account_counts` for its `PostDispatch` instruction.
 fn outbox_dispatch(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     dispatch: OutboxDispatch,
+    metadata: Vec<u8>,
+    custom_hook: Option<Pubkey>,
+    hook_account_counts: Option<Vec<u8>>,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
@@ -633,9 +679,8 @@ fn outbox_dispatch(
     // Make sure an account can't be written to that already exists.
     verify_account_uninitialized(dispatched_message_account_info)?;
 
-    if accounts_iter.next().is_some() {
-        return Err(ProgramError::from(Error::ExtraneousAccount));
-    }
+    // Accounts 7..N: The post-dispatch hook programs and their accounts.
+    let hook_infos = accounts_iter.as_slice();
 
     if dispatch.message_body.len() > MAX_MESSAGE_BODY_BYTES {
         return Err(ProgramError::from(Error::MaxMessageSizeExceeded));
@@ -663,12 +708,16 @@ fn outbox_dispatch(
     let id = message.id();
     outbox.tree.ingest(id);
 
+    let hooks = outbox.post_dispatch_hooks(custom_hook);
+    let hook_account_counts = hook_account_counts.unwrap_or_else(|| vec![0; hooks.len()]);
+    let hook_infos = split_post_dispatch_hook_infos(&hooks, &hook_account_counts, hook_infos)?;
+
     // Create the dispatched message PDA.
     let dispatched_message_account = DispatchedMessageAccount::from(DispatchedMessage::new(
         message.nonce,
         Clock::get()?.slot,
         *unique_message_account_info.key,
-        encoded_message,
+        encoded_message.clone(),
     ));
     let dispatched_message_account_size: usize = dispatched_message_account.size();
     create_pda_account(
@@ -702,13 +751,90 @@ fn outbox_dispatch(
         id
     );
 
-    // Store the Outbox with the new updates.
-    OutboxAccount::from(outbox).store(outbox_info, true)?;
+    // Store the Outbox with the new updates. Outboxes created before hooks were
+    // introduced are reallocated to fit them, with the payer topping up the rent.
+    OutboxAccount::from(outbox).store_with_rent_exempt_realloc(
+        outbox_info,
+        &Rent::get()?,
+        payer_info,
+        system_program_info,
+    )?;
+
+    invoke_post_dispatch_hooks(&hooks, &hook_infos, metadata, encoded_message)?;
 
     set_return_data(id.as_ref());
     Ok(())
 }
 
+/// Splits `hook_infos` into the accounts of each hook in `hooks`.
+///
+/// `hook_infos` is expected to contain, for each hook in order, the hook program
+/// followed by the number of accounts in `hook_account_counts` for that hook.
+/// Returns the accounts of each hook, without the hook program.
+fn split_post_dispatch_hook_infos<'a, 'b>(
+    hooks: &[Pubkey],
+    hook_account_counts: &[u8],
+    hook_infos: &'a [AccountInfo<'b>],
+) -> Result<Vec<&'a [AccountInfo<'b>]>, ProgramError> {
+    if hook_account_counts.len() != hooks.len() {
+        return Err(ProgramError::from(Error::HookAccountCountMismatch));
+    }
+
+    let mut remaining_infos = hook_infos;
+    let mut split_infos = Vec::with_capacity(hooks.len());
+    for (hook, account_count) in hooks.iter().zip(hook_account_counts) {
+        let (hook_program_info, infos) = remaining_infos
+            .split_first()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if hook_program_info.key != hook {
+            return Err(ProgramError::from(Error::UnexpectedHookProgram));
+        }
+        let account_count = *account_count as usize;
+        if infos.len() < account_count {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        let (post_dispatch_infos, rest) = infos.split_at(account_count);
+        split_infos.push(post_dispatch_infos);
+        remaining_infos = rest;
+    }
+
+    if !remaining_infos.is_empty() {
+        return Err(ProgramError::from(Error::ExtraneousAccount));
+    }
+
+    Ok(split_infos)
+}
+
+/// Calls the `PostDispatch` instruction of each hook in `hooks` with the
+/// accounts split off for it by `split_post_dispatch_hook_infos`.
+fn invoke_post_dispatch_hooks(
+    hooks: &[Pubkey],
+    hook_infos: &[&[AccountInfo]],
+    metadata: Vec<u8>,
+    encoded_message: Vec<u8>,
+) -> ProgramResult {
+    let post_dispatch_data = PostDispatchHookInstruction::PostDispatch(
+        PostDispatchInstruction::new(metadata, encoded_message),
+    )
+    .encode()?;
+
+    for (hook, post_dispatch_infos) in hooks.iter().zip(hook_infos) {
+        let post_dispatch_account_metas = post_dispatch_infos
+            .iter()
+            .map(|info| AccountMeta {
+                pubkey: *info.key,
+                is_signer: info.is_signer,
+                is_writable: info.is_writable,
+            })
+            .collect();
+        let post_dispatch =
+            Instruction::new_with_bytes(*hook, &post_dispatch_data, post_dispatch_account_metas);
+        invoke(&post_dispatch, post_dispatch_infos)?;
+    }
+
+    Ok(())
+}
+
 /// Gets the number of dispatched messages as little endian encoded return data.
 ///
 /// Accounts:
@@ -826,6 +952,209 @@ fn get_owner(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     Ok(())
 }
 
+/// Updates owner-managed Outbox config, e.g. the post-dispatch hooks.
+/// Outboxes created before the config was introduced are reallocated to fit it,
+/// with the owner paying the rent.
+///
+/// Accounts:
+/// 0. `[executable]` The system program.
+/// 1. `[writeable]` The Outbox PDA account.
+/// 2. `[signer, writeable]` The owner of the Mailbox.
+fn outbox_set_config(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    set_config: impl FnOnce(&mut Outbox),
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account 0: The system program.
+    let system_program_info = next_account_info(accounts_iter)?;
+    if system_program_info.key != &solana_program::system_program::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Account 1: Outbox PDA.
+    let outbox_info = next_account_info(accounts_iter)?;
+    let mut outbox = Outbox::verify_account_and_fetch_inner(program_id, outbox_info)?;
+
+    // Account 2: The owner of the Mailbox.
+    let owner_info = next_account_info(accounts_iter)?;
+    // Errors if the owner account isn't correct or isn't a signer.
+    outbox.ensure_owner_signer(owner_info)?;
+
+    if accounts_iter.next().is_some() {
+        return Err(ProgramError::from(Error::ExtraneousAccount));
+    }
+
+    set_config(&mut outbox);
+
+    OutboxAccount::from(outbox).store_with_rent_exempt_realloc(
+        outbox_info,
+        &Rent::get()?,
+        owner_info,
+        system_program_info,
+    )?;
+
+    Ok(())
+}
+
+/// Closes a dispatched message PDA, transferring its excess lamports to the configured
+/// beneficiary. Dispatched message PDAs are only used by off-chain indexers, which find
+/// messages by nonce. So rather than deleting the PDA, the encoded message is removed and
+/// only the header is kept, which leads indexers to the dispatch transaction that logged
+/// the full message via the SPL Noop program.
+///
+/// Accounts:
+/// 0. `[]` The Outbox PDA account.
+/// 1. `[writeable]` The dispatched message PDA.
+/// 2. `[writeable]` The beneficiary configured in the Outbox's message PDA close config.
+/// 3. `[signer]` The owner of the Mailbox, or any account once the permissionless
+///    close delay has passed since the message was dispatched.
+fn close_dispatched_message(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account 0: Outbox PDA.
+    let outbox_info = next_account_info(accounts_iter)?;
+    let outbox = Outbox::verify_account_and_fetch_inner(program_id, outbox_info)?;
+
+    // Account 1: Dispatched message PDA.
+    let dispatched_message_info = next_account_info(accounts_iter)?;
+    if dispatched_message_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let dispatched_message =
+        DispatchedMessageAccount::fetch(&mut &dispatched_message_info.data.borrow()[..])?
+            .into_inner();
+    let (expected_dispatched_message_key, _expected_dispatched_message_bump) =
+        Pubkey::find_program_address(
+            mailbox_dispatched_message_pda_seeds!(&dispatched_message.unique_message_pubkey),
+            program_id,
+        );
+    if dispatched_message_info.key != &expected_dispatched_message_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Account 2: Beneficiary.
+    let beneficiary_info = next_account_info(accounts_iter)?;
+
+    // Account 3: Closer.
+    let closer_info = next_account_info(accounts_iter)?;
+
+    if accounts_iter.next().is_some() {
+        return Err(ProgramError::from(Error::ExtraneousAccount));
+    }
+
+    // Closed PDAs have no encoded message left.
+    if dispatched_message.encoded_message.is_empty() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let beneficiary = outbox.ensure_message_pda_closable(
+        closer_info,
+        dispatched_message.slot,
+        Clock::get()?.slot,
+    )?;
+    if beneficiary_info.key != &beneficiary {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Keep only the header, which remains rent exempt, and move the rest of the lamports
+    // to the beneficiary.
+    let closed_dispatched_message_account = DispatchedMessageAccount::from(DispatchedMessage::new(
+        dispatched_message.nonce,
+        dispatched_message.slot,
+        dispatched_message.unique_message_pubkey,
+        vec![],
+    ));
+    let closed_size = closed_dispatched_message_account.size();
+    dispatched_message_info.realloc(closed_size, false)?;
+    closed_dispatched_message_account.store(dispatched_message_info, false)?;
+    let reclaimed_lamports = dispatched_message_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(closed_size));
+    **dispatched_message_info.try_borrow_mut_lamports()? -= reclaimed_lamports;
+    **beneficiary_info.try_borrow_mut_lamports()? += reclaimed_lamports;
+
+    msg!(
+        "Closed dispatched message PDA {} with nonce {}",
+        dispatched_message_info.key,
+        dispatched_message.nonce,
+    );
+
+    Ok(())
+}
+
+/// Reclaims the rent of a processed message PDA, transferring it to the configured beneficiary.
+/// Because the existence of a processed message PDA is what prevents a message from
+/// being processed more than once, the PDA isn't closed. Instead, its data is removed
+/// and only the lamports in excess of the rent exemption for an empty account are reclaimed.
+///
+/// Accounts:
+/// 0. `[]` The Outbox PDA account.
+/// 1. `[writeable]` The processed message PDA.
+/// 2. `[writeable]` The beneficiary configured in the Outbox's message PDA close config.
+/// 3. `[signer]` The owner of the Mailbox, or any account once the permissionless
+///    close delay has passed since the message was processed.
+fn close_processed_message(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    // Account 0: Outbox PDA.
+    let outbox_info = next_account_info(accounts_iter)?;
+    let outbox = Outbox::verify_account_and_fetch_inner(program_id, outbox_info)?;
+
+    // Account 1: Processed message PDA.
+    let processed_message_info = next_account_info(accounts_iter)?;
+    if processed_message_info.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let processed_message =
+        ProcessedMessageAccount::fetch(&mut &processed_message_info.data.borrow()[..])?
+            .into_inner();
+    let (expected_processed_message_key, _expected_processed_message_bump) =
+        Pubkey::find_program_address(
+            mailbox_processed_message_pda_seeds!(processed_message.message_id),
+            program_id,
+        );
+    if processed_message_info.key != &expected_processed_message_key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Account 2: Beneficiary.
+    let beneficiary_info = next_account_info(accounts_iter)?;
+
+    // Account 3: Closer.
+    let closer_info = next_account_info(accounts_iter)?;
+
+    if accounts_iter.next().is_some() {
+        return Err(ProgramError::from(Error::ExtraneousAccount));
+    }
+
+    let beneficiary = outbox.ensure_message_pda_closable(
+        closer_info,
+        processed_message.slot,
+        Clock::get()?.slot,
+    )?;
+    if beneficiary_info.key != &beneficiary {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Remove the data, keeping the account rent exempt and owned by this program.
+    let reclaimed_lamports = processed_message_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+    processed_message_info.realloc(0, false)?;
+    **processed_message_info.try_borrow_mut_lamports()? -= reclaimed_lamports;
+    **beneficiary_info.try_borrow_mut_lamports()? += reclaimed_lamports;
+
+    msg!(
+        "Closed processed message PDA {} for message {}",
+        processed_message_info.key,
+        processed_message.message_id,
+    );
+
+    Ok(())
+}
+
 /// Transfers ownership.
 ///
 /// Accounts:
@@ -852,3 +1181,91 @@ fn transfer_ownership(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn account_infos<'a>(
+        keys: &'a [Pubkey],
+        lamports: &'a mut [u64],
+        owner: &'a Pubkey,
+    ) -> Vec<AccountInfo<'a>> {
+        keys.iter()
+            .zip(lamports.iter_mut())
+            .map(|(key, lamports)| {
+                AccountInfo::new(key, false, false, lamports, &mut [], owner, false, 0)
+            })
+            .collect()
+    }
+
+    fn keys(infos: &[AccountInfo]) -> Vec<Pubkey> {
+        infos.iter().map(|info| *info.key).collect()
+    }
+
+    #[test]
+    fn test_split_post_dispatch_hook_infos() {
+        let first_hook = Pubkey::new_unique();
+        let second_hook = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        // The first hook requires the second hook's program account, which must
+        // not be mistaken for the start of the second hook's accounts
+        let keys_in = [
+            first_hook,
+            second_hook,
+            account,
+            second_hook,
+            second_hook,
+            account,
+        ];
+        let mut lamports = [0; 6];
+        let infos = account_infos(&keys_in, &mut lamports, &owner);
+
+        let split =
+            split_post_dispatch_hook_infos(&[first_hook, second_hook], &[2, 2], &infos).unwrap();
+        assert_eq!(
+            split.iter().map(|infos| keys(infos)).collect::<Vec<_>>(),
+            vec![vec![second_hook, account], vec![second_hook, account]]
+        );
+
+        // The same hook may be called twice
+        let split =
+            split_post_dispatch_hook_infos(&[second_hook, second_hook], &[1, 0], &infos[3..])
+                .unwrap();
+        assert_eq!(
+            split.iter().map(|infos| keys(infos)).collect::<Vec<_>>(),
+            vec![vec![second_hook], vec![]]
+        );
+    }
+
+    #[test]
+    fn test_split_post_dispatch_hook_infos_errors() {
+        let hook = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let keys_in = [hook, account];
+        let mut lamports = [0; 2];
+        let infos = account_infos(&keys_in, &mut lamports, &owner);
+
+        assert_eq!(
+            split_post_dispatch_hook_infos(&[hook], &[], &infos).unwrap_err(),
+            ProgramError::from(Error::HookAccountCountMismatch)
+        );
+        assert_eq!(
+            split_post_dispatch_hook_infos(&[hook], &[2], &infos).unwrap_err(),
+            ProgramError::NotEnoughAccountKeys
+        );
+        assert_eq!(
+            split_post_dispatch_hook_infos(&[hook], &[0], &infos).unwrap_err(),
+            ProgramError::from(Error::ExtraneousAccount)
+        );
+        assert_eq!(
+            split_post_dispatch_hook_infos(&[account], &[1], &infos).unwrap_err(),
+            ProgramError::from(Error::UnexpectedHookProgram)
+        );
+        assert!(split_post_dispatch_hook_infos(&[], &[], &[])
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -174,6 +174,7 @@ export {
   AgentSignerHexKey,
   AgentSignerKeyType,
   AgentSignerNode,
+  GasOracleUpdaterConfig,
   GasPaymentEnforcement,
   GasPaymentEnforcementPolicyType,
   RelayerConfig,
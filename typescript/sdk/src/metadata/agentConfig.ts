@@ -49,6 +49,8 @@ export enum AgentSignerKeyType {
   Hex = 'hexKey',
   Node = 'node',
   Cosmos = 'cosmosKey',
+  Keystore = 'keystore',
+  Remote = 'remote',
 }
 
 const AgentSignerHexKeySchema = z
@@ -62,6 +64,14 @@ const AgentSignerAwsKeySchema = z
     type: z.literal(AgentSignerKeyType.Aws).optional(),
     id: z.string().describe('The UUID identifying the AWS KMS key'),
     region: z.string().describe('The AWS region'),
+    endpoint: z
+      .string()
+      .optional()
+      .describe('A KMS compatible endpoint to use instead of AWS'),
+    prefix: z
+      .string()
+      .optional()
+      .describe('The bech32 prefix for the address, required for cosmos chains'),
   })
   .describe(
     'An AWS signer. Note that AWS credentials must be inserted into the env separately.',
@@ -73,6 +83,50 @@ const AgentSignerCosmosKeySchema = z
     key: ZHash,
   })
   .describe('Cosmos key');
+const AgentSignerKeystoreSchema = z
+  .object({
+    type: z.literal(AgentSignerKeyType.Keystore).optional(),
+    path: z.string().describe('The path to the encrypted keystore file'),
+    passphraseFile: z
+      .string()
+      .optional()
+      .describe('A file containing the keystore passphrase'),
+    passphraseEnv: z
+      .string()
+      .optional()
+      .describe('An env var containing the keystore passphrase'),
+    prefix: z
+      .string()
+      .optional()
+      .describe('The bech32 prefix for the address, required for cosmos chains'),
+  })
+  .describe(
+    'A key in an encrypted keystore file. Exactly one of `passphraseFile` or `passphraseEnv` must be set.',
+  );
+const AgentSignerRemoteSchema = z
+  .object({
+    type: z.literal(AgentSignerKeyType.Remote),
+    url: z.string().url().describe('The base url of the signing service'),
+    address: ZHash.describe('The address of the key'),
+    timeout: ZUint.optional().describe(
+      'How long to wait for a signature in seconds, defaults to 10',
+    ),
+    caCert: z
+      .string()
+      .optional()
+      .describe('A PEM encoded CA certificate to trust for the service'),
+    clientCert: z
+      .string()
+      .optional()
+      .describe('A PEM encoded client certificate for mutual TLS'),
+    clientKey: z
+      .string()
+      .optional()
+      .describe('A PEM encoded PKCS #8 client key for mutual TLS'),
+  })
+  .describe(
+    'A key held by a remote signing service implementing the web3signer eth1 API',
+  );
 const AgentSignerNodeSchema = z
   .object({
     type: z.literal(AgentSignerKeyType.Node),
@@ -83,12 +137,16 @@ const AgentSignerSchema = z.union([
   AgentSignerHexKeySchema,
   AgentSignerAwsKeySchema,
   AgentSignerCosmosKeySchema,
+  AgentSignerKeystoreSchema,
+  AgentSignerRemoteSchema,
   AgentSignerNodeSchema,
 ]);
 
 export type AgentSignerHexKey = z.infer<typeof AgentSignerHexKeySchema>;
 export type AgentSignerAwsKey = z.infer<typeof AgentSignerAwsKeySchema>;
 export type AgentSignerCosmosKey = z.infer<typeof AgentSignerNodeSchema>;
+export type AgentSignerKeystore = z.infer<typeof AgentSignerKeystoreSchema>;
+export type AgentSignerRemote = z.infer<typeof AgentSignerRemoteSchema>;
 export type AgentSignerNode = z.infer<typeof AgentSignerNodeSchema>;
 export type AgentSigner = z.infer<typeof AgentSignerSchema>;
 
@@ -137,6 +195,12 @@ export const AgentChainMetadataSchema = ChainMetadataSchemaObject.merge(
     signer: AgentSignerSchema.optional().describe(
       'The signer to use for this chain',
     ),
+    additionalSigners: z
+      .array(AgentSignerSchema)
+      .optional()
+      .describe(
+        'Further signers the relayer submits transactions with concurrently to `signer`.',
+      ),
     index: z
       .object({
         from: ZUint.optional().describe(
@@ -151,6 +215,13 @@ export const AgentChainMetadataSchema = ChainMetadataSchemaObject.merge(
           .describe(
             'The indexing method to use for this chain; will attempt to choose a suitable default if not specified.',
           ),
+        subscriptionUrl: z
+          .string()
+          .url()
+          .optional()
+          .describe(
+            'A websocket RPC URL used to subscribe to new logs as they are emitted, in addition to polling. Only supported on EVM chains.',
+          ),
       })
       .optional(),
   })
@@ -172,6 +243,8 @@ export const AgentChainMetadataSchema = ChainMetadataSchemaObject.merge(
             AgentSignerKeyType.Hex,
             signerType === AgentSignerKeyType.Aws,
             signerType === AgentSignerKeyType.Node,
+            AgentSignerKeyType.Keystore,
+            AgentSignerKeyType.Remote,
           ].includes(signerType)
         ) {
           return false;
@@ -179,13 +252,25 @@ export const AgentChainMetadataSchema = ChainMetadataSchemaObject.merge(
         break;
 
       case ProtocolType.Cosmos:
-        if (![AgentSignerKeyType.Cosmos].includes(signerType)) {
+        if (
+          ![
+            AgentSignerKeyType.Cosmos,
+            AgentSignerKeyType.Aws,
+            AgentSignerKeyType.Keystore,
+          ].includes(signerType)
+        ) {
           return false;
         }
         break;
 
       case ProtocolType.Sealevel:
-        if (![AgentSignerKeyType.Hex].includes(signerType)) {
+        if (
+          ![
+            AgentSignerKeyType.Hex,
+            AgentSignerKeyType.Aws,
+            AgentSignerKeyType.Keystore,
+          ].includes(signerType)
+        ) {
           return false;
         }
         break;
@@ -332,6 +417,22 @@ export const RelayerAgentConfigSchema = AgentConfigSchema.extend({
     .describe(
       'A list of app contexts and their matching lists to use for metrics. A message will be classified as the first matching app context.',
     ),
+  minimumSignerBalances: z
+    .record(ZUWei)
+    .optional()
+    .describe(
+      'Minimum signer balance per destination chain name, in the smallest denomination of the native token. Submissions to a chain are paused while the balance is below it.',
+    ),
+  configReloadInterval: ZUint.optional().describe(
+    'How often, in seconds, to check the config files for changes to the whitelist, blacklist, gas payment enforcement and metric app contexts. Defaults to 30, 0 disables watching.',
+  ),
+  adminToken: z
+    .string()
+    .min(1)
+    .optional()
+    .describe(
+      'Bearer token for the admin endpoints of the relayer API, `POST /config_reload` and `GET /gas_report`. The admin endpoints are disabled when unset.',
+    ),
 });
 
 export type RelayerConfig = z.infer<typeof RelayerAgentConfigSchema>;
@@ -341,6 +442,12 @@ export const ScraperAgentConfigSchema = AgentConfigSchema.extend({
   chainsToScrape: CommaSeperatedChainList.describe(
     'Comma separated list of chain names to scrape',
   ),
+  serveQueryApi: z
+    .boolean()
+    .optional()
+    .describe(
+      'If true, serves a read only API over the scraped messages, their deliveries and gas payments on the metrics server port.',
+    ),
 });
 
 export type ScraperConfig = z.infer<typeof ScraperAgentConfigSchema>;
@@ -381,10 +488,55 @@ export const ValidatorAgentConfigSchema = AgentConfigSchema.extend({
   interval: ZUint.optional().describe(
     'How long to wait between checking for new checkpoints in seconds.',
   ),
+  revokePreviousStorageLocations: z
+    .boolean()
+    .optional()
+    .describe(
+      'Whether to write a signed revocation to previously announced storage locations once the configured checkpoint syncer has been announced.',
+    ),
 });
 
 export type ValidatorConfig = z.infer<typeof ValidatorAgentConfigSchema>;
 
+const GasOracleConfigSchema = z.object({
+  tokenExchangeRate: ZUWei.describe(
+    "The exchange rate of the remote native token to the origin's native token, scaled as expected by the origin's gas oracle.",
+  ),
+  tokenDecimals: ZUint.lt(256)
+    .optional()
+    .describe(
+      'The number of decimals of the remote native token, for gas oracles that account for decimals themselves.',
+    ),
+  minGasPrice: ZUWei.optional().describe(
+    "The minimum gas price to set, also used when the remote chain's provider does not report a gas price.",
+  ),
+});
+
+export const GasOracleUpdaterAgentConfigSchema = AgentConfigSchema.extend({
+  gasOracles: z
+    .record(z.record(GasOracleConfigSchema))
+    .describe(
+      'The gas oracles to keep up to date, keyed by the origin chain of the IGP and then by the remote chain.',
+    ),
+  interval: ZUint.optional().describe(
+    'How frequently to poll remote gas prices, in seconds.',
+  ),
+  driftThresholdBps: ZUint.optional().describe(
+    'The drift from the on-chain gas data, in basis points, past which an update is pushed.',
+  ),
+  maxUpdateAge: ZUint.optional().describe(
+    "The number of slots since the gas data was last updated at which an update is pushed even if it didn't drift, for gas oracles whose gas data goes stale, i.e. Sealevel price accounts. Should be below their maxStalenessSlots.",
+  ),
+  dryRun: z
+    .boolean()
+    .optional()
+    .describe('If true, only logs the updates that would be pushed.'),
+});
+
+export type GasOracleUpdaterConfig = z.infer<
+  typeof GasOracleUpdaterAgentConfigSchema
+>;
+
 export type AgentConfig = z.infer<typeof AgentConfigSchema>;
 
 // Note this works well for EVM chains only, and likely needs some love
@@ -171,6 +171,12 @@ export const ChainMetadataSchemaObject = z.object({
       reorgPeriod: ZUint.optional().describe(
         'Number of blocks before a transaction has a near-zero chance of reverting.',
       ),
+      finality: z
+        .enum(['blockLag', 'safe', 'finalized'])
+        .optional()
+        .describe(
+          'How agents determine the latest final block. The safe and finalized block tags are only supported on EVM chains.',
+        ),
       estimateBlockTime: z
         .number()
         .positive()
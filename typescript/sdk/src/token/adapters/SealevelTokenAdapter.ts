@@ -263,12 +263,19 @@ export abstract class SealevelHypTokenAdapter
     const fromWalletPubKey = new PublicKey(fromAccountOwner);
     const mailboxPubKey = new PublicKey(this.addresses.mailbox);
 
-    const keys = this.getTransferInstructionKeyList({
+    const hooks = this.getPostDispatchHookKeyList({
       sender: fromWalletPubKey,
-      mailbox: mailboxPubKey,
       randomWallet: randomWallet.publicKey,
       igp: await this.getIgpKeys(),
     });
+    const keys = [
+      ...this.getTransferInstructionKeyList({
+        sender: fromWalletPubKey,
+        mailbox: mailboxPubKey,
+        randomWallet: randomWallet.publicKey,
+      }),
+      ...hooks.keys,
+    ];
 
     const value = new SealevelInstructionWrapper({
       instruction: SealevelHypTokenInstruction.TransferRemote,
@@ -276,6 +283,7 @@ export abstract class SealevelHypTokenAdapter
         destination_domain: destination,
         recipient: addressToBytes(recipient),
         amount_or_id: BigInt(weiAmountOrId),
+        hook_account_counts: hooks.hookAccountCounts,
       }),
     });
     const serializedData = serialize(SealevelTransferRemoteSchema, value);
@@ -313,13 +321,17 @@ export abstract class SealevelHypTokenAdapter
     return tx;
   }
 
-  async getIgpKeys(): Promise<KeyListParams['igp']> {
+  async getIgpKeys(): Promise<HookKeyListParams['igp']> {
     const tokenData = await this.getTokenAccountData();
     if (!tokenData.interchain_gas_paymaster) return undefined;
     const igpConfig = tokenData.interchain_gas_paymaster;
     if (igpConfig.type === SealevelInterchainGasPaymasterType.Igp) {
+      if (!igpConfig.igp_account_pub_key) {
+        throw new Error('igpAccount field expected for Sealevel IGP');
+      }
       return {
         programId: igpConfig.program_id_pubkey,
+        igpAccount: igpConfig.igp_account_pub_key,
       };
     } else if (
       igpConfig.type === SealevelInterchainGasPaymasterType.OverheadIgp
@@ -348,9 +360,8 @@ export abstract class SealevelHypTokenAdapter
     sender,
     mailbox,
     randomWallet,
-    igp,
   }: KeyListParams): Array<AccountMeta> {
-    let keys = [
+    const keys = [
       // 0.   [executable] The system program.
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       // 1.   [executable] The spl_noop program.
@@ -390,58 +401,69 @@ export abstract class SealevelHypTokenAdapter
         isWritable: true,
       },
     ];
-    if (igp) {
-      keys = [
-        ...keys,
-        // 9.    [executable] The IGP program.
-        { pubkey: igp.programId, isSigner: false, isWritable: false },
-        // 10.   [writeable] The IGP program data.
-        {
-          pubkey: SealevelOverheadIgpAdapter.deriveIgpProgramPda(igp.programId),
-          isSigner: false,
-          isWritable: true,
-        },
-        // 11.   [writeable] Gas payment PDA.
-        {
-          pubkey: SealevelOverheadIgpAdapter.deriveGasPaymentPda(
-            igp.programId,
-            randomWallet,
-          ),
-          isSigner: false,
-          isWritable: true,
-        },
-      ];
-      if (igp.igpAccount && igp.innerIgpAccount) {
-        keys = [
-          ...keys,
-          // 12.   [] OPTIONAL - The Overhead IGP account, if the configured IGP is an Overhead IGP
-          {
-            pubkey: igp.igpAccount,
-            isSigner: false,
-            isWritable: false,
-          },
-          // 13.   [writeable] The Overhead's inner IGP account
-          {
-            pubkey: igp.innerIgpAccount,
-            isSigner: false,
-            isWritable: true,
-          },
-        ];
-      } else {
-        keys = [
-          ...keys,
-          // 12.   [writeable] The IGP account.
-          {
-            pubkey: igp.programId,
-            isSigner: false,
-            isWritable: true,
-          },
-        ];
-      }
-    }
     return keys;
   }
 
+  // Should match the mailbox's post-dispatch hook accounts, which follow the
+  // token plugin's transfer-in accounts. This assumes the token's IGP is the
+  // only post-dispatch hook, i.e. the outbox has no required hook, and that
+  // the destination's gas oracle isn't a price account.
+  // https://github.com/hyperlane-xyz/hyperlane-monorepo/blob/main/rust/sealevel/programs/mailbox/README.md
+  getPostDispatchHookKeyList({
+    sender,
+    randomWallet,
+    igp,
+  }: HookKeyListParams): {
+    keys: Array<AccountMeta>;
+    hookAccountCounts: number[];
+  } {
+    if (!igp) return { keys: [], hookAccountCounts: [] };
+    const igpKeys = [
+      // [] The IGP program, as the hook program.
+      { pubkey: igp.programId, isSigner: false, isWritable: false },
+    ];
+    const payForGasKeys = [
+      // [executable] The system program.
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      // [signer] The payer.
+      { pubkey: sender, isSigner: true, isWritable: true },
+      // [writeable] The IGP program data.
+      {
+        pubkey: SealevelOverheadIgpAdapter.deriveIgpProgramPda(igp.programId),
+        isSigner: false,
+        isWritable: true,
+      },
+      // [signer] Unique gas payment account.
+      { pubkey: randomWallet, isSigner: true, isWritable: false },
+      // [writeable] Gas payment PDA.
+      {
+        pubkey: SealevelOverheadIgpAdapter.deriveGasPaymentPda(
+          igp.programId,
+          randomWallet,
+        ),
+        isSigner: false,
+        isWritable: true,
+      },
+    ];
+    if (igp.innerIgpAccount) {
+      payForGasKeys.push(
+        // [writeable] The Overhead's inner IGP account.
+        { pubkey: igp.innerIgpAccount, isSigner: false, isWritable: true },
+        // [] The Overhead IGP account.
+        { pubkey: igp.igpAccount, isSigner: false, isWritable: false },
+      );
+    } else {
+      payForGasKeys.push(
+        // [writeable] The IGP account.
+        { pubkey: igp.igpAccount, isSigner: false, isWritable: true },
+      );
+    }
+    return {
+      keys: [...igpKeys, ...payForGasKeys],
+      hookAccountCounts: [payForGasKeys.length],
+    };
+  }
+
   // https://github.com/hyperlane-xyz/hyperlane-monorepo/blob/main/rust/sealevel/programs/mailbox/src/pda_seeds.rs#L19
   deriveMailboxOutboxAccount(mailbox: PublicKey): PublicKey {
     return super.derivePda(['hyperlane', '-', 'outbox'], mailbox);
@@ -633,9 +655,14 @@ interface KeyListParams {
   sender: PublicKey;
   mailbox: PublicKey;
   randomWallet: PublicKey;
+}
+
+interface HookKeyListParams {
+  sender: PublicKey;
+  randomWallet: PublicKey;
   igp?: {
     programId: PublicKey;
-    igpAccount?: PublicKey;
+    igpAccount: PublicKey;
     innerIgpAccount?: PublicKey;
   };
 }
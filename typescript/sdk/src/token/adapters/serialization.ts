@@ -126,6 +126,7 @@ export class SealevelTransferRemoteInstruction {
   recipient!: Uint8Array;
   recipient_pubkey!: PublicKey;
   amount_or_id!: number;
+  hook_account_counts!: number[];
   constructor(public readonly fields: any) {
     Object.assign(this, fields);
     this.recipient_pubkey = new PublicKey(this.recipient);
@@ -151,6 +152,7 @@ export const SealevelTransferRemoteSchema = new Map<any, any>([
         ['destination_domain', 'u32'],
         ['recipient', [32]],
         ['amount_or_id', 'u256'],
+        ['hook_account_counts', ['u8']],
       ],
     },
   ],